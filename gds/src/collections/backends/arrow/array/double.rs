@@ -9,9 +9,12 @@ use crate::types::ValueType;
 
 use super::ArrowArrayBehavior;
 
-const DEFAULT_DOUBLE_VALUE: f64 = 0.0;
 const EMPTY_EXTENSIONS: [Extension; 0] = [];
 
+fn default_double_value() -> f64 {
+    ValueType::Double.default_as()
+}
+
 #[derive(Clone, Debug)]
 pub struct ArrowDoubleArray {
     array: Arc<PrimitiveArray<f64>>,
@@ -20,13 +23,13 @@ pub struct ArrowDoubleArray {
 
 impl ArrowDoubleArray {
     pub fn new() -> Self {
-        Self::with_defaults(0, DEFAULT_DOUBLE_VALUE)
+        Self::with_defaults(0, default_double_value())
     }
 
     pub fn from_arc(array: Arc<PrimitiveArray<f64>>) -> Self {
         Self {
             array,
-            default_value: DEFAULT_DOUBLE_VALUE,
+            default_value: default_double_value(),
         }
     }
 
@@ -34,7 +37,7 @@ impl ArrowDoubleArray {
         let array = PrimitiveArray::from_vec(values);
         Self {
             array: Arc::new(array),
-            default_value: DEFAULT_DOUBLE_VALUE,
+            default_value: default_double_value(),
         }
     }
 
@@ -288,7 +291,7 @@ impl Collections<f64> for ArrowDoubleArray {
         let primitive: PrimitiveArray<f64> = mutable.into();
         Self {
             array: Arc::new(primitive),
-            default_value: DEFAULT_DOUBLE_VALUE,
+            default_value: default_double_value(),
         }
     }
 
@@ -9,9 +9,12 @@ use crate::types::ValueType;
 
 use super::ArrowArrayBehavior;
 
-const DEFAULT_FLOAT_VALUE: f32 = 0.0;
 const EMPTY_EXTENSIONS: [Extension; 0] = [];
 
+fn default_float_value() -> f32 {
+    ValueType::Float.default_as::<f64>() as f32
+}
+
 #[derive(Clone, Debug)]
 pub struct ArrowFloatArray {
     array: Arc<PrimitiveArray<f32>>,
@@ -20,13 +23,13 @@ pub struct ArrowFloatArray {
 
 impl ArrowFloatArray {
     pub fn new() -> Self {
-        Self::with_defaults(0, DEFAULT_FLOAT_VALUE)
+        Self::with_defaults(0, default_float_value())
     }
 
     pub fn from_arc(array: Arc<PrimitiveArray<f32>>) -> Self {
         Self {
             array,
-            default_value: DEFAULT_FLOAT_VALUE,
+            default_value: default_float_value(),
         }
     }
 
@@ -34,7 +37,7 @@ impl ArrowFloatArray {
         let array = PrimitiveArray::from_vec(values);
         Self {
             array: Arc::new(array),
-            default_value: DEFAULT_FLOAT_VALUE,
+            default_value: default_float_value(),
         }
     }
 
@@ -290,7 +293,7 @@ impl Collections<f32> for ArrowFloatArray {
         let primitive: PrimitiveArray<f32> = mutable.into();
         Self {
             array: Arc::new(primitive),
-            default_value: DEFAULT_FLOAT_VALUE,
+            default_value: default_float_value(),
         }
     }
 
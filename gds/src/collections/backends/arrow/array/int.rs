@@ -10,9 +10,12 @@ use crate::types::ValueType;
 
 use super::ArrowArrayBehavior;
 
-const DEFAULT_INT_VALUE: i32 = 0;
 const EMPTY_EXTENSIONS: [Extension; 0] = [];
 
+fn default_int_value() -> i32 {
+    ValueType::Int.default_as::<i64>() as i32
+}
+
 #[derive(Clone, Debug)]
 pub struct ArrowIntArray {
     array: Arc<PrimitiveArray<i32>>,
@@ -21,13 +24,13 @@ pub struct ArrowIntArray {
 
 impl ArrowIntArray {
     pub fn new() -> Self {
-        Self::with_defaults(0, DEFAULT_INT_VALUE)
+        Self::with_defaults(0, default_int_value())
     }
 
     pub fn from_arc(array: Arc<PrimitiveArray<i32>>) -> Self {
         Self {
             array,
-            default_value: DEFAULT_INT_VALUE,
+            default_value: default_int_value(),
         }
     }
 
@@ -35,7 +38,7 @@ impl ArrowIntArray {
         let array = PrimitiveArray::from_vec(values);
         Self {
             array: Arc::new(array),
-            default_value: DEFAULT_INT_VALUE,
+            default_value: default_int_value(),
         }
     }
 
@@ -290,7 +293,7 @@ impl Collections<i32> for ArrowIntArray {
         let primitive: PrimitiveArray<i32> = mutable.into();
         Self {
             array: Arc::new(primitive),
-            default_value: DEFAULT_INT_VALUE,
+            default_value: default_int_value(),
         }
     }
 
@@ -9,9 +9,12 @@ use arrow2::array::{Array, MutablePrimitiveArray, PrimitiveArray};
 
 use super::ArrowArrayBehavior;
 
-const DEFAULT_LONG_VALUE: i64 = 0;
 const EMPTY_EXTENSIONS: [Extension; 0] = [];
 
+fn default_long_value() -> i64 {
+    ValueType::Long.default_as()
+}
+
 #[derive(Clone, Debug)]
 pub struct ArrowLongArray {
     array: Arc<PrimitiveArray<i64>>,
@@ -20,13 +23,13 @@ pub struct ArrowLongArray {
 
 impl ArrowLongArray {
     pub fn new() -> Self {
-        Self::with_defaults(0, DEFAULT_LONG_VALUE)
+        Self::with_defaults(0, default_long_value())
     }
 
     pub fn from_arc(array: Arc<PrimitiveArray<i64>>) -> Self {
         Self {
             array,
-            default_value: DEFAULT_LONG_VALUE,
+            default_value: default_long_value(),
         }
     }
 
@@ -34,7 +37,7 @@ impl ArrowLongArray {
         let array = PrimitiveArray::from_vec(values);
         Self {
             array: Arc::new(array),
-            default_value: DEFAULT_LONG_VALUE,
+            default_value: default_long_value(),
         }
     }
 
@@ -287,7 +290,7 @@ impl Collections<i64> for ArrowLongArray {
         let primitive: PrimitiveArray<i64> = mutable.into();
         Self {
             array: Arc::new(primitive),
-            default_value: DEFAULT_LONG_VALUE,
+            default_value: default_long_value(),
         }
     }
 
@@ -3,10 +3,12 @@
 //! Floating-point optimized variant for storing double-precision values efficiently
 //! while supporting massive datasets that exceed standard array limitations.
 
+use super::huge_spilling_array::{HugeArrayStorage, SpillingCursor, SpillingHugeArray};
 use crate::collections::cursor::{HugeCursor, HugeCursorSupport, PagedCursor, SinglePageCursor};
 use crate::collections::PageUtil;
 use crate::concurrency::Concurrency;
 use crate::core::utils::paged::ParallelDoublePageCreator;
+use std::io;
 
 /// Maximum size for single-page arrays
 const MAX_ARRAY_LENGTH: usize = 1 << 28;
@@ -62,6 +64,8 @@ pub enum HugeDoubleArray {
     Single(SingleHugeDoubleArray),
     /// Multi-page implementation for arrays > MAX_ARRAY_LENGTH
     Paged(PagedHugeDoubleArray),
+    /// Disk-backed pages, see [`HugeArrayStorage::Mmap`]
+    Spilling(SpillingHugeArray<f64>),
 }
 
 impl HugeDoubleArray {
@@ -83,6 +87,54 @@ impl HugeDoubleArray {
         }
     }
 
+    /// Creates a zero-filled array of the given size in `storage`.
+    ///
+    /// [`HugeArrayStorage::Memory`] is equivalent to [`HugeDoubleArray::new`];
+    /// [`HugeArrayStorage::Mmap`] keeps the values in a memory-mapped scratch
+    /// file of which at most `DEFAULT_RESIDENT_PAGES` pages stay mapped, see
+    /// [`with_max_resident_pages`](Self::with_max_resident_pages).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scratch file cannot be created or sized.
+    pub fn with_storage(size: usize, storage: HugeArrayStorage) -> io::Result<Self> {
+        match storage {
+            HugeArrayStorage::Memory => Ok(Self::new(size)),
+            HugeArrayStorage::Mmap(path) => Ok(Self::Spilling(SpillingHugeArray::new(size, path)?)),
+        }
+    }
+
+    /// Limits how many pages of a disk-backed array stay mapped at once.
+    ///
+    /// Has no effect on in-memory arrays.
+    pub fn with_max_resident_pages(mut self, max_resident_pages: usize) -> Self {
+        if let Self::Spilling(arr) = &mut self {
+            arr.set_max_resident_pages(max_resident_pages);
+        }
+        self
+    }
+
+    /// Returns `true` if the values live in a memory-mapped file.
+    pub fn is_disk_backed(&self) -> bool {
+        matches!(self, Self::Spilling(_))
+    }
+
+    /// Returns the number of mapped pages; 0 for in-memory arrays.
+    pub fn resident_pages(&self) -> usize {
+        match self {
+            Self::Spilling(arr) => arr.resident_pages(),
+            _ => 0,
+        }
+    }
+
+    /// Returns how many pages were evicted from the page cache; 0 for in-memory arrays.
+    pub fn evictions(&self) -> usize {
+        match self {
+            Self::Spilling(arr) => arr.evictions(),
+            _ => 0,
+        }
+    }
+
     /// Creates a new array from the provided values.
     pub fn from_vec(values: Vec<f64>) -> Self {
         let size = values.len();
@@ -153,6 +205,7 @@ impl HugeDoubleArray {
         match self {
             Self::Single(arr) => arr.get(index),
             Self::Paged(arr) => arr.get(index),
+            Self::Spilling(arr) => arr.get(index),
         }
     }
 
@@ -165,6 +218,7 @@ impl HugeDoubleArray {
         match self {
             Self::Single(arr) => arr.set(index, value),
             Self::Paged(arr) => arr.set(index, value),
+            Self::Spilling(arr) => arr.set(index, value),
         }
     }
 
@@ -184,6 +238,7 @@ impl HugeDoubleArray {
         match self {
             Self::Single(arr) => arr.add_to(index, delta),
             Self::Paged(arr) => arr.add_to(index, delta),
+            Self::Spilling(arr) => arr.set(index, arr.get(index) + delta),
         }
     }
 
@@ -207,6 +262,7 @@ impl HugeDoubleArray {
         match self {
             Self::Single(arr) => arr.set_all(gen),
             Self::Paged(arr) => arr.set_all(gen),
+            Self::Spilling(arr) => arr.set_all(gen),
         }
     }
 
@@ -225,6 +281,29 @@ impl HugeDoubleArray {
         match self {
             Self::Single(arr) => arr.fill(value),
             Self::Paged(arr) => arr.fill(value),
+            Self::Spilling(arr) => arr.fill(value),
+        }
+    }
+
+    /// Copies `src` into the array starting at index `start`.
+    ///
+    /// Copies whole page slices at a time instead of setting every element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start + src.len()` exceeds the array size
+    pub fn copy_from_slice(&mut self, start: usize, src: &[f64]) {
+        assert!(
+            start + src.len() <= self.size(),
+            "slice of length {} does not fit at index {} of array of size {}",
+            src.len(),
+            start,
+            self.size()
+        );
+        match self {
+            Self::Single(arr) => arr.copy_from_slice(start, src),
+            Self::Paged(arr) => arr.copy_from_slice(start, src),
+            Self::Spilling(arr) => arr.copy_from_slice(start, src),
         }
     }
 
@@ -233,6 +312,7 @@ impl HugeDoubleArray {
         match self {
             Self::Single(arr) => arr.size(),
             Self::Paged(arr) => arr.size(),
+            Self::Spilling(arr) => arr.size(),
         }
     }
 
@@ -241,6 +321,7 @@ impl HugeDoubleArray {
         match self {
             Self::Single(arr) => arr.size_of(),
             Self::Paged(arr) => arr.size_of(),
+            Self::Spilling(arr) => arr.size_of(),
         }
     }
 
@@ -298,6 +379,41 @@ impl HugeDoubleArray {
         }
     }
 
+    /// Returns true if both arrays have the same size and every pair of
+    /// elements differs by at most `tolerance`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gds::collections::backends::huge::HugeDoubleArray;
+    ///
+    /// let a = HugeDoubleArray::from_vec(vec![1.0, 2.0]);
+    /// let b = HugeDoubleArray::from_vec(vec![1.0, 2.0 + 1e-9]);
+    /// assert!(a.approx_eq(&b, 1e-6));
+    /// assert!(!a.approx_eq(&b, 1e-12));
+    /// ```
+    pub fn approx_eq(&self, other: &HugeDoubleArray, tolerance: f64) -> bool {
+        if self.size() != other.size() {
+            return false;
+        }
+        let size = self.size();
+        self.pages()
+            .into_iter()
+            .flatten()
+            .zip(other.pages().into_iter().flatten())
+            .take(size)
+            .all(|(a, b)| (a - b).abs() <= tolerance)
+    }
+
+    /// Returns the backing pages as slices, in index order.
+    fn pages(&self) -> Vec<&[f64]> {
+        match self {
+            Self::Single(arr) => vec![arr.data.as_slice()],
+            Self::Paged(arr) => arr.pages.iter().map(Vec::as_slice).collect(),
+            Self::Spilling(arr) => (0..arr.page_count()).map(|page| arr.page(page)).collect(),
+        }
+    }
+
     /// Inherent helper so callers (and doctests) can call `new_cursor()` without
     /// importing the `HugeCursorSupport` trait.
     pub fn new_cursor(&self) -> HugeDoubleArrayCursor<'_> {
@@ -307,6 +423,7 @@ impl HugeDoubleArray {
                 let capacity = arr.size;
                 HugeDoubleArrayCursor::Paged(PagedCursor::new(&arr.pages, capacity))
             }
+            Self::Spilling(arr) => HugeDoubleArrayCursor::Spilling(SpillingCursor::new(arr)),
         }
     }
 }
@@ -348,6 +465,9 @@ impl<'a> HugeCursorSupport<'a> for HugeDoubleArray {
                 let capacity = arr.size;
                 HugeDoubleArrayCursor::Paged(PagedCursor::new(&arr.pages, capacity))
             }
+            HugeDoubleArray::Spilling(arr) => {
+                HugeDoubleArrayCursor::Spilling(SpillingCursor::new(arr))
+            }
         }
     }
 }
@@ -356,6 +476,7 @@ impl<'a> HugeCursorSupport<'a> for HugeDoubleArray {
 pub enum HugeDoubleArrayCursor<'a> {
     Single(SinglePageCursor<'a, f64>),
     Paged(PagedCursor<'a, f64>),
+    Spilling(SpillingCursor<'a, f64>),
 }
 
 impl<'a> HugeCursor<'a> for HugeDoubleArrayCursor<'a> {
@@ -365,6 +486,7 @@ impl<'a> HugeCursor<'a> for HugeDoubleArrayCursor<'a> {
         match self {
             Self::Single(cursor) => cursor.next(),
             Self::Paged(cursor) => cursor.next(),
+            Self::Spilling(cursor) => cursor.next(),
         }
     }
 
@@ -372,6 +494,7 @@ impl<'a> HugeCursor<'a> for HugeDoubleArrayCursor<'a> {
         match self {
             Self::Single(cursor) => cursor.base(),
             Self::Paged(cursor) => cursor.base(),
+            Self::Spilling(cursor) => cursor.base(),
         }
     }
 
@@ -379,6 +502,7 @@ impl<'a> HugeCursor<'a> for HugeDoubleArrayCursor<'a> {
         match self {
             Self::Single(cursor) => cursor.array(),
             Self::Paged(cursor) => cursor.array(),
+            Self::Spilling(cursor) => cursor.array(),
         }
     }
 
@@ -386,6 +510,7 @@ impl<'a> HugeCursor<'a> for HugeDoubleArrayCursor<'a> {
         match self {
             Self::Single(cursor) => cursor.offset(),
             Self::Paged(cursor) => cursor.offset(),
+            Self::Spilling(cursor) => cursor.offset(),
         }
     }
 
@@ -393,6 +518,7 @@ impl<'a> HugeCursor<'a> for HugeDoubleArrayCursor<'a> {
         match self {
             Self::Single(cursor) => cursor.limit(),
             Self::Paged(cursor) => cursor.limit(),
+            Self::Spilling(cursor) => cursor.limit(),
         }
     }
 
@@ -400,6 +526,7 @@ impl<'a> HugeCursor<'a> for HugeDoubleArrayCursor<'a> {
         match self {
             Self::Single(cursor) => cursor.set_range(from, to),
             Self::Paged(cursor) => cursor.set_range(from, to),
+            Self::Spilling(cursor) => cursor.set_range(from, to),
         }
     }
 
@@ -407,6 +534,7 @@ impl<'a> HugeCursor<'a> for HugeDoubleArrayCursor<'a> {
         match self {
             Self::Single(cursor) => cursor.reset(),
             Self::Paged(cursor) => cursor.reset(),
+            Self::Spilling(cursor) => cursor.reset(),
         }
     }
 }
@@ -449,6 +577,10 @@ impl SingleHugeDoubleArray {
         self.data.fill(value);
     }
 
+    fn copy_from_slice(&mut self, start: usize, src: &[f64]) {
+        self.data[start..start + src.len()].copy_from_slice(src);
+    }
+
     fn size(&self) -> usize {
         self.data.len()
     }
@@ -557,6 +689,18 @@ impl PagedHugeDoubleArray {
         }
     }
 
+    fn copy_from_slice(&mut self, start: usize, mut src: &[f64]) {
+        let mut index = start;
+        while !src.is_empty() {
+            let page = &mut self.pages[PageUtil::page_index(index, self.page_shift)];
+            let index_in_page = PageUtil::index_in_page(index, self.page_mask);
+            let length = src.len().min(page.len() - index_in_page);
+            page[index_in_page..index_in_page + length].copy_from_slice(&src[..length]);
+            src = &src[length..];
+            index += length;
+        }
+    }
+
     fn size(&self) -> usize {
         self.size
     }
@@ -581,6 +725,17 @@ mod tests {
         assert_eq!(array.get(0), 0.0);
     }
 
+    #[test]
+    fn test_approx_eq() {
+        let a = HugeDoubleArray::from_vec(vec![0.5, 1.5, 2.5]);
+        let b = HugeDoubleArray::from_vec(vec![0.5, 1.5 + 1e-7, 2.5 - 1e-7]);
+        assert!(a.approx_eq(&b, 1e-6));
+        assert!(!a.approx_eq(&b, 1e-9));
+
+        let shorter = HugeDoubleArray::from_vec(vec![0.5, 1.5]);
+        assert!(!a.approx_eq(&shorter, 1.0));
+    }
+
     #[test]
     fn test_get_set() {
         let mut array = HugeDoubleArray::new(10);
@@ -898,6 +1053,42 @@ mod tests {
         assert_eq!(array.get(50), 1.0);
         assert_eq!(array.get(51), -1.0);
     }
+
+    #[test]
+    fn test_fill_multi_page() {
+        let mut array = HugeDoubleArray::Paged(PagedHugeDoubleArray::new(2000));
+        array.fill(7.0);
+        assert!(array.iter().all(|value| value == 7.0));
+        assert_eq!(array.iter().count(), 2000);
+    }
+
+    #[test]
+    fn test_copy_from_slice_across_pages() {
+        let mut array = HugeDoubleArray::Paged(PagedHugeDoubleArray::new(2000));
+        let src: Vec<f64> = (0..1000).map(|i| i as f64).collect();
+        // Pages hold 512 elements, so this spans the boundaries at 512 and 1024
+        array.copy_from_slice(300, &src);
+
+        for index in 0..2000 {
+            let expected = if (300..1300).contains(&index) {
+                src[index - 300]
+            } else {
+                Default::default()
+            };
+            assert_eq!(array.get(index), expected);
+        }
+
+        let mut single = HugeDoubleArray::new(10);
+        single.copy_from_slice(8, &src[..2]);
+        assert_eq!(single.get(9), src[1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit")]
+    fn test_copy_from_slice_out_of_bounds() {
+        let mut array = HugeDoubleArray::new(10);
+        array.copy_from_slice(9, &[Default::default(); 2]);
+    }
 }
 
 // Collections impl via macro
@@ -3,10 +3,13 @@
 //! Core numeric array implementation for graph data science, designed to handle
 //! massive datasets that exceed standard array limitations.
 
+use super::huge_spilling_array::{HugeArrayStorage, SpillingCursor, SpillingHugeArray};
 use crate::collections::cursor::{HugeCursor, HugeCursorSupport, PagedCursor, SinglePageCursor};
 use crate::collections::{ArrayUtil, PageUtil};
 use crate::concurrency::Concurrency;
 use crate::core::utils::paged::ParallelLongPageCreator;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::io;
 
 /// Maximum size for single-page arrays (from PageUtil)
 const MAX_ARRAY_LENGTH: usize = 1 << 28; // ~268 million elements
@@ -67,6 +70,8 @@ pub enum HugeLongArray {
     Single(SingleHugeLongArray),
     /// Multi-page implementation for arrays > MAX_ARRAY_LENGTH
     Paged(PagedHugeLongArray),
+    /// Disk-backed pages, see [`HugeArrayStorage::Mmap`]
+    Spilling(SpillingHugeArray<i64>),
 }
 
 impl HugeLongArray {
@@ -81,6 +86,54 @@ impl HugeLongArray {
         }
     }
 
+    /// Creates a zero-filled array of the given size in `storage`.
+    ///
+    /// [`HugeArrayStorage::Memory`] is equivalent to [`HugeLongArray::new`];
+    /// [`HugeArrayStorage::Mmap`] keeps the values in a memory-mapped scratch
+    /// file of which at most `DEFAULT_RESIDENT_PAGES` pages stay mapped, see
+    /// [`with_max_resident_pages`](Self::with_max_resident_pages).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scratch file cannot be created or sized.
+    pub fn with_storage(size: usize, storage: HugeArrayStorage) -> io::Result<Self> {
+        match storage {
+            HugeArrayStorage::Memory => Ok(Self::new(size)),
+            HugeArrayStorage::Mmap(path) => Ok(Self::Spilling(SpillingHugeArray::new(size, path)?)),
+        }
+    }
+
+    /// Limits how many pages of a disk-backed array stay mapped at once.
+    ///
+    /// Has no effect on in-memory arrays.
+    pub fn with_max_resident_pages(mut self, max_resident_pages: usize) -> Self {
+        if let Self::Spilling(arr) = &mut self {
+            arr.set_max_resident_pages(max_resident_pages);
+        }
+        self
+    }
+
+    /// Returns `true` if the values live in a memory-mapped file.
+    pub fn is_disk_backed(&self) -> bool {
+        matches!(self, Self::Spilling(_))
+    }
+
+    /// Returns the number of mapped pages; 0 for in-memory arrays.
+    pub fn resident_pages(&self) -> usize {
+        match self {
+            Self::Spilling(arr) => arr.resident_pages(),
+            _ => 0,
+        }
+    }
+
+    /// Returns how many pages were evicted from the page cache; 0 for in-memory arrays.
+    pub fn evictions(&self) -> usize {
+        match self {
+            Self::Spilling(arr) => arr.evictions(),
+            _ => 0,
+        }
+    }
+
     /// Inherent helper so callers (and doctests) can call `new_cursor()` without
     /// importing the `HugeCursorSupport` trait.
     pub fn new_cursor(&self) -> HugeLongArrayCursor<'_> {
@@ -90,6 +143,7 @@ impl HugeLongArray {
                 let capacity = arr.size;
                 HugeLongArrayCursor::Paged(PagedCursor::new(&arr.pages, capacity))
             }
+            Self::Spilling(arr) => HugeLongArrayCursor::Spilling(SpillingCursor::new(arr)),
         }
     }
 
@@ -211,6 +265,7 @@ impl HugeLongArray {
         match self {
             Self::Single(arr) => arr.get(index),
             Self::Paged(arr) => arr.get(index),
+            Self::Spilling(arr) => arr.get(index),
         }
     }
 
@@ -223,6 +278,7 @@ impl HugeLongArray {
         match self {
             Self::Single(arr) => arr.set(index, value),
             Self::Paged(arr) => arr.set(index, value),
+            Self::Spilling(arr) => arr.set(index, value),
         }
     }
 
@@ -242,6 +298,7 @@ impl HugeLongArray {
         match self {
             Self::Single(arr) => arr.add_to(index, delta),
             Self::Paged(arr) => arr.add_to(index, delta),
+            Self::Spilling(arr) => arr.set(index, arr.get(index) + delta),
         }
     }
 
@@ -265,6 +322,7 @@ impl HugeLongArray {
         match self {
             Self::Single(arr) => arr.set_all(gen),
             Self::Paged(arr) => arr.set_all(gen),
+            Self::Spilling(arr) => arr.set_all(gen),
         }
     }
 
@@ -283,6 +341,29 @@ impl HugeLongArray {
         match self {
             Self::Single(arr) => arr.fill(value),
             Self::Paged(arr) => arr.fill(value),
+            Self::Spilling(arr) => arr.fill(value),
+        }
+    }
+
+    /// Copies `src` into the array starting at index `start`.
+    ///
+    /// Copies whole page slices at a time instead of setting every element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start + src.len()` exceeds the array size
+    pub fn copy_from_slice(&mut self, start: usize, src: &[i64]) {
+        assert!(
+            start + src.len() <= self.size(),
+            "slice of length {} does not fit at index {} of array of size {}",
+            src.len(),
+            start,
+            self.size()
+        );
+        match self {
+            Self::Single(arr) => arr.copy_from_slice(start, src),
+            Self::Paged(arr) => arr.copy_from_slice(start, src),
+            Self::Spilling(arr) => arr.copy_from_slice(start, src),
         }
     }
 
@@ -291,6 +372,7 @@ impl HugeLongArray {
         match self {
             Self::Single(arr) => arr.size(),
             Self::Paged(arr) => arr.size(),
+            Self::Spilling(arr) => arr.size(),
         }
     }
 
@@ -299,6 +381,7 @@ impl HugeLongArray {
         match self {
             Self::Single(arr) => arr.size_of(),
             Self::Paged(arr) => arr.size_of(),
+            Self::Spilling(arr) => arr.size_of(),
         }
     }
 
@@ -347,6 +430,7 @@ impl HugeLongArray {
         match self {
             Self::Single(arr) => arr.binary_search(search_value),
             Self::Paged(arr) => arr.binary_search(search_value),
+            Self::Spilling(arr) => arr.binary_search(search_value),
         }
     }
 
@@ -369,6 +453,63 @@ impl HugeLongArray {
             index: 0,
         }
     }
+
+    /// Returns the backing pages as slices, in index order.
+    fn pages(&self) -> Vec<&[i64]> {
+        match self {
+            Self::Single(arr) => vec![arr.data.as_slice()],
+            Self::Paged(arr) => arr.pages.iter().map(Vec::as_slice).collect(),
+            Self::Spilling(arr) => (0..arr.page_count()).map(|page| arr.page(page)).collect(),
+        }
+    }
+}
+
+/// Two arrays are equal when they have the same size and the same elements,
+/// regardless of whether they are single-page or paged.
+///
+/// # Examples
+///
+/// ```
+/// use gds::collections::backends::huge::HugeLongArray;
+///
+/// let a = HugeLongArray::from_vec(vec![1, 2, 3]);
+/// let b = HugeLongArray::from_vec(vec![1, 2, 3]);
+/// assert!(a.eq(&b));
+/// ```
+impl PartialEq for HugeLongArray {
+    fn eq(&self, other: &Self) -> bool {
+        if self.size() != other.size() {
+            return false;
+        }
+        let size = self.size();
+        self.pages().into_iter().flatten().take(size).eq(other
+            .pages()
+            .into_iter()
+            .flatten()
+            .take(size))
+    }
+}
+
+impl Eq for HugeLongArray {}
+
+// Serialized as a plain sequence of values
+impl Serialize for HugeLongArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+// Deserialized into memory, whatever the storage of the serialized array
+impl<'de> Deserialize<'de> for HugeLongArray {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Self::from_vec(Vec::deserialize(deserializer)?))
+    }
 }
 
 /// Iterator for HugeLongArray
@@ -408,6 +549,7 @@ impl<'a> HugeCursorSupport<'a> for HugeLongArray {
                 let capacity = arr.size;
                 HugeLongArrayCursor::Paged(PagedCursor::new(&arr.pages, capacity))
             }
+            HugeLongArray::Spilling(arr) => HugeLongArrayCursor::Spilling(SpillingCursor::new(arr)),
         }
     }
 }
@@ -416,6 +558,7 @@ impl<'a> HugeCursorSupport<'a> for HugeLongArray {
 pub enum HugeLongArrayCursor<'a> {
     Single(SinglePageCursor<'a, i64>),
     Paged(PagedCursor<'a, i64>),
+    Spilling(SpillingCursor<'a, i64>),
 }
 
 impl<'a> HugeCursor<'a> for HugeLongArrayCursor<'a> {
@@ -425,6 +568,7 @@ impl<'a> HugeCursor<'a> for HugeLongArrayCursor<'a> {
         match self {
             Self::Single(cursor) => cursor.next(),
             Self::Paged(cursor) => cursor.next(),
+            Self::Spilling(cursor) => cursor.next(),
         }
     }
 
@@ -432,6 +576,7 @@ impl<'a> HugeCursor<'a> for HugeLongArrayCursor<'a> {
         match self {
             Self::Single(cursor) => cursor.base(),
             Self::Paged(cursor) => cursor.base(),
+            Self::Spilling(cursor) => cursor.base(),
         }
     }
 
@@ -439,6 +584,7 @@ impl<'a> HugeCursor<'a> for HugeLongArrayCursor<'a> {
         match self {
             Self::Single(cursor) => cursor.array(),
             Self::Paged(cursor) => cursor.array(),
+            Self::Spilling(cursor) => cursor.array(),
         }
     }
 
@@ -446,6 +592,7 @@ impl<'a> HugeCursor<'a> for HugeLongArrayCursor<'a> {
         match self {
             Self::Single(cursor) => cursor.offset(),
             Self::Paged(cursor) => cursor.offset(),
+            Self::Spilling(cursor) => cursor.offset(),
         }
     }
 
@@ -453,6 +600,7 @@ impl<'a> HugeCursor<'a> for HugeLongArrayCursor<'a> {
         match self {
             Self::Single(cursor) => cursor.limit(),
             Self::Paged(cursor) => cursor.limit(),
+            Self::Spilling(cursor) => cursor.limit(),
         }
     }
 
@@ -460,6 +608,7 @@ impl<'a> HugeCursor<'a> for HugeLongArrayCursor<'a> {
         match self {
             Self::Single(cursor) => cursor.set_range(from, to),
             Self::Paged(cursor) => cursor.set_range(from, to),
+            Self::Spilling(cursor) => cursor.set_range(from, to),
         }
     }
 
@@ -467,6 +616,7 @@ impl<'a> HugeCursor<'a> for HugeLongArrayCursor<'a> {
         match self {
             Self::Single(cursor) => cursor.reset(),
             Self::Paged(cursor) => cursor.reset(),
+            Self::Spilling(cursor) => cursor.reset(),
         }
     }
 }
@@ -509,6 +659,10 @@ impl SingleHugeLongArray {
         self.data.fill(value);
     }
 
+    fn copy_from_slice(&mut self, start: usize, src: &[i64]) {
+        self.data[start..start + src.len()].copy_from_slice(src);
+    }
+
     fn size(&self) -> usize {
         self.data.len()
     }
@@ -532,7 +686,7 @@ pub struct PagedHugeLongArray {
 }
 
 impl PagedHugeLongArray {
-    fn new(size: usize) -> Self {
+    pub(crate) fn new(size: usize) -> Self {
         // Calculate page size for i64 elements with 4KB pages
         let page_size =
             PageUtil::page_size_for(PageUtil::PAGE_SIZE_4KB, std::mem::size_of::<i64>());
@@ -620,6 +774,18 @@ impl PagedHugeLongArray {
         }
     }
 
+    fn copy_from_slice(&mut self, start: usize, mut src: &[i64]) {
+        let mut index = start;
+        while !src.is_empty() {
+            let page = &mut self.pages[PageUtil::page_index(index, self.page_shift)];
+            let index_in_page = PageUtil::index_in_page(index, self.page_mask);
+            let length = src.len().min(page.len() - index_in_page);
+            page[index_in_page..index_in_page + length].copy_from_slice(&src[..length]);
+            src = &src[length..];
+            index += length;
+        }
+    }
+
     fn size(&self) -> usize {
         self.size
     }
@@ -669,6 +835,21 @@ mod tests {
         assert_eq!(array.get(0), 0);
     }
 
+    #[test]
+    fn test_eq() {
+        let a = HugeLongArray::from_vec(vec![1, 2, 3, 4]);
+        let b = HugeLongArray::of(vec![vec![1, 2], vec![3, 4]], 4);
+        assert!(a.eq(&b));
+        assert_eq!(a, a.clone());
+
+        let shorter = HugeLongArray::from_vec(vec![1, 2, 3]);
+        assert_ne!(a, shorter);
+
+        let mut changed = a.clone();
+        changed.set(3, 5);
+        assert_ne!(a, changed);
+    }
+
     #[test]
     fn test_get_set() {
         let mut array = HugeLongArray::new(10);
@@ -998,6 +1179,42 @@ mod tests {
         let sum: i64 = array.iter().take(10).sum();
         assert_eq!(sum, 90); // 0 + 2 + 4 + 6 + 8 + 10 + 12 + 14 + 16 + 18
     }
+
+    #[test]
+    fn test_fill_multi_page() {
+        let mut array = HugeLongArray::Paged(PagedHugeLongArray::new(2000));
+        array.fill(7);
+        assert!(array.iter().all(|value| value == 7));
+        assert_eq!(array.iter().count(), 2000);
+    }
+
+    #[test]
+    fn test_copy_from_slice_across_pages() {
+        let mut array = HugeLongArray::Paged(PagedHugeLongArray::new(2000));
+        let src: Vec<i64> = (0..1000).collect();
+        // Pages hold 512 elements, so this spans the boundaries at 512 and 1024
+        array.copy_from_slice(300, &src);
+
+        for index in 0..2000 {
+            let expected = if (300..1300).contains(&index) {
+                src[index - 300]
+            } else {
+                Default::default()
+            };
+            assert_eq!(array.get(index), expected);
+        }
+
+        let mut single = HugeLongArray::new(10);
+        single.copy_from_slice(8, &src[..2]);
+        assert_eq!(single.get(9), src[1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit")]
+    fn test_copy_from_slice_out_of_bounds() {
+        let mut array = HugeLongArray::new(10);
+        array.copy_from_slice(9, &[Default::default(); 2]);
+    }
 }
 
 // Collections impl via macro
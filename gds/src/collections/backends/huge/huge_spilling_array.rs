@@ -0,0 +1,576 @@
+//! Disk-spilling storage for huge numeric arrays
+//!
+//! Graphs larger than RAM cannot hold every per-node array in memory. With
+//! [`HugeArrayStorage::Mmap`], a [`HugeLongArray`](super::HugeLongArray) or
+//! [`HugeDoubleArray`](super::HugeDoubleArray) keeps its values in a scratch
+//! file that is memory-mapped as a whole; once more pages were touched than
+//! the page cache allows, the least recently used ones are flushed and
+//! released back to the OS, for reads and writes alike.
+
+use crate::collections::cursor::HugeCursor;
+use crate::collections::PageUtil;
+use memmap2::{MmapMut, MmapOptions};
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::marker::PhantomData;
+use std::mem::{align_of, size_of};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Default number of pages a disk-backed array keeps resident.
+pub const DEFAULT_RESIDENT_PAGES: usize = 1024;
+
+/// Where the values of a huge array live.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum HugeArrayStorage {
+    /// All values stay in memory.
+    #[default]
+    Memory,
+    /// Values live in a scratch file at the given path that is memory-mapped
+    /// and paged in on demand. The file is created (or truncated) on
+    /// construction and removed when the array is dropped.
+    Mmap(PathBuf),
+}
+
+/// Element type that can be stored in a [`SpillingHugeArray`].
+///
+/// # Safety
+///
+/// Every bit pattern of `size_of::<Self>()` bytes must be a valid value, as
+/// elements are read directly from the mapped scratch file.
+pub unsafe trait SpillElement: Copy + Default + Send + Sync + 'static {}
+
+// SAFETY: plain integers and floats are valid for every bit pattern.
+unsafe impl SpillElement for i64 {}
+// SAFETY: see above.
+unsafe impl SpillElement for f64 {}
+
+/// Disk-backed pages of a huge array.
+///
+/// The scratch file is mapped once; pages have the size of the in-memory
+/// paged arrays and become resident on first access. Recency is tracked with
+/// a CLOCK sweep: every access marks its page as referenced, and eviction
+/// releases the first resident page the sweep finds unreferenced.
+///
+/// Reads through `&self` and writes through `&mut self` share the same
+/// accounting, so a read-only scan keeps at most
+/// [`set_max_resident_pages`](Self::set_max_resident_pages) pages resident.
+/// Evicting flushes the page and advises the OS that its memory is not
+/// needed; the mapping itself stays, so slices handed out by
+/// [`page`](Self::page) remain valid and fault their page back in from the
+/// file when read again. On platforms without `madvise` eviction only
+/// flushes, and the OS decides when to reclaim the memory.
+pub struct SpillingHugeArray<T: SpillElement> {
+    size: usize,
+    page_size: usize,
+    page_shift: u32,
+    path: PathBuf,
+    /// Mapping of the whole scratch file; `None` for empty arrays
+    mapping: Option<MmapMut>,
+    page_count: usize,
+    resident_pages: Vec<AtomicBool>,
+    referenced: Vec<AtomicBool>,
+    resident: AtomicUsize,
+    max_resident: usize,
+    /// CLOCK position; the lock also serializes evictions
+    clock_hand: parking_lot::Mutex<usize>,
+    evictions: AtomicUsize,
+    _element: PhantomData<T>,
+}
+
+impl<T: SpillElement> SpillingHugeArray<T> {
+    /// Creates a zero-filled array of `size` elements backed by the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scratch file cannot be created, sized or mapped.
+    pub fn new(size: usize, path: PathBuf) -> io::Result<Self> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)?;
+        file.set_len((size * size_of::<T>()) as u64)?;
+        Self::from_file(size, path, file, DEFAULT_RESIDENT_PAGES)
+    }
+
+    fn from_file(size: usize, path: PathBuf, file: File, max_resident: usize) -> io::Result<Self> {
+        let page_size = PageUtil::page_size_for(PageUtil::PAGE_SIZE_4KB, size_of::<T>());
+        let page_count = PageUtil::num_pages_for(size, page_size);
+        // SAFETY: the scratch file is created and truncated by this array and
+        // never handed out, so no other mapping or process modifies it, and
+        // the mapping covers exactly the file length set at construction.
+        let mapping = if size == 0 {
+            None
+        } else {
+            Some(unsafe { MmapOptions::new().len(size * size_of::<T>()).map_mut(&file)? })
+        };
+        if let Some(mapping) = &mapping {
+            assert_eq!(
+                mapping.as_ptr() as usize % align_of::<T>(),
+                0,
+                "mapped file is not aligned"
+            );
+        }
+        Ok(Self {
+            size,
+            page_size,
+            page_shift: page_size.trailing_zeros(),
+            path,
+            mapping,
+            page_count,
+            resident_pages: (0..page_count).map(|_| AtomicBool::new(false)).collect(),
+            referenced: (0..page_count).map(|_| AtomicBool::new(false)).collect(),
+            resident: AtomicUsize::new(0),
+            max_resident,
+            clock_hand: parking_lot::Mutex::new(0),
+            evictions: AtomicUsize::new(0),
+            _element: PhantomData,
+        })
+    }
+
+    /// Limits how many pages stay resident at once; at least one is kept.
+    pub fn set_max_resident_pages(&mut self, max_resident_pages: usize) {
+        self.max_resident = max_resident_pages.max(1);
+        self.trim(None);
+    }
+
+    /// Returns the value at the given index.
+    pub fn get(&self, index: usize) -> T {
+        self.page(index >> self.page_shift)[index & (self.page_size - 1)]
+    }
+
+    /// Sets the value at the given index.
+    pub fn set(&mut self, index: usize, value: T) {
+        let page_size = self.page_size;
+        self.page_mut(index >> self.page_shift)[index & (page_size - 1)] = value;
+    }
+
+    /// Sets all elements using the provided generator function.
+    pub fn set_all<F>(&mut self, gen: F)
+    where
+        F: Fn(usize) -> T,
+    {
+        for page in 0..self.page_count() {
+            let base = page << self.page_shift;
+            for (offset, value) in self.page_mut(page).iter_mut().enumerate() {
+                *value = gen(base + offset);
+            }
+        }
+    }
+
+    /// Fills all elements with the specified value.
+    pub fn fill(&mut self, value: T) {
+        for page in 0..self.page_count() {
+            self.page_mut(page).fill(value);
+        }
+    }
+
+    /// Copies `src` into the array starting at index `start`.
+    pub fn copy_from_slice(&mut self, start: usize, src: &[T]) {
+        let mut copied = 0;
+        while copied < src.len() {
+            let index = start + copied;
+            let in_page = index & (self.page_size - 1);
+            let page = self.page_mut(index >> self.page_shift);
+            let len = (page.len() - in_page).min(src.len() - copied);
+            page[in_page..in_page + len].copy_from_slice(&src[copied..copied + len]);
+            copied += len;
+        }
+    }
+
+    /// Returns the number of elements in the array.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the memory held by resident pages in bytes.
+    pub fn size_of(&self) -> usize {
+        self.resident_pages() * self.page_size * size_of::<T>()
+    }
+
+    /// Returns the number of elements per page.
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Returns the number of pages.
+    pub fn page_count(&self) -> usize {
+        self.page_count
+    }
+
+    /// Returns the number of currently resident pages.
+    pub fn resident_pages(&self) -> usize {
+        self.resident.load(Ordering::Relaxed)
+    }
+
+    /// Returns how many pages were evicted from the page cache so far.
+    pub fn evictions(&self) -> usize {
+        self.evictions.load(Ordering::Relaxed)
+    }
+
+    /// Returns the values of page `page`, making it resident if needed.
+    ///
+    /// Touching a page that is not resident may evict another one, so a
+    /// scan through `&self` stays within the resident page limit.
+    pub fn page(&self, page: usize) -> &[T] {
+        self.touch(page);
+        let (start, len) = self.page_bounds(page);
+        let mapping = self.mapping.as_ref().expect("non-empty array has a mapping");
+        // SAFETY: the mapping is aligned for `T` (checked on construction),
+        // the range holds whole elements within the mapping, and every bit
+        // pattern is a valid `T` (see `SpillElement`). Writers need
+        // `&mut self`, so the values cannot change while the slice lives.
+        unsafe {
+            std::slice::from_raw_parts(
+                (mapping.as_ptr() as *const T).add(start),
+                len,
+            )
+        }
+    }
+
+    fn page_mut(&mut self, page: usize) -> &mut [T] {
+        self.touch(page);
+        let (start, len) = self.page_bounds(page);
+        let mapping = self.mapping.as_mut().expect("non-empty array has a mapping");
+        // SAFETY: see `page`; the exclusive borrow of `self` rules out other
+        // slices of this page.
+        unsafe {
+            std::slice::from_raw_parts_mut((mapping.as_mut_ptr() as *mut T).add(start), len)
+        }
+    }
+
+    /// First element and length of `page`
+    fn page_bounds(&self, page: usize) -> (usize, usize) {
+        let start = page << self.page_shift;
+        (start, self.page_size.min(self.size - start))
+    }
+
+    /// Marks `page` as referenced and resident, evicting others over the limit.
+    fn touch(&self, page: usize) {
+        self.referenced[page].store(true, Ordering::Relaxed);
+        if !self.resident_pages[page].swap(true, Ordering::AcqRel) {
+            let resident = self.resident.fetch_add(1, Ordering::AcqRel) + 1;
+            if resident > self.max_resident {
+                self.trim(Some(page));
+            }
+        }
+    }
+
+    /// Evicts pages other than `keep` until at most the limit are resident.
+    fn trim(&self, keep: Option<usize>) {
+        let mut clock_hand = self.clock_hand.lock();
+        // Two sweeps clear every reference bit, so a third finds a victim
+        // unless every other resident page was re-touched concurrently.
+        let mut remaining_steps = 3 * self.page_count;
+        while self.resident.load(Ordering::Acquire) > self.max_resident && remaining_steps > 0 {
+            remaining_steps -= 1;
+            let page = *clock_hand;
+            *clock_hand = (page + 1) % self.page_count;
+            if Some(page) == keep || !self.resident_pages[page].load(Ordering::Acquire) {
+                continue;
+            }
+            // Referenced pages get a second chance
+            if self.referenced[page].swap(false, Ordering::Relaxed) {
+                continue;
+            }
+            if self.resident_pages[page].swap(false, Ordering::AcqRel) {
+                self.release(page);
+                self.resident.fetch_sub(1, Ordering::AcqRel);
+                self.evictions.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Writes `page` back and returns its memory to the OS.
+    fn release(&self, page: usize) {
+        let Some(mapping) = &self.mapping else {
+            return;
+        };
+        let (start, len) = self.page_bounds(page);
+        let (offset, bytes) = (start * size_of::<T>(), len * size_of::<T>());
+        // Writes survive eviction; flushing bounds the dirty memory the OS
+        // keeps for released pages.
+        let _ = mapping.flush_range(offset, bytes);
+        // Dropping the pages of a shared file mapping keeps their contents
+        // in the file; the next access reads them back in.
+        #[cfg(unix)]
+        let _ = mapping.advise_range(memmap2::Advice::DontNeed, offset, bytes);
+    }
+}
+
+impl<T: SpillElement + PartialOrd> SpillingHugeArray<T> {
+    /// Performs binary search for the given value (array must be sorted).
+    ///
+    /// Returns the index if found, or -(insertion_point + 1) if not found.
+    pub fn binary_search(&self, search_value: T) -> isize {
+        let (mut low, mut high) = (0usize, self.size);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let value = self.get(mid);
+            if value < search_value {
+                low = mid + 1;
+            } else if value > search_value {
+                high = mid;
+            } else {
+                return mid as isize;
+            }
+        }
+        -(low as isize + 1)
+    }
+}
+
+/// Copies the values into a new scratch file next to the original.
+///
+/// # Panics
+///
+/// Panics if the scratch file cannot be copied.
+impl<T: SpillElement> Clone for SpillingHugeArray<T> {
+    fn clone(&self) -> Self {
+        if let Some(mapping) = &self.mapping {
+            let _ = mapping.flush();
+        }
+        let mut file_name = self.path.file_name().unwrap_or_default().to_os_string();
+        file_name.push(format!("-{}", uuid::Uuid::new_v4()));
+        let path = self.path.with_file_name(file_name);
+        std::fs::copy(&self.path, &path)
+            .and_then(|_| OpenOptions::new().read(true).write(true).open(&path))
+            .and_then(|file| Self::from_file(self.size, path.clone(), file, self.max_resident))
+            .unwrap_or_else(|error| {
+                panic!(
+                    "failed to copy {} to {}: {}",
+                    self.path.display(),
+                    path.display(),
+                    error
+                )
+            })
+    }
+}
+
+impl<T: SpillElement> Drop for SpillingHugeArray<T> {
+    fn drop(&mut self) {
+        self.mapping = None;
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+impl<T: SpillElement> fmt::Debug for SpillingHugeArray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpillingHugeArray")
+            .field("size", &self.size)
+            .field("path", &self.path)
+            .field("resident_pages", &self.resident_pages())
+            .field("max_resident_pages", &self.max_resident)
+            .finish()
+    }
+}
+
+/// Cursor over the pages of a [`SpillingHugeArray`].
+pub struct SpillingCursor<'a, T: SpillElement> {
+    array: &'a SpillingHugeArray<T>,
+    page_index: isize,
+    from_page: usize,
+    max_page: usize,
+    end: usize,
+    base: usize,
+    offset: usize,
+    limit: usize,
+    current_array: Option<&'a [T]>,
+}
+
+impl<'a, T: SpillElement> SpillingCursor<'a, T> {
+    /// Create a cursor over the whole array.
+    pub fn new(array: &'a SpillingHugeArray<T>) -> Self {
+        let mut cursor = Self {
+            array,
+            page_index: -1,
+            from_page: 0,
+            max_page: 0,
+            end: 0,
+            base: 0,
+            offset: 0,
+            limit: 0,
+            current_array: None,
+        };
+        cursor.reset();
+        cursor
+    }
+}
+
+impl<'a, T: SpillElement> HugeCursor<'a> for SpillingCursor<'a, T> {
+    type Array = [T];
+
+    fn next(&mut self) -> bool {
+        let current = (self.page_index + 1) as usize;
+        if self.end == 0 || current > self.max_page {
+            return false;
+        }
+        self.page_index = current as isize;
+        self.current_array = Some(self.array.page(current));
+
+        if current != self.from_page {
+            self.base = current * self.array.page_size;
+            self.offset = 0;
+            self.limit = if current == self.max_page {
+                self.end - self.base
+            } else {
+                self.array.page_size
+            };
+        }
+        true
+    }
+
+    fn base(&self) -> usize {
+        self.base
+    }
+
+    fn array(&self) -> Option<&'a [T]> {
+        self.current_array
+    }
+
+    fn offset(&self) -> usize {
+        self.offset
+    }
+
+    fn limit(&self) -> usize {
+        self.limit
+    }
+
+    fn set_range(&mut self, start: usize, end: usize) {
+        let page_size = self.array.page_size;
+        self.from_page = start / page_size;
+        self.max_page = end.saturating_sub(1) / page_size;
+        self.page_index = self.from_page as isize - 1;
+        self.end = if start < end { end } else { 0 };
+        self.base = self.from_page * page_size;
+        self.offset = start - self.base;
+        self.limit = if self.from_page == self.max_page {
+            end - self.base
+        } else {
+            page_size
+        };
+        self.current_array = None;
+    }
+
+    fn reset(&mut self) {
+        self.set_range(0, self.array.size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::backends::huge::{HugeDoubleArray, HugeLongArray};
+    use crate::collections::cursor::init_cursor;
+
+    fn scratch_path() -> PathBuf {
+        std::env::temp_dir().join(format!("gds-spill-{}.bin", uuid::Uuid::new_v4()))
+    }
+
+    #[test]
+    fn disk_backed_values_round_trip_through_evictions() {
+        let path = scratch_path();
+        let page_size = PageUtil::page_size_for(PageUtil::PAGE_SIZE_4KB, size_of::<i64>());
+        let size = page_size * 6 + 17;
+        let mut array = HugeLongArray::with_storage(size, HugeArrayStorage::Mmap(path.clone()))
+            .unwrap()
+            .with_max_resident_pages(2);
+        assert!(array.is_disk_backed());
+
+        for index in 0..size {
+            array.set(index, index as i64 * 3 - 1);
+        }
+        assert!(array.resident_pages() <= 2);
+        assert!(array.evictions() >= 5);
+
+        for index in (0..size).rev() {
+            assert_eq!(array.get(index), index as i64 * 3 - 1);
+        }
+        array.add_to(0, 1);
+        assert!(array.resident_pages() <= 2);
+        assert_eq!(array.get(0), 0);
+
+        let mut cursor = array.new_cursor();
+        init_cursor(&array, &mut cursor);
+        let mut sum = 0i64;
+        while cursor.next() {
+            let page = cursor.array().unwrap();
+            sum += page[cursor.offset()..cursor.limit()].iter().sum::<i64>();
+        }
+        assert_eq!(sum, array.iter().sum::<i64>());
+
+        drop(array);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn read_scans_stay_within_the_resident_limit() {
+        let page_size = PageUtil::page_size_for(PageUtil::PAGE_SIZE_4KB, size_of::<i64>());
+        let size = page_size * 8 + 5;
+        let mut array = HugeLongArray::with_storage(size, HugeArrayStorage::Mmap(scratch_path()))
+            .unwrap()
+            .with_max_resident_pages(3);
+        array.set_all(|index| index as i64);
+        assert!(array.resident_pages() <= 3);
+
+        // Only reads from here on; none of them may exceed the limit.
+        let evictions = array.evictions();
+        assert_eq!(array.iter().sum::<i64>(), (0..size as i64).sum::<i64>());
+        assert!(array.resident_pages() <= 3);
+        assert_eq!(array.to_vec()[size - 1], size as i64 - 1);
+        assert!(array.resident_pages() <= 3);
+
+        let mut cursor = array.new_cursor();
+        init_cursor(&array, &mut cursor);
+        let mut visited = 0;
+        while cursor.next() {
+            visited += cursor.limit() - cursor.offset();
+            assert!(array.resident_pages() <= 3);
+        }
+        assert_eq!(visited, size);
+        assert_eq!(array.binary_search(size as i64 / 2), size as isize / 2);
+        assert!(array.resident_pages() <= 3);
+        assert!(array.evictions() > evictions);
+    }
+
+    #[test]
+    fn double_array_in_memory_and_on_disk_agree() {
+        let size = 5_000;
+        let mut memory = HugeDoubleArray::with_storage(size, HugeArrayStorage::Memory).unwrap();
+        let mut disk = HugeDoubleArray::with_storage(size, HugeArrayStorage::Mmap(scratch_path()))
+            .unwrap()
+            .with_max_resident_pages(1);
+
+        memory.fill(0.5);
+        disk.fill(0.5);
+        memory.copy_from_slice(size - 3, &[1.0, 2.0, 2.25]);
+        disk.copy_from_slice(size - 3, &[1.0, 2.0, 2.25]);
+
+        assert_eq!(memory.to_vec(), disk.to_vec());
+        assert_eq!(disk.get(0), 0.5);
+        assert!(!memory.is_disk_backed());
+        assert_eq!(memory.resident_pages(), 0);
+
+        let copy = disk.clone();
+        disk.set(0, 4.0);
+        assert_eq!(copy.get(0), 0.5);
+        assert_eq!(copy.to_vec()[size - 1], 2.25);
+    }
+
+    #[test]
+    fn binary_search_matches_in_memory_array() {
+        let values: Vec<i64> = (0..3_000).map(|i| i * 2).collect();
+        let memory = HugeLongArray::from_vec(values.clone());
+        let mut disk =
+            HugeLongArray::with_storage(values.len(), HugeArrayStorage::Mmap(scratch_path()))
+                .unwrap();
+        disk.copy_from_slice(0, &values);
+
+        for search in [-1, 0, 7, 1_000, 5_998, 6_001] {
+            assert_eq!(disk.binary_search(search), memory.binary_search(search));
+        }
+        assert_eq!(disk, memory);
+    }
+}
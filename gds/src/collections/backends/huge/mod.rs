@@ -15,6 +15,7 @@ pub mod huge_boolean_array;
 pub mod huge_char_array;
 pub mod huge_object_array;
 pub mod huge_atomic_array;
+pub mod huge_spilling_array;
 
 // Re-export existing HugeArray types (backward compatibility)
 pub use huge_int_array::HugeIntArray;
@@ -26,6 +27,9 @@ pub use huge_short_array::HugeShortArray;
 pub use huge_boolean_array::HugeBooleanArray;
 pub use huge_char_array::HugeCharArray;
 pub use huge_object_array::HugeObjectArray;
+pub use huge_spilling_array::{
+    HugeArrayStorage, SpillElement, SpillingCursor, SpillingHugeArray, DEFAULT_RESIDENT_PAGES,
+};
 
 // Re-export atomic array types
 pub use huge_atomic_array::*;
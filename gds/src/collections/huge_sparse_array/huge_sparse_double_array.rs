@@ -40,6 +40,7 @@
 /// assert!(array.contains(0));
 /// assert!(!array.contains(999));
 /// ```
+use crate::types::ValueType;
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
@@ -163,6 +164,14 @@ pub struct HugeSparseDoubleArrayBuilder {
     set_indices: Arc<RwLock<std::collections::HashSet<usize>>>,
 }
 
+/// Builder whose unset indices read as the canonical default of `Double`,
+/// see [`ValueType::default_value`].
+impl Default for HugeSparseDoubleArrayBuilder {
+    fn default() -> Self {
+        Self::new(ValueType::Double.default_as())
+    }
+}
+
 impl HugeSparseDoubleArrayBuilder {
     /// Creates a new builder with the specified default value.
     fn new(default_value: f64) -> Self {
@@ -478,4 +487,16 @@ mod tests {
         assert_eq!(array.get(1), std::f64::consts::E);
         assert_eq!(array.get(2), std::f64::consts::SQRT_2);
     }
+
+    #[test]
+    fn test_default_builder_uses_canonical_default() {
+        let mut builder = HugeSparseDoubleArrayBuilder::default();
+        builder.set(5, 2.5);
+        let array = builder.build();
+
+        assert_eq!(array.get(5), 2.5);
+        assert_eq!(array.get(4), 0.0);
+        assert_eq!(array.get(100_000), 0.0);
+        assert!(!array.contains(4));
+    }
 }
@@ -1,3 +1,5 @@
+use crate::types::ValueType;
+use crate::values::{DefaultDoubleArray, FloatingPointArray};
 use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
 
@@ -158,6 +160,22 @@ pub struct HugeSparseDoubleArrayArrayBuilder {
     set_indices: Arc<RwLock<HashSet<usize>>>,
 }
 
+/// Builder whose unset indices read as the canonical default of `DoubleArray`,
+/// see [`ValueType::default_value`].
+impl Default for HugeSparseDoubleArrayArrayBuilder {
+    fn default() -> Self {
+        let default = ValueType::DoubleArray.default_value();
+        Self::new(
+            default
+                .as_any()
+                .downcast_ref::<DefaultDoubleArray>()
+                .map(|array| array.double_array_value())
+                .unwrap_or_default(),
+            0,
+        )
+    }
+}
+
 impl HugeSparseDoubleArrayArrayBuilder {
     /// Create a new builder with the specified default value and capacity hint.
     fn new(default_value: Vec<f64>, capacity: usize) -> Self {
@@ -405,4 +423,15 @@ mod tests {
         assert_eq!(array.capacity(), 1000);
         assert_eq!(array.get(500), &vec![1.1]);
     }
+
+    #[test]
+    fn test_default_builder_uses_canonical_default() {
+        let builder = HugeSparseDoubleArrayArrayBuilder::default();
+        builder.set(3, vec![1.5]);
+        let array = builder.build();
+
+        assert_eq!(array.get(3), &vec![1.5]);
+        assert!(array.get(2).is_empty());
+        assert!(array.get(10_000).is_empty());
+    }
 }
@@ -39,6 +39,7 @@
 /// assert!(array.contains(0));
 /// assert!(!array.contains(999));
 /// ```
+use crate::types::ValueType;
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
@@ -162,6 +163,14 @@ pub struct HugeSparseLongArrayBuilder {
     set_indices: Arc<RwLock<std::collections::HashSet<usize>>>,
 }
 
+/// Builder whose unset indices read as the canonical default of `Long`,
+/// see [`ValueType::default_value`].
+impl Default for HugeSparseLongArrayBuilder {
+    fn default() -> Self {
+        Self::new(ValueType::Long.default_as())
+    }
+}
+
 impl HugeSparseLongArrayBuilder {
     /// Creates a new builder with the specified default value.
     fn new(default_value: i64) -> Self {
@@ -461,4 +470,15 @@ mod tests {
         assert_eq!(array.get(5000), 42);
         assert!(array.capacity() >= 10_000);
     }
+
+    #[test]
+    fn test_default_builder_uses_canonical_default() {
+        let mut builder = HugeSparseLongArrayBuilder::default();
+        builder.set(5, 7);
+        let array = builder.build();
+
+        assert_eq!(array.get(5), 7);
+        assert_eq!(array.get(4), 0);
+        assert_eq!(array.get(100_000), 0);
+    }
 }
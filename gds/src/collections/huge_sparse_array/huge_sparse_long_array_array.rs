@@ -39,6 +39,8 @@
 /// assert!(array.contains(0));
 /// assert!(!array.contains(999));
 /// ```
+use crate::types::ValueType;
+use crate::values::{DefaultLongArray, IntegralArray};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
@@ -161,6 +163,21 @@ pub struct HugeSparseLongArrayArrayBuilder {
     set_indices: Arc<RwLock<std::collections::HashSet<usize>>>,
 }
 
+/// Builder whose unset indices read as the canonical default of `LongArray`,
+/// see [`ValueType::default_value`].
+impl Default for HugeSparseLongArrayArrayBuilder {
+    fn default() -> Self {
+        let default = ValueType::LongArray.default_value();
+        Self::new(
+            default
+                .as_any()
+                .downcast_ref::<DefaultLongArray>()
+                .map(|array| array.long_array_value())
+                .unwrap_or_default(),
+        )
+    }
+}
+
 impl HugeSparseLongArrayArrayBuilder {
     /// Creates a new builder with the specified default value.
     fn new(default_value: Vec<i64>) -> Self {
@@ -89,6 +89,7 @@ pub mod primitive;
 
 // Re-export types from core for backward compatibility
 pub use crate::core::utils::paged::HugeAtomicBitSet;
+pub use crate::core::utils::paged::HugeLongArrayQueue;
 
 // Re-export BitSet and HugeSparseLongArray for backward compatibility
 pub use bit_set::BitSet;
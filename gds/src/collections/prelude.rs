@@ -53,7 +53,7 @@ pub use super::backends::vec::{
 #[cfg(feature = "arrow")]
 pub use super::backends::arrow::{
     ArrowIntArray,
-    ArrowArray,
+    ArrowPrimitiveArray,
 };
 
 // Universal adapter - trait-based abstraction
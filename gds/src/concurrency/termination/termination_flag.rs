@@ -26,17 +26,20 @@ pub struct TerminationFlag {
     monitor: Arc<dyn TerminationMonitor + Send + Sync>,
     last_check: parking_lot::Mutex<Instant>,
     cached_running: AtomicBool,
+    stopped: Arc<AtomicBool>,
     interval: Duration,
 }
 
 impl Clone for TerminationFlag {
     fn clone(&self) -> Self {
-        // Create a new TerminationFlag with the same monitor and interval
-        // The cached state will be reset to the default (running)
+        // Create a new TerminationFlag with the same monitor and interval.
+        // The cached monitor state is reset to running, while the stop state
+        // is shared so that stopping any clone stops all of them.
         Self {
             monitor: self.monitor.clone(),
             last_check: parking_lot::Mutex::new(Instant::now()),
             cached_running: AtomicBool::new(true),
+            stopped: Arc::clone(&self.stopped),
             interval: self.interval,
         }
     }
@@ -85,6 +88,7 @@ impl TerminationFlag {
             monitor: Arc::new(monitor),
             last_check: parking_lot::Mutex::new(Instant::now()),
             cached_running: AtomicBool::new(true),
+            stopped: Arc::new(AtomicBool::new(false)),
             interval,
         }
     }
@@ -103,6 +107,9 @@ impl TerminationFlag {
     /// assert!(flag.running());
     /// ```
     pub fn running(&self) -> bool {
+        if self.stopped.load(Ordering::Acquire) {
+            return false;
+        }
         let now = Instant::now();
         let mut last_check = self.last_check.lock();
 
@@ -137,6 +144,26 @@ impl TerminationFlag {
         }
     }
 
+    /// Stops the computation cooperatively.
+    ///
+    /// Every later call to [`running`](Self::running) on this flag or any of
+    /// its clones returns `false`, so workers holding a clone see the stop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gds::concurrency::TerminationFlag;
+    ///
+    /// let flag = TerminationFlag::running_true();
+    /// let worker = flag.clone();
+    /// flag.stop();
+    /// assert!(!flag.running());
+    /// assert!(!worker.running());
+    /// ```
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::Release);
+    }
+
     /// Forcefully terminates the computation.
     ///
     /// This immediately panics with a `TerminatedException`.
@@ -224,6 +251,18 @@ mod tests {
         assert!(!flag.running());
     }
 
+    #[test]
+    fn test_stop_is_seen_by_clones() {
+        let flag = TerminationFlag::running_true();
+        let before = flag.clone();
+        flag.stop();
+        let after = flag.clone();
+
+        assert!(!flag.running());
+        assert!(!before.running());
+        assert!(!after.running());
+    }
+
     #[test]
     fn test_assert_running_success() {
         let flag = TerminationFlag::running_true();
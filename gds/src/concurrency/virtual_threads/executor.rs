@@ -6,6 +6,38 @@
 use crate::concurrency::Concurrency;
 use crate::concurrency::{TerminatedException, TerminationFlag};
 use rayon::prelude::*;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Error type for [`Executor::scope_with_timeout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScopeError {
+    /// The termination flag was stopped before or during the scope
+    Terminated,
+    /// The scope ran longer than the given timeout
+    TimedOut(Duration),
+}
+
+impl fmt::Display for ScopeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScopeError::Terminated => write!(f, "{}", TerminatedException),
+            ScopeError::TimedOut(timeout) => {
+                write!(f, "The execution did not finish within {:?}.", timeout)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScopeError {}
+
+impl From<TerminatedException> for ScopeError {
+    fn from(_: TerminatedException) -> Self {
+        ScopeError::Terminated
+    }
+}
 
 /// Parallel executor for graph algorithms.
 ///
@@ -100,6 +132,65 @@ impl Executor {
         Ok(work(&scope))
     }
 
+    /// Execute work within a synchronization scope bounded by a timeout.
+    ///
+    /// Behaves like [`scope`](Self::scope), but a watchdog stops
+    /// `termination` once `timeout` elapses. Spawned work stops cooperatively:
+    /// `spawn_many` and friends check the flag between tasks, and long-running
+    /// tasks must check it themselves.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Err(ScopeError::TimedOut)` if the timeout elapsed before the
+    /// work finished; the flag stays stopped in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gds::concurrency::virtual_threads::Executor;
+    /// use gds::concurrency::{Concurrency, TerminationFlag};
+    /// use std::time::Duration;
+    ///
+    /// let executor = Executor::new(Concurrency::of(4));
+    /// let termination = TerminationFlag::running_true();
+    ///
+    /// let sum = executor
+    ///     .scope_with_timeout(Duration::from_secs(10), &termination, |_scope| 1 + 1)
+    ///     .unwrap();
+    /// assert_eq!(sum, 2);
+    /// ```
+    pub fn scope_with_timeout<F, R>(
+        &self,
+        timeout: Duration,
+        termination: &TerminationFlag,
+        work: F,
+    ) -> Result<R, ScopeError>
+    where
+        F: FnOnce(&super::Scope) -> R + Send,
+        R: Send,
+    {
+        let timed_out = AtomicBool::new(false);
+        let (done, finished) = mpsc::channel::<()>();
+
+        let result = std::thread::scope(|threads| {
+            let timed_out = &timed_out;
+            threads.spawn(move || {
+                if finished.recv_timeout(timeout) == Err(mpsc::RecvTimeoutError::Timeout) {
+                    timed_out.store(true, Ordering::Release);
+                    termination.stop();
+                }
+            });
+            let result = self.scope(termination, work);
+            drop(done);
+            result
+        });
+
+        if timed_out.load(Ordering::Acquire) {
+            return Err(ScopeError::TimedOut(timeout));
+        }
+        Ok(result?)
+    }
+
     /// Execute a simple parallel loop over a range.
     ///
     /// This is a convenience method for the common case of iterating over nodes.
@@ -413,4 +504,62 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_scope_with_timeout_stops_runaway_work() {
+        let executor = Executor::new(Concurrency::of(4));
+        let termination = TerminationFlag::running_true();
+        let counter = AtomicUsize::new(0);
+        let started = std::time::Instant::now();
+
+        let result =
+            executor.scope_with_timeout(Duration::from_millis(50), &termination, |scope| {
+                scope.spawn_many(1_000_000, |_| {
+                    std::thread::sleep(Duration::from_millis(1));
+                    counter.fetch_add(1, Ordering::Relaxed);
+                });
+            });
+
+        assert_eq!(result, Err(ScopeError::TimedOut(Duration::from_millis(50))));
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert!(counter.load(Ordering::Relaxed) < 1_000_000);
+        assert!(!termination.running());
+    }
+
+    #[test]
+    fn test_scope_with_timeout_stops_cloned_flags() {
+        let executor = Executor::new(Concurrency::of(4));
+        let termination = TerminationFlag::running_true();
+        let worker = termination.clone();
+
+        let result =
+            executor.scope_with_timeout(Duration::from_millis(20), &termination, |_scope| {
+                while worker.running() {
+                    std::thread::sleep(Duration::from_millis(1));
+                }
+            });
+
+        assert_eq!(result, Err(ScopeError::TimedOut(Duration::from_millis(20))));
+    }
+
+    #[test]
+    fn test_scope_with_timeout_fast_work_completes() {
+        let executor = Executor::new(Concurrency::of(4));
+        let termination = TerminationFlag::running_true();
+        let counter = AtomicUsize::new(0);
+
+        let result = executor.scope_with_timeout(Duration::from_secs(10), &termination, |scope| {
+            scope.spawn_many(100, |_| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            });
+            counter.load(Ordering::Relaxed)
+        });
+
+        assert_eq!(result, Ok(100));
+        assert!(termination.running());
+
+        let stopped = TerminationFlag::stop_running();
+        let result = executor.scope_with_timeout(Duration::from_secs(10), &stopped, |_| ());
+        assert_eq!(result, Err(ScopeError::Terminated));
+    }
 }
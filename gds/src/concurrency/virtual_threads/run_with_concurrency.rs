@@ -7,6 +7,7 @@
 
 use crate::concurrency::{virtual_threads::Executor, Concurrency};
 use crate::concurrency::TerminationFlag;
+use rayon::prelude::*;
 
 /// Builder for flexible task execution with concurrency control.
 ///
@@ -232,6 +233,34 @@ impl RunWithConcurrencyParams {
     }
 }
 
+/// Run tasks and collect their return values in input order.
+///
+/// This is the map-style counterpart to [`RunWithConcurrency`]: with a
+/// concurrency of 1 the tasks run sequentially on the calling thread,
+/// otherwise in parallel. Either way `result[i]` is the value of `tasks[i]`.
+///
+/// # Examples
+///
+/// ```
+/// use gds::concurrency::virtual_threads::run_with_concurrency_collect;
+/// use gds::concurrency::Concurrency;
+///
+/// let tasks: Vec<_> = (0..4).map(|i| move || i * 10).collect();
+/// let results = run_with_concurrency_collect(Concurrency::of(2), tasks);
+/// assert_eq!(results, vec![0, 10, 20, 30]);
+/// ```
+pub fn run_with_concurrency_collect<T, F>(concurrency: Concurrency, tasks: Vec<F>) -> Vec<T>
+where
+    F: FnOnce() -> T + Send,
+    T: Send,
+{
+    if concurrency.value() == 1 {
+        tasks.into_iter().map(|task| task()).collect()
+    } else {
+        tasks.into_par_iter().map(|task| task()).collect()
+    }
+}
+
 /// Helper function to create a runnable closure from a function.
 pub fn runnable<F>(f: F) -> Box<dyn FnOnce() + Send>
 where
@@ -438,4 +467,24 @@ mod tests {
             .build();
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_run_with_concurrency_collect_keeps_input_order() {
+        let tasks: Vec<Box<dyn FnOnce() -> usize + Send>> = (0..100usize)
+            .map(|i| Box::new(move || i * i) as Box<dyn FnOnce() -> usize + Send>)
+            .collect();
+
+        let results = run_with_concurrency_collect(Concurrency::of(4), tasks);
+
+        assert_eq!(results, (0..100).map(|i| i * i).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_run_with_concurrency_collect_sequential() {
+        let tasks: Vec<_> = (0..10).map(|i| move || i + 1).collect();
+        let results = run_with_concurrency_collect(Concurrency::of(1), tasks);
+        assert_eq!(results, (1..=10).collect::<Vec<_>>());
+        let empty: Vec<fn() -> u8> = Vec::new();
+        assert!(run_with_concurrency_collect(Concurrency::of(4), empty).is_empty());
+    }
 }
@@ -3,8 +3,10 @@
 //! This provides thread-local state management for parallel algorithms,
 //! useful for accumulating per-thread results before final aggregation.
 
-use std::cell::RefCell;
-use std::marker::PhantomData;
+use parking_lot::{MappedMutexGuard, Mutex, MutexGuard};
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
 
 /// Per-thread worker context.
 ///
@@ -12,12 +14,17 @@ use std::marker::PhantomData;
 /// which is useful for algorithms that need to accumulate results
 /// locally before final aggregation (reducing contention).
 ///
+/// The slots are sized on the first [`local`](Self::local) call from a
+/// Rayon worker, with one slot per thread of that worker's pool, so the
+/// context can be created outside the pool that runs the work. Pool workers
+/// then never wait on each other. Threads outside a pool, and workers of a
+/// larger pool used later, share one overflow slot and take turns.
+///
 /// # Examples
 ///
 /// ```
 /// use gds::concurrency::virtual_threads::{WorkerContext, Executor};
-/// use gds::concurrency::Concurrency;
-/// use gds::termination::TerminationFlag;
+/// use gds::concurrency::{Concurrency, TerminationFlag};
 ///
 /// // Each worker accumulates a local sum
 /// let context = WorkerContext::new(|| 0usize);
@@ -25,21 +32,73 @@ use std::marker::PhantomData;
 /// let executor = Executor::new(Concurrency::of(4));
 /// let termination = TerminationFlag::running_true();
 ///
-/// executor.parallel_for(0, 1000, &termination, |i| {
-///     context.with(|local_sum| {
-///         *local_sum += i;
-///     });
-/// });
+/// executor
+///     .parallel_for(0, 1000, &termination, |i| {
+///         *context.local() += i;
+///     })
+///     .unwrap();
 ///
-/// // Collect results from all workers
-/// let total: usize = context.collect().into_iter().sum();
+/// // Merge the per-worker sums once all work is done
+/// let total = context.reduce(|a, b| a + b).unwrap_or(0);
+/// assert_eq!(total, 499_500);
 /// ```
 pub struct WorkerContext<T> {
     init: Box<dyn Fn() -> T + Send + Sync>,
-    _phantom: PhantomData<T>,
+    slots: OnceLock<Vec<Slot<T>>>,
+    overflow: Slot<T>,
+}
+
+/// One worker's state and the thread currently holding it.
+struct Slot<T> {
+    state: Mutex<Option<T>>,
+    /// [`thread_marker`] of the thread holding the state, 0 if none
+    holder: AtomicUsize,
+}
+
+impl<T> Slot<T> {
+    fn new() -> Self {
+        Self {
+            state: Mutex::new(None),
+            holder: AtomicUsize::new(0),
+        }
+    }
+}
+
+/// Non-zero id of the calling thread, unique among running threads.
+fn thread_marker() -> usize {
+    thread_local! {
+        static MARKER: u8 = const { 0 };
+    }
+    MARKER.with(|marker| marker as *const u8 as usize)
+}
+
+/// Mutable handle to one worker's state, returned by [`WorkerContext::local`].
+pub struct WorkerLocal<'a, T> {
+    state: MappedMutexGuard<'a, T>,
+    holder: &'a AtomicUsize,
+}
+
+impl<T> Deref for WorkerLocal<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.state
+    }
 }
 
-impl<T: 'static> WorkerContext<T> {
+impl<T> DerefMut for WorkerLocal<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.state
+    }
+}
+
+impl<T> Drop for WorkerLocal<'_, T> {
+    fn drop(&mut self) {
+        self.holder.store(0, Ordering::Release);
+    }
+}
+
+impl<T: Send> WorkerContext<T> {
     /// Create a new worker context with an initialization function.
     ///
     /// The init function is called once per worker thread to create
@@ -50,7 +109,48 @@ impl<T: 'static> WorkerContext<T> {
     {
         Self {
             init: Box::new(init),
-            _phantom: PhantomData,
+            slots: OnceLock::new(),
+            overflow: Slot::new(),
+        }
+    }
+
+    /// Mutable handle to the calling worker's state.
+    ///
+    /// The state is created by the init function on first access.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the calling thread still holds a handle from an earlier
+    /// call, which would otherwise deadlock. This includes a worker that
+    /// holds the handle across a nested parallel call and picks up another
+    /// task of the same context in the meantime.
+    pub fn local(&self) -> WorkerLocal<'_, T> {
+        let slot = match rayon::current_thread_index() {
+            Some(index) => self
+                .slots
+                .get_or_init(|| {
+                    (0..rayon::current_num_threads())
+                        .map(|_| Slot::new())
+                        .collect()
+                })
+                .get(index)
+                .unwrap_or(&self.overflow),
+            None => &self.overflow,
+        };
+
+        let marker = thread_marker();
+        assert_ne!(
+            slot.holder.load(Ordering::Acquire),
+            marker,
+            "WorkerContext::local called again while this thread still holds its handle"
+        );
+        let state = MutexGuard::map(slot.state.lock(), |state| {
+            state.get_or_insert_with(|| (self.init)())
+        });
+        slot.holder.store(marker, Ordering::Release);
+        WorkerLocal {
+            state,
+            holder: &slot.holder,
         }
     }
 
@@ -61,40 +161,44 @@ impl<T: 'static> WorkerContext<T> {
     where
         F: FnOnce(&mut T) -> R,
     {
-        thread_local! {
-            static CONTEXT: RefCell<Option<Box<dyn std::any::Any>>> = RefCell::new(None);
-        }
-
-        CONTEXT.with(|cell| {
-            let mut storage = cell.borrow_mut();
-
-            // Initialize if needed
-            if storage.is_none() {
-                *storage = Some(Box::new((self.init)()));
-            }
-
-            // Get mutable reference to stored value
-            let any_ref = storage.as_mut().unwrap();
-            let value: &mut T = any_ref
-                .downcast_mut()
-                .expect("Type mismatch in WorkerContext");
+        f(&mut self.local())
+    }
 
-            f(value)
-        })
+    fn all_slots(&self) -> impl Iterator<Item = &Slot<T>> {
+        self.slots
+            .get()
+            .into_iter()
+            .flatten()
+            .chain(std::iter::once(&self.overflow))
     }
 
     /// Collect all worker-local values.
     ///
-    /// Note: This is a simplified version. In a real implementation,
-    /// you'd want to track all worker threads and collect from each.
-    /// For now, this returns an empty vec as we don't track workers globally.
+    /// Returns a copy of the state of every worker that accessed the context.
     pub fn collect(&self) -> Vec<T>
     where
         T: Clone,
     {
-        // TODO: Track worker states globally for proper collection
-        // For now, this is a placeholder
-        Vec::new()
+        self.all_slots()
+            .filter_map(|slot| slot.state.lock().clone())
+            .collect()
+    }
+
+    /// Merge all worker-local values into one.
+    ///
+    /// Call this once the parallel work is done. Returns `None` if no
+    /// worker accessed the context.
+    pub fn reduce<F>(self, reduce: F) -> Option<T>
+    where
+        F: Fn(T, T) -> T,
+    {
+        self.slots
+            .into_inner()
+            .into_iter()
+            .flatten()
+            .chain(std::iter::once(self.overflow))
+            .filter_map(|slot| slot.state.into_inner())
+            .reduce(reduce)
     }
 }
 
@@ -106,7 +210,7 @@ pub struct WorkerLocalAggregator<T> {
     context: WorkerContext<T>,
 }
 
-impl<T: Default + 'static> WorkerLocalAggregator<T> {
+impl<T: Default + Send + 'static> WorkerLocalAggregator<T> {
     /// Create a new worker-local aggregator with default initialization.
     pub fn new() -> Self {
         Self {
@@ -115,13 +219,13 @@ impl<T: Default + 'static> WorkerLocalAggregator<T> {
     }
 }
 
-impl<T: Default + 'static> Default for WorkerLocalAggregator<T> {
+impl<T: Default + Send + 'static> Default for WorkerLocalAggregator<T> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T: 'static> WorkerLocalAggregator<T> {
+impl<T: Send + 'static> WorkerLocalAggregator<T> {
     /// Create a new worker-local aggregator with custom initialization.
     pub fn with_init<F>(init: F) -> Self
     where
@@ -197,6 +301,69 @@ mod tests {
         assert!(local_sum <= 4950, "Sum should not exceed total");
     }
 
+    #[test]
+    fn test_worker_context_reduces_partial_sums() {
+        let context = WorkerContext::new(|| 0u64);
+        let executor = Executor::new(Concurrency::of(4));
+        let termination = TerminationFlag::running_true();
+
+        executor
+            .parallel_for(0, 100_000, &termination, |i| {
+                *context.local() += i as u64;
+            })
+            .unwrap();
+
+        let partials = context.collect();
+        assert!(!partials.is_empty());
+        assert!(partials.len() <= rayon::current_num_threads() + 1);
+        assert_eq!(context.reduce(|a, b| a + b), Some(4_999_950_000));
+    }
+
+    #[test]
+    fn test_worker_context_sizes_slots_from_the_running_pool() {
+        let context = WorkerContext::new(|| 0u64);
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(rayon::current_num_threads() + 3)
+            .build()
+            .unwrap();
+        let executor = Executor::new(Concurrency::of(pool.current_num_threads()));
+        let termination = TerminationFlag::running_true();
+
+        pool.install(|| {
+            executor
+                .parallel_for(0, 10_000, &termination, |i| {
+                    *context.local() += i as u64;
+                })
+                .unwrap();
+        });
+
+        assert_eq!(
+            context.slots.get().map(Vec::len),
+            Some(pool.current_num_threads())
+        );
+        assert_eq!(context.reduce(|a, b| a + b), Some(49_995_000));
+    }
+
+    #[test]
+    #[should_panic(expected = "still holds its handle")]
+    fn test_worker_context_rejects_reentrant_access() {
+        let context = WorkerContext::new(|| 0usize);
+        let _first = context.local();
+        let _second = context.local();
+    }
+
+    #[test]
+    fn test_worker_context_reduce_without_access() {
+        let context = WorkerContext::new(Vec::<usize>::new);
+        assert_eq!(
+            context.reduce(|mut a, b| {
+                a.extend(b);
+                a
+            }),
+            None
+        );
+    }
+
     #[test]
     fn test_worker_local_aggregator_default() {
         let aggregator = WorkerLocalAggregator::<usize>::new();
@@ -1,6 +1,6 @@
 //! Algorithm configuration types and builders
 
-use super::base_types::{AlgoBaseConfig, ConcurrencyConfig, IterationsConfig};
+use super::base_types::{AlgoBaseConfig, ConcurrencyConfig, IterationsConfig, PivotStrategy};
 use super::pregel_config::PregelRuntimeConfig;
 use super::validation::{validate_positive, validate_range};
 use crate::core::utils::partition::Partitioning;
@@ -125,6 +125,7 @@ define_config!(
         base: AlgoBaseConfig = AlgoBaseConfig::default(),
         sampling_size: Option<usize> = None,
         sampling_seed: Option<u64> = None,
+        pivot_strategy: PivotStrategy = PivotStrategy::Uniform,
     }
 );
 
@@ -188,10 +189,12 @@ mod tests {
         let config = BetweennessCentralityConfig::builder()
             .sampling_size(Some(100))
             .sampling_seed(Some(42))
+            .pivot_strategy(PivotStrategy::DegreeWeighted)
             .build()
             .unwrap();
 
         assert_eq!(config.sampling_size, Some(100));
         assert_eq!(config.sampling_seed, Some(42));
+        assert_eq!(config.pivot_strategy, PivotStrategy::DegreeWeighted);
     }
 }
@@ -145,6 +145,20 @@ pub enum TrainingMethod {
     SemiSupervised,
 }
 
+/// How the source nodes of sampled betweenness are chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub enum PivotStrategy {
+    /// Distinct sources, uniformly without replacement
+    #[default]
+    Uniform,
+    /// Uniform sources with replacement
+    Random,
+    /// Sources with replacement, proportional to their degree. Isolated and
+    /// peripheral nodes contribute little to betweenness, so this usually
+    /// gives lower-variance estimates.
+    DegreeWeighted,
+}
+
 /// Graph schema for model training
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct GraphSchema {
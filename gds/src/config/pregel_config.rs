@@ -16,6 +16,9 @@ define_config!(
             if let Some(tol) = cfg.tolerance {
                 crate::config::validation::ConfigValidation::validate_positive(tol, "tolerance")?;
             }
+            if let Some(capacity) = cfg.message_queue_capacity {
+                crate::config::validation::ConfigValidation::validate_positive(capacity as f64, "messageQueueCapacity")?;
+            }
             Ok(())
         },
         base: crate::config::base_types::AlgoBaseConfig = crate::config::base_types::AlgoBaseConfig::default(),
@@ -24,6 +27,7 @@ define_config!(
         is_asynchronous: bool = false,
         partitioning: crate::core::utils::partition::Partitioning = crate::core::utils::partition::Partitioning::Range,
         track_sender: bool = false,
+        message_queue_capacity: Option<usize> = None,
     }
 );
 
@@ -51,6 +55,12 @@ pub trait PregelRuntimeConfig:
     fn partitioning(&self) -> crate::core::utils::partition::Partitioning;
     fn track_sender(&self) -> bool;
 
+    /// Maximum number of unread messages an asynchronous messenger holds
+    /// before senders block; `None` leaves the queues unbounded.
+    fn message_queue_capacity(&self) -> Option<usize> {
+        None
+    }
+
     fn use_fork_join(&self) -> bool {
         matches!(
             self.partitioning(),
@@ -71,4 +81,8 @@ impl PregelRuntimeConfig for PregelConfig {
     fn track_sender(&self) -> bool {
         self.track_sender
     }
+
+    fn message_queue_capacity(&self) -> Option<usize> {
+        self.message_queue_capacity
+    }
 }
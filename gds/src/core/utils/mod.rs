@@ -28,6 +28,8 @@
 //! - Set intersections and vector similarity operations
 //! - Cache-efficient binary search (Eytzinger layout)
 //! - Lazy batch collection for parallel processing
+//! - Bitset-backed node sets with O(1) membership
+//! - Summary statistics and histograms over `f64` values
 
 pub mod array_layout;
 pub mod ascending_long_comparator;
@@ -35,6 +37,7 @@ pub mod clock_service;
 pub mod intersections;
 pub mod lazy_batch_collection;
 pub mod mapped_id_node_property_values;
+pub mod node_set;
 pub mod original_id_node_property_values;
 pub mod progress_timer;
 pub mod time_util;
@@ -45,6 +48,7 @@ pub mod progress;
 pub mod queue;
 pub mod set_bits_iterable;
 pub mod shuffle;
+pub mod statistics;
 pub mod two_arrays_sort;
 pub mod warnings;
 
@@ -55,6 +59,7 @@ pub use clock_service::{Clock, ClockService};
 pub use intersections::Intersections;
 pub use lazy_batch_collection::LazyBatchCollection;
 pub use mapped_id_node_property_values::MappedIdNodePropertyValues;
+pub use node_set::NodeSet;
 pub use original_id_node_property_values::OriginalIdNodePropertyValues;
 pub use progress_timer::ProgressTimer;
 pub use set_bits_iterable::SetBitsIterable;
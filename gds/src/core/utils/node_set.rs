@@ -0,0 +1,155 @@
+//! Bitset-backed set of node ids.
+//!
+//! Algorithms that take source or target node sets test membership once per
+//! visited node. A `Vec` of ids makes every test linear in the set size;
+//! `NodeSet` answers it with a single bit lookup.
+
+use crate::core::utils::paged::HugeAtomicBitSet;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A set of node ids in `0..capacity` backed by a [`HugeAtomicBitSet`].
+///
+/// `contains`, `insert` and `len` are O(1). Iteration yields ids in ascending
+/// order and scans the bitset words, so it is O(capacity / 64). Inserts are
+/// thread-safe, so workers may fill the same set concurrently.
+///
+/// # Examples
+///
+/// ```text
+/// use gds::core::utils::NodeSet;
+///
+/// let targets = NodeSet::from_ids(vec![3, 5]);
+/// assert!(targets.contains(3));
+/// assert!(!targets.contains(4));
+/// assert_eq!(targets.iter().collect::<Vec<_>>(), vec![3, 5]);
+/// ```
+pub struct NodeSet {
+    bits: HugeAtomicBitSet,
+    capacity: usize,
+    len: AtomicUsize,
+}
+
+impl NodeSet {
+    /// Creates an empty set that can hold ids in `0..capacity`.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            bits: HugeAtomicBitSet::new(capacity),
+            capacity,
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    /// Creates a set holding the given ids, sized to the largest one.
+    ///
+    /// Duplicate ids are stored once.
+    pub fn from_ids<I>(ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        let ids: Vec<u64> = ids.into_iter().collect();
+        let capacity = ids.iter().max().map_or(0, |&max| max as usize + 1);
+        let set = Self::new(capacity);
+        for id in ids {
+            set.insert(id);
+        }
+        set
+    }
+
+    /// Adds `id` to the set and returns `true` if it was not present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not below the capacity.
+    pub fn insert(&self, id: u64) -> bool {
+        let index = id as usize;
+        assert!(
+            index < self.capacity,
+            "node id {} exceeds NodeSet capacity {}",
+            id,
+            self.capacity
+        );
+        let was_set = self.bits.get_and_set(index);
+        if !was_set {
+            self.len.fetch_add(1, Ordering::Relaxed);
+        }
+        !was_set
+    }
+
+    /// Returns `true` if `id` is in the set. Ids beyond the capacity are never contained.
+    pub fn contains(&self, id: u64) -> bool {
+        let index = id as usize;
+        index < self.capacity && self.bits.get(index)
+    }
+
+    /// Returns the number of ids in the set.
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the set holds no ids.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the exclusive upper bound of ids the set can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the ids in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = u64> {
+        let mut ids = Vec::with_capacity(self.len());
+        self.bits.for_each_set_bit(|index| ids.push(index as u64));
+        ids.into_iter()
+    }
+}
+
+impl FromIterator<u64> for NodeSet {
+    fn from_iter<I: IntoIterator<Item = u64>>(ids: I) -> Self {
+        Self::from_ids(ids)
+    }
+}
+
+impl fmt::Debug for NodeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodeSet")
+            .field("capacity", &self.capacity)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_membership_and_iteration() {
+        let set = NodeSet::from_ids(vec![70, 3, 5, 3]);
+
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.capacity(), 71);
+        assert!(set.contains(3));
+        assert!(set.contains(5));
+        assert!(set.contains(70));
+        assert!(!set.contains(4));
+        assert!(!set.contains(1_000));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![3, 5, 70]);
+    }
+
+    #[test]
+    fn test_insert_reports_new_ids() {
+        let set = NodeSet::new(10);
+        assert!(set.is_empty());
+        assert!(set.insert(9));
+        assert!(!set.insert(9));
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds NodeSet capacity")]
+    fn test_insert_beyond_capacity() {
+        NodeSet::new(4).insert(4);
+    }
+}
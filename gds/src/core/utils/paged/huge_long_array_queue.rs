@@ -7,6 +7,10 @@ use crate::collections::HugeLongArray;
 /// - Level-order traversal in trees
 /// - Producer-consumer patterns in parallel graph processing
 /// - Stream processing with bounded memory
+///
+/// The queue can be used in two modes. `add`/`remove` treat the capacity as a
+/// hard bound and panic on overflow, while `push_back`/`pop_front` grow the
+/// backing array on demand, which suits frontiers of unknown size.
 pub struct HugeLongArrayQueue {
     array: HugeLongArray,
     capacity: usize,
@@ -62,6 +66,25 @@ impl HugeLongArrayQueue {
         removed
     }
 
+    /// Appends an element to the rear of the queue, growing the storage if needed.
+    ///
+    /// Growth doubles the capacity, so pushes are amortized O(1).
+    pub fn push_back(&mut self, value: i64) {
+        if self.is_full() {
+            self.grow();
+        }
+        self.add(value);
+    }
+
+    /// Removes and returns the front element, or `None` if the queue is empty.
+    pub fn pop_front(&mut self) -> Option<i64> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.remove())
+        }
+    }
+
     /// Returns the front element without removing it.
     ///
     /// # Panics
@@ -91,6 +114,11 @@ impl HugeLongArrayQueue {
         (self.tail + 1) % self.capacity == self.head
     }
 
+    /// Returns the number of elements the queue can hold before it is full.
+    pub fn capacity(&self) -> usize {
+        self.capacity - 1
+    }
+
     /// Returns the remaining capacity.
     pub fn remaining_capacity(&self) -> usize {
         self.capacity - 1 - self.size()
@@ -163,11 +191,30 @@ impl HugeLongArrayQueue {
         self.clear();
         result
     }
+
+    fn grow(&mut self) {
+        let queue_size = self.size();
+        let new_capacity = (self.capacity() * 2).max(1) + 1;
+        let mut array = HugeLongArray::new(new_capacity);
+
+        let mut current = self.head;
+        for index in 0..queue_size {
+            array.set(index, self.array.get(current));
+            current = (current + 1) % self.capacity;
+        }
+
+        self.array = array;
+        self.capacity = new_capacity;
+        self.head = 0;
+        self.tail = queue_size;
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::HugeLongArrayQueue;
+    use crate::collections::backends::huge::huge_long_array::PagedHugeLongArray;
+    use crate::collections::{HugeLongArray, PageUtil};
 
     #[test]
     fn add_remove_round_trip() {
@@ -287,4 +334,86 @@ mod tests {
         queue.add(2);
         assert!(queue.is_full());
     }
+
+    #[test]
+    fn push_pop_is_fifo() {
+        let mut queue = HugeLongArrayQueue::new(4);
+        for value in 0..4 {
+            queue.push_back(value);
+        }
+        assert_eq!(queue.pop_front(), Some(0));
+        assert_eq!(queue.pop_front(), Some(1));
+        queue.push_back(4);
+        assert_eq!(queue.to_vec(), vec![2, 3, 4]);
+        assert_eq!(queue.pop_front(), Some(2));
+        assert_eq!(queue.pop_front(), Some(3));
+        assert_eq!(queue.pop_front(), Some(4));
+        assert_eq!(queue.pop_front(), None);
+    }
+
+    #[test]
+    fn push_back_grows_after_wraparound() {
+        let mut queue = HugeLongArrayQueue::new(3);
+        queue.push_back(1);
+        queue.push_back(2);
+        queue.push_back(3);
+        assert_eq!(queue.pop_front(), Some(1));
+        assert_eq!(queue.pop_front(), Some(2));
+        // Tail has wrapped around; growing must preserve FIFO order.
+        queue.push_back(4);
+        queue.push_back(5);
+        queue.push_back(6);
+        assert!(queue.capacity() > 3);
+        assert_eq!(queue.drain(), vec![3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn push_back_handles_more_than_one_page() {
+        // Paged storage is only chosen for huge sizes, so page a small queue directly
+        let page_size =
+            PageUtil::page_size_for(PageUtil::PAGE_SIZE_4KB, std::mem::size_of::<i64>());
+        let capacity = 2 * page_size + 1;
+        let mut queue = HugeLongArrayQueue {
+            array: HugeLongArray::Paged(PagedHugeLongArray::new(capacity + 1)),
+            capacity: capacity + 1,
+            head: 0,
+            tail: 0,
+        };
+
+        // Hold up to half a page while the head and tail wrap around every page
+        let count = 3 * capacity as i64 + 7;
+        let mut expected = 0;
+        for value in 0..count {
+            queue.push_back(value);
+            if queue.size() > page_size / 2 {
+                assert_eq!(queue.pop_front(), Some(expected));
+                expected += 1;
+            }
+        }
+        assert_eq!(queue.capacity(), capacity);
+
+        // Growing copies the wrapped elements out of every page in FIFO order
+        let backlog = count + capacity as i64;
+        for value in count..backlog {
+            queue.push_back(value);
+        }
+        assert!(queue.capacity() > capacity);
+        while let Some(value) = queue.pop_front() {
+            assert_eq!(value, expected);
+            expected += 1;
+        }
+        assert_eq!(expected, backlog);
+    }
+
+    #[test]
+    fn clear_keeps_grown_storage() {
+        let mut queue = HugeLongArrayQueue::new(1);
+        for value in 0..100 {
+            queue.push_back(value);
+        }
+        let capacity = queue.capacity();
+        queue.clear();
+        assert!(queue.is_empty());
+        assert_eq!(queue.capacity(), capacity);
+    }
 }
@@ -50,6 +50,9 @@ const MIN_PARTITION_CAPACITY: f64 = 0.67;
 /// Default minimum batch size for parallel processing.
 pub const DEFAULT_BATCH_SIZE: usize = 10;
 
+/// Number of batches per worker targeted by [`PartitionUtils::adaptive_batch_size`].
+const BATCHES_PER_WORKER: usize = 4;
+
 /// High-performance graph partitioning utilities.
 pub struct PartitionUtils;
 
@@ -189,7 +192,7 @@ impl PartitionUtils {
     pub fn degree_partition<TASK, F>(
         node_count: usize,
         relationship_count: usize,
-        degrees: Box<dyn DegreeFunction>,
+        degrees: Box<dyn DegreeFunction + '_>,
         concurrency: usize,
         task_creator: F,
         min_batch_size: Option<usize>,
@@ -215,6 +218,9 @@ impl PartitionUtils {
 
     /// Creates degree partitions with a specified batch size.
     ///
+    /// Every node's degree counts towards the partition that holds it, so the
+    /// partition sizes add up to the total degree.
+    ///
     /// # Arguments
     /// * `node_count` - Total number of nodes
     /// * `degrees` - Function to get node degrees
@@ -222,7 +228,7 @@ impl PartitionUtils {
     /// * `task_creator` - Function to create tasks from partitions
     pub fn degree_partition_with_batch_size<TASK, F>(
         node_count: usize,
-        degrees: Box<dyn DegreeFunction>,
+        degrees: Box<dyn DegreeFunction + '_>,
         batch_size: usize,
         task_creator: F,
     ) -> Vec<TASK>
@@ -238,24 +244,21 @@ impl PartitionUtils {
 
         while start < node_count {
             let mut partition_size = 0;
-            let mut node_id = start.saturating_sub(1);
+            let mut end = start;
 
             // Find the next partition boundary
-            while node_id < node_count - 1
-                && (node_id + 1).saturating_sub(start) < Partition::MAX_NODE_COUNT
-            {
-                let degree = degrees.degree(node_id + 1);
+            while end < node_count && end - start < Partition::MAX_NODE_COUNT {
+                let degree = degrees.degree(end);
                 let partition_is_large_enough = partition_size >= min_partition_size;
 
                 if partition_size + degree > batch_size && partition_is_large_enough {
                     break;
                 }
 
-                node_id += 1;
+                end += 1;
                 partition_size += degree;
             }
 
-            let end = node_id + 1;
             partitions.push(DegreePartition::of(start, end - start, partition_size));
             start = end;
         }
@@ -322,6 +325,21 @@ impl PartitionUtils {
         std::cmp::max(batch_size, min_batch_size)
     }
 
+    /// Calculates a batch size that yields a few batches per worker.
+    ///
+    /// Targets `total / (concurrency * 4)` elements per batch so that workers
+    /// finishing early can pick up remaining batches. The result is clamped to
+    /// `[DEFAULT_BATCH_SIZE, Partition::MAX_NODE_COUNT]`, so tiny inputs end up
+    /// in a single batch instead of many near-empty ones.
+    ///
+    /// # Arguments
+    /// * `total` - Total number of elements to process
+    /// * `concurrency` - Number of parallel workers
+    pub fn adaptive_batch_size(total: usize, concurrency: usize) -> usize {
+        let batch_count = concurrency.max(1) * BATCHES_PER_WORKER;
+        BitUtil::ceil_div(total, batch_count).clamp(DEFAULT_BATCH_SIZE, Partition::MAX_NODE_COUNT)
+    }
+
     /// Creates tasks from partitions.
     fn tasks<TASK, F>(node_count: usize, batch_size: usize, task_creator: F) -> Vec<TASK>
     where
@@ -459,6 +477,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_degree_partition_counts_first_node() {
+        // Node 0 alone exceeds the batch size and must fill a partition by itself.
+        let degrees = Box::new(|node: usize| if node == 0 { 100 } else { 1 });
+        let partitions = PartitionUtils::degree_partition_with_batch_size(101, degrees, 50, |p| p);
+
+        assert_eq!(partitions[0], DegreePartition::of(0, 1, 100));
+        assert_eq!(partitions[1], DegreePartition::of(1, 50, 50));
+        assert_eq!(
+            partitions.iter().map(|p| p.node_count()).sum::<usize>(),
+            101
+        );
+    }
+
+    #[test]
+    fn test_degree_partition_sizes_sum_to_total_degree() {
+        let degree_of = |node: usize| (node * 7) % 13 + 1;
+        let total: usize = (0..200).map(degree_of).sum();
+        let partitions =
+            PartitionUtils::degree_partition_with_batch_size(200, Box::new(degree_of), 64, |p| p);
+
+        let mut next_start = 0;
+        for partition in &partitions {
+            assert_eq!(partition.start_node(), next_start);
+            let degree: usize = (next_start..next_start + partition.node_count())
+                .map(degree_of)
+                .sum();
+            assert_eq!(partition.relationship_count(), degree);
+            next_start += partition.node_count();
+        }
+        assert_eq!(next_start, 200);
+        assert_eq!(
+            partitions
+                .iter()
+                .map(|p| p.relationship_count())
+                .sum::<usize>(),
+            total
+        );
+    }
+
     #[test]
     fn test_degree_partition_stream() {
         let degrees = Box::new(ConstantDegree(10));
@@ -477,6 +535,27 @@ mod tests {
         assert_eq!(PartitionUtils::adjusted_batch_size(1000, 20, 100), 100);
     }
 
+    #[test]
+    fn test_adaptive_batch_size() {
+        for concurrency in [1, 4, 16] {
+            let batch_size = PartitionUtils::adaptive_batch_size(1_000_000, concurrency);
+            let partitions =
+                PartitionUtils::range_partition_with_batch_size(1_000_000, batch_size, |p| p);
+            assert!(partitions.len() >= concurrency);
+            assert_eq!(partitions.len(), concurrency * 4);
+        }
+
+        assert_eq!(
+            PartitionUtils::adaptive_batch_size(5, 8),
+            DEFAULT_BATCH_SIZE
+        );
+        assert_eq!(
+            PartitionUtils::adaptive_batch_size(0, 8),
+            DEFAULT_BATCH_SIZE
+        );
+        assert_eq!(PartitionUtils::adaptive_batch_size(100, 0), 25);
+    }
+
     #[test]
     fn test_actual_batch_size() {
         assert_eq!(PartitionUtils::actual_batch_size(0, 100, 1000), 100);
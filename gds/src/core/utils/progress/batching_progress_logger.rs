@@ -19,6 +19,7 @@
 //! Batching progress logger for high-performance concurrent progress tracking.
 
 use super::{MessageFactory, ProgressLogger, Task, UNKNOWN_VOLUME};
+use crate::concurrency::{TerminatedException, TerminationFlag};
 use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
 
 /// Maximum interval for logging (2^13 = 8192).
@@ -67,6 +68,13 @@ thread_local! {
 ///     logger.log_progress();  // Batched - only logs occasionally
 /// }
 /// ```
+///
+/// # Cancellation
+///
+/// A logger built with [`with_termination_flag`](Self::with_termination_flag)
+/// also observes cancellation: [`try_log_progress`](Self::try_log_progress)
+/// records progress and then fails with [`TerminatedException`] once the flag
+/// stops running, so polling loops need a single call for both concerns.
 pub struct BatchingProgressLogger {
     // Configuration
     task_volume: AtomicU64,
@@ -77,6 +85,9 @@ pub struct BatchingProgressLogger {
     // Counters
     progress_counter: AtomicI64,
     global_percentage: AtomicI64,
+
+    // Cancellation
+    termination_flag: Option<TerminationFlag>,
 }
 
 impl BatchingProgressLogger {
@@ -97,6 +108,30 @@ impl BatchingProgressLogger {
             concurrency,
             progress_counter: AtomicI64::new(0),
             global_percentage: AtomicI64::new(0),
+            termination_flag: None,
+        }
+    }
+
+    /// Check the given termination flag on every [`try_log_progress`](Self::try_log_progress).
+    pub fn with_termination_flag(mut self, termination_flag: TerminationFlag) -> Self {
+        self.termination_flag = Some(termination_flag);
+        self
+    }
+
+    /// Termination flag checked by this logger, if any.
+    pub fn termination_flag(&self) -> Option<&TerminationFlag> {
+        self.termination_flag.as_ref()
+    }
+
+    /// Log progress and check for cancellation in one call.
+    ///
+    /// The progress is recorded even if the computation was terminated.
+    /// Without a termination flag this never fails.
+    pub fn try_log_progress(&self, progress: i64) -> Result<(), TerminatedException> {
+        self.log_progress_internal(progress, || None);
+        match &self.termination_flag {
+            Some(flag) if !flag.running() => Err(TerminatedException),
+            _ => Ok(()),
         }
     }
 
@@ -354,6 +389,30 @@ mod tests {
         assert!(progress >= 10);
     }
 
+    #[test]
+    fn test_try_log_progress_without_flag() {
+        let logger = BatchingProgressLogger::new("Test".to_string(), 10, 1);
+        for _ in 0..10 {
+            assert!(logger.try_log_progress(1).is_ok());
+        }
+        assert!(logger.termination_flag().is_none());
+    }
+
+    #[test]
+    fn test_try_log_progress_reports_termination() {
+        let logger = BatchingProgressLogger::new("Test".to_string(), 100, 1)
+            .with_termination_flag(TerminationFlag::running_true());
+
+        for _ in 0..10 {
+            assert!(logger.try_log_progress(1).is_ok());
+        }
+
+        logger.termination_flag().unwrap().stop();
+
+        assert_eq!(logger.try_log_progress(1), Err(TerminatedException));
+        assert!(logger.try_log_progress(1).is_err());
+    }
+
     #[test]
     fn test_reset() {
         let mut logger = BatchingProgressLogger::new("Test".to_string(), 10000, 4);
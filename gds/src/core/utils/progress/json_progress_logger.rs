@@ -0,0 +1,200 @@
+//! Progress logger emitting JSON lines for machine consumption.
+
+use super::{MessageFactory, ProgressLogger};
+use serde_json::{json, Value};
+use std::io::Write;
+
+/// Progress logger that writes one JSON object per line to a sink.
+///
+/// Progress events carry the task name, the accumulated progress and the task
+/// volume; log messages carry the task name, level and message:
+///
+/// ```text
+/// {"task":"PageRank","progress":250,"volume":1000}
+/// {"task":"PageRank","level":"info","message":"Start"}
+/// ```
+///
+/// Unlike [`BatchingProgressLogger`](super::BatchingProgressLogger) every
+/// progress call produces a line, so orchestration tools see exact counts.
+/// Write errors are ignored; progress reporting never fails the algorithm.
+///
+/// # Example
+///
+/// ```
+/// use gds::core::utils::progress::{JsonProgressLogger, ProgressLogger};
+///
+/// let mut logger = JsonProgressLogger::new("Algorithm".to_string(), 10, Vec::new());
+/// logger.log_progress_amount(5);
+///
+/// let output = String::from_utf8(logger.into_sink()).unwrap();
+/// assert_eq!(output, "{\"progress\":5,\"task\":\"Algorithm\",\"volume\":10}\n");
+/// ```
+pub struct JsonProgressLogger<W: Write + Send> {
+    task_name: String,
+    task_volume: i64,
+    progress: i64,
+    sink: W,
+}
+
+impl<W: Write + Send> JsonProgressLogger<W> {
+    /// Create a logger writing to `sink`.
+    ///
+    /// # Parameters
+    /// - `task_name`: Name of the task
+    /// - `task_volume`: Total volume of work
+    /// - `sink`: Destination of the JSON lines
+    pub fn new(task_name: String, task_volume: i64, sink: W) -> Self {
+        Self {
+            task_name,
+            task_volume,
+            progress: 0,
+            sink,
+        }
+    }
+
+    /// Progress accumulated since creation or the last reset.
+    pub fn progress(&self) -> i64 {
+        self.progress
+    }
+
+    /// Consume the logger and return its sink.
+    pub fn into_sink(self) -> W {
+        self.sink
+    }
+
+    fn write_line(&mut self, value: Value) {
+        let _ = writeln!(self.sink, "{}", value);
+    }
+
+    fn write_progress(&mut self, message: Option<String>) {
+        let mut line = json!({
+            "task": self.task_name,
+            "progress": self.progress,
+            "volume": self.task_volume,
+        });
+        if let Some(message) = message {
+            line["message"] = Value::String(message);
+        }
+        self.write_line(line);
+    }
+
+    fn write_message(&mut self, level: &str, msg: &str) {
+        let line = json!({
+            "task": self.task_name,
+            "level": level,
+            "message": msg,
+        });
+        self.write_line(line);
+    }
+}
+
+impl<W: Write + Send> ProgressLogger for JsonProgressLogger<W> {
+    fn get_task(&self) -> &str {
+        &self.task_name
+    }
+
+    fn set_task(&mut self, task: String) {
+        self.task_name = task;
+    }
+
+    fn log_progress_with_message(&mut self, progress: i64, msg_factory: MessageFactory) {
+        self.progress += progress;
+        self.write_progress(msg_factory());
+    }
+
+    fn log_message(&mut self, msg: &str) {
+        self.write_message("info", msg);
+    }
+
+    fn log_debug(&mut self, msg: &str) {
+        self.write_message("debug", msg);
+    }
+
+    fn log_warning(&mut self, msg: &str) {
+        self.write_message("warning", msg);
+    }
+
+    fn log_error(&mut self, msg: &str) {
+        self.write_message("error", msg);
+    }
+
+    fn log_finish_percentage(&mut self) {
+        self.progress = self.task_volume;
+        self.write_progress(None);
+    }
+
+    fn reset(&mut self, new_task_volume: i64) -> i64 {
+        self.progress = 0;
+        std::mem::replace(&mut self.task_volume, new_task_volume)
+    }
+
+    fn release(&mut self) {
+        let _ = self.sink.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(logger: JsonProgressLogger<Vec<u8>>) -> Vec<Value> {
+        String::from_utf8(logger.into_sink())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_progress_lines() {
+        let mut logger = JsonProgressLogger::new("PageRank".to_string(), 100, Vec::new());
+        logger.log_progress_amount(30);
+        logger.log_progress_amount(20);
+        logger.log_progress();
+        assert_eq!(logger.progress(), 51);
+
+        let lines = lines(logger);
+        assert_eq!(lines.len(), 3);
+        for line in &lines {
+            assert_eq!(line["task"], "PageRank");
+            assert_eq!(line["volume"], 100);
+        }
+        let progress: Vec<i64> = lines
+            .iter()
+            .map(|line| line["progress"].as_i64().unwrap())
+            .collect();
+        assert_eq!(progress, vec![30, 50, 51]);
+    }
+
+    #[test]
+    fn test_message_lines() {
+        let mut logger = JsonProgressLogger::new("Louvain".to_string(), 10, Vec::new());
+        logger.log_start_default();
+        logger.start_subtask("Level 1");
+        logger.log_warning("slow \"convergence\"");
+        logger.finish_subtask("Level 1");
+        logger.log_finish_percentage();
+
+        let lines = lines(logger);
+        assert_eq!(lines[0]["message"], "Start");
+        assert_eq!(lines[0]["level"], "info");
+        assert_eq!(lines[1]["task"], "Louvain :: Level 1");
+        assert_eq!(lines[2]["level"], "warning");
+        assert_eq!(lines[2]["message"], "slow \"convergence\"");
+        assert_eq!(lines[3]["message"], "Finished");
+        assert_eq!(lines[4]["task"], "Louvain");
+        assert_eq!(lines[4]["progress"], 10);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut logger = JsonProgressLogger::new("Test".to_string(), 10, Vec::new());
+        logger.log_progress_amount(4);
+        assert_eq!(logger.reset(20), 10);
+        logger.log_progress_amount(1);
+
+        let lines = lines(logger);
+        assert_eq!(lines[1]["progress"], 1);
+        assert_eq!(lines[1]["volume"], 20);
+    }
+}
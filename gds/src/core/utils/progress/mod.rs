@@ -53,6 +53,7 @@
 pub mod batching_progress_logger;
 pub mod empty_task_store;
 pub mod job_id;
+pub mod json_progress_logger;
 pub mod observable_task_store;
 pub mod per_database_task_store;
 pub mod progress_logger;
@@ -70,6 +71,7 @@ pub mod user_task;
 pub use batching_progress_logger::{BatchingProgressLogger, MAXIMUM_LOG_INTERVAL};
 pub use empty_task_store::EmptyTaskStore;
 pub use job_id::JobId;
+pub use json_progress_logger::JsonProgressLogger;
 pub use observable_task_store::ObservableTaskStore;
 pub use per_database_task_store::PerDatabaseTaskStore;
 pub use progress_logger::{MessageFactory, ProgressLogger, NO_MESSAGE};
@@ -189,6 +189,7 @@ impl TaskStore for PerDatabaseTaskStore {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::utils::progress::tasks::Status;
     use std::sync::{Arc, Mutex};
 
     // Test listener that tracks calls
@@ -349,6 +350,77 @@ mod tests {
         assert_eq!(tasks.len(), 2);
     }
 
+    #[test]
+    fn test_find_and_cancel_by_job_id() {
+        let store = PerDatabaseTaskStore::new();
+        let job_a = JobId::new();
+        let job_b = JobId::new();
+        let task_a = Task::new("PageRank".to_string(), 100)
+            .with_sub_tasks(vec![Task::new("iteration".to_string(), 10)]);
+        let task_b = Task::new("Louvain".to_string(), 200);
+        task_a.start();
+        task_a.sub_tasks()[0].start();
+        task_b.start();
+
+        store.store("alice".to_string(), job_a.clone(), task_a.clone());
+        store.store("bob".to_string(), job_b.clone(), task_b.clone());
+
+        let found = store.find_by_job_id(&job_a).unwrap();
+        assert_eq!(found.username, "alice");
+        assert_eq!(found.task.description, "PageRank");
+        assert!(store.find_by_job_id(&JobId::new()).is_none());
+
+        assert!(store.cancel(&job_a));
+        assert!(!store.cancel(&JobId::new()));
+
+        assert_eq!(task_a.status(), Status::Canceled);
+        assert_eq!(task_a.sub_tasks()[0].status(), Status::Canceled);
+        assert_eq!(
+            store.query("alice", &job_a).unwrap().task.status(),
+            Status::Canceled
+        );
+        assert_eq!(task_b.status(), Status::Running);
+        assert_eq!(
+            store.find_by_job_id(&job_b).unwrap().task.status(),
+            Status::Running
+        );
+    }
+
+    #[test]
+    fn test_cancel_keeps_terminal_status() {
+        let store = PerDatabaseTaskStore::new();
+        let job_id = JobId::new();
+        let task = Task::new("Done".to_string(), 1);
+        task.start();
+        task.finish();
+        store.store("alice".to_string(), job_id.clone(), task.clone());
+
+        assert!(store.cancel(&job_id));
+        assert_eq!(task.status(), Status::Finished);
+    }
+
+    #[test]
+    fn test_cancelled_task_ignores_later_transitions() {
+        let store = PerDatabaseTaskStore::new();
+        let job_id = JobId::new();
+        let task = Task::new("Pending".to_string(), 1);
+        store.store("alice".to_string(), job_id.clone(), task.clone());
+
+        assert!(store.cancel(&job_id));
+        task.start();
+        assert_eq!(task.status(), Status::Canceled);
+        task.finish();
+        task.fail();
+        assert_eq!(task.status(), Status::Canceled);
+
+        let running = Task::new("Running".to_string(), 1);
+        running.start();
+        running.fail();
+        running.start();
+        running.finish();
+        assert_eq!(running.status(), Status::Failed);
+    }
+
     #[test]
     fn test_query_by_username() {
         let store = PerDatabaseTaskStore::new();
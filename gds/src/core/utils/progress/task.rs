@@ -21,6 +21,10 @@
 //! This is a placeholder - the full Task hierarchy will be implemented
 //! in the tasks/ submodule.
 
+use super::tasks::{Progress, Status};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
 /// Marker for unknown task volume.
 pub const UNKNOWN_VOLUME: usize = usize::MAX;
 
@@ -28,11 +32,18 @@ pub const UNKNOWN_VOLUME: usize = usize::MAX;
 ///
 /// This is a simplified placeholder. The full Task trait hierarchy
 /// will be implemented in the tasks/ module.
+///
+/// The status follows the lifecycle in [`Status`] and is shared between
+/// clones, so a task cancelled through a [`TaskStore`](super::TaskStore)
+/// query is observed by the code holding the original.
 #[derive(Debug, Clone)]
 pub struct Task {
     pub description: String,
     pub volume: usize,
     start_time_millis: i64,
+    sub_tasks: Vec<Task>,
+    status: Arc<Mutex<Status>>,
+    progress: Arc<AtomicUsize>,
 }
 
 impl Task {
@@ -48,6 +59,9 @@ impl Task {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_millis() as i64,
+            sub_tasks: Vec::new(),
+            status: Arc::new(Mutex::new(Status::Pending)),
+            progress: Arc::new(AtomicUsize::new(0)),
         }
     }
 
@@ -60,6 +74,9 @@ impl Task {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_millis() as i64,
+            sub_tasks: Vec::new(),
+            status: Arc::new(Mutex::new(Status::Pending)),
+            progress: Arc::new(AtomicUsize::new(0)),
         }
     }
 
@@ -82,4 +99,81 @@ impl Task {
     pub fn start_time(&self) -> i64 {
         self.start_time_millis
     }
+
+    /// Attach subtasks to this task.
+    pub fn with_sub_tasks(mut self, sub_tasks: Vec<Task>) -> Self {
+        self.sub_tasks = sub_tasks;
+        self
+    }
+
+    /// Get subtasks.
+    pub fn sub_tasks(&self) -> &[Task] {
+        &self.sub_tasks
+    }
+
+    /// Get current status.
+    pub fn status(&self) -> Status {
+        *self.status.lock().unwrap()
+    }
+
+    /// Add `value` units of work to the progress.
+    ///
+    /// Clones of a task share their progress, so updates are visible through
+    /// the copy kept in a task store.
+    pub fn log_progress(&self, value: usize) {
+        self.progress.fetch_add(value, Ordering::Relaxed);
+    }
+
+    /// Get the units of work done so far.
+    pub fn current_progress(&self) -> usize {
+        self.progress.load(Ordering::Relaxed)
+    }
+
+    /// Get progress relative to the volume.
+    pub fn get_progress(&self) -> Progress {
+        Progress::of(self.current_progress(), self.volume)
+    }
+
+    /// Mark the task as running.
+    ///
+    /// Only a pending task can start, so a task cancelled before it started
+    /// stays cancelled.
+    pub fn start(&self) {
+        let mut status = self.status.lock().unwrap();
+        if *status == Status::Pending {
+            *status = Status::Running;
+        }
+    }
+
+    /// Mark the task as finished, unless it already reached a terminal status.
+    pub fn finish(&self) {
+        self.complete(Status::Finished);
+    }
+
+    /// Mark the task as failed, unless it already reached a terminal status.
+    pub fn fail(&self) {
+        self.complete(Status::Failed);
+    }
+
+    fn complete(&self, terminal: Status) {
+        let mut status = self.status.lock().unwrap();
+        if !status.is_terminal() {
+            *status = terminal;
+        }
+    }
+
+    /// Cancel the task and all of its subtasks.
+    ///
+    /// Tasks that already reached a terminal status keep it.
+    pub fn cancel(&self) {
+        {
+            let mut status = self.status.lock().unwrap();
+            if !status.is_terminal() {
+                *status = Status::Canceled;
+            }
+        }
+        for sub_task in &self.sub_tasks {
+            sub_task.cancel();
+        }
+    }
 }
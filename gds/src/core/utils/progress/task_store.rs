@@ -43,6 +43,27 @@ pub trait TaskStore: Send + Sync {
     /// Query specific task by username and job ID.
     fn query(&self, username: &str, job_id: &JobId) -> Option<UserTask>;
 
+    /// Find the task of a job, whichever user started it.
+    ///
+    /// Job ids are unique per job; if several users share one, an arbitrary
+    /// match is returned.
+    fn find_by_job_id(&self, job_id: &JobId) -> Option<UserTask> {
+        self.query_by_job_id(job_id).into_iter().next()
+    }
+
+    /// Cancel the task of a job together with its subtasks.
+    ///
+    /// Returns `false` if no task is stored for the job. The task stays in
+    /// the store with status [`Status::Canceled`](super::tasks::Status::Canceled)
+    /// so that the running algorithm can observe the cancellation.
+    fn cancel(&self, job_id: &JobId) -> bool {
+        let user_tasks = self.query_by_job_id(job_id);
+        for user_task in &user_tasks {
+            user_task.task.cancel();
+        }
+        !user_tasks.is_empty()
+    }
+
     /// Check if store is empty.
     fn is_empty(&self) -> bool;
 
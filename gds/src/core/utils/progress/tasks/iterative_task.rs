@@ -1,7 +1,7 @@
 #[cfg(test)]
 use super::UNKNOWN_VOLUME;
-use super::{Progress, Task, TaskVisitor};
-use std::sync::Arc;
+use super::{Progress, Status, Task, TaskVisitor};
+use std::sync::{Arc, Mutex};
 
 /// Execution modes for iterative tasks.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,8 +16,14 @@ pub enum IterativeTaskMode {
 
 /// Task that executes iterations of subtasks.
 /// Supports dynamic, open, and fixed iteration modes.
+///
+/// Iterations are driven with [`begin_iteration`](Self::begin_iteration) and
+/// [`finish_iteration`](Self::finish_iteration); subtasks for iterations that
+/// were not unrolled up front are created from the supplier on demand.
 pub struct IterativeTask {
     base: Task,
+    /// Iteration subtasks created after construction
+    added_sub_tasks: Mutex<Vec<Arc<Task>>>,
     sub_tasks_supplier: Arc<dyn Fn() -> Vec<Arc<Task>> + Send + Sync>,
     mode: IterativeTaskMode,
     max_iterations: usize,
@@ -51,12 +57,37 @@ impl IterativeTask {
 
         Self {
             base: Task::new(description, sub_tasks),
+            added_sub_tasks: Mutex::new(Vec::new()),
             sub_tasks_supplier,
             mode,
             max_iterations,
         }
     }
 
+    /// Create an iterative task that creates the subtasks of each iteration
+    /// only when the iteration begins.
+    ///
+    /// With `Some(max_iterations)` the task is [`Dynamic`](IterativeTaskMode::Dynamic),
+    /// otherwise [`Open`](IterativeTaskMode::Open). Unlike unrolling, this
+    /// costs nothing for iterations that never run.
+    pub fn incremental(
+        description: String,
+        sub_tasks_supplier: Arc<dyn Fn() -> Vec<Arc<Task>> + Send + Sync>,
+        max_iterations: Option<usize>,
+    ) -> Self {
+        let mode = match max_iterations {
+            Some(_) => IterativeTaskMode::Dynamic,
+            None => IterativeTaskMode::Open,
+        };
+        Self {
+            base: Task::new(description, vec![]),
+            added_sub_tasks: Mutex::new(Vec::new()),
+            sub_tasks_supplier,
+            mode,
+            max_iterations: max_iterations.unwrap_or(0),
+        }
+    }
+
     /// Get the base task.
     pub fn base(&self) -> &Task {
         &self.base
@@ -74,18 +105,93 @@ impl IterativeTask {
         }
     }
 
+    /// Get progress in iterations: completed iterations out of the maximum,
+    /// or of unknown volume in open mode.
+    pub fn iteration_progress(&self) -> Progress {
+        let completed = self.current_iteration();
+        match self.mode {
+            IterativeTaskMode::Open => Progress::unknown(completed),
+            _ => Progress::of(completed, self.max_iterations),
+        }
+    }
+
+    /// Get all subtasks, including iterations added after construction.
+    pub fn sub_tasks(&self) -> Vec<Arc<Task>> {
+        let mut sub_tasks = self.base.sub_tasks().to_vec();
+        sub_tasks.extend(self.added_sub_tasks.lock().unwrap().iter().cloned());
+        sub_tasks
+    }
+
+    /// Start the next iteration and return its subtasks.
+    ///
+    /// Starts the task itself on the first call. Subtasks for the iteration
+    /// are created from the supplier if they were not unrolled up front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the maximum number of iterations was already executed, or
+    /// if the previous iteration was not finished.
+    pub fn begin_iteration(&self) -> Vec<Arc<Task>> {
+        if self.base.status() == Status::Pending {
+            self.base.start();
+        }
+
+        let iteration = self.current_iteration();
+        let tasks_per_iteration = self.tasks_per_iteration();
+        let first = iteration * tasks_per_iteration;
+        if self.sub_tasks().len() < first + tasks_per_iteration {
+            if self.mode != IterativeTaskMode::Open && iteration >= self.max_iterations {
+                panic!(
+                    "Cannot begin iteration {} of iterative task '{}' with at most {} iterations.",
+                    iteration + 1,
+                    self.base.description(),
+                    self.max_iterations
+                );
+            }
+            self.add_iteration_internal();
+        }
+
+        let iteration_tasks = self.sub_tasks()[first..first + tasks_per_iteration].to_vec();
+        for sub_task in &iteration_tasks {
+            sub_task.start();
+        }
+        iteration_tasks
+    }
+
+    /// Finish the subtasks of the running iteration.
+    pub fn finish_iteration(&self) {
+        let tasks_per_iteration = self.tasks_per_iteration();
+        let first = self.current_iteration() * tasks_per_iteration;
+        for sub_task in self
+            .sub_tasks()
+            .iter()
+            .skip(first)
+            .take(tasks_per_iteration)
+        {
+            if sub_task.status() == Status::Running {
+                sub_task.finish();
+            }
+        }
+    }
+
     /// Get next subtask after validation.
     #[allow(dead_code)] // Reserved for iteration control
     fn next_subtask_after_validation(&self) -> Option<Arc<Task>> {
+        let pending = |sub_tasks: Vec<Arc<Task>>| {
+            sub_tasks
+                .into_iter()
+                .find(|sub_task| sub_task.status() == Status::Pending)
+        };
+
         // First check if there's a pending subtask
-        if let Some(next) = self.base.next_subtask() {
+        if let Some(next) = pending(self.sub_tasks()) {
             return Some(next);
         }
 
         // For open mode or if we haven't reached max iterations, add more
         if self.mode == IterativeTaskMode::Open || self.can_add_more_iterations() {
             self.add_iteration_internal();
-            self.base.next_subtask()
+            pending(self.sub_tasks())
         } else {
             None
         }
@@ -115,7 +221,7 @@ impl IterativeTask {
         }
 
         let mut completed = 0;
-        for sub_task in self.base.sub_tasks() {
+        for sub_task in self.sub_tasks() {
             let status = sub_task.status();
             if status.is_terminal() {
                 completed += 1;
@@ -149,14 +255,10 @@ impl IterativeTask {
         }
     }
 
-    /// Add a new iteration (internal, modifies base task's subtasks).
-    /// Note: This is a simplified version since Rust doesn't allow easy mutation of Arc<Task>.
-    #[allow(dead_code)] // Reserved for dynamic iteration addition
+    /// Add the subtasks of a new iteration.
     fn add_iteration_internal(&self) {
-        // In the Java version, this modifies the subtasks list.
-        // In Rust, we would need to use interior mutability (Mutex) on the subtasks.
-        // For now, this is a placeholder that matches the interface.
-        // A full implementation would require refactoring Task to have mutable subtasks.
+        let iteration_tasks = (self.sub_tasks_supplier)();
+        self.added_sub_tasks.lock().unwrap().extend(iteration_tasks);
     }
 
     /// Accept a visitor (Visitor pattern).
@@ -168,7 +270,6 @@ impl IterativeTask {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::utils::progress::tasks::Status;
 
     fn create_sub_tasks_supplier() -> Arc<dyn Fn() -> Vec<Arc<Task>> + Send + Sync> {
         Arc::new(|| {
@@ -375,4 +476,63 @@ mod tests {
         // Open mode can always add more
         assert!(task.can_add_more_iterations());
     }
+
+    #[test]
+    fn test_iterative_task_begin_and_finish_iterations() {
+        let supplier: Arc<dyn Fn() -> Vec<Arc<Task>> + Send + Sync> =
+            Arc::new(|| vec![Arc::new(Task::new("Superstep".to_string(), vec![]))]);
+        let task = IterativeTask::incremental("Supersteps".to_string(), supplier, Some(20));
+        assert_eq!(task.mode(), IterativeTaskMode::Dynamic);
+        assert!(task.sub_tasks().is_empty());
+
+        for iteration in 0..3 {
+            let iteration_tasks = task.begin_iteration();
+            assert_eq!(iteration_tasks.len(), 1);
+            assert_eq!(iteration_tasks[0].status(), Status::Running);
+            assert_eq!(task.current_iteration(), iteration);
+            task.finish_iteration();
+        }
+        assert_eq!(task.base().status(), Status::Running);
+
+        let sub_tasks = task.sub_tasks();
+        assert_eq!(sub_tasks.len(), 3);
+        assert!(sub_tasks
+            .iter()
+            .all(|sub_task| sub_task.status() == Status::Finished));
+        assert_eq!(task.current_iteration(), 3);
+        assert_eq!(task.iteration_progress(), Progress::of(3, 20));
+
+        task.finish();
+        assert_eq!(task.base().status(), Status::Finished);
+    }
+
+    #[test]
+    fn test_iterative_task_begin_iteration_uses_unrolled_subtasks() {
+        let supplier = create_sub_tasks_supplier();
+        let sub_tasks = unroll_tasks(&supplier, 1);
+        let task = IterativeTask::new(
+            "Unrolled".to_string(),
+            sub_tasks,
+            supplier,
+            IterativeTaskMode::Fixed,
+        );
+
+        let iteration_tasks = task.begin_iteration();
+        assert!(Arc::ptr_eq(
+            &iteration_tasks[0],
+            &task.base().sub_tasks()[0]
+        ));
+        task.finish_iteration();
+        assert_eq!(task.iteration_progress(), Progress::of(1, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot begin iteration 2")]
+    fn test_iterative_task_begin_iteration_beyond_max() {
+        let supplier = create_sub_tasks_supplier();
+        let task = IterativeTask::incremental("Bounded".to_string(), supplier, Some(1));
+        task.begin_iteration();
+        task.finish_iteration();
+        task.begin_iteration();
+    }
 }
@@ -19,6 +19,7 @@
 //! Progress value representing task completion state.
 
 use std::fmt;
+use std::time::Duration;
 
 /// Marker for unknown volume.
 pub const UNKNOWN_VOLUME: usize = usize::MAX;
@@ -96,6 +97,25 @@ impl Progress {
     pub fn has_unknown_volume(&self) -> bool {
         self.volume == UNKNOWN_VOLUME
     }
+
+    /// Estimate the remaining time from the throughput so far.
+    ///
+    /// Assumes the remaining volume is processed at the rate observed over
+    /// `elapsed`. Returns `None` for unknown volume or before any progress was
+    /// made, and zero once the task is complete. Also returns `None` if the
+    /// estimate does not fit in a `Duration`.
+    pub fn eta(&self, elapsed: Duration) -> Option<Duration> {
+        if self.has_unknown_volume() || self.progress == 0 {
+            return None;
+        }
+        if self.is_complete() {
+            return Some(Duration::ZERO);
+        }
+        let remaining = (self.volume - self.progress) as u128;
+        let nanos = elapsed.as_nanos().checked_mul(remaining)? / self.progress as u128;
+        let secs = u64::try_from(nanos / 1_000_000_000).ok()?;
+        Some(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+    }
 }
 
 impl fmt::Display for Progress {
@@ -117,6 +137,7 @@ impl fmt::Display for Progress {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::utils::progress::tasks::LeafTask;
 
     #[test]
     fn test_progress_creation() {
@@ -159,6 +180,26 @@ mod tests {
         assert_eq!(done.percentage(), 100.0);
     }
 
+    #[test]
+    fn test_eta() {
+        let task = LeafTask::new("leaf".to_string(), 1000);
+        task.log_progress(250);
+
+        let elapsed = Duration::from_secs(20);
+        assert_eq!(task.get_progress().eta(elapsed), Some(elapsed * 3));
+
+        assert_eq!(Progress::zero(100).eta(elapsed), None);
+        assert_eq!(Progress::unknown(42).eta(elapsed), None);
+        assert_eq!(Progress::of(150, 100).eta(elapsed), Some(Duration::ZERO));
+
+        // Estimates beyond Duration::MAX are unknown rather than a panic
+        assert_eq!(Progress::of(1, usize::MAX - 1).eta(Duration::MAX), None);
+        assert_eq!(
+            Progress::of(1, 1 << 40).eta(Duration::from_secs(1 << 40)),
+            None
+        );
+    }
+
     #[test]
     fn test_percentage() {
         let p = Progress::of(25, 100);
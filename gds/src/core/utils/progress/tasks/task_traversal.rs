@@ -18,7 +18,9 @@
 
 //! Task hierarchy traversal utilities.
 
-use super::{DepthAwareTaskVisitor, Task};
+use super::{DepthAwareTaskVisitor, Task, TaskVisitor};
+use std::cell::RefCell;
+use std::fmt::Write;
 
 /// Utility for traversing task hierarchies with depth tracking.
 ///
@@ -54,6 +56,40 @@ impl TaskTraversal {
         Self::visit_pre_order_with_depth_internal(task, visitor, 0);
     }
 
+    /// Render the task hierarchy as an indented tree, one task per line.
+    ///
+    /// Each level of depth indents the description by two spaces.
+    ///
+    /// # Example
+    /// ```
+    /// use gds::core::utils::progress::tasks::{Task, TaskTraversal};
+    /// use std::sync::Arc;
+    ///
+    /// let child = Arc::new(Task::new("child".to_string(), vec![]));
+    /// let root = Task::new("root".to_string(), vec![child]);
+    /// assert_eq!(TaskTraversal::to_tree_string(&root), "root\n  child\n");
+    /// ```
+    pub fn to_tree_string(root: &Task) -> String {
+        let mut renderer = TreeRenderer::default();
+        Self::visit_pre_order_with_depth(root, &mut renderer);
+        renderer.output.into_inner()
+    }
+
+    /// Render the task hierarchy as a Graphviz DOT digraph.
+    ///
+    /// Tasks become nodes `n0, n1, ...` in pre-order, labelled with their
+    /// description; every parent-child relationship becomes an edge.
+    pub fn to_dot(root: &Task) -> String {
+        let mut renderer = DotRenderer::default();
+        Self::visit_pre_order_with_depth(root, &mut renderer);
+
+        let mut dot = String::from("digraph tasks {\n");
+        dot.push_str(&renderer.nodes.into_inner());
+        dot.push_str(&renderer.edges.into_inner());
+        dot.push_str("}\n");
+        dot
+    }
+
     /// Internal recursive implementation of pre-order traversal with depth.
     fn visit_pre_order_with_depth_internal(
         task: &Task,
@@ -73,6 +109,74 @@ impl TaskTraversal {
     }
 }
 
+/// Appends one indented line per visited task.
+#[derive(Default)]
+struct TreeRenderer {
+    output: RefCell<String>,
+    depth: usize,
+}
+
+impl TaskVisitor for TreeRenderer {
+    fn visit(&self, task: &Task) {
+        let mut output = self.output.borrow_mut();
+        let _ = writeln!(output, "{}{}", "  ".repeat(self.depth), task.description());
+    }
+}
+
+impl DepthAwareTaskVisitor for TreeRenderer {
+    fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+    }
+
+    fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+/// Collects DOT node and edge statements.
+///
+/// `ancestors[d]` is the id of the most recently visited task at depth `d`,
+/// which in pre-order is the parent of the next task at depth `d + 1`.
+#[derive(Default)]
+struct DotRenderer {
+    nodes: RefCell<String>,
+    edges: RefCell<String>,
+    ancestors: RefCell<Vec<usize>>,
+    next_id: RefCell<usize>,
+    depth: usize,
+}
+
+impl TaskVisitor for DotRenderer {
+    fn visit(&self, task: &Task) {
+        let mut next_id = self.next_id.borrow_mut();
+        let id = *next_id;
+        *next_id += 1;
+
+        let label = task
+            .description()
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"");
+        let _ = writeln!(self.nodes.borrow_mut(), "  n{} [label=\"{}\"];", id, label);
+
+        let mut ancestors = self.ancestors.borrow_mut();
+        ancestors.truncate(self.depth);
+        if let Some(parent) = ancestors.last() {
+            let _ = writeln!(self.edges.borrow_mut(), "  n{} -> n{};", parent, id);
+        }
+        ancestors.push(id);
+    }
+}
+
+impl DepthAwareTaskVisitor for DotRenderer {
+    fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+    }
+
+    fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,4 +301,51 @@ mod tests {
         assert!(left_index < leaf_index);
         assert!(leaf_index < right_index);
     }
+
+    fn pipeline() -> Task {
+        let load = Arc::new(Task::new("load".to_string(), vec![]));
+        let level1 = Arc::new(Task::new("level 1".to_string(), vec![]));
+        let level2 = Arc::new(Task::new("level \"2\"".to_string(), vec![]));
+        let compute = Arc::new(Task::new("compute".to_string(), vec![level1, level2]));
+        let write = Arc::new(Task::new("write".to_string(), vec![]));
+        Task::new("pipeline".to_string(), vec![load, compute, write])
+    }
+
+    #[test]
+    fn test_to_tree_string() {
+        let tree = TaskTraversal::to_tree_string(&pipeline());
+        let lines: Vec<&str> = tree.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "pipeline",
+                "  load",
+                "  compute",
+                "    level 1",
+                "    level \"2\"",
+                "  write",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let dot = TaskTraversal::to_dot(&pipeline());
+        assert!(dot.starts_with("digraph tasks {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.contains("  n0 [label=\"pipeline\"];"));
+        assert!(dot.contains("  n4 [label=\"level \\\"2\\\"\"];"));
+
+        let edges: Vec<&str> = dot.lines().filter(|line| line.contains("->")).collect();
+        assert_eq!(
+            edges,
+            vec![
+                "  n0 -> n1;",
+                "  n0 -> n2;",
+                "  n2 -> n3;",
+                "  n2 -> n4;",
+                "  n0 -> n5;",
+            ]
+        );
+    }
 }
@@ -0,0 +1,228 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A bounded priority queue retaining the `k` best elements by a comparator.
+///
+/// Elements that compare as [`Ordering::Less`] are considered better. Internally
+/// the queue is a binary heap with the *worst* retained element at the root, so
+/// an insert is `O(log k)` and overflowing elements evict the current worst.
+///
+/// Ties are resolved by insertion order: among equal elements the one offered
+/// first is kept, which makes the retained set deterministic.
+pub struct BoundedPriorityQueue<T, C = fn(&T, &T) -> Ordering> {
+    bound: usize,
+    heap: Vec<Entry<T>>,
+    comparator: C,
+    next_sequence: u64,
+}
+
+struct Entry<T> {
+    value: T,
+    sequence: u64,
+}
+
+impl<T: Ord> BoundedPriorityQueue<T> {
+    /// Creates a queue that keeps the smallest elements (lower is better).
+    pub fn min(bound: usize) -> Self {
+        Self::with_comparator(bound, |a: &T, b: &T| a.cmp(b))
+    }
+
+    /// Creates a queue that keeps the largest elements (higher is better).
+    pub fn max(bound: usize) -> Self {
+        Self::with_comparator(bound, |a: &T, b: &T| b.cmp(a))
+    }
+}
+
+impl<T, C> BoundedPriorityQueue<T, C>
+where
+    C: Fn(&T, &T) -> Ordering,
+{
+    /// Creates a queue that keeps the `bound` best elements, where `comparator`
+    /// returns [`Ordering::Less`] if the first argument is better.
+    pub fn with_comparator(bound: usize, comparator: C) -> Self {
+        assert!(bound > 0, "queue bound must be positive");
+        Self {
+            bound,
+            heap: Vec::with_capacity(bound),
+            comparator,
+            next_sequence: 0,
+        }
+    }
+
+    /// Tries to add an element, returning `true` if the element was retained.
+    pub fn offer(&mut self, value: T) -> bool {
+        let entry = Entry {
+            value,
+            sequence: self.next_sequence,
+        };
+        self.next_sequence += 1;
+
+        if self.heap.len() < self.bound {
+            self.heap.push(entry);
+            self.sift_up(self.heap.len() - 1);
+            return true;
+        }
+
+        // A later entry never beats an equal earlier one, so `Less` is required.
+        if self.compare(&entry, &self.heap[0]) == Ordering::Less {
+            self.heap[0] = entry;
+            self.sift_down(0);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the worst retained element, i.e. the next one to be evicted.
+    pub fn peek_worst(&self) -> Option<&T> {
+        self.heap.first().map(|entry| &entry.value)
+    }
+
+    /// Returns `true` if the queue holds `bound` elements.
+    pub fn is_full(&self) -> bool {
+        self.heap.len() == self.bound
+    }
+
+    /// Returns the maximum number of retained elements.
+    pub fn bound(&self) -> usize {
+        self.bound
+    }
+
+    /// Returns the current number of elements in the queue.
+    pub fn size(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns `true` if the queue holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Iterates over the retained elements in unspecified order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.heap.iter().map(|entry| &entry.value)
+    }
+
+    /// Removes all elements, keeping the allocated storage.
+    pub fn clear(&mut self) {
+        self.heap.clear();
+        self.next_sequence = 0;
+    }
+
+    /// Removes all elements and returns them from best to worst.
+    pub fn drain_sorted(&mut self) -> Vec<T> {
+        let mut entries = std::mem::take(&mut self.heap);
+        entries.sort_by(|a, b| self.compare(a, b));
+        self.next_sequence = 0;
+        entries.into_iter().map(|entry| entry.value).collect()
+    }
+
+    /// Consumes the queue and returns its elements from best to worst.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        self.drain_sorted()
+    }
+
+    fn compare(&self, a: &Entry<T>, b: &Entry<T>) -> Ordering {
+        (self.comparator)(&a.value, &b.value).then(a.sequence.cmp(&b.sequence))
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if self.compare(&self.heap[index], &self.heap[parent]) == Ordering::Greater {
+                self.heap.swap(index, parent);
+                index = parent;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        let len = self.heap.len();
+        loop {
+            let left = 2 * index + 1;
+            let right = left + 1;
+            let mut worst = index;
+
+            if left < len && self.compare(&self.heap[left], &self.heap[worst]) == Ordering::Greater
+            {
+                worst = left;
+            }
+            if right < len
+                && self.compare(&self.heap[right], &self.heap[worst]) == Ordering::Greater
+            {
+                worst = right;
+            }
+            if worst == index {
+                break;
+            }
+            self.heap.swap(index, worst);
+            index = worst;
+        }
+    }
+}
+
+impl<T: fmt::Debug, C> fmt::Debug for BoundedPriorityQueue<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoundedPriorityQueue")
+            .field("bound", &self.bound)
+            .field("size", &self.heap.len())
+            .field(
+                "elements",
+                &self
+                    .heap
+                    .iter()
+                    .map(|entry| &entry.value)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoundedPriorityQueue;
+
+    #[test]
+    fn retains_exactly_the_k_best() {
+        let mut queue = BoundedPriorityQueue::max(10);
+        // Offer 0..100 in a scrambled but deterministic order.
+        for i in 0..100u64 {
+            queue.offer((i * 37) % 100);
+        }
+        assert_eq!(queue.size(), 10);
+        assert!(queue.is_full());
+        assert_eq!(queue.peek_worst(), Some(&90));
+        assert_eq!(
+            queue.into_sorted_vec(),
+            vec![99, 98, 97, 96, 95, 94, 93, 92, 91, 90]
+        );
+    }
+
+    #[test]
+    fn ties_keep_earliest_offered() {
+        let mut queue =
+            BoundedPriorityQueue::with_comparator(2, |a: &(u32, char), b: &(u32, char)| {
+                a.0.cmp(&b.0)
+            });
+        assert!(queue.offer((1, 'a')));
+        assert!(queue.offer((1, 'b')));
+        assert!(!queue.offer((1, 'c')));
+        assert!(queue.offer((0, 'd')));
+        assert_eq!(queue.into_sorted_vec(), vec![(0, 'd'), (1, 'a')]);
+    }
+
+    #[test]
+    fn drain_yields_sorted_and_reuses_queue() {
+        let mut queue = BoundedPriorityQueue::min(4);
+        for value in [7, 3, 9, 1, 5, 8] {
+            queue.offer(value);
+        }
+        assert_eq!(queue.drain_sorted(), vec![1, 3, 5, 7]);
+        assert!(queue.is_empty());
+
+        queue.offer(2);
+        assert_eq!(queue.drain_sorted(), vec![2]);
+    }
+}
@@ -1,10 +1,12 @@
 mod bounded_long_long_priority_queue;
 mod bounded_long_priority_queue;
+mod bounded_priority_queue;
 mod huge_long_priority_queue;
 mod queue_based_spliterator;
 
 pub use bounded_long_long_priority_queue::BoundedLongLongPriorityQueue;
 pub use bounded_long_priority_queue::BoundedLongPriorityQueue;
+pub use bounded_priority_queue::BoundedPriorityQueue;
 pub use huge_long_priority_queue::HugeLongPriorityQueue;
 pub use queue_based_spliterator::{
     BlockingQueue, BlockingQueueError, QueueBasedSpliterator, SpliteratorCharacteristics,
@@ -91,7 +91,8 @@ impl SplittableRandom {
         self.next64() as u32
     }
 
-    fn mix64(seed: u64) -> u64 {
+    /// SplitMix64 finalizer: a bijective, well-distributed hash of `seed`.
+    pub(crate) fn mix64(seed: u64) -> u64 {
         let mut z = seed;
         z ^= z >> 30;
         z = z.wrapping_mul(0xbf58_476d_1ce4_e5b9);
@@ -1,3 +1,8 @@
+use crate::config::ConfigError;
+use crate::projection::eval::procedure::{
+    AlgorithmError, ConfigError as ProcedureConfigError, ExecutorError, ValidationError,
+};
+use crate::types::graph_store::GraphStoreError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -10,6 +15,48 @@ pub enum ApiError {
     InternalError(String),
 }
 
+/// Crate-wide error unifying the errors of the individual layers
+///
+/// Graph store, configuration, validation, algorithm and executor errors all
+/// convert into `GdsError`, so code spanning several layers can propagate
+/// them with `?` into a single `Result<T, GdsError>`. Each variant wraps the
+/// original error unchanged: `Display` and `source()` are forwarded, and
+/// matching on the variant recovers the specific error for precise handling.
+///
+/// ```rust,ignore
+/// fn load_and_run(store: &mut DefaultGraphStore) -> Result<(), GdsError> {
+///     store.add_node_property_f64("seed".into(), vec![0.0; 3])?; // GraphStoreError
+///     executor.compute(&mut algorithm, config)?; // ExecutorError
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Error)]
+pub enum GdsError {
+    #[error(transparent)]
+    GraphStore(#[from] GraphStoreError),
+
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+
+    #[error(transparent)]
+    ProcedureConfig(#[from] ProcedureConfigError),
+
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+
+    #[error(transparent)]
+    Algorithm(#[from] AlgorithmError),
+
+    #[error(transparent)]
+    Executor(#[from] ExecutorError),
+
+    #[error(transparent)]
+    Api(#[from] ApiError),
+
+    #[error(transparent)]
+    MemoryEstimation(#[from] MemoryEstimationError),
+}
+
 /// Memory estimation not implemented for this algorithm
 ///
 /// **Translation**: `MemoryEstimationNotImplementedException.java`
@@ -72,4 +119,65 @@ mod tests {
         let internal = ApiError::InternalError("oops".to_string());
         assert_eq!(internal.to_string(), "Internal Error: oops");
     }
+
+    #[test]
+    fn test_gds_error_wraps_layer_errors() {
+        fn assert_wrapped(
+            error: impl Into<GdsError> + ToString,
+            is_variant: fn(&GdsError) -> bool,
+        ) {
+            let message = error.to_string();
+            let wrapped: GdsError = error.into();
+            assert!(is_variant(&wrapped), "unexpected variant {:?}", wrapped);
+            assert_eq!(wrapped.to_string(), message);
+        }
+
+        assert_wrapped(
+            GraphStoreError::PropertyNotFound("weight".to_string()),
+            |e| {
+                matches!(
+                    e,
+                    GdsError::GraphStore(GraphStoreError::PropertyNotFound(_))
+                )
+            },
+        );
+        assert_wrapped(ConfigError::EmptyPath, |e| {
+            matches!(e, GdsError::Config(ConfigError::EmptyPath))
+        });
+        assert_wrapped(
+            ProcedureConfigError::MissingParameter("concurrency".to_string()),
+            |e| matches!(e, GdsError::ProcedureConfig(_)),
+        );
+        assert_wrapped(ValidationError::Parameter("bad".to_string()), |e| {
+            matches!(e, GdsError::Validation(_))
+        });
+        assert_wrapped(
+            AlgorithmError::Convergence {
+                iterations: 20,
+                message: "tolerance not reached".to_string(),
+            },
+            |e| matches!(e, GdsError::Algorithm(AlgorithmError::Convergence { .. })),
+        );
+        assert_wrapped(ExecutorError::EmptyGraph, |e| {
+            matches!(e, GdsError::Executor(ExecutorError::EmptyGraph))
+        });
+        assert_wrapped(ApiError::NotFound("graph".to_string()), |e| {
+            matches!(e, GdsError::Api(_))
+        });
+        assert_wrapped(MemoryEstimationError::NotImplemented, |e| {
+            matches!(e, GdsError::MemoryEstimation(_))
+        });
+    }
+
+    #[test]
+    fn test_gds_error_propagates_with_question_mark() {
+        fn fails() -> Result<(), GdsError> {
+            Err(GraphStoreError::InvalidOperation("read only".to_string()))?;
+            Ok(())
+        }
+
+        let error = fails().unwrap_err();
+        assert_eq!(error.to_string(), "Invalid operation: read only");
+        assert!(matches!(error, GdsError::GraphStore(_)));
+    }
 }
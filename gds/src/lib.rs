@@ -27,6 +27,9 @@ pub mod values;
 pub use types::*;
 pub use values::*;
 
+// Fluent algorithm API entry point
+pub use procedures::facades::Graph;
+
 // Re-export procedure macros for procedures module
 #[cfg(feature = "procedures")]
 pub use projection::codegen::algorithm::*;  
\ No newline at end of file
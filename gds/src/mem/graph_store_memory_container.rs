@@ -3,6 +3,7 @@
 //! Tracks memory usage per user for stored graphs.
 
 use super::user_entity_memory::UserEntityMemory;
+use crate::projection::RelationshipType;
 use std::collections::HashMap;
 
 /// Event representing a graph being added
@@ -61,6 +62,8 @@ impl GraphStoreRemovedEvent {
 pub struct GraphStoreMemoryContainer {
     // Map: username -> (graph_name -> memory_bytes)
     graphs_memory: HashMap<String, HashMap<String, usize>>,
+    // Map: username -> (graph_name -> (relationship type -> memory_bytes))
+    relationship_types_memory: HashMap<String, HashMap<String, HashMap<RelationshipType, usize>>>,
     graph_store_reserved_memory_total: usize,
 }
 
@@ -72,6 +75,7 @@ impl GraphStoreMemoryContainer {
 
     /// Adds a graph and returns the new total reserved memory
     pub fn add_graph(&mut self, user: &str, graph_name: &str, memory_in_bytes: usize) -> usize {
+        self.remove_breakdown(user, graph_name);
         self.graph_store_reserved_memory_total += memory_in_bytes;
 
         self.graphs_memory
@@ -82,6 +86,25 @@ impl GraphStoreMemoryContainer {
         self.graph_store_reserved_memory_total
     }
 
+    /// Adds a graph with the memory of each of its relationship types
+    ///
+    /// The graph is recorded with the sum of `breakdown` as its memory, so
+    /// the per-graph and total figures stay consistent with the breakdown.
+    /// Returns the new total reserved memory.
+    pub fn add_graph_detailed(
+        &mut self,
+        user: &str,
+        graph_name: &str,
+        breakdown: HashMap<RelationshipType, usize>,
+    ) -> usize {
+        let total = self.add_graph(user, graph_name, breakdown.values().sum());
+        self.relationship_types_memory
+            .entry(user.to_string())
+            .or_default()
+            .insert(graph_name.to_string(), breakdown);
+        total
+    }
+
     /// Adds a graph using an event and returns the new total reserved memory
     pub fn add_graph_event(&mut self, event: GraphStoreAddedEvent) -> usize {
         self.add_graph(event.user(), event.graph_name(), event.memory_in_bytes())
@@ -97,10 +120,20 @@ impl GraphStoreMemoryContainer {
                 self.graph_store_reserved_memory_total -= memory_to_remove;
             }
         }
+        self.remove_breakdown(user, graph_name);
 
         self.graph_store_reserved_memory_total
     }
 
+    fn remove_breakdown(&mut self, user: &str, graph_name: &str) {
+        if let Some(user_breakdowns) = self.relationship_types_memory.get_mut(user) {
+            user_breakdowns.remove(graph_name);
+            if user_breakdowns.is_empty() {
+                self.relationship_types_memory.remove(user);
+            }
+        }
+    }
+
     /// Removes a graph using an event and returns the new total reserved memory
     pub fn remove_graph_event(&mut self, event: GraphStoreRemovedEvent) -> usize {
         self.remove_graph(event.user(), event.graph_name())
@@ -142,6 +175,18 @@ impl GraphStoreMemoryContainer {
             .unwrap_or(0)
     }
 
+    /// Returns the memory per relationship type of a graph
+    ///
+    /// `None` if the graph was not added with
+    /// [`add_graph_detailed`](Self::add_graph_detailed).
+    pub fn relationship_type_memory(
+        &self,
+        user: &str,
+        graph_name: &str,
+    ) -> Option<&HashMap<RelationshipType, usize>> {
+        self.relationship_types_memory.get(user)?.get(graph_name)
+    }
+
     /// Returns all users who have graphs
     pub fn graph_users(&self) -> Vec<String> {
         self.graphs_memory.keys().cloned().collect()
@@ -266,4 +311,36 @@ mod tests {
 
         assert_eq!(container.graph_store_reserved_memory(), 0);
     }
+
+    #[test]
+    fn test_relationship_type_breakdown() {
+        use crate::types::graph::RelationshipTopology;
+
+        let knows = RelationshipTopology::new(vec![vec![1, 2], vec![2], vec![]], None);
+        let likes = RelationshipTopology::new(
+            vec![vec![1], vec![0, 2], vec![0, 1]],
+            Some(vec![vec![1, 2], vec![0, 2], vec![1]]),
+        );
+        let breakdown = HashMap::from([
+            (RelationshipType::of("KNOWS"), knows.memory_usage_bytes()),
+            (RelationshipType::of("LIKES"), likes.memory_usage_bytes()),
+        ]);
+        assert!(likes.memory_usage_bytes() > knows.memory_usage_bytes());
+
+        let mut container = GraphStoreMemoryContainer::new();
+        container.add_graph("alice", "other", 500);
+        let total = container.add_graph_detailed("alice", "social", breakdown.clone());
+
+        let expected = knows.memory_usage_bytes() + likes.memory_usage_bytes();
+        assert_eq!(total, 500 + expected);
+        assert_eq!(container.memory_of_graphs("alice"), 500 + expected);
+        assert_eq!(
+            container.relationship_type_memory("alice", "social"),
+            Some(&breakdown)
+        );
+        assert_eq!(container.relationship_type_memory("alice", "other"), None);
+
+        assert_eq!(container.remove_graph("alice", "social"), 500);
+        assert_eq!(container.relationship_type_memory("alice", "social"), None);
+    }
 }
@@ -2,6 +2,7 @@
 // pub mod directed_edge_splitter;
 // pub mod edge_splitter;
 pub mod fraction_splitter;
+pub mod node_split;
 // pub mod split_relationships;
 pub mod stratified_kfold_splitter;
 pub mod training_examples_split;
@@ -13,6 +14,7 @@ pub mod training_examples_split;
 // pub use directed_edge_splitter::DirectedEdgeSplitter;
 // pub use edge_splitter::{EdgeSplitter, SplitResult};
 pub use fraction_splitter::FractionSplitter;
+pub use node_split::split_nodes;
 // pub use split_relationships::SplitRelationships;
 pub use stratified_kfold_splitter::StratifiedKFoldSplitter;
 pub use training_examples_split::{ReadOnlyHugeLongArray, TrainingExamplesSplit};
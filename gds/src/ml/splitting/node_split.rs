@@ -0,0 +1,80 @@
+//! Reproducible train/test splits of node ids.
+//!
+//! Every node is assigned by hashing `(node_id, seed)` instead of shuffling,
+//! so a node always lands in the same split for a given seed, no matter in
+//! which order or on how many threads the nodes are split.
+
+use crate::core::utils::shuffle::SplittableRandom;
+
+/// Split `node_ids` into `(train, test)` sets.
+///
+/// A node goes to the test set if its hash, mapped uniformly to `[0, 1)`, is
+/// below `test_fraction`, so the test set holds roughly that fraction of the
+/// nodes. Both sets keep the input order.
+///
+/// # Panics
+///
+/// Panics if `test_fraction` is not within `[0, 1]`.
+pub fn split_nodes(node_ids: &[u64], test_fraction: f64, seed: u64) -> (Vec<u64>, Vec<u64>) {
+    assert!(
+        (0.0..=1.0).contains(&test_fraction),
+        "test fraction must be within [0, 1], got {}",
+        test_fraction
+    );
+
+    let seed_hash = SplittableRandom::mix64(seed);
+    node_ids
+        .iter()
+        .copied()
+        .partition(|&node_id| unit_hash(node_id, seed_hash) >= test_fraction)
+}
+
+/// Hash of `node_id` under the hashed seed, uniform in `[0, 1)`.
+fn unit_hash(node_id: u64, seed_hash: u64) -> f64 {
+    let hash = SplittableRandom::mix64(node_id ^ seed_hash);
+    (hash >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_split_is_reproducible_and_order_independent() {
+        let nodes: Vec<u64> = (0..10_000).collect();
+        let (train, test) = split_nodes(&nodes, 0.2, 42);
+        assert_eq!(split_nodes(&nodes, 0.2, 42), (train.clone(), test.clone()));
+
+        let reversed: Vec<u64> = nodes.iter().rev().copied().collect();
+        let (_, reversed_test) = split_nodes(&reversed, 0.2, 42);
+        assert_eq!(
+            reversed_test.into_iter().collect::<HashSet<_>>(),
+            test.iter().copied().collect::<HashSet<_>>()
+        );
+
+        let (_, other_test) = split_nodes(&nodes, 0.2, 7);
+        assert_ne!(other_test, test);
+    }
+
+    #[test]
+    fn test_split_is_disjoint_covering_and_proportional() {
+        let nodes: Vec<u64> = (0..10_000).map(|node| node * 3 + 1).collect();
+        let (train, test) = split_nodes(&nodes, 0.3, 1);
+
+        let train_set: HashSet<u64> = train.iter().copied().collect();
+        let test_set: HashSet<u64> = test.iter().copied().collect();
+        assert!(train_set.is_disjoint(&test_set));
+        assert_eq!(train.len() + test.len(), nodes.len());
+        assert_eq!(
+            &train_set | &test_set,
+            nodes.iter().copied().collect::<HashSet<_>>()
+        );
+
+        let fraction = test.len() as f64 / nodes.len() as f64;
+        assert!((fraction - 0.3).abs() < 0.02, "test fraction {}", fraction);
+
+        assert_eq!(split_nodes(&nodes, 0.0, 1).1.len(), 0);
+        assert_eq!(split_nodes(&nodes, 1.0, 1).0.len(), 0);
+    }
+}
@@ -0,0 +1,59 @@
+//! PregelCheckpoint - Serializable snapshot of a Pregel computation
+//!
+//! A checkpoint is taken between supersteps and captures everything the BSP
+//! loop needs to continue: the node values, which nodes voted to halt, the
+//! messages waiting for the next superstep and the superstep number itself.
+//! Feed it to [`PregelBuilder::resume_from`](crate::pregel::PregelBuilder::resume_from)
+//! to pick the computation up again, possibly in another process.
+
+use crate::types::ValueType;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Values of one node property, indexed by node id.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NodeValueColumn {
+    Double(Vec<f64>),
+    Long(Vec<i64>),
+    LongArray(Vec<Vec<i64>>),
+    DoubleArray(Vec<Vec<f64>>),
+}
+
+impl NodeValueColumn {
+    /// Value type of the property this column holds.
+    pub fn value_type(&self) -> ValueType {
+        match self {
+            NodeValueColumn::Double(_) => ValueType::Double,
+            NodeValueColumn::Long(_) => ValueType::Long,
+            NodeValueColumn::LongArray(_) => ValueType::LongArray,
+            NodeValueColumn::DoubleArray(_) => ValueType::DoubleArray,
+        }
+    }
+}
+
+/// Snapshot of a Pregel computation between two supersteps.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PregelCheckpoint {
+    /// The superstep a resumed computation starts with
+    pub superstep: usize,
+
+    /// Whether the computation had already converged
+    pub did_converge: bool,
+
+    /// Node property values, keyed by property
+    pub node_values: BTreeMap<String, NodeValueColumn>,
+
+    /// Vote-to-halt flag per node
+    pub voted_to_halt: Vec<bool>,
+
+    /// Messages waiting for delivery per node, `None` if the messenger
+    /// cannot enumerate them
+    pub messages: Option<Vec<Vec<f64>>>,
+}
+
+impl PregelCheckpoint {
+    /// Number of nodes the checkpoint holds state for.
+    pub fn node_count(&self) -> usize {
+        self.voted_to_halt.len()
+    }
+}
@@ -266,6 +266,7 @@ impl<C: PregelRuntimeConfig + Clone, I: MessageIterator> ForkJoinComputeStep<C,
     fn compute_batch(&mut self) {
         let is_initial_superstep = self.compute_context.is_initial_superstep();
 
+        self.messenger.begin_batch();
         self.node_batch.consume(|node_id_usize| {
             // Convert usize node_id from Partition to u64 for contexts
             let node_id = node_id_usize as u64;
@@ -302,6 +303,7 @@ impl<C: PregelRuntimeConfig + Clone, I: MessageIterator> ForkJoinComputeStep<C,
                 (self.compute_fn)(&mut self.compute_context, &mut messages);
             }
         });
+        self.messenger.end_batch();
 
         // Log progress for entire batch
         if let Some(task) = &self.progress_task {
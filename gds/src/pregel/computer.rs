@@ -9,12 +9,14 @@
 //! - Abstract interface with concrete ForkJoin implementation
 
 use crate::collections::HugeAtomicBitSet;
+use crate::core::utils::partition::{PartitionUtils, Partitioning};
 use crate::core::utils::progress::tasks::LeafTask;
 use crate::pregel::{
     ComputeFn, ForkJoinComputeStep, InitFn, MessageIterator, Messenger, NodeValue, Partition,
     PregelRuntimeConfig,
 };
 use crate::types::graph::Graph;
+use rayon::prelude::*;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
@@ -88,8 +90,11 @@ pub struct ForkJoinComputer<C: PregelRuntimeConfig + Clone, I: MessageIterator>
     /// Flag tracking if any message was sent in current iteration
     sent_message: Arc<AtomicBool>,
 
-    /// Root task for current iteration (set by init_iteration)
-    root_task: Option<ForkJoinComputeStep<C, I>>,
+    /// Node batches each iteration starts from, one root task per batch
+    partitions: Vec<Partition>,
+
+    /// Root tasks for current iteration (set by init_iteration)
+    root_tasks: Vec<ForkJoinComputeStep<C, I>>,
 }
 
 impl<C: PregelRuntimeConfig + Clone, I: MessageIterator> ForkJoinComputer<C, I> {
@@ -105,6 +110,7 @@ impl<C: PregelRuntimeConfig + Clone, I: MessageIterator> ForkJoinComputer<C, I>
         vote_bits: Arc<HugeAtomicBitSet>,
         progress_task: Option<Arc<LeafTask>>,
     ) -> Self {
+        let partitions = Self::partitions(&graph, &config);
         Self {
             graph,
             init_fn,
@@ -115,7 +121,33 @@ impl<C: PregelRuntimeConfig + Clone, I: MessageIterator> ForkJoinComputer<C, I>
             vote_bits,
             progress_task,
             sent_message: Arc::new(AtomicBool::new(false)),
-            root_task: None,
+            partitions,
+            root_tasks: Vec::new(),
+        }
+    }
+
+    /// Split the nodes into the batches each iteration starts from.
+    ///
+    /// `Partitioning::Degree` cuts the node range so that every batch holds
+    /// roughly `relationship_count / concurrency` relationships, which keeps a
+    /// few high-degree nodes from turning one batch into a straggler. All
+    /// other strategies start from a single batch and rely on fork-join
+    /// splitting by node count.
+    fn partitions(graph: &Arc<dyn Graph>, config: &C) -> Vec<Partition> {
+        let node_count = graph.node_count();
+        match config.partitioning() {
+            Partitioning::Degree if node_count > 0 => {
+                let degrees = Arc::clone(graph);
+                PartitionUtils::degree_partition(
+                    node_count,
+                    graph.relationship_count(),
+                    Box::new(move |node: usize| degrees.degree(node as i64)),
+                    config.concurrency().max(1),
+                    |partition| *partition.as_partition(),
+                    None,
+                )
+            }
+            _ => vec![Partition::new(0, node_count)],
         }
     }
 }
@@ -129,32 +161,41 @@ impl<C: PregelRuntimeConfig + Clone, I: MessageIterator> PregelComputer<C>
     }
 
     fn init_iteration(&mut self, iteration: usize) {
+        // Make the messages sent in the previous superstep readable
+        self.messenger.init_iteration(iteration);
+
         // Reset sent message flag for this iteration
         self.sent_message.store(false, Ordering::Relaxed);
 
-        // Create partition covering all nodes
-        let partition = Partition::new(0, self.graph.node_count());
-
-        // Create root fork-join compute step for this iteration
-        self.root_task = Some(ForkJoinComputeStep::new(
-            Arc::clone(&self.init_fn),
-            Arc::clone(&self.compute_fn),
-            self.config.clone(),
-            Arc::clone(&self.graph),
-            partition,
-            Arc::clone(&self.node_values),
-            Arc::clone(&self.messenger),
-            Arc::clone(&self.vote_bits),
-            iteration,
-            Arc::clone(&self.sent_message),
-            self.progress_task.clone(),
-        ));
+        // Create one root fork-join compute step per partition for this iteration
+        self.root_tasks = self
+            .partitions
+            .iter()
+            .map(|&partition| {
+                ForkJoinComputeStep::new(
+                    Arc::clone(&self.init_fn),
+                    Arc::clone(&self.compute_fn),
+                    self.config.clone(),
+                    Arc::clone(&self.graph),
+                    partition,
+                    Arc::clone(&self.node_values),
+                    Arc::clone(&self.messenger),
+                    Arc::clone(&self.vote_bits),
+                    iteration,
+                    Arc::clone(&self.sent_message),
+                    self.progress_task.clone(),
+                )
+            })
+            .collect();
     }
 
     fn run_iteration(&mut self) {
-        // Execute the root task (will recursively subdivide via Rayon)
-        if let Some(task) = self.root_task.take() {
-            task.compute();
+        // Execute the root tasks (each will recursively subdivide via Rayon)
+        let mut tasks = std::mem::take(&mut self.root_tasks);
+        if tasks.len() == 1 {
+            tasks.pop().unwrap().compute();
+        } else {
+            tasks.into_par_iter().for_each(|task| task.compute());
         }
     }
 
@@ -273,9 +314,149 @@ impl<C: PregelRuntimeConfig + Clone, I: MessageIterator> Default for PregelCompu
 
 #[cfg(test)]
 mod tests {
-    // TODO: Add tests once we have mock implementations
-    // - Test init_computation (no-op)
-    // - Test init_iteration creates root task
-    // - Test run_iteration executes task
-    // - Test has_converged checks sent_message and vote_bits
+    use super::*;
+    use crate::config::PregelConfig;
+    use crate::pregel::{
+        PregelBuilder, PregelSchema, SyncQueueMessageIterator, SyncQueueMessenger,
+    };
+    use crate::types::graph_store::{DefaultGraphStore, GraphStore};
+    use crate::types::ValueType;
+
+    const LEAVES: u64 = 3000;
+
+    /// Star with hub 0, stored in both directions so leaves reach the hub
+    fn star() -> Arc<dyn Graph> {
+        DefaultGraphStore::star(LEAVES as usize + 1).get_graph()
+    }
+
+    fn config(partitioning: Partitioning) -> PregelConfig {
+        let mut config = PregelConfig {
+            partitioning,
+            ..PregelConfig::default()
+        };
+        config.base.concurrency = 4;
+        config
+    }
+
+    /// Each node sums the ids of its neighbours in the first superstep
+    fn run(partitioning: Partitioning) -> (Vec<Partition>, Vec<f64>) {
+        let graph = star();
+        let init_fn: InitFn<PregelConfig> = Arc::new(|context| {
+            context.set_node_value("sum", 0.0);
+        });
+        let compute_fn: ComputeFn<PregelConfig, SyncQueueMessageIterator> =
+            Arc::new(|context, messages| {
+                if context.is_initial_superstep() {
+                    let id = context.node_id() as f64;
+                    context.send_to_neighbors(id);
+                } else {
+                    let sum = messages.sum::<f64>();
+                    context.set_node_value("sum", sum);
+                }
+                context.vote_to_halt();
+            });
+
+        let batches = ForkJoinComputer::<PregelConfig, SyncQueueMessageIterator>::partitions(
+            &graph,
+            &config(partitioning),
+        );
+
+        let result = PregelBuilder::new()
+            .graph(Arc::clone(&graph))
+            .config(config(partitioning))
+            .schema(
+                PregelSchema::builder()
+                    .add_public("sum", ValueType::Double)
+                    .build(),
+            )
+            .init_fn(init_fn)
+            .compute_fn(compute_fn)
+            .messenger(Arc::new(SyncQueueMessenger::new(graph.node_count())))
+            .build()
+            .run();
+        let sums = (0..graph.node_count())
+            .map(|node| result.node_values.double_value("sum", node))
+            .collect();
+        (batches, sums)
+    }
+
+    #[test]
+    fn test_init_iteration_delivers_previous_messages() {
+        // Driven without the executor: the computer alone prepares the messenger
+        let graph = DefaultGraphStore::ring(2).get_graph();
+        let schema = PregelSchema::builder()
+            .add_public("sum", ValueType::Double)
+            .build();
+        let node_values = Arc::new(parking_lot::RwLock::new(NodeValue::of(
+            &schema,
+            2,
+            crate::concurrency::Concurrency::of(1),
+        )));
+        let init_fn: InitFn<PregelConfig> = Arc::new(|context| {
+            context.set_node_value("sum", 0.0);
+        });
+        let compute_fn: ComputeFn<PregelConfig, SyncQueueMessageIterator> =
+            Arc::new(|context, messages| {
+                if context.is_initial_superstep() {
+                    let id = context.node_id() as f64;
+                    context.send_to_neighbors(id + 1.0);
+                } else {
+                    let sum = messages.sum::<f64>();
+                    context.set_node_value("sum", sum);
+                }
+            });
+
+        let mut computer = PregelComputerBuilder::new()
+            .graph(Arc::clone(&graph))
+            .init_fn(init_fn)
+            .compute_fn(compute_fn)
+            .config(config(Partitioning::Range))
+            .node_values(Arc::clone(&node_values))
+            .messenger(Arc::new(SyncQueueMessenger::new(2)))
+            .vote_bits(Arc::new(HugeAtomicBitSet::new(2)))
+            .build();
+        for iteration in 0..2 {
+            computer.init_iteration(iteration);
+            computer.run_iteration();
+        }
+
+        assert_eq!(node_values.read().double_value("sum", 0), 2.0);
+        assert_eq!(node_values.read().double_value("sum", 1), 1.0);
+    }
+
+    #[test]
+    fn test_range_partitioning_uses_single_batch() {
+        let (batches, _) = run(Partitioning::Range);
+        assert_eq!(batches, vec![Partition::new(0, LEAVES as usize + 1)]);
+    }
+
+    #[test]
+    fn test_degree_partitioning_balances_star() {
+        let graph = star();
+        let (batches, _) = run(Partitioning::Degree);
+
+        // The hub carries half of all relationships and gets a batch of its
+        // own; the leaves are spread evenly over the remaining batches.
+        assert_eq!(batches[0], Partition::new(0, 1));
+        assert!(batches.len() > 2);
+        let total = graph.relationship_count();
+        let per_batch = total / 4;
+        for batch in &batches[1..] {
+            let degree: usize = (batch.start_node()..batch.start_node() + batch.node_count())
+                .map(|node| graph.degree(node as i64))
+                .sum();
+            assert!(degree <= per_batch, "batch {:?} holds {}", batch, degree);
+        }
+        let covered: usize = batches.iter().map(|batch| batch.node_count()).sum();
+        assert_eq!(covered, graph.node_count());
+    }
+
+    #[test]
+    fn test_degree_partitioning_matches_range_results() {
+        let (_, range) = run(Partitioning::Range);
+        let (_, degree) = run(Partitioning::Degree);
+        assert_eq!(range, degree);
+        assert_eq!(range[0], (1..=LEAVES).sum::<u64>() as f64);
+        assert!(range[1..].iter().all(|&sum| sum == 0.0));
+    }
 }
@@ -198,6 +198,49 @@ impl<C: PregelRuntimeConfig, I: crate::pregel::MessageIterator> ComputeContext<C
         }
     }
 
+    /// Iterate over nodes with a relationship pointing to the current node.
+    ///
+    /// The Pregel builder must be declared
+    /// [`bidirectional`](crate::pregel::PregelBuilder::bidirectional), which
+    /// ensures the graph is inverse indexed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph is not inverse indexed.
+    pub fn for_each_inverse_neighbor<F>(&self, consumer: F)
+    where
+        F: FnMut(u64),
+    {
+        self.base.for_each_inverse_neighbor(consumer);
+    }
+
+    /// Send a message along outgoing and incoming relationships alike.
+    ///
+    /// Treats the graph as undirected: every node adjacent to the current
+    /// node in either direction receives the message exactly once. Like
+    /// [`for_each_inverse_neighbor`](Self::for_each_inverse_neighbor), this
+    /// needs a [`bidirectional`](crate::pregel::PregelBuilder::bidirectional)
+    /// builder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph is not inverse indexed.
+    pub fn send_to_all_neighbors(&mut self, message: f64) {
+        let mut neighbors = Vec::new();
+        self.base.for_each_neighbor(|neighbor_id| {
+            neighbors.push(neighbor_id);
+        });
+        self.base.for_each_inverse_neighbor(|neighbor_id| {
+            neighbors.push(neighbor_id);
+        });
+        neighbors.sort_unstable();
+        neighbors.dedup();
+
+        for target in neighbors {
+            self.send_to(target, message);
+        }
+    }
+
     /// Send a message to a specific node.
     ///
     /// # Java equivalent
@@ -227,3 +270,112 @@ impl<C: PregelRuntimeConfig, I: crate::pregel::MessageIterator> ComputeContext<C
         self.vote_bits.set(node_id as usize);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::config::PregelConfig;
+    use crate::pregel::{
+        ComputeFn, InitFn, Pregel, PregelBuildError, PregelBuilder, PregelSchema,
+        SyncQueueMessageIterator, SyncQueueMessenger,
+    };
+    use crate::projection::RelationshipType;
+    use crate::types::graph::RelationshipTopology;
+    use crate::types::graph_store::{DefaultGraphStore, GraphStore};
+    use crate::types::random::generators::typed_store;
+    use crate::types::schema::Direction;
+    use crate::types::ValueType;
+    use std::sync::Arc;
+
+    /// Directed graph over `edges`, with the incoming lists indexed on request
+    fn store(
+        node_count: usize,
+        edges: &[(usize, usize)],
+        inverse_indexed: bool,
+    ) -> DefaultGraphStore {
+        let mut store = typed_store(
+            "g",
+            node_count,
+            [("REL", Direction::Directed, edges.to_vec())],
+        );
+        if inverse_indexed {
+            let mut outgoing = vec![Vec::new(); node_count];
+            let mut incoming = vec![Vec::new(); node_count];
+            for &(source, target) in edges {
+                outgoing[source].push(target as i64);
+                incoming[target].push(source as i64);
+            }
+            store.add_relationship_type(
+                RelationshipType::of("REL"),
+                RelationshipTopology::new(outgoing, Some(incoming)),
+            );
+        }
+        store
+    }
+
+    /// `sender` sends 1.0 to all neighbors, summed per node into "received"
+    fn pregel(
+        store: &DefaultGraphStore,
+        sender: u64,
+    ) -> Result<Pregel<PregelConfig, SyncQueueMessageIterator>, PregelBuildError> {
+        let graph = store.get_graph();
+        let node_count = graph.node_count();
+        let init_fn: InitFn<PregelConfig> = Arc::new(|_| {});
+        let compute_fn: ComputeFn<PregelConfig, SyncQueueMessageIterator> =
+            Arc::new(move |context, messages| {
+                if context.is_initial_superstep() {
+                    if context.node_id() == sender {
+                        context.send_to_all_neighbors(1.0);
+                    }
+                } else {
+                    let sum = messages.sum::<f64>();
+                    context.set_node_value("received", sum);
+                }
+                context.vote_to_halt();
+            });
+
+        PregelBuilder::new()
+            .graph(graph)
+            .config(PregelConfig::default())
+            .schema(
+                PregelSchema::builder()
+                    .add_public("received", ValueType::Double)
+                    .build(),
+            )
+            .init_fn(init_fn)
+            .compute_fn(compute_fn)
+            .messenger(Arc::new(SyncQueueMessenger::new(node_count)))
+            .bidirectional()
+            .try_build()
+    }
+
+    fn received(store: &DefaultGraphStore, sender: u64) -> Vec<f64> {
+        let result = pregel(store, sender).unwrap().run();
+        (0..store.node_count())
+            .map(|node| result.node_values.double_value("received", node))
+            .collect()
+    }
+
+    #[test]
+    fn test_send_to_all_neighbors_is_symmetric() {
+        // Single directed edge 0 -> 1
+        let store = store(2, &[(0, 1)], true);
+        assert_eq!(received(&store, 0), vec![0.0, 1.0]);
+        assert_eq!(received(&store, 1), vec![1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_send_to_all_neighbors_deduplicates() {
+        // 0 <-> 1 in both directions plus 2 -> 0
+        let store = store(3, &[(0, 1), (1, 0), (2, 0)], true);
+        assert_eq!(received(&store, 0), vec![0.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_bidirectional_build_requires_inverse_index() {
+        let store = store(2, &[(0, 1)], false);
+        assert!(matches!(
+            pregel(&store, 1),
+            Err(PregelBuildError::NotInverseIndexed)
+        ));
+    }
+}
@@ -276,6 +276,34 @@ impl<C: PregelRuntimeConfig> NodeCentricContext<C> {
         }
     }
 
+    /// Calls the consumer for each node with a relationship pointing to the
+    /// currently processed node.
+    ///
+    /// # Java equivalent
+    ///
+    /// ```java
+    /// void forEachInverseNeighbor(LongConsumer targetConsumer)
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph is not inverse indexed.
+    pub fn for_each_inverse_neighbor<F>(&self, mut consumer: F)
+    where
+        F: FnMut(u64),
+    {
+        assert!(
+            self.graph.characteristics().is_inverse_indexed(),
+            "Incoming neighbors require an inverse indexed graph"
+        );
+        let stream = self
+            .graph
+            .stream_inverse_relationships(self.node_id as i64, 0.0);
+        for cursor in stream {
+            consumer(cursor.source_id() as u64);
+        }
+    }
+
     /// Calls the consumer for each neighbor of the given node.
     ///
     /// # Java equivalent
@@ -5,13 +5,16 @@
 
 use crate::collections::HugeAtomicBitSet;
 use crate::concurrency::Concurrency;
-use crate::core::utils::progress::tasks::LeafTask;
+use crate::core::utils::progress::tasks::{IterativeTask, LeafTask, Task};
+use crate::core::utils::progress::{self, TaskRegistry};
 use crate::pregel::{
     projection::PropertyProjection, ComputeFn, DefaultValue, ForkJoinComputer, InitFn,
-    MasterComputeContext, Messenger, NodeValue, PregelComputer, PregelResult, PregelRuntimeConfig,
-    PregelSchema,
+    MasterComputeContext, Messenger, NodeValue, PregelCheckpoint, PregelComputer, PregelResult,
+    PregelRuntimeConfig, PregelSchema,
 };
 use crate::types::graph::Graph;
+use crate::types::ValueType;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// Main executor for Pregel computations.
@@ -54,15 +57,41 @@ pub struct Pregel<C: PregelRuntimeConfig + Clone, I: crate::pregel::MessageItera
     /// Node property values (results)
     node_values: Arc<parking_lot::RwLock<NodeValue>>,
 
-    /// Message passing system (held for lifecycle management)
-    #[allow(dead_code)]
+    /// Message passing system
     messenger: Arc<dyn Messenger<I>>,
 
+    /// Vote-to-halt tracking (shared with the computer)
+    vote_bits: Arc<HugeAtomicBitSet>,
+
     /// The computer that executes iterations
     computer: ForkJoinComputer<C, I>,
 
     /// Progress tracking task (optional)
     progress_task: Option<Arc<LeafTask>>,
+
+    /// One "Superstep" subtask per superstep run, bounded by `max_iterations`
+    iteration_task: Arc<IterativeTask>,
+
+    /// Task store entry mirroring `iteration_task`, if one was registered
+    stored_task: Option<progress::Task>,
+
+    /// The next superstep to run
+    next_iteration: usize,
+
+    /// Whether the last superstep converged
+    did_converge: bool,
+
+    /// Whether the computation and progress task have been started
+    started: bool,
+
+    /// Double property whose values are recorded for the tracked nodes
+    history_key: Option<String>,
+
+    /// Recorded value per superstep for each tracked node
+    history: HashMap<u64, Vec<f64>>,
+
+    /// Nodes that had not voted to halt after each superstep
+    active_node_counts: Vec<usize>,
 }
 
 impl<C: PregelRuntimeConfig + Clone, I: crate::pregel::MessageIterator> Pregel<C, I> {
@@ -103,6 +132,10 @@ impl<C: PregelRuntimeConfig + Clone, I: crate::pregel::MessageIterator> Pregel<C
         // Initialize node values from PropertyStore (if property_source is set)
         Self::initialize_from_property_store(&graph, &schema, &node_values);
 
+        if let Some(capacity) = config.message_queue_capacity() {
+            messenger.limit_unread_messages(capacity);
+        }
+
         // Create vote bits for convergence tracking
         let vote_bits = Arc::new(HugeAtomicBitSet::new(graph.node_count()));
 
@@ -118,13 +151,30 @@ impl<C: PregelRuntimeConfig + Clone, I: crate::pregel::MessageIterator> Pregel<C
             progress_task.clone(),
         );
 
+        let superstep_supplier: Arc<dyn Fn() -> Vec<Arc<Task>> + Send + Sync> =
+            Arc::new(|| vec![Arc::new(Task::new("Superstep".to_string(), vec![]))]);
+        let iteration_task = Arc::new(IterativeTask::incremental(
+            "Pregel".to_string(),
+            superstep_supplier,
+            Some(config.max_iterations()),
+        ));
+
         Self {
             config,
             graph,
             node_values,
             messenger,
+            vote_bits,
             computer,
             progress_task,
+            iteration_task,
+            stored_task: None,
+            next_iteration: 0,
+            did_converge: false,
+            started: false,
+            history_key: None,
+            history: HashMap::new(),
+            active_node_counts: Vec::new(),
         }
     }
 
@@ -199,24 +249,67 @@ impl<C: PregelRuntimeConfig + Clone, I: crate::pregel::MessageIterator> Pregel<C
     /// println!("Converged: {}", result.did_converge);
     /// ```
     pub fn run(mut self) -> PregelResult {
-        let mut did_converge = false;
+        self.run_supersteps(self.config.max_iterations());
 
-        // Initialize computation
-        self.computer.init_computation();
-
-        // Track progress - start task if present
+        // Finish task if present
         if let Some(task) = &self.progress_task {
-            task.base().start();
+            task.finish();
+        }
+        self.iteration_task.finish();
+        if let Some(task) = &self.stored_task {
+            task.finish();
         }
 
-        let mut iteration = 0;
-        for iter in 0..self.config.max_iterations() {
-            iteration = iter;
+        // Release resources
+        self.computer.release();
+
+        // Return results - unwrap Arc<RwLock<NodeValue>> to get NodeValue
+        let node_values = Arc::try_unwrap(self.node_values)
+            .map(|lock| lock.into_inner())
+            .unwrap_or_else(|_arc| NodeValue::stub()); // Fallback if still shared
+
+        PregelResult::new(
+            node_values,
+            self.next_iteration.saturating_sub(1),
+            self.did_converge,
+        )
+        .with_history(self.history)
+        .with_active_node_counts(self.active_node_counts)
+    }
+
+    /// Run at most `count` further supersteps.
+    ///
+    /// Stops early on convergence or when `max_iterations` is reached, and
+    /// returns whether the computation has converged. Call [`Pregel::run`]
+    /// afterwards to finish the computation and collect the result.
+    pub fn run_supersteps(&mut self, count: usize) -> bool {
+        if !self.started {
+            self.started = true;
+
+            // Initialize computation
+            self.computer.init_computation();
+
+            // Track progress - start task if present
+            if let Some(task) = &self.progress_task {
+                task.base().start();
+            }
+            if let Some(task) = &self.stored_task {
+                task.start();
+            }
+        }
+
+        let end = self
+            .next_iteration
+            .saturating_add(count)
+            .min(self.config.max_iterations());
+        while !self.did_converge && self.next_iteration < end {
+            let iteration = self.next_iteration;
 
             // Log iteration progress
             if let Some(task) = &self.progress_task {
                 task.log_progress(1); // Log one unit of progress per iteration
             }
+            self.iteration_task.begin_iteration();
 
             // Initialize iteration in computer
             // (Messenger init is handled by computer/compute_step)
@@ -228,28 +321,134 @@ impl<C: PregelRuntimeConfig + Clone, I: crate::pregel::MessageIterator> Pregel<C
             // Run master compute step (convergence check)
             let master_converged = self.run_master_compute(iteration);
 
-            // Check convergence
-            did_converge = master_converged || self.computer.has_converged();
+            self.record_history();
+            self.active_node_counts
+                .push(self.vote_bits.size() - self.vote_bits.cardinality());
 
-            if did_converge {
-                break;
+            // Check convergence
+            self.did_converge = master_converged || self.computer.has_converged();
+            self.next_iteration += 1;
+            self.iteration_task.finish_iteration();
+            if let Some(task) = &self.stored_task {
+                task.log_progress(1);
             }
         }
 
-        // Finish task if present
-        if let Some(task) = &self.progress_task {
-            task.finish();
+        self.did_converge
+    }
+
+    /// Task tree of the supersteps run so far.
+    ///
+    /// Each superstep adds a subtask, so progress reads as "iteration n of
+    /// at most `max_iterations`" through [`IterativeTask::iteration_progress`].
+    pub fn iteration_task(&self) -> &Arc<IterativeTask> {
+        &self.iteration_task
+    }
+
+    /// Register the progress of the iteration task in the task store of
+    /// `registry`, see [`PregelBuilder::task_registry`].
+    fn register_iteration_task(&mut self, registry: &TaskRegistry) {
+        let task = progress::Task::new(
+            self.iteration_task.base().description().to_string(),
+            self.config.max_iterations(),
+        );
+        task.log_progress(self.next_iteration);
+        registry.register_task(task.clone());
+        self.stored_task = Some(task);
+    }
+
+    /// Start recording the `property_key` value of `node_ids` after every
+    /// superstep, see [`PregelBuilder::track_history`].
+    fn track_history(
+        &mut self,
+        property_key: &str,
+        node_ids: Vec<u64>,
+    ) -> Result<(), HistoryError> {
+        let value_type = self
+            .node_values
+            .read()
+            .schema()
+            .elements()
+            .iter()
+            .find(|element| element.property_key == property_key)
+            .map(|element| element.property_type)
+            .ok_or_else(|| HistoryError::UnknownProperty(property_key.to_string()))?;
+        if value_type != ValueType::Double {
+            return Err(HistoryError::NotDouble {
+                property_key: property_key.to_string(),
+                value_type,
+            });
+        }
+        if let Some(&node_id) = node_ids
+            .iter()
+            .find(|&&node_id| node_id as usize >= self.graph.node_count())
+        {
+            return Err(HistoryError::NodeNotInGraph(node_id));
         }
 
-        // Release resources
-        self.computer.release();
+        self.history_key = Some(property_key.to_string());
+        self.history = node_ids
+            .into_iter()
+            .map(|node| (node, Vec::new()))
+            .collect();
+        Ok(())
+    }
 
-        // Return results - unwrap Arc<RwLock<NodeValue>> to get NodeValue
-        let node_values = Arc::try_unwrap(self.node_values)
-            .map(|lock| lock.into_inner())
-            .unwrap_or_else(|_arc| NodeValue::stub()); // Fallback if still shared
+    fn record_history(&mut self) {
+        let Some(key) = &self.history_key else {
+            return;
+        };
+        let node_values = self.node_values.read();
+        for (&node, values) in self.history.iter_mut() {
+            values.push(node_values.double_value(key, node as usize));
+        }
+    }
+
+    /// Snapshot the computation between supersteps.
+    ///
+    /// Captures node values, vote-to-halt flags, the messages waiting for the
+    /// next superstep and the superstep number. Resume with
+    /// [`PregelBuilder::resume_from`].
+    pub fn checkpoint(&self) -> PregelCheckpoint {
+        PregelCheckpoint {
+            superstep: self.next_iteration,
+            did_converge: self.did_converge,
+            node_values: self.node_values.read().columns(),
+            voted_to_halt: (0..self.graph.node_count())
+                .map(|node| self.vote_bits.get(node))
+                .collect(),
+            messages: self.messenger.pending_messages(),
+        }
+    }
+
+    /// Restore the state captured by [`Pregel::checkpoint`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the checkpoint was taken on a graph with a different node
+    /// count or does not fit the schema.
+    fn restore(&mut self, checkpoint: &PregelCheckpoint) {
+        assert_eq!(
+            checkpoint.node_count(),
+            self.graph.node_count(),
+            "checkpoint node count does not match the graph"
+        );
 
-        PregelResult::new(node_values, iteration, did_converge)
+        self.node_values
+            .write()
+            .restore_columns(&checkpoint.node_values);
+        for (node, &voted) in checkpoint.voted_to_halt.iter().enumerate() {
+            if voted {
+                self.vote_bits.set(node);
+            } else {
+                self.vote_bits.clear_bit(node);
+            }
+        }
+        if let Some(messages) = &checkpoint.messages {
+            self.messenger.restore_pending_messages(messages);
+        }
+        self.next_iteration = checkpoint.superstep;
+        self.did_converge = checkpoint.did_converge;
     }
 
     /// Run the master compute step for convergence checking.
@@ -273,6 +472,35 @@ impl<C: PregelRuntimeConfig + Clone, I: crate::pregel::MessageIterator> Pregel<C
     }
 }
 
+/// Why [`PregelBuilder::track_history`] cannot track the requested values.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum HistoryError {
+    #[error("history property '{0}' is not in the schema")]
+    UnknownProperty(String),
+
+    #[error("history property '{property_key}' is {value_type}, not Double")]
+    NotDouble {
+        property_key: String,
+        value_type: ValueType,
+    },
+
+    #[error("tracked node {0} is not in the graph")]
+    NodeNotInGraph(u64),
+}
+
+/// Why [`PregelBuilder::try_build`] cannot build an executor.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PregelBuildError {
+    #[error("{0} is required")]
+    MissingField(&'static str),
+
+    #[error("the computation reads incoming relationships, but the graph is not inverse indexed")]
+    NotInverseIndexed,
+
+    #[error(transparent)]
+    History(#[from] HistoryError),
+}
+
 /// Builder for creating Pregel instances with a fluent API.
 pub struct PregelBuilder<C: PregelRuntimeConfig, I: crate::pregel::MessageIterator> {
     graph: Option<Arc<dyn Graph>>,
@@ -282,6 +510,10 @@ pub struct PregelBuilder<C: PregelRuntimeConfig, I: crate::pregel::MessageIterat
     compute_fn: Option<ComputeFn<C, I>>,
     messenger: Option<Arc<dyn Messenger<I>>>,
     progress_task: Option<Arc<LeafTask>>,
+    checkpoint: Option<PregelCheckpoint>,
+    bidirectional: bool,
+    history: Option<(String, Vec<u64>)>,
+    task_registry: Option<TaskRegistry>,
 }
 
 impl<C: PregelRuntimeConfig + Clone, I: crate::pregel::MessageIterator> PregelBuilder<C, I> {
@@ -295,6 +527,10 @@ impl<C: PregelRuntimeConfig + Clone, I: crate::pregel::MessageIterator> PregelBu
             compute_fn: None,
             messenger: None,
             progress_task: None,
+            checkpoint: None,
+            bidirectional: false,
+            history: None,
+            task_registry: None,
         }
     }
 
@@ -340,21 +576,99 @@ impl<C: PregelRuntimeConfig + Clone, I: crate::pregel::MessageIterator> PregelBu
         self
     }
 
+    /// Continue from a checkpoint instead of starting at superstep 0.
+    ///
+    /// The messenger must be freshly created; the checkpointed messages are
+    /// re-sent through it.
+    pub fn resume_from(mut self, checkpoint: PregelCheckpoint) -> Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// Declare that the compute function reads incoming relationships.
+    ///
+    /// Required for [`ComputeContext::for_each_inverse_neighbor`] and
+    /// [`ComputeContext::send_to_all_neighbors`]; building then fails unless
+    /// the graph is inverse indexed.
+    ///
+    /// [`ComputeContext::for_each_inverse_neighbor`]: crate::pregel::ComputeContext::for_each_inverse_neighbor
+    /// [`ComputeContext::send_to_all_neighbors`]: crate::pregel::ComputeContext::send_to_all_neighbors
+    pub fn bidirectional(mut self) -> Self {
+        self.bidirectional = true;
+        self
+    }
+
+    /// Record the `property_key` value of `node_ids` after every superstep.
+    ///
+    /// The values end up in [`PregelResult::history`]. Only the listed nodes
+    /// are tracked, which keeps memory bounded on large graphs. Replaces any
+    /// history tracking requested before.
+    ///
+    /// Building fails if `property_key` is not a `Double` property of the
+    /// schema or a node id is not in the graph.
+    pub fn track_history(mut self, property_key: &str, node_ids: Vec<u64>) -> Self {
+        self.history = Some((property_key.to_string(), node_ids));
+        self
+    }
+
+    /// Report superstep progress to the task store of `registry` (optional).
+    ///
+    /// Registers a task named after the iteration task, with one unit of
+    /// volume per superstep up to `max_iterations`. It is started by the
+    /// first superstep and finished by [`Pregel::run`]; it stays in the store
+    /// until the caller unregisters it.
+    pub fn task_registry(mut self, registry: TaskRegistry) -> Self {
+        self.task_registry = Some(registry);
+        self
+    }
+
     /// Build the Pregel executor.
     ///
     /// # Panics
     ///
-    /// Panics if any required field is missing.
+    /// Panics if [`try_build`](Self::try_build) fails or if the checkpoint
+    /// does not match the graph and schema.
     pub fn build(self) -> Pregel<C, I> {
-        Pregel::new(
-            self.graph.expect("graph is required"),
-            self.config.expect("config is required"),
-            self.schema.expect("schema is required"),
-            self.init_fn.expect("init_fn is required"),
-            self.compute_fn.expect("compute_fn is required"),
-            self.messenger.expect("messenger is required"),
+        self.try_build().unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Build the Pregel executor, reporting a missing field, a
+    /// [`bidirectional`](Self::bidirectional) computation on a graph without
+    /// an inverse index or an unsuitable [`track_history`](Self::track_history)
+    /// request as an error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the checkpoint does not match the graph and schema.
+    pub fn try_build(self) -> Result<Pregel<C, I>, PregelBuildError> {
+        let graph = self.graph.ok_or(PregelBuildError::MissingField("graph"))?;
+        if self.bidirectional && !graph.characteristics().is_inverse_indexed() {
+            return Err(PregelBuildError::NotInverseIndexed);
+        }
+        let mut pregel = Pregel::new(
+            graph,
+            self.config
+                .ok_or(PregelBuildError::MissingField("config"))?,
+            self.schema
+                .ok_or(PregelBuildError::MissingField("schema"))?,
+            self.init_fn
+                .ok_or(PregelBuildError::MissingField("init_fn"))?,
+            self.compute_fn
+                .ok_or(PregelBuildError::MissingField("compute_fn"))?,
+            self.messenger
+                .ok_or(PregelBuildError::MissingField("messenger"))?,
             self.progress_task, // Optional
-        )
+        );
+        if let Some((property_key, node_ids)) = self.history {
+            pregel.track_history(&property_key, node_ids)?;
+        }
+        if let Some(checkpoint) = &self.checkpoint {
+            pregel.restore(checkpoint);
+        }
+        if let Some(registry) = &self.task_registry {
+            pregel.register_iteration_task(registry);
+        }
+        Ok(pregel)
     }
 }
 
@@ -368,8 +682,275 @@ impl<C: PregelRuntimeConfig + Clone, I: crate::pregel::MessageIterator> Default
 
 #[cfg(test)]
 mod tests {
-    // TODO: Add integration tests once we have:
-    // - Mock graph implementation
-    // - Mock messenger implementation
-    // - Simple test algorithm (e.g., node count)
+    use super::*;
+    use crate::config::PregelConfig;
+    use crate::core::utils::progress::tasks::{Progress, Status};
+    use crate::pregel::{SyncQueueMessageIterator, SyncQueueMessenger};
+    use crate::types::graph_store::{DefaultGraphStore, GraphStore};
+    use crate::types::random::{RandomGraphConfig, RandomRelationshipConfig};
+    use crate::types::ValueType;
+
+    fn graph() -> Arc<dyn Graph> {
+        DefaultGraphStore::random(&RandomGraphConfig {
+            seed: Some(23),
+            node_count: 30,
+            relationships: vec![RandomRelationshipConfig::new("REL", 0.2)],
+            ..RandomGraphConfig::default()
+        })
+        .unwrap()
+        .get_graph()
+    }
+
+    /// Unnormalised PageRank that never halts, plus a per-node superstep counter
+    fn builder(graph: &Arc<dyn Graph>) -> PregelBuilder<PregelConfig, SyncQueueMessageIterator> {
+        let config = PregelConfig {
+            max_iterations: 10,
+            ..PregelConfig::default()
+        };
+        let schema = PregelSchema::builder()
+            .add_public("rank", ValueType::Double)
+            .add_public("steps", ValueType::Long)
+            .build();
+        let init_fn: InitFn<PregelConfig> = Arc::new(|context| {
+            context.set_node_value("rank", 1.0);
+        });
+        let compute_fn: ComputeFn<PregelConfig, SyncQueueMessageIterator> =
+            Arc::new(|context, messages| {
+                if !context.is_initial_superstep() {
+                    let rank = 0.15 + 0.85 * messages.sum::<f64>();
+                    context.set_node_value("rank", rank);
+                }
+                let steps = context.long_node_value("steps") + 1;
+                context.set_node_value_long("steps", steps);
+                let degree = context.degree();
+                if degree > 0 {
+                    let share = context.double_node_value("rank") / degree as f64;
+                    context.send_to_neighbors(share);
+                }
+            });
+
+        PregelBuilder::new()
+            .graph(Arc::clone(graph))
+            .config(config)
+            .schema(schema)
+            .init_fn(init_fn)
+            .compute_fn(compute_fn)
+            .messenger(Arc::new(SyncQueueMessenger::new(graph.node_count())))
+    }
+
+    fn values(result: &PregelResult) -> Vec<(f64, i64)> {
+        (0..result.node_values.node_count())
+            .map(|node| {
+                (
+                    result.node_values.double_value("rank", node),
+                    result.node_values.long_value("steps", node),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_resume_from_checkpoint_matches_uninterrupted_run() {
+        let graph = graph();
+        let uninterrupted = builder(&graph).build().run();
+
+        let mut first_half = builder(&graph).build();
+        assert!(!first_half.run_supersteps(5));
+        let checkpoint = first_half.checkpoint();
+        assert_eq!(checkpoint.superstep, 5);
+        drop(first_half);
+
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        let restored: PregelCheckpoint = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, checkpoint);
+
+        let resumed = builder(&graph).resume_from(restored).build().run();
+        assert_eq!(resumed.ran_iterations, uninterrupted.ran_iterations);
+        assert_eq!(resumed.did_converge, uninterrupted.did_converge);
+        assert_eq!(values(&resumed), values(&uninterrupted));
+        assert!(values(&resumed).iter().all(|&(_, steps)| steps == 10));
+    }
+
+    #[test]
+    fn test_track_history_records_every_superstep() {
+        let graph = graph();
+        let config = PregelConfig {
+            max_iterations: 20,
+            ..PregelConfig::default()
+        };
+        let init_fn: InitFn<PregelConfig> = Arc::new(|context| {
+            context.set_node_value("rank", 1.0);
+        });
+        let compute_fn: ComputeFn<PregelConfig, SyncQueueMessageIterator> =
+            Arc::new(|context, messages| {
+                if !context.is_initial_superstep() {
+                    let rank = 0.15 + 0.85 * messages.sum::<f64>();
+                    context.set_node_value("rank", rank);
+                }
+                let degree = context.degree();
+                if degree > 0 {
+                    let share = context.double_node_value("rank") / degree as f64;
+                    context.send_to_neighbors(share);
+                }
+            });
+
+        let result = PregelBuilder::new()
+            .graph(Arc::clone(&graph))
+            .config(config)
+            .schema(
+                PregelSchema::builder()
+                    .add_public("rank", ValueType::Double)
+                    .add_public("steps", ValueType::Long)
+                    .build(),
+            )
+            .init_fn(init_fn)
+            .compute_fn(compute_fn)
+            .messenger(Arc::new(SyncQueueMessenger::new(graph.node_count())))
+            .track_history("rank", vec![3, 7])
+            .build()
+            .run();
+
+        assert_eq!(result.history.len(), 2);
+        for node in [3, 7] {
+            let history = &result.history[&node];
+            assert_eq!(history.len(), 20);
+            assert_eq!(
+                *history.last().unwrap(),
+                result.node_values.double_value("rank", node as usize)
+            );
+
+            // Changes may oscillate step to step, but shrink block by block
+            let deltas: Vec<f64> = history.windows(2).map(|w| (w[1] - w[0]).abs()).collect();
+            let block_max: Vec<f64> = deltas
+                .chunks(5)
+                .map(|block| block.iter().copied().fold(0.0, f64::max))
+                .collect();
+            assert!(
+                block_max.windows(2).all(|m| m[1] < m[0]),
+                "node {} did not converge: {:?}",
+                node,
+                history
+            );
+            assert!(*block_max.last().unwrap() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_active_node_counts_decrease_as_nodes_halt() {
+        let graph = graph();
+        let node_count = graph.node_count();
+        let init_fn: InitFn<PregelConfig> = Arc::new(|_| {});
+        // Node n stays active until superstep n
+        let compute_fn: ComputeFn<PregelConfig, SyncQueueMessageIterator> =
+            Arc::new(|context, _messages| {
+                if context.superstep() as u64 >= context.node_id() {
+                    context.vote_to_halt();
+                }
+            });
+
+        let result = PregelBuilder::new()
+            .graph(Arc::clone(&graph))
+            .config(PregelConfig {
+                max_iterations: 100,
+                ..PregelConfig::default()
+            })
+            .schema(PregelSchema::builder().build())
+            .init_fn(init_fn)
+            .compute_fn(compute_fn)
+            .messenger(Arc::new(SyncQueueMessenger::new(node_count)))
+            .build()
+            .run();
+
+        assert!(result.did_converge);
+        let expected: Vec<usize> = (1..=node_count).rev().map(|n| n - 1).collect();
+        assert_eq!(result.active_node_counts, expected);
+        assert!(result
+            .active_node_counts
+            .windows(2)
+            .all(|counts| counts[1] < counts[0]));
+        assert_eq!(result.active_node_counts.last(), Some(&0));
+    }
+
+    #[test]
+    fn test_task_registry_reports_supersteps_to_task_store() {
+        use crate::core::utils::progress::{JobId, PerDatabaseTaskStore, TaskStore};
+
+        let graph = graph();
+        let store: Arc<dyn TaskStore> = Arc::new(PerDatabaseTaskStore::new());
+        let registry = TaskRegistry::new("alice".to_string(), Arc::clone(&store), JobId::new());
+
+        let mut pregel = builder(&graph).task_registry(registry.clone()).build();
+        let task = registry.current_task().unwrap().task;
+        assert_eq!(task.description(), "Pregel");
+        assert_eq!(task.volume(), 10);
+        assert_eq!(task.status(), Status::Pending);
+
+        pregel.run_supersteps(4);
+        let task = registry.current_task().unwrap().task;
+        assert_eq!(task.status(), Status::Running);
+        assert_eq!(task.current_progress(), 4);
+
+        pregel.run();
+        let task = registry.current_task().unwrap().task;
+        assert_eq!(task.status(), Status::Finished);
+        assert!(task.get_progress().is_complete());
+        assert_eq!(store.task_count(), 1);
+    }
+
+    #[test]
+    fn test_track_history_rejects_unsuitable_requests() {
+        let graph = graph();
+        let history_error = |property_key: &str, node_ids: Vec<u64>| {
+            builder(&graph)
+                .track_history(property_key, node_ids)
+                .try_build()
+                .err()
+        };
+
+        assert_eq!(
+            history_error("score", vec![0]),
+            Some(PregelBuildError::History(HistoryError::UnknownProperty(
+                "score".to_string()
+            )))
+        );
+        assert_eq!(
+            history_error("steps", vec![0]),
+            Some(PregelBuildError::History(HistoryError::NotDouble {
+                property_key: "steps".to_string(),
+                value_type: ValueType::Long,
+            }))
+        );
+        assert_eq!(
+            history_error("rank", vec![0, 30]),
+            Some(PregelBuildError::History(HistoryError::NodeNotInGraph(30)))
+        );
+        assert!(builder(&graph).build().run().history.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "checkpoint node count")]
+    fn test_resume_rejects_checkpoint_of_other_graph() {
+        let graph = graph();
+        let mut checkpoint = builder(&graph).build().checkpoint();
+        checkpoint.voted_to_halt.push(false);
+        builder(&graph).resume_from(checkpoint).build();
+    }
+
+    #[test]
+    fn test_iteration_task_adds_a_subtask_per_superstep() {
+        let graph = graph();
+        let pregel = builder(&graph).build();
+        let iteration_task = Arc::clone(pregel.iteration_task());
+        assert!(iteration_task.sub_tasks().is_empty());
+
+        pregel.run();
+
+        let supersteps = iteration_task.sub_tasks();
+        assert_eq!(supersteps.len(), 10);
+        assert!(supersteps
+            .iter()
+            .all(|superstep| superstep.status() == Status::Finished));
+        assert_eq!(iteration_task.iteration_progress(), Progress::of(10, 10));
+        assert_eq!(iteration_task.base().status(), Status::Finished);
+    }
 }
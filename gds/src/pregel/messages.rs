@@ -190,6 +190,40 @@ pub trait Messenger<ITERATOR: MessageIterator>: Send + Sync {
         None
     }
 
+    /// Messages waiting for delivery in the next superstep, one list per node.
+    ///
+    /// Used to checkpoint a computation between supersteps. Returns `None` if
+    /// the messenger cannot enumerate its buffered messages.
+    fn pending_messages(&self) -> Option<Vec<Vec<f64>>> {
+        None
+    }
+
+    /// Re-buffer messages captured by [`Messenger::pending_messages`].
+    ///
+    /// The messages are sent as if each node had sent them to itself, so
+    /// sender tracking does not survive a checkpoint.
+    fn restore_pending_messages(&self, messages: &[Vec<f64>]) {
+        for (node_id, node_messages) in messages.iter().enumerate() {
+            for &message in node_messages {
+                self.send_to(node_id as u64, node_id as u64, message);
+            }
+        }
+    }
+
+    /// Bound the number of unread messages this messenger holds.
+    ///
+    /// Set from `PregelConfig::message_queue_capacity` before the first
+    /// superstep. Messengers that cannot bound their queues ignore it.
+    fn limit_unread_messages(&self, _capacity: usize) {}
+
+    /// Called by a compute batch before it processes its nodes.
+    ///
+    /// Lets a bounded messenger know how many batches can still read messages.
+    fn begin_batch(&self) {}
+
+    /// Called by a compute batch after it processed its nodes.
+    fn end_batch(&self) {}
+
     /// Release resources used by this messenger.
     ///
     /// Called after the Pregel computation completes to free memory.
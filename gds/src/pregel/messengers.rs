@@ -6,7 +6,7 @@
 //! 2. **AsyncQueueMessenger**: Single-buffered queues for asynchronous message passing
 //! 3. **ReducingMessenger**: Atomic double arrays with reducers for aggregated message passing
 
-use crate::collections::{HugeAtomicDoubleArray, HugeAtomicLongArray};
+use crate::collections::{HugeAtomicBitSet, HugeAtomicDoubleArray, HugeAtomicLongArray};
 use crate::pregel::{AsyncDoubleQueues, SyncDoubleQueues};
 use crate::pregel::{MessageIterator, MessageReducer, Messenger};
 
@@ -129,6 +129,15 @@ impl Messenger<SyncQueueMessageIterator> for SyncQueueMessenger {
         }
     }
 
+    fn pending_messages(&self) -> Option<Vec<Vec<f64>>> {
+        let queues = self.queues.read();
+        Some(
+            (0..queues.node_count())
+                .map(|node_id| queues.pending(node_id).to_vec())
+                .collect(),
+        )
+    }
+
     fn release(&self) {
         // HugeObjectArray doesn't require explicit release in Rust
         // Memory will be freed when dropped
@@ -212,6 +221,23 @@ impl MessageIterator for AsyncQueueMessageIterator {
 /// ```
 pub struct AsyncQueueMessenger {
     queues: parking_lot::RwLock<AsyncDoubleQueues>,
+    backpressure: parking_lot::Mutex<Backpressure>,
+    capacity_available: parking_lot::Condvar,
+}
+
+/// Unread message accounting for a bounded [`AsyncQueueMessenger`]
+#[derive(Default)]
+struct Backpressure {
+    /// Unread message bound; `None` for unbounded queues
+    capacity: Option<usize>,
+    pending: usize,
+    peak_pending: usize,
+    /// Messages admitted over the bound because no reader was left
+    overflowed: usize,
+    /// Senders waiting for a reader to free capacity
+    blocked: usize,
+    /// Compute batches between `begin_batch` and `end_batch`
+    active_batches: usize,
 }
 
 impl AsyncQueueMessenger {
@@ -219,8 +245,79 @@ impl AsyncQueueMessenger {
     pub fn new(node_count: usize) -> Self {
         Self {
             queues: parking_lot::RwLock::new(AsyncDoubleQueues::new(node_count)),
+            backpressure: parking_lot::Mutex::new(Backpressure::default()),
+            capacity_available: parking_lot::Condvar::new(),
         }
     }
+
+    /// Create a messenger that holds at most `capacity` unread messages.
+    ///
+    /// See [`Messenger::limit_unread_messages`] for how senders are held back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn bounded(node_count: usize, capacity: usize) -> Self {
+        let messenger = Self::new(node_count);
+        messenger.limit_unread_messages(capacity);
+        messenger
+    }
+
+    /// Create a messenger bounded by the config's `message_queue_capacity`.
+    pub fn from_config<C: crate::config::PregelRuntimeConfig>(
+        node_count: usize,
+        config: &C,
+    ) -> Self {
+        match config.message_queue_capacity() {
+            Some(capacity) => Self::bounded(node_count, capacity),
+            None => Self::new(node_count),
+        }
+    }
+
+    /// Unread message bound, if any.
+    pub fn capacity(&self) -> Option<usize> {
+        self.backpressure.lock().capacity
+    }
+
+    /// Largest number of unread messages held at once (bounded mode only).
+    pub fn peak_pending(&self) -> usize {
+        self.backpressure.lock().peak_pending
+    }
+
+    /// Number of messages admitted over the bound (bounded mode only).
+    ///
+    /// Non-zero when the bound was reached while no other compute batch
+    /// was left to read, e.g. with a single batch or concurrency 1.
+    pub fn overflowed_messages(&self) -> usize {
+        self.backpressure.lock().overflowed
+    }
+
+    /// Number of senders currently waiting for capacity.
+    pub fn blocked_senders(&self) -> usize {
+        self.backpressure.lock().blocked
+    }
+
+    /// Take one unit of capacity, waiting while another batch can still read.
+    fn acquire(&self) {
+        let mut backpressure = self.backpressure.lock();
+        let Some(capacity) = backpressure.capacity else {
+            return;
+        };
+        while backpressure.pending >= capacity {
+            // The sender's own batch counts as active; some other batch that
+            // is not blocked itself must remain to read messages. Without
+            // one, waiting would never end, so the message is admitted.
+            if backpressure.active_batches <= backpressure.blocked + 1 {
+                backpressure.overflowed += 1;
+                break;
+            }
+            backpressure.blocked += 1;
+            self.capacity_available.wait(&mut backpressure);
+            backpressure.blocked -= 1;
+        }
+        backpressure.pending += 1;
+        backpressure.peak_pending = backpressure.peak_pending.max(backpressure.pending);
+    }
 }
 
 impl Messenger<AsyncQueueMessageIterator> for AsyncQueueMessenger {
@@ -232,6 +329,7 @@ impl Messenger<AsyncQueueMessageIterator> for AsyncQueueMessenger {
 
     fn send_to(&self, _source_node_id: u64, target_node_id: u64, message: f64) {
         assert!(!message.is_nan(), "Cannot send NaN as a message");
+        self.acquire();
         self.queues.write().push(target_node_id as usize, message);
     }
 
@@ -246,9 +344,61 @@ impl Messenger<AsyncQueueMessageIterator> for AsyncQueueMessenger {
         _is_first_iteration: bool,
     ) {
         // In async mode, messages are always available
+        if self.capacity().is_none() {
+            let queues = self.queues.read();
+            message_iterator.init(queues.messages(node_id as usize));
+            return;
+        }
+
+        // Bounded queues deliver the same messages; only the unread count drops
+        let delivered = {
+            let mut queues = self.queues.write();
+            message_iterator.init(queues.messages(node_id as usize));
+            queues.deliver(node_id as usize)
+        };
+        if delivered > 0 {
+            let mut backpressure = self.backpressure.lock();
+            backpressure.pending = backpressure.pending.saturating_sub(delivered);
+            self.capacity_available.notify_all();
+        }
+    }
+
+    /// Bound the unread messages to `capacity`.
+    ///
+    /// `send_to` blocks the producer while the bound is reached and resumes
+    /// once another compute batch reads messages. Reading does not remove
+    /// messages, it only marks them as read, so nodes see the same messages
+    /// as with unbounded queues. If no other running batch is left to read,
+    /// as with a single batch or concurrency 1, waiting would never end, so
+    /// the message is admitted over the bound and counted in
+    /// [`AsyncQueueMessenger::overflowed_messages`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    fn limit_unread_messages(&self, capacity: usize) {
+        assert!(capacity > 0, "Message queue capacity must be positive");
+        self.backpressure.lock().capacity = Some(capacity);
+    }
+
+    fn begin_batch(&self) {
+        self.backpressure.lock().active_batches += 1;
+    }
+
+    fn end_batch(&self) {
+        let mut backpressure = self.backpressure.lock();
+        backpressure.active_batches -= 1;
+        // Blocked senders re-check whether a reader is left
+        self.capacity_available.notify_all();
+    }
+
+    fn pending_messages(&self) -> Option<Vec<Vec<f64>>> {
         let queues = self.queues.read();
-        let messages = queues.messages(node_id as usize);
-        message_iterator.init(messages);
+        Some(
+            (0..queues.node_count())
+                .map(|node_id| queues.messages(node_id).to_vec())
+                .collect(),
+        )
     }
 
     fn release(&self) {
@@ -325,6 +475,8 @@ impl MessageIterator for ReducingMessageIterator {
 /// algorithms like PageRank where messages are summed.
 ///
 /// Uses double-buffered atomic arrays that are swapped between iterations.
+/// Whether a node received a message is tracked separately from its value,
+/// so a reduced value that equals the reducer's identity is still delivered.
 ///
 /// # Example
 ///
@@ -348,6 +500,9 @@ impl MessageIterator for ReducingMessageIterator {
 pub struct ReducingMessenger {
     send_array: parking_lot::RwLock<HugeAtomicDoubleArray>,
     receive_array: parking_lot::RwLock<HugeAtomicDoubleArray>,
+    /// Nodes that were sent at least one message, per buffer
+    send_received: parking_lot::RwLock<HugeAtomicBitSet>,
+    receive_received: parking_lot::RwLock<HugeAtomicBitSet>,
     reducer: Box<dyn MessageReducer<f64>>,
     track_sender: bool,
     send_sender_array: Option<parking_lot::RwLock<HugeAtomicLongArray>>,
@@ -398,6 +553,8 @@ impl ReducingMessenger {
         Self {
             send_array: parking_lot::RwLock::new(send_array),
             receive_array: parking_lot::RwLock::new(receive_array),
+            send_received: parking_lot::RwLock::new(HugeAtomicBitSet::new(node_count)),
+            receive_received: parking_lot::RwLock::new(HugeAtomicBitSet::new(node_count)),
             reducer,
             track_sender,
             send_sender_array,
@@ -413,6 +570,10 @@ impl Messenger<ReducingMessageIterator> for ReducingMessenger {
         let mut receive = self.receive_array.write();
         std::mem::swap(&mut *send, &mut *receive);
 
+        let mut send_received = self.send_received.write();
+        std::mem::swap(&mut *send_received, &mut *self.receive_received.write());
+        send_received.clear();
+
         // Swap sender arrays if tracking
         if self.track_sender {
             let mut send_sender = self.send_sender_array.as_ref().unwrap().write();
@@ -430,6 +591,7 @@ impl Messenger<ReducingMessageIterator> for ReducingMessenger {
 
     fn send_to(&self, source_node_id: u64, target_node_id: u64, message: f64) {
         let target = target_node_id as usize;
+        self.send_received.read().set(target);
 
         if self.track_sender {
             // Atomic update with sender tracking
@@ -444,9 +606,9 @@ impl Messenger<ReducingMessageIterator> for ReducingMessenger {
                 // compare_and_exchange returns witness value (equals current if successful)
                 let witness = send_array.compare_and_exchange(target, current, reduced);
 
-                if (witness - current).abs() < f64::EPSILON {
+                if witness.to_bits() == current.to_bits() {
                     // Success - update sender if the reduced value changed
-                    if (reduced - current).abs() > f64::EPSILON {
+                    if reduced.to_bits() != current.to_bits() {
                         send_sender_array.write().set(target, source_node_id as i64);
                     }
                     break;
@@ -465,7 +627,7 @@ impl Messenger<ReducingMessageIterator> for ReducingMessenger {
 
                 let witness = send_array.compare_and_exchange(target, current, reduced);
 
-                if (witness - current).abs() < f64::EPSILON {
+                if witness.to_bits() == current.to_bits() {
                     break;
                 } else {
                     continue;
@@ -490,7 +652,9 @@ impl Messenger<ReducingMessageIterator> for ReducingMessenger {
         // Get and replace with identity (consume the message)
         let receive_array = self.receive_array.read();
         let message = receive_array.get_and_replace(node, identity);
-        let has_message = (message - identity).abs() > f64::EPSILON;
+        let receive_received = self.receive_received.read();
+        let has_message = receive_received.get(node);
+        receive_received.clear_bit(node);
 
         let sender = if self.track_sender && has_message {
             let sender_array = self.receive_sender_array.as_ref().unwrap();
@@ -502,6 +666,22 @@ impl Messenger<ReducingMessageIterator> for ReducingMessenger {
         message_iterator.init(message, has_message, sender);
     }
 
+    fn pending_messages(&self) -> Option<Vec<Vec<f64>>> {
+        let send_array = self.send_array.read();
+        let send_received = self.send_received.read();
+        Some(
+            (0..send_array.size())
+                .map(|node| {
+                    if send_received.get(node) {
+                        vec![send_array.get(node)]
+                    } else {
+                        Vec::new()
+                    }
+                })
+                .collect(),
+        )
+    }
+
     fn sender(&self, node_id: u64) -> Option<u64> {
         if self.track_sender {
             let sender_array = self.receive_sender_array.as_ref().unwrap();
@@ -519,7 +699,7 @@ impl Messenger<ReducingMessageIterator> for ReducingMessenger {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::pregel::{MaxReducer, MinReducer, SumReducer};
+    use crate::pregel::{FnReducer, MaxReducer, MinReducer, SumReducer};
 
     #[test]
     fn test_sync_queue_messenger_basic() {
@@ -545,6 +725,31 @@ mod tests {
         assert_eq!(iter.next(), Some(3.0));
     }
 
+    #[test]
+    fn test_pending_messages_survive_restore() {
+        let messenger = SyncQueueMessenger::new(3);
+        messenger.send_to(0, 1, 1.0);
+        messenger.send_to(0, 1, 2.0);
+        messenger.send_to(1, 2, 3.0);
+        let pending = messenger.pending_messages().unwrap();
+        assert_eq!(pending, vec![vec![], vec![1.0, 2.0], vec![3.0]]);
+
+        let restored = SyncQueueMessenger::new(3);
+        restored.restore_pending_messages(&pending);
+        restored.init_iteration(1);
+        let mut iter = restored.message_iterator();
+        restored.init_message_iterator(&mut iter, 1, false);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1.0, 2.0]);
+
+        let reducing = ReducingMessenger::new(3, Box::new(SumReducer), false);
+        reducing.send_to(0, 2, 1.5);
+        reducing.send_to(1, 2, 2.5);
+        assert_eq!(
+            reducing.pending_messages().unwrap(),
+            vec![vec![], vec![], vec![4.0]]
+        );
+    }
+
     #[test]
     fn test_sync_queue_messenger_first_iteration() {
         let messenger = SyncQueueMessenger::new(3);
@@ -583,6 +788,203 @@ mod tests {
         messenger.init_iteration(1); // Should compact
     }
 
+    #[test]
+    fn test_unbounded_async_messenger_keeps_messages_on_read() {
+        let messenger = AsyncQueueMessenger::new(3);
+        messenger.send_to(0, 1, 1.0);
+
+        for _ in 0..2 {
+            let mut iter = messenger.message_iterator();
+            messenger.init_message_iterator(&mut iter, 1, false);
+            assert_eq!(iter.collect::<Vec<_>>(), vec![1.0]);
+        }
+    }
+
+    #[test]
+    fn test_bounded_async_messenger_keeps_messages_on_read() {
+        let messenger = AsyncQueueMessenger::bounded(3, 4);
+        messenger.send_to(0, 1, 1.0);
+
+        for _ in 0..2 {
+            let mut iter = messenger.message_iterator();
+            messenger.init_message_iterator(&mut iter, 1, false);
+            assert_eq!(iter.collect::<Vec<_>>(), vec![1.0]);
+        }
+        // Reading frees capacity even though the message stays readable.
+        for i in 0..4 {
+            messenger.send_to(0, 2, i as f64);
+        }
+        assert_eq!(messenger.peak_pending(), 4);
+        assert_eq!(messenger.overflowed_messages(), 0);
+    }
+
+    #[test]
+    fn test_bounded_async_messenger_applies_backpressure() {
+        use std::sync::Arc;
+
+        let messenger = Arc::new(AsyncQueueMessenger::bounded(2, 4));
+        // Reader and producer are both running batches.
+        messenger.begin_batch();
+        messenger.begin_batch();
+
+        let producer = {
+            let messenger = Arc::clone(&messenger);
+            std::thread::spawn(move || {
+                for i in 0..100 {
+                    messenger.send_to(0, 1, i as f64);
+                }
+                messenger.end_batch();
+            })
+        };
+
+        // Unread, the producer stalls at the bound.
+        while messenger.blocked_senders() == 0 {
+            std::thread::yield_now();
+        }
+        assert_eq!(messenger.peak_pending(), 4);
+
+        let mut iter = messenger.message_iterator();
+        let mut received = Vec::new();
+        while received.len() < 100 {
+            messenger.init_message_iterator(&mut iter, 1, false);
+            received = iter.by_ref().collect();
+            std::thread::yield_now();
+        }
+        producer.join().unwrap();
+        messenger.end_batch();
+
+        assert_eq!(received, (0..100).map(|i| i as f64).collect::<Vec<_>>());
+        assert_eq!(messenger.peak_pending(), 4);
+        assert_eq!(messenger.overflowed_messages(), 0);
+    }
+
+    #[test]
+    fn test_bounded_async_messenger_admits_over_bound_without_reader() {
+        let messenger = AsyncQueueMessenger::bounded(2, 2);
+        messenger.begin_batch();
+        for i in 0..3 {
+            messenger.send_to(0, 1, i as f64);
+        }
+        messenger.end_batch();
+
+        assert_eq!(messenger.overflowed_messages(), 1);
+        let mut iter = messenger.message_iterator();
+        messenger.init_message_iterator(&mut iter, 1, false);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0.0, 1.0, 2.0]);
+    }
+
+    /// Min-label propagation over a random graph, returning the final labels
+    fn propagate_min_labels(
+        messenger: std::sync::Arc<AsyncQueueMessenger>,
+        message_queue_capacity: Option<usize>,
+        concurrency: usize,
+    ) -> Vec<f64> {
+        use crate::config::PregelConfig;
+        use crate::pregel::{ComputeFn, InitFn, PregelBuilder, PregelSchema};
+        use crate::types::graph_store::{DefaultGraphStore, GraphStore};
+        use crate::types::random::{RandomGraphConfig, RandomRelationshipConfig};
+        use crate::types::ValueType;
+        use std::sync::Arc;
+
+        let store = DefaultGraphStore::random(&RandomGraphConfig {
+            seed: Some(17),
+            node_count: 40,
+            relationships: vec![RandomRelationshipConfig::new("REL", 0.1)],
+            ..RandomGraphConfig::default()
+        })
+        .unwrap();
+        let mut config = PregelConfig {
+            is_asynchronous: true,
+            message_queue_capacity,
+            ..PregelConfig::default()
+        };
+        config.base.concurrency = concurrency;
+
+        let init_fn: InitFn<PregelConfig> = Arc::new(|context| {
+            let label = context.node_id() as f64;
+            context.set_node_value("label", label);
+        });
+        let compute_fn: ComputeFn<PregelConfig, AsyncQueueMessageIterator> =
+            Arc::new(|context, messages| {
+                let label = context.double_node_value("label");
+                let smallest = messages.fold(label, f64::min);
+                if context.is_initial_superstep() || smallest < label {
+                    context.set_node_value("label", smallest);
+                    context.send_to_neighbors(smallest);
+                }
+                context.vote_to_halt();
+            });
+
+        let result = PregelBuilder::new()
+            .graph(store.get_graph())
+            .config(config)
+            .schema(
+                PregelSchema::builder()
+                    .add_public("label", ValueType::Double)
+                    .build(),
+            )
+            .init_fn(init_fn)
+            .compute_fn(compute_fn)
+            .messenger(messenger)
+            .build()
+            .run();
+        (0..40)
+            .map(|node| result.node_values.double_value("label", node))
+            .collect()
+    }
+
+    #[test]
+    fn test_bounded_async_messenger_keeps_pregel_results() {
+        use std::sync::Arc;
+
+        let unbounded = propagate_min_labels(Arc::new(AsyncQueueMessenger::new(40)), None, 4);
+        // The executor applies the configured capacity to the messenger.
+        let messenger = Arc::new(AsyncQueueMessenger::new(40));
+        let bounded = propagate_min_labels(Arc::clone(&messenger), Some(512), 4);
+        assert_eq!(bounded, unbounded);
+        assert_eq!(messenger.capacity(), Some(512));
+        assert!(messenger.peak_pending() > 0);
+        assert!(messenger.peak_pending() <= 512);
+    }
+
+    #[test]
+    fn test_bounded_async_messenger_single_reader_keeps_pregel_results() {
+        use std::sync::Arc;
+
+        let unbounded = propagate_min_labels(Arc::new(AsyncQueueMessenger::new(40)), None, 1);
+        let messenger = Arc::new(AsyncQueueMessenger::new(40));
+        let bounded = propagate_min_labels(Arc::clone(&messenger), Some(4), 1);
+
+        // The bound is reached with nobody else to read, yet nothing panics
+        // and the labels match the unbounded run.
+        assert_eq!(bounded, unbounded);
+        assert!(messenger.overflowed_messages() > 0);
+        assert!(messenger.peak_pending() > 4);
+    }
+
+    #[test]
+    fn test_from_config_uses_message_queue_capacity() {
+        use crate::config::PregelConfig;
+
+        let config = PregelConfig {
+            message_queue_capacity: Some(8),
+            ..PregelConfig::default()
+        };
+        assert!(config.validate().is_ok());
+        let messenger = AsyncQueueMessenger::from_config(4, &config);
+        assert_eq!(messenger.capacity(), Some(8));
+        assert_eq!(
+            AsyncQueueMessenger::from_config(4, &PregelConfig::default()).capacity(),
+            None
+        );
+
+        let invalid = PregelConfig {
+            message_queue_capacity: Some(0),
+            ..PregelConfig::default()
+        };
+        assert!(invalid.validate().is_err());
+    }
+
     #[test]
     #[should_panic(expected = "Cannot send NaN as a message")]
     fn test_async_queue_messenger_rejects_nan() {
@@ -659,6 +1061,38 @@ mod tests {
         assert_eq!(iter.next(), None);
     }
 
+    #[test]
+    fn test_reducing_messenger_custom_product_reducer() {
+        let reducer = Box::new(FnReducer::new(1.0, |a, b| a * b));
+        let messenger = ReducingMessenger::new(3, reducer, false);
+
+        messenger.send_to(0, 1, 2.0);
+        messenger.send_to(2, 1, 3.0);
+        messenger.send_to(0, 1, 4.0);
+        // A message equal to the identity still counts as received.
+        messenger.send_to(1, 2, 1.0);
+
+        messenger.init_iteration(1);
+
+        let mut iter = messenger.message_iterator();
+        messenger.init_message_iterator(&mut iter, 1, false);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![24.0]);
+
+        let mut iter = messenger.message_iterator();
+        messenger.init_message_iterator(&mut iter, 2, false);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1.0]);
+
+        let mut iter = messenger.message_iterator();
+        messenger.init_message_iterator(&mut iter, 0, false);
+        assert!(iter.is_empty());
+
+        // Messages do not leak into the next iteration.
+        messenger.init_iteration(2);
+        let mut iter = messenger.message_iterator();
+        messenger.init_message_iterator(&mut iter, 1, false);
+        assert!(iter.is_empty());
+    }
+
     #[test]
     fn test_reducing_messenger_iterator_reset() {
         let reducer = Box::new(SumReducer);
@@ -45,6 +45,7 @@
 //! ```
 
 // Core traits and configuration
+mod checkpoint;
 mod computation;
 mod compute_step;
 mod computer;
@@ -64,11 +65,12 @@ pub use crate::core::utils::partition::Partition;
 
 // Re-exports from this module
 pub use crate::config::{Partitioning, PregelConfig, PregelRuntimeConfig};
+pub use checkpoint::{NodeValueColumn, PregelCheckpoint};
 pub use computation::{BasePregelComputation, PregelComputation};
 pub use compute_step::{ComputeFn, ForkJoinComputeStep, InitFn};
 pub use computer::{ForkJoinComputer, PregelComputer, PregelComputerBuilder};
 pub use context::{ComputeContext, InitContext, MasterComputeContext, NodeCentricContext};
-pub use executor::{Pregel, PregelBuilder};
+pub use executor::{HistoryError, Pregel, PregelBuildError, PregelBuilder};
 pub use messages::{
     empty_messages, EmptyMessageIterator, EmptyMessages, MessageIterator, MessageReducer, Messages,
     Messenger,
@@ -80,7 +82,7 @@ pub use messengers::{
 pub use node_value::NodeValue;
 pub use projection::{default_value_to_gds, materialize_pregel_values, PropertyProjection};
 pub use queues::{AsyncDoubleQueues, AsyncQueueIterator, SyncDoubleQueues, SyncQueueIterator};
-pub use reducers::{CountReducer, MaxReducer, MinReducer, Reducer, SumReducer};
+pub use reducers::{CountReducer, FnReducer, MaxReducer, MinReducer, Reducer, SumReducer};
 pub use result::PregelResult;
 pub use schema::{DefaultValue, Element, PregelSchema, PregelSchemaBuilder, Visibility};
 
@@ -5,9 +5,9 @@
 
 use crate::collections::{HugeDoubleArray, HugeLongArray, HugeObjectArray};
 use crate::concurrency::Concurrency;
-use crate::pregel::{DefaultValue, Element, PregelSchema};
+use crate::pregel::{DefaultValue, Element, NodeValueColumn, PregelSchema};
 use crate::types::ValueType;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 #[cfg(test)]
 use crate::pregel::Visibility;
@@ -78,6 +78,19 @@ impl NodeValue {
         &self.schema
     }
 
+    /// Number of nodes the property arrays hold values for.
+    pub fn node_count(&self) -> usize {
+        self.properties
+            .values()
+            .next()
+            .map_or(0, |array| match array {
+                PropertyArray::Double(arr) => arr.size(),
+                PropertyArray::Long(arr) => arr.size(),
+                PropertyArray::LongArray(arr) => arr.size(),
+                PropertyArray::DoubleArray(arr) => arr.size(),
+            })
+    }
+
     /// Get a double value for a specific node and property.
     pub fn double_value(&self, key: &str, node_id: usize) -> f64 {
         self.check_property(key, ValueType::Double);
@@ -150,6 +163,66 @@ impl NodeValue {
         }
     }
 
+    /// Copy every property into plain per-node columns, keyed by property.
+    pub(crate) fn columns(&self) -> BTreeMap<String, NodeValueColumn> {
+        let node_count = self.node_count();
+        self.properties
+            .iter()
+            .map(|(key, array)| {
+                let column = match array {
+                    PropertyArray::Double(arr) => {
+                        NodeValueColumn::Double((0..node_count).map(|n| arr.get(n)).collect())
+                    }
+                    PropertyArray::Long(arr) => {
+                        NodeValueColumn::Long((0..node_count).map(|n| arr.get(n)).collect())
+                    }
+                    PropertyArray::LongArray(arr) => NodeValueColumn::LongArray(
+                        (0..node_count).map(|n| arr.get(n).clone()).collect(),
+                    ),
+                    PropertyArray::DoubleArray(arr) => NodeValueColumn::DoubleArray(
+                        (0..node_count).map(|n| arr.get(n).clone()).collect(),
+                    ),
+                };
+                (key.clone(), column)
+            })
+            .collect()
+    }
+
+    /// Overwrite properties with columns produced by [`NodeValue::columns`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a column is missing from the schema, has a different type or
+    /// holds more values than there are nodes.
+    pub(crate) fn restore_columns(&mut self, columns: &BTreeMap<String, NodeValueColumn>) {
+        for (key, column) in columns {
+            self.check_property(key, column.value_type());
+            match (self.properties.get_mut(key).unwrap(), column) {
+                (PropertyArray::Double(arr), NodeValueColumn::Double(values)) => {
+                    for (node, &value) in values.iter().enumerate() {
+                        arr.set(node, value);
+                    }
+                }
+                (PropertyArray::Long(arr), NodeValueColumn::Long(values)) => {
+                    for (node, &value) in values.iter().enumerate() {
+                        arr.set(node, value);
+                    }
+                }
+                (PropertyArray::LongArray(arr), NodeValueColumn::LongArray(values)) => {
+                    for (node, value) in values.iter().enumerate() {
+                        arr.set(node, value.clone());
+                    }
+                }
+                (PropertyArray::DoubleArray(arr), NodeValueColumn::DoubleArray(values)) => {
+                    for (node, value) in values.iter().enumerate() {
+                        arr.set(node, value.clone());
+                    }
+                }
+                _ => unreachable!("Type was validated"),
+            }
+        }
+    }
+
     /// Check that a property exists and has the expected type.
     fn check_property(&self, key: &str, expected_type: ValueType) {
         let actual_type = self.property_types.get(key).copied();
@@ -136,6 +136,16 @@ impl SyncDoubleQueues {
         self.read_queues.get(node_id)
     }
 
+    /// Number of nodes these queues hold messages for.
+    pub fn node_count(&self) -> usize {
+        self.read_queues.size()
+    }
+
+    /// Messages sent to a node in the current iteration, delivered after the next swap.
+    pub fn pending(&self, node_id: usize) -> &[f64] {
+        self.write_queues.get(node_id)
+    }
+
     /// Create a message iterator for a specific node.
     ///
     /// # Arguments
@@ -359,6 +369,8 @@ pub struct AsyncDoubleQueues {
 struct AsyncQueue {
     messages: Vec<f64>,
     head: usize,
+    /// Index of the first message not yet returned by `deliver`
+    delivered: usize,
 }
 
 impl AsyncDoubleQueues {
@@ -428,6 +440,21 @@ impl AsyncDoubleQueues {
         queue.head >= queue.messages.len()
     }
 
+    /// Mark all messages of a node as delivered without removing them.
+    ///
+    /// Delivered messages stay readable through `messages()`; only `pop()`
+    /// removes messages from the queue.
+    ///
+    /// # Returns
+    ///
+    /// The number of messages that were not delivered before
+    pub fn deliver(&mut self, node_id: usize) -> usize {
+        let queue = self.queues.get_mut(node_id);
+        let start = queue.delivered.max(queue.head);
+        queue.delivered = queue.messages.len();
+        queue.delivered - start
+    }
+
     /// Compact all queues to reclaim space.
     ///
     /// Removes consumed messages (before head pointer) from queues where the
@@ -444,6 +471,7 @@ impl AsyncDoubleQueues {
                 if capacity > 0 && (queue.head as f64 / capacity as f64) > Self::COMPACT_THRESHOLD {
                     // Remove consumed messages
                     queue.messages.drain(0..queue.head);
+                    queue.delivered = queue.delivered.saturating_sub(queue.head);
                     queue.head = 0;
                 }
             }
@@ -466,6 +494,11 @@ impl AsyncDoubleQueues {
         &queue.messages[queue.head..]
     }
 
+    /// Number of nodes these queues hold messages for.
+    pub fn node_count(&self) -> usize {
+        self.queues.size()
+    }
+
     /// Create a message iterator for a specific node.
     ///
     /// # Arguments
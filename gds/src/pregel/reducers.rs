@@ -107,6 +107,56 @@ impl MessageReducer<f64> for CountReducer {
     }
 }
 
+/// Custom reducer built from an identity element and a combining closure
+///
+/// Use this for reductions beyond the standard ones. The closure must be
+/// associative and commutative, since messages arrive in no particular
+/// order, and `reduce(identity, x)` must equal `x`.
+///
+/// # Example
+///
+/// ```ignore
+/// let product = FnReducer::new(1.0, |a, b| a * b);
+/// assert_eq!(product.reduce(product.reduce(2.0, 3.0), 4.0), 24.0);
+/// assert_eq!(product.identity(), 1.0);
+/// ```
+#[derive(Clone, Copy)]
+pub struct FnReducer<F> {
+    identity: f64,
+    reduce: F,
+}
+
+impl<F> FnReducer<F>
+where
+    F: Fn(f64, f64) -> f64 + Send + Sync,
+{
+    /// Create a reducer with the given identity element and combining closure
+    pub fn new(identity: f64, reduce: F) -> Self {
+        Self { identity, reduce }
+    }
+}
+
+impl<F> MessageReducer<f64> for FnReducer<F>
+where
+    F: Fn(f64, f64) -> f64 + Send + Sync,
+{
+    fn reduce(&self, current: f64, message: f64) -> f64 {
+        (self.reduce)(current, message)
+    }
+
+    fn identity(&self) -> f64 {
+        self.identity
+    }
+}
+
+impl<F> fmt::Debug for FnReducer<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnReducer")
+            .field("identity", &self.identity)
+            .finish_non_exhaustive()
+    }
+}
+
 /// Enum representing all standard reducer types
 ///
 /// Used for parsing reducer specifications from strings and for
@@ -248,6 +298,14 @@ mod tests {
         assert_eq!(reducer.reduce(reducer.identity(), 999.0), 1.0);
     }
 
+    #[test]
+    fn test_fn_reducer() {
+        let reducer = FnReducer::new(1.0, |a, b| a * b);
+        assert_eq!(reducer.identity(), 1.0);
+        assert_eq!(reducer.reduce(reducer.reduce(2.0, 3.0), 4.0), 24.0);
+        assert_eq!(reducer.reduce(reducer.identity(), 42.0), 42.0);
+    }
+
     #[test]
     fn test_reducer_parse() {
         assert_eq!(Reducer::parse("sum").unwrap(), Reducer::Sum);
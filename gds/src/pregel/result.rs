@@ -3,7 +3,16 @@
 //! Represents the immutable output of a Pregel computation including
 //! computed node values and metadata about the execution.
 
+use crate::collections::backends::vec::{VecDouble, VecDoubleArray, VecLong, VecLongArray};
 use crate::pregel::node_value::NodeValue;
+use crate::projection::NodeLabel;
+use crate::types::graph_store::{DefaultGraphStore, GraphStore, GraphStoreError, GraphStoreResult};
+use crate::types::properties::node::{
+    DefaultDoubleArrayNodePropertyValues, DefaultDoubleNodePropertyValues,
+    DefaultLongArrayNodePropertyValues, DefaultLongNodePropertyValues, NodePropertyValues,
+};
+use crate::types::ValueType;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 /// Represents the result of a Pregel computation.
@@ -38,6 +47,14 @@ pub struct PregelResult {
     /// - `true`: Converged naturally (all nodes voted to halt)
     /// - `false`: Stopped after reaching maximum iteration limit
     pub did_converge: bool,
+
+    /// Value of each tracked node after every superstep, see
+    /// [`PregelBuilder::track_history`](crate::pregel::PregelBuilder::track_history).
+    /// Empty unless history tracking was requested.
+    pub history: HashMap<u64, Vec<f64>>,
+
+    /// Number of nodes that had not voted to halt after each superstep.
+    pub active_node_counts: Vec<usize>,
 }
 
 impl PregelResult {
@@ -53,7 +70,106 @@ impl PregelResult {
             node_values: Arc::new(node_values),
             ran_iterations,
             did_converge,
+            history: HashMap::new(),
+            active_node_counts: Vec::new(),
+        }
+    }
+
+    /// Attach the per-superstep values recorded for tracked nodes.
+    pub fn with_history(mut self, history: HashMap<u64, Vec<f64>>) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Attach the number of active nodes recorded after each superstep.
+    pub fn with_active_node_counts(mut self, active_node_counts: Vec<usize>) -> Self {
+        self.active_node_counts = active_node_counts;
+        self
+    }
+
+    /// Add the final values of the Pregel property `property_key` to `store`.
+    ///
+    /// The node property column follows the element type in the Pregel
+    /// schema: `Double` and `Long` become scalar columns, `DoubleArray` and
+    /// `LongArray` array columns. The property is registered for all nodes
+    /// under the same key.
+    ///
+    /// # Errors
+    ///
+    /// - `PropertyNotFound` if the schema has no element `property_key`
+    /// - `InvalidOperation` if the node counts differ or the element type
+    ///   has no node property column
+    pub fn write_to_store(
+        &self,
+        store: &mut DefaultGraphStore,
+        property_key: &str,
+    ) -> GraphStoreResult<()> {
+        let values = &self.node_values;
+        let value_type = values
+            .schema()
+            .property_type(property_key)
+            .ok_or_else(|| GraphStoreError::PropertyNotFound(property_key.to_string()))?;
+
+        let node_count = store.node_count();
+        if values.node_count() != node_count {
+            return Err(GraphStoreError::InvalidOperation(format!(
+                "Pregel result has {} nodes, graph store has {}",
+                values.node_count(),
+                node_count
+            )));
         }
+
+        let nodes = 0..node_count;
+        let property_values: Arc<dyn NodePropertyValues> = match value_type {
+            ValueType::Double => Arc::new(DefaultDoubleNodePropertyValues::from_collection(
+                VecDouble::from(
+                    nodes
+                        .map(|node| values.double_value(property_key, node))
+                        .collect::<Vec<_>>(),
+                ),
+                node_count,
+            )),
+            ValueType::Long => Arc::new(DefaultLongNodePropertyValues::from_collection(
+                VecLong::from(
+                    nodes
+                        .map(|node| values.long_value(property_key, node))
+                        .collect::<Vec<_>>(),
+                ),
+                node_count,
+            )),
+            ValueType::DoubleArray => {
+                Arc::new(DefaultDoubleArrayNodePropertyValues::from_collection(
+                    VecDoubleArray::from(
+                        nodes
+                            .map(|node| {
+                                Some(values.double_array_value(property_key, node).to_vec())
+                            })
+                            .collect::<Vec<_>>(),
+                    ),
+                    node_count,
+                ))
+            }
+            ValueType::LongArray => Arc::new(DefaultLongArrayNodePropertyValues::from_collection(
+                VecLongArray::from(
+                    nodes
+                        .map(|node| Some(values.long_array_value(property_key, node).to_vec()))
+                        .collect::<Vec<_>>(),
+                ),
+                node_count,
+            )),
+            other => {
+                return Err(GraphStoreError::InvalidOperation(format!(
+                    "Pregel property '{}' of type {:?} cannot be written to the store",
+                    property_key, other
+                )))
+            }
+        };
+
+        store.add_node_property(
+            HashSet::from([NodeLabel::all_nodes()]),
+            property_key,
+            property_values,
+        )
     }
 }
 
@@ -74,4 +190,62 @@ mod tests {
         assert_eq!(result.ran_iterations, 100);
         assert!(!result.did_converge);
     }
+
+    #[test]
+    fn test_write_to_store_round_trips_values() {
+        use crate::config::PregelConfig;
+        use crate::pregel::{
+            ComputeFn, InitFn, PregelBuilder, PregelSchema, SyncQueueMessageIterator,
+            SyncQueueMessenger,
+        };
+        use crate::types::random::{RandomGraphConfig, RandomRelationshipConfig};
+
+        let config = RandomGraphConfig {
+            seed: Some(5),
+            node_count: 12,
+            relationships: vec![RandomRelationshipConfig::new("REL", 0.3)],
+            ..RandomGraphConfig::default()
+        };
+        let mut store = DefaultGraphStore::random(&config).unwrap();
+        let graph = store.get_graph();
+
+        let schema = PregelSchema::builder()
+            .add_public("degree", ValueType::Double)
+            .add_public("scaled_id", ValueType::Long)
+            .build();
+        let init_fn: InitFn<PregelConfig> = Arc::new(|context| {
+            let degree = context.degree() as f64;
+            let scaled_id = context.node_id() as i64 * 10;
+            context.set_node_value("degree", degree);
+            context.set_node_value_long("scaled_id", scaled_id);
+        });
+        let compute_fn: ComputeFn<PregelConfig, SyncQueueMessageIterator> =
+            Arc::new(|context, _messages| context.vote_to_halt());
+
+        let result = PregelBuilder::new()
+            .graph(graph)
+            .config(PregelConfig::default())
+            .schema(schema)
+            .init_fn(init_fn)
+            .compute_fn(compute_fn)
+            .messenger(Arc::new(SyncQueueMessenger::new(12)))
+            .build()
+            .run();
+
+        result.write_to_store(&mut store, "degree").unwrap();
+        result.write_to_store(&mut store, "scaled_id").unwrap();
+        assert!(result.write_to_store(&mut store, "missing").is_err());
+
+        let degrees = store.node_property_values("degree").unwrap();
+        let scaled_ids = store.node_property_values("scaled_id").unwrap();
+        assert_eq!(degrees.value_type(), ValueType::Double);
+        assert_eq!(scaled_ids.value_type(), ValueType::Long);
+        for node in 0..12u64 {
+            assert_eq!(
+                degrees.double_value(node).unwrap(),
+                store.degree(node as i64) as f64
+            );
+            assert_eq!(scaled_ids.long_value(node).unwrap(), node as i64 * 10);
+        }
+    }
 }
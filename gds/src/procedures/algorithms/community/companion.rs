@@ -6,6 +6,7 @@
 
 use super::super::stubs::{LongNodePropertyValues, NodePropertyValues};
 use super::consecutive_values::ConsecutiveLongNodePropertyValues;
+use super::size_filter::{community_size_percentile, CommunitySizeFilteredValues};
 
 /// Community algorithm companion utilities
 ///
@@ -140,6 +141,32 @@ impl CommunityCompanion {
         Self::node_property_values(consecutive_ids, filtered)
     }
 
+    /// Remove communities smaller than a percentile of the community sizes
+    ///
+    /// A fixed minimum community size does not carry over between graphs of
+    /// different scale; a percentile adapts to the size distribution of each
+    /// result. Sizes use the nearest-rank percentile, so with sizes
+    /// `[1, 1, 2, 10, 50]` the 50th percentile is 2 and only the two singleton
+    /// communities are removed.
+    ///
+    /// ## Parameters
+    ///
+    /// - `results`: Community property values to filter
+    /// - `percentile`: Percentile in `0.0..=100.0` of community sizes to keep at or above
+    /// - `concurrency`: Number of threads for parallel processing (currently sequential)
+    ///
+    /// ## Returns
+    ///
+    /// Node property values where members of removed communities have no value
+    pub fn filter_small_communities_by_percentile(
+        results: Box<dyn LongNodePropertyValues>,
+        percentile: f64,
+        _concurrency: usize,
+    ) -> Box<dyn LongNodePropertyValues> {
+        let min_size = community_size_percentile(results.as_ref(), percentile);
+        Box::new(CommunitySizeFilteredValues::new(results, min_size))
+    }
+
     /// Extract seeding node property values
     ///
     /// Translation of: `extractSeedingNodePropertyValues()` (lines 197-211)
@@ -248,6 +275,25 @@ mod tests {
         assert_eq!(result.node_count(), 5);
     }
 
+    #[test]
+    fn test_filter_small_communities_by_percentile() {
+        // Community sizes: 1 -> 1, 2 -> 1, 3 -> 2, 4 -> 10, 5 -> 50
+        let mut values = vec![1, 2, 3, 3];
+        values.extend(std::iter::repeat_n(4, 10));
+        values.extend(std::iter::repeat_n(5, 50));
+        let props = Box::new(TestLongProperty { values });
+
+        let result = CommunityCompanion::filter_small_communities_by_percentile(props, 50.0, 1);
+
+        assert_eq!(result.node_count(), 64);
+        assert!(!result.has_value(0));
+        assert!(!result.has_value(1));
+        assert!((2..64).all(|node| result.has_value(node)));
+        assert_eq!(result.long_value(2), 3);
+        assert_eq!(result.long_value(4), 4);
+        assert_eq!(result.long_value(63), 5);
+    }
+
     #[test]
     fn test_extract_seeding_property_stub() {
         let result = CommunityCompanion::extract_seeding_property("test_property");
@@ -33,7 +33,12 @@
 //! changed, useful for incremental/iterative algorithms.
 //!
 //! ### Community Filtering
-//! `CommunityCompanion` provides utilities like minimum community size filtering.
+//! `CommunityCompanion` provides utilities like minimum community size filtering,
+//! either with a fixed minimum or relative to a percentile of community sizes.
+//!
+//! ### Modularity
+//! `modularity` scores any community assignment, so results of different
+//! community detection algorithms can be compared on the same metric.
 //!
 //! ## Usage
 //!
@@ -58,8 +63,12 @@
 pub mod companion;
 pub mod consecutive_values;
 pub mod incremental_values;
+pub mod modularity;
+pub mod size_filter;
 
 pub use companion::*;
 pub use consecutive_values::*;
 pub use incremental_values::*;
+pub use modularity::{modularity, relationship_weighted_graph};
+pub use size_filter::*;
 
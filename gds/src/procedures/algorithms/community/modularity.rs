@@ -0,0 +1,196 @@
+//! Modularity Scoring
+//!
+//! **Translation Source**: `org.neo4j.gds.modularity.ModularityCalculator`
+//!
+//! Scores any community assignment, independently of the algorithm that
+//! produced it, so Louvain, Leiden and Label Propagation results can be
+//! compared on the same metric.
+//!
+//! ## Formula
+//!
+//! With `W` the total stored relationship weight, and per community `c` the
+//! internal weight `in_c`, outgoing weight `out_c` and incoming weight `inc_c`:
+//!
+//! ```text
+//! Q = Σ_c [ in_c / W - (out_c · inc_c) / W² ]
+//! ```
+//!
+//! For directed graphs this is the Leicht-Newman directed modularity. Undirected
+//! graphs store every relationship in both directions, so `out_c == inc_c` and
+//! the formula reduces to the classic `Σ_c [ in_c / 2m - (tot_c / 2m)² ]`.
+
+use crate::collections::HugeLongArray;
+use crate::projection::eval::procedure::AlgorithmError;
+use crate::projection::RelationshipType;
+use crate::types::graph::Graph;
+use crate::types::prelude::GraphStore;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Compute the modularity of `communities` (one community id per node)
+///
+/// With `relationship_weight_property` set, relationship weights are read from
+/// that property; otherwise every relationship has weight 1.0. Returns `0.0`
+/// for graphs without relationships.
+pub fn modularity<G: GraphStore>(
+    graph_store: &G,
+    communities: &HugeLongArray,
+    relationship_weight_property: Option<&str>,
+) -> Result<f64, AlgorithmError> {
+    let graph = relationship_weighted_graph(graph_store, relationship_weight_property)?;
+    let weighted = relationship_weight_property.is_some();
+
+    if communities.size() < graph.node_count() {
+        return Err(AlgorithmError::InvalidGraph(format!(
+            "expected a community for each of {} nodes, got {}",
+            graph.node_count(),
+            communities.size()
+        )));
+    }
+
+    // community -> (internal, outgoing, incoming)
+    let mut sums: HashMap<i64, (f64, f64, f64)> = HashMap::new();
+    let mut total_weight = 0.0;
+
+    for node in 0..graph.node_count() {
+        let source_community = communities.get(node);
+        for cursor in graph.stream_relationships(node as i64, 1.0) {
+            let weight = if weighted { cursor.property() } else { 1.0 };
+            let target_community = communities.get(cursor.target_id() as usize);
+
+            total_weight += weight;
+            sums.entry(source_community).or_default().1 += weight;
+            sums.entry(target_community).or_default().2 += weight;
+            if source_community == target_community {
+                sums.entry(source_community).or_default().0 += weight;
+            }
+        }
+    }
+
+    if total_weight == 0.0 {
+        return Ok(0.0);
+    }
+
+    Ok(sums
+        .values()
+        .map(|(internal, outgoing, incoming)| {
+            internal / total_weight - (outgoing * incoming) / (total_weight * total_weight)
+        })
+        .sum())
+}
+
+/// Graph view whose relationship property is `relationship_weight_property`
+///
+/// Every relationship type carrying the property selects it. Fails if no type
+/// has the property. Without a property the unfiltered graph is returned.
+pub fn relationship_weighted_graph<G: GraphStore>(
+    graph_store: &G,
+    relationship_weight_property: Option<&str>,
+) -> Result<Arc<dyn Graph>, AlgorithmError> {
+    let Some(property) = relationship_weight_property else {
+        return Ok(graph_store.get_graph());
+    };
+
+    let relationship_types = graph_store.relationship_types();
+    let selectors: HashMap<RelationshipType, String> = relationship_types
+        .iter()
+        .filter(|rel_type| graph_store.has_relationship_property(rel_type, property))
+        .map(|rel_type| (rel_type.clone(), property.to_string()))
+        .collect();
+    if selectors.is_empty() {
+        return Err(AlgorithmError::Graph(format!(
+            "relationship weight property '{}' not found",
+            property
+        )));
+    }
+
+    graph_store
+        .get_graph_with_types_and_selectors(&relationship_types, &selectors)
+        .map_err(|e| AlgorithmError::Graph(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::graph_store::DefaultGraphStore;
+    use crate::types::properties::relationship::impls::default_relationship_property_values::DefaultRelationshipPropertyValues;
+    use crate::types::random::generators::typed_store;
+    use crate::types::schema::Direction;
+
+    /// Two 5-cliques {0..5} and {5..10} joined by the edge 4 - 5, stored in
+    /// both directions, with `bridge_weight` on the bridge and 1.0 elsewhere.
+    fn two_cliques(bridge_weight: f64) -> DefaultGraphStore {
+        let mut edges = Vec::new();
+        for offset in [0, 5] {
+            for a in 0..5 {
+                for b in 0..5 {
+                    if a != b {
+                        edges.push((offset + a, offset + b, 1.0));
+                    }
+                }
+            }
+        }
+        edges.extend([(4, 5, bridge_weight), (5, 4, bridge_weight)]);
+        // Property values follow the stored order: by source, then target
+        edges.sort_by_key(|&(source, target, _)| (source, target));
+
+        let mut store = typed_store(
+            "cliques",
+            10,
+            [(
+                "REL",
+                Direction::Directed,
+                edges
+                    .iter()
+                    .map(|&(source, target, _)| (source, target))
+                    .collect(),
+            )],
+        );
+        let weights: Vec<f64> = edges.iter().map(|&(_, _, weight)| weight).collect();
+        let count = weights.len();
+        store
+            .add_relationship_property(
+                RelationshipType::of("REL"),
+                "weight",
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    weights, count,
+                )),
+            )
+            .unwrap();
+        store
+    }
+
+    fn clique_partition() -> HugeLongArray {
+        HugeLongArray::from_vec((0..10).map(|n| n / 5).collect())
+    }
+
+    #[test]
+    fn test_two_clique_partition_is_high() {
+        let store = two_cliques(1.0);
+        let q = modularity(&store, &clique_partition(), None).unwrap();
+        // 2 * (20/42 - (21/42)^2)
+        assert!((q - (2.0 * (20.0 / 42.0 - 0.25))).abs() < 1e-9);
+        assert!(q > 0.45);
+    }
+
+    #[test]
+    fn test_mixed_partition_is_near_zero() {
+        let store = two_cliques(1.0);
+        let alternating = HugeLongArray::from_vec((0..10).map(|n| n % 2).collect());
+        let q = modularity(&store, &alternating, None).unwrap();
+        assert!(q.abs() < 0.15, "modularity {} should be near zero", q);
+
+        let singletons = HugeLongArray::from_vec((0..10).collect());
+        assert!(modularity(&store, &singletons, None).unwrap() < 0.0);
+    }
+
+    #[test]
+    fn test_weighted_bridge_lowers_modularity() {
+        let store = two_cliques(20.0);
+        let unweighted = modularity(&store, &clique_partition(), None).unwrap();
+        let weighted = modularity(&store, &clique_partition(), Some("weight")).unwrap();
+        assert!(weighted < unweighted);
+
+        assert!(modularity(&store, &clique_partition(), Some("missing")).is_err());
+    }
+}
@@ -8,7 +8,14 @@
 //!
 //! Formula: betweenness(v) = sum of (sigma[s,v] / sigma[s,t]) * delta[t]
 //!                           for all s,t where path goes through v
+//!
+//! `compute_sampled` runs the two phases from `k` sampled sources only and
+//! weights each source's dependencies by `1 / (k * p(s))`, where `p(s)` is the
+//! probability of drawing `s` under the `PivotStrategy`. The estimate is
+//! unbiased for every strategy; they differ in variance.
 
+use super::spec::PivotStrategy;
+use crate::core::utils::shuffle::{Random, SplittableRandom};
 use std::collections::VecDeque;
 
 #[derive(Clone)]
@@ -50,7 +57,7 @@ impl BetweennessCentralityComputationRuntime {
         // Process each node as a source
         for source_node in 0..node_count {
             self.forward_phase(source_node, &get_neighbors);
-            self.backward_phase(source_node, node_count);
+            self.backward_phase(source_node, 1.0);
         }
 
         // Normalize for undirected graphs (divide by 2)
@@ -64,6 +71,39 @@ impl BetweennessCentralityComputationRuntime {
         }
     }
 
+    /// Estimate betweenness from `sampling_size` sources chosen by `strategy`
+    ///
+    /// With `sampling_size >= node_count` and the `Uniform` strategy this is
+    /// the exact computation.
+    pub fn compute_sampled(
+        &mut self,
+        node_count: usize,
+        sampling_size: usize,
+        strategy: PivotStrategy,
+        seed: Option<u64>,
+        get_neighbors: impl Fn(usize) -> Vec<usize>,
+    ) -> BetweennessCentralityComputationResult {
+        for c in self.centralities.iter_mut() {
+            *c = 0.0;
+        }
+
+        let mut random = SplittableRandom::with_seed(seed);
+        for (source_node, weight) in
+            select_pivots(node_count, sampling_size, strategy, &mut random, &get_neighbors)
+        {
+            self.forward_phase(source_node, &get_neighbors);
+            self.backward_phase(source_node, weight);
+        }
+
+        for c in self.centralities.iter_mut() {
+            *c /= 2.0;
+        }
+
+        BetweennessCentralityComputationResult {
+            centralities: self.centralities.clone(),
+        }
+    }
+
     /// Phase 1: Forward BFS from source node
     /// Computes sigma (path counts) and predecessors
     fn forward_phase(
@@ -113,8 +153,9 @@ impl BetweennessCentralityComputationRuntime {
     }
 
     /// Phase 2: Backward dependency propagation
-    /// Process nodes in reverse BFS order to calculate dependencies
-    fn backward_phase(&mut self, source_node: usize, _node_count: usize) {
+    /// Process nodes in reverse BFS order to calculate dependencies,
+    /// adding them to the centralities scaled by `weight`
+    fn backward_phase(&mut self, source_node: usize, weight: f64) {
         // Reset delta
         self.delta.iter_mut().for_each(|d| *d = 0.0);
 
@@ -141,8 +182,63 @@ impl BetweennessCentralityComputationRuntime {
             // Accumulate centrality at this node (except for source nodes)
             // The dependency value represents how much this node benefits other nodes
             if node != source_node {
-                self.centralities[node] += self.delta[node];
+                self.centralities[node] += weight * self.delta[node];
+            }
+        }
+    }
+}
+
+/// Sampled sources with their estimator weights `1 / (k * p(s))`
+fn select_pivots(
+    node_count: usize,
+    sampling_size: usize,
+    strategy: PivotStrategy,
+    random: &mut SplittableRandom,
+    get_neighbors: impl Fn(usize) -> Vec<usize>,
+) -> Vec<(usize, f64)> {
+    if node_count == 0 || sampling_size == 0 {
+        return Vec::new();
+    }
+
+    match strategy {
+        PivotStrategy::Uniform => {
+            let k = sampling_size.min(node_count);
+            let mut nodes: Vec<usize> = (0..node_count).collect();
+            for i in 0..k {
+                let j = random.next_long(i, node_count);
+                nodes.swap(i, j);
+            }
+            let weight = node_count as f64 / k as f64;
+            nodes[..k].iter().map(|&node| (node, weight)).collect()
+        }
+        PivotStrategy::Random => {
+            let weight = node_count as f64 / sampling_size as f64;
+            (0..sampling_size)
+                .map(|_| (random.next_long(0, node_count), weight))
+                .collect()
+        }
+        PivotStrategy::DegreeWeighted => {
+            // Cumulative degrees; isolated nodes can never be drawn, which is
+            // fine as their dependencies are all zero.
+            let mut cumulative = Vec::with_capacity(node_count);
+            let mut total = 0usize;
+            for node in 0..node_count {
+                total += get_neighbors(node).len();
+                cumulative.push(total);
             }
+            if total == 0 {
+                return Vec::new();
+            }
+
+            (0..sampling_size)
+                .map(|_| {
+                    let ticket = random.next_long(0, total);
+                    let node = cumulative.partition_point(|&sum| sum <= ticket);
+                    let degree = cumulative[node] - if node == 0 { 0 } else { cumulative[node - 1] };
+                    let probability = degree as f64 / total as f64;
+                    (node, 1.0 / (sampling_size as f64 * probability))
+                })
+                .collect()
         }
     }
 }
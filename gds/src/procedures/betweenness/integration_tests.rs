@@ -3,6 +3,7 @@
 #[cfg(test)]
 mod tests {
     use super::super::computation::BetweennessCentralityComputationRuntime;
+    use super::super::spec::PivotStrategy;
     use std::collections::HashMap;
 
     fn build_graph(edges: Vec<(usize, usize)>, node_count: usize) -> HashMap<usize, Vec<usize>> {
@@ -146,4 +147,81 @@ mod tests {
                     "Node {}: centrality should be non-negative", i);
         }
     }
+
+    /// Mean squared error of sampled estimates against the exact values,
+    /// summed over all nodes, across `runs` seeds
+    fn sampling_error(
+        graph: &HashMap<usize, Vec<usize>>,
+        node_count: usize,
+        strategy: PivotStrategy,
+        runs: u64,
+    ) -> f64 {
+        let neighbors = |node: usize| graph.get(&node).cloned().unwrap_or_default();
+        let exact = BetweennessCentralityComputationRuntime::new(node_count)
+            .compute(node_count, neighbors)
+            .centralities;
+
+        let mut squared_error = 0.0;
+        for seed in 0..runs {
+            let estimate = BetweennessCentralityComputationRuntime::new(node_count)
+                .compute_sampled(node_count, 4, strategy, Some(seed), neighbors)
+                .centralities;
+            squared_error += estimate
+                .iter()
+                .zip(&exact)
+                .map(|(e, x)| (e - x).powi(2))
+                .sum::<f64>();
+        }
+        squared_error / runs as f64
+    }
+
+    /// Two 5-cliques joined by the path 4-10-11-12-5, plus isolated nodes 13..19
+    fn barbell_with_isolated_nodes() -> HashMap<usize, Vec<usize>> {
+        let mut edges = Vec::new();
+        for offset in [0, 5] {
+            for a in 0..5 {
+                for b in (a + 1)..5 {
+                    edges.push((offset + a, offset + b));
+                }
+            }
+        }
+        edges.extend([(4, 10), (10, 11), (11, 12), (12, 5)]);
+        build_graph(edges, 19)
+    }
+
+    #[test]
+    fn test_sampling_all_nodes_is_exact() {
+        let graph = barbell_with_isolated_nodes();
+        let neighbors = |node: usize| graph.get(&node).cloned().unwrap_or_default();
+
+        let exact = BetweennessCentralityComputationRuntime::new(19).compute(19, neighbors);
+        let sampled = BetweennessCentralityComputationRuntime::new(19).compute_sampled(
+            19,
+            19,
+            PivotStrategy::Uniform,
+            Some(7),
+            neighbors,
+        );
+
+        for (s, e) in sampled.centralities.iter().zip(&exact.centralities) {
+            assert!((s - e).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_degree_weighted_pivots_reduce_variance() {
+        let graph = barbell_with_isolated_nodes();
+
+        let uniform = sampling_error(&graph, 19, PivotStrategy::Uniform, 200);
+        let random = sampling_error(&graph, 19, PivotStrategy::Random, 200);
+        let degree_weighted = sampling_error(&graph, 19, PivotStrategy::DegreeWeighted, 200);
+
+        assert!(
+            degree_weighted < uniform,
+            "degree weighted {} should beat uniform {}",
+            degree_weighted,
+            uniform
+        );
+        assert!(degree_weighted < random);
+    }
 }
@@ -10,6 +10,6 @@ pub mod storage;
 pub mod computation;
 pub mod integration_tests;
 
-pub use spec::{BetweennessCentralityAlgorithmSpec, BetweennessCentralityConfig, BetweennessCentralityResult};
+pub use spec::{BetweennessCentralityAlgorithmSpec, BetweennessCentralityConfig, BetweennessCentralityResult, PivotStrategy};
 pub use storage::BetweennessCentralityStorageRuntime;
 pub use computation::BetweennessCentralityComputationRuntime;
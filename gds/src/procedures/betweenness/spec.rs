@@ -1,14 +1,28 @@
 //! Betweenness Centrality Specification
 use serde::{Deserialize, Serialize};
 
+pub use crate::config::PivotStrategy;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BetweennessCentralityConfig {
     pub concurrency: usize,
+    /// Number of source nodes to sample; `None` uses every node
+    #[serde(default)]
+    pub sampling_size: Option<usize>,
+    #[serde(default)]
+    pub sampling_seed: Option<u64>,
+    #[serde(default)]
+    pub pivot_strategy: PivotStrategy,
 }
 
 impl Default for BetweennessCentralityConfig {
     fn default() -> Self {
-        Self { concurrency: 4 }
+        Self {
+            concurrency: 4,
+            sampling_size: None,
+            sampling_seed: None,
+            pivot_strategy: PivotStrategy::Uniform,
+        }
     }
 }
 
@@ -30,6 +30,7 @@ fn test_bfs_config_validation() {
         track_paths: true,
         concurrency: 4,
         delta: 1,
+        compute_levels: false,
     };
     
     assert!(config.validate().is_ok());
@@ -106,6 +107,7 @@ fn test_bfs_result_serialization() {
         ],
         nodes_visited: 4,
         computation_time_ms: 5,
+        levels: None,
     };
     
     // Test serialization
@@ -142,3 +144,57 @@ fn test_bfs_with_executor() {
     let error = result.unwrap_err();
     assert!(error.to_string().contains("Graph not found"));
 }
+
+#[test]
+fn test_bfs_with_node_filter() {
+    use crate::projection::eval::procedure::{ExecutorError, NodeFilter};
+    use std::sync::Arc;
+
+    // 0 -> 1 -> 2 -> 3 and 0 -> 4; only nodes 0, 1 and 3 score above 0.5
+    let mut store = crate::types::random::generators::typed_store(
+        "test_graph",
+        5,
+        [(
+            "REL",
+            crate::types::schema::Direction::Directed,
+            vec![(0, 1), (1, 2), (2, 3), (0, 4)],
+        )],
+    );
+    store
+        .add_node_property_f64("score".to_string(), vec![0.9, 0.8, 0.1, 0.7, 0.2])
+        .unwrap();
+
+    let visited = |executor: &mut ProcedureExecutor, source_node: u64| {
+        let config_input = json!({
+            "source_node": source_node,
+            "target_nodes": [],
+            "track_paths": false,
+            "concurrency": 1,
+            "delta": 64
+        });
+        let mut algorithm = BFSAlgorithmSpec::new("test_graph".to_string());
+        executor
+            .compute(&mut algorithm, &config_input)
+            .map(|result| {
+                let mut nodes: Vec<u32> =
+                    result.visited_nodes.into_iter().map(|(node, _)| node).collect();
+                nodes.sort_unstable();
+                nodes
+            })
+    };
+
+    let store = Arc::new(store);
+    let mut unfiltered =
+        ProcedureExecutor::new(ExecutionContext::mock(Arc::clone(&store)), ExecutionMode::Stream);
+    assert_eq!(visited(&mut unfiltered, 0).unwrap(), vec![0, 1, 2, 3, 4]);
+
+    // Node 3 passes the filter but is only reachable through node 2
+    let mut filtered = ProcedureExecutor::new(ExecutionContext::mock(store), ExecutionMode::Stream)
+        .with_node_filter(NodeFilter::property_above("score", 0.5));
+    assert_eq!(visited(&mut filtered, 0).unwrap(), vec![0, 1]);
+
+    // A traversal cannot start from a rejected node
+    let error = visited(&mut filtered, 2).unwrap_err();
+    assert!(matches!(error, ExecutorError::NodeFilter(_)));
+    assert!(error.to_string().contains("source_node 2"));
+}
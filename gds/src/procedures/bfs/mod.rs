@@ -11,6 +11,6 @@ pub mod computation;
 pub mod integration_tests;
 
 // Re-export main types
-pub use spec::{BFSAlgorithmSpec, BfsConfig, BfsResult};
+pub use spec::{BFSAlgorithmSpec, BfsConfig, BfsResult, UNREACHABLE_LEVEL};
 pub use storage::BfsStorageRuntime;
 pub use computation::BfsComputationRuntime;
@@ -4,6 +4,7 @@
 //!
 //! This module defines the BFS algorithm specification, configuration, and result types.
 
+use crate::collections::HugeLongArray;
 use crate::define_algorithm_spec;
 use crate::projection::codegen::config::validation::ConfigError;
 use crate::projection::eval::procedure::AlgorithmSpec;
@@ -33,6 +34,9 @@ pub struct BfsConfig {
     pub concurrency: usize,
     /// Delta parameter for chunking (default 64)
     pub delta: usize,
+    /// Whether to report each node's BFS level in `BfsResult::levels`
+    #[serde(default)]
+    pub compute_levels: bool,
 }
 
 impl Default for BfsConfig {
@@ -44,6 +48,7 @@ impl Default for BfsConfig {
             track_paths: false,
             concurrency: 1,
             delta: 64, // Default delta from Java BFS
+            compute_levels: false,
         }
     }
 }
@@ -61,6 +66,9 @@ impl BfsConfig {
     }
 }
 
+/// Level of nodes the traversal did not reach
+pub const UNREACHABLE_LEVEL: i64 = -1;
+
 /// BFS algorithm result
 ///
 /// Translation of: `BFSResult.java` (lines 76-120)
@@ -75,6 +83,9 @@ pub struct BfsResult {
     pub nodes_visited: usize,
     /// Computation time in milliseconds
     pub computation_time_ms: u64,
+    /// Hops from the source per node (if compute_levels was enabled);
+    /// nodes that were not reached hold `UNREACHABLE_LEVEL`
+    pub levels: Option<HugeLongArray>,
 }
 
 /// Individual path result from BFS
@@ -112,7 +123,8 @@ define_algorithm_spec! {
             parsed_config.track_paths,
             parsed_config.concurrency,
             parsed_config.delta,
-        );
+        )
+        .with_compute_levels(parsed_config.compute_levels);
 
         let mut computation = BfsComputationRuntime::new(
             parsed_config.source_node,
@@ -149,6 +161,7 @@ mod tests {
             }],
             nodes_visited: 3,
             computation_time_ms: 5,
+            levels: None,
         };
 
         assert_eq!(result.visited_nodes.len(), 3);
@@ -179,6 +192,7 @@ mod tests {
         assert!(config.max_depth.is_none());
         assert!(!config.track_paths);
         assert_eq!(config.concurrency, 1);
+        assert!(!config.compute_levels);
     }
 
     #[test]
@@ -6,7 +6,8 @@
 //! and algorithm orchestration using the Java GDS parallel BFS architecture.
 
 use super::computation::BfsComputationRuntime;
-use super::spec::{BfsResult, BfsPathResult};
+use super::spec::{BfsResult, BfsPathResult, UNREACHABLE_LEVEL};
+use crate::collections::HugeLongArray;
 use crate::procedures::traversal::{ExitPredicate, Aggregator, FollowExitPredicate, TargetExitPredicate, OneHopAggregator};
 use crate::projection::eval::procedure::AlgorithmError;
 use crate::types::graph::Graph;
@@ -30,6 +31,8 @@ pub struct BfsStorageRuntime {
     pub concurrency: usize,
     /// Delta parameter for chunking (default 64)
     pub delta: usize,
+    /// Whether to record the BFS level of every node
+    pub compute_levels: bool,
     /// Exit predicate for controlling traversal
     pub exit_predicate: Box<dyn ExitPredicate>,
     /// Aggregator function for computing weights
@@ -59,11 +62,18 @@ impl BfsStorageRuntime {
             track_paths,
             concurrency,
             delta,
+            compute_levels: false,
             exit_predicate,
             aggregator: Box::new(OneHopAggregator),
         }
     }
 
+    /// Enable or disable level tracking
+    pub fn with_compute_levels(mut self, compute_levels: bool) -> Self {
+        self.compute_levels = compute_levels;
+        self
+    }
+
     /// Create new BFS storage runtime with custom predicates
     pub fn with_predicates(
         source_node: u32,
@@ -82,6 +92,7 @@ impl BfsStorageRuntime {
             track_paths,
             concurrency,
             delta,
+            compute_levels: false,
             exit_predicate,
             aggregator,
         }
@@ -113,6 +124,14 @@ impl BfsStorageRuntime {
         traversed_nodes[0] = self.source_node;
         weights[0] = 0.0;
 
+        // Levels are assigned on discovery: one hop past the discovering node
+        let mut levels = self.compute_levels.then(|| {
+            let mut levels = HugeLongArray::new(node_count);
+            levels.fill(UNREACHABLE_LEVEL);
+            levels.set(self.source_node as usize, 0);
+            levels
+        });
+
         // Main BFS loop with depth control
         let mut current_depth = 0;
         let max_depth = self.max_depth.unwrap_or(u32::MAX);
@@ -152,6 +171,9 @@ impl BfsStorageRuntime {
                         for neighbor in neighbors {
                             if !visited[neighbor as usize] {
                                 visited[neighbor as usize] = true;
+                                if let Some(levels) = levels.as_mut() {
+                                    levels.set(neighbor as usize, current_depth as i64 + 1);
+                                }
                                 let new_index = traversed_nodes_length.fetch_add(1, Ordering::SeqCst);
                                 if new_index < node_count {
                                     traversed_nodes[new_index] = neighbor;
@@ -208,6 +230,7 @@ impl BfsStorageRuntime {
             paths,
             nodes_visited: final_length,
             computation_time_ms: computation_time,
+            levels,
         })
     }
 
@@ -297,4 +320,39 @@ mod tests {
         assert!(result.nodes_visited <= 3); // Source + immediate neighbors
         assert!(result.computation_time_ms >= 0);
     }
+
+    #[test]
+    fn test_bfs_levels_on_tree() {
+        // 0 -> {1, 2}, 1 -> {3, 4}, 2 -> {5}; node 6 only points into the tree
+        let tree = crate::types::random::generators::typed_store(
+            "tree",
+            7,
+            [(
+                "REL",
+                crate::types::schema::Direction::Directed,
+                vec![(0, 1), (0, 2), (1, 3), (1, 4), (2, 5), (6, 0)],
+            )],
+        );
+        let graph = tree.graph();
+
+        let storage = BfsStorageRuntime::new(0, vec![], None, false, 1, 64).with_compute_levels(true);
+        let mut computation = BfsComputationRuntime::new(0, false, 1);
+        let result = storage.compute_bfs(&mut computation, Some(graph.as_ref())).unwrap();
+
+        // Levels survive a serialization round trip
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["levels"], serde_json::json!([0, 1, 1, 2, 2, 2, -1]));
+        let restored: BfsResult = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.levels, result.levels);
+
+        let levels = result.levels.expect("levels requested");
+        let expected = [0, 1, 1, 2, 2, 2, UNREACHABLE_LEVEL];
+        for (node, &level) in expected.iter().enumerate() {
+            assert_eq!(levels.get(node), level, "node {}", node);
+        }
+
+        let storage = BfsStorageRuntime::new(0, vec![], None, false, 1, 64);
+        let result = storage.compute_bfs(&mut computation, Some(graph.as_ref())).unwrap();
+        assert!(result.levels.is_none());
+    }
 }
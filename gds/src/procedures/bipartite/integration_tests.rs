@@ -0,0 +1,93 @@
+//! Bipartite Check Integration Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::procedures::bipartite::computation::BipartiteComputationRuntime;
+    use std::collections::HashMap;
+
+    fn create_graph(edges: &[(usize, usize)], node_count: usize) -> HashMap<usize, Vec<usize>> {
+        let mut graph = HashMap::new();
+        for i in 0..node_count {
+            graph.insert(i, Vec::new());
+        }
+        for &(from, to) in edges {
+            graph.entry(from).or_insert_with(Vec::new).push(to);
+            graph.entry(to).or_insert_with(Vec::new).push(from);
+        }
+        graph
+    }
+
+    fn assert_is_cycle(graph: &HashMap<usize, Vec<usize>>, cycle: &[u64]) {
+        for (index, &node) in cycle.iter().enumerate() {
+            let next = cycle[(index + 1) % cycle.len()] as usize;
+            assert!(
+                graph[&(node as usize)].contains(&next),
+                "{} -> {} is not an edge",
+                node,
+                next
+            );
+        }
+    }
+
+    #[test]
+    fn test_complete_bipartite_graph() {
+        // K_{3,4}: {0, 1, 2} x {3, 4, 5, 6}
+        let mut edges = Vec::new();
+        for left in 0..3 {
+            for right in 3..7 {
+                edges.push((left, right));
+            }
+        }
+        let graph = create_graph(&edges, 7);
+
+        let mut runtime = BipartiteComputationRuntime::new();
+        let result = runtime.compute(7, |node| graph.get(&node).cloned().unwrap_or_default());
+
+        assert!(result.is_bipartite);
+        assert!(result.odd_cycle.is_none());
+        let (left, right) = result.partitions();
+        assert_eq!(left, vec![0, 1, 2]);
+        assert_eq!(right, vec![3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_triangle_reports_odd_cycle() {
+        let graph = create_graph(&[(0, 1), (1, 2), (2, 0)], 3);
+
+        let mut runtime = BipartiteComputationRuntime::new();
+        let result = runtime.compute(3, |node| graph.get(&node).cloned().unwrap_or_default());
+
+        assert!(!result.is_bipartite);
+        let cycle = result.odd_cycle.clone().unwrap();
+        assert_eq!(cycle.len(), 3);
+        assert_is_cycle(&graph, &cycle);
+        assert_eq!(result.partitions(), (vec![], vec![]));
+    }
+
+    #[test]
+    fn test_odd_cycle_in_second_component() {
+        // Even square 0-1-2-3 and pentagon 4-5-6-7-8
+        let graph = create_graph(
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 0),
+                (4, 5),
+                (5, 6),
+                (6, 7),
+                (7, 8),
+                (8, 4),
+            ],
+            9,
+        );
+
+        let mut runtime = BipartiteComputationRuntime::new();
+        let result = runtime.compute(9, |node| graph.get(&node).cloned().unwrap_or_default());
+
+        assert!(!result.is_bipartite);
+        let cycle = result.odd_cycle.unwrap();
+        assert_eq!(cycle.len() % 2, 1);
+        assert_is_cycle(&graph, &cycle);
+    }
+}
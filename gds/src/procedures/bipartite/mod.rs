@@ -0,0 +1,14 @@
+//! Bipartite Check Algorithm
+//!
+//! Two-colors the graph with BFS. A graph is bipartite exactly when no
+//! relationship joins two nodes of the same color; otherwise the conflicting
+//! relationship closes an odd cycle, which is reported as the witness.
+
+pub mod spec;
+pub mod storage;
+pub mod computation;
+pub mod integration_tests;
+
+pub use spec::{BipartiteAlgorithmSpec, BipartiteConfig, BipartiteResult};
+pub use storage::BipartiteStorageRuntime;
+pub use computation::BipartiteComputationRuntime;
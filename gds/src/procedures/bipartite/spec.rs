@@ -0,0 +1,36 @@
+//! Bipartite Check Algorithm Specification
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BipartiteConfig {
+    pub concurrency: usize,
+}
+
+impl Default for BipartiteConfig {
+    fn default() -> Self {
+        Self { concurrency: 4 }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BipartiteResult {
+    pub is_bipartite: bool,
+    /// The two sides of the bipartition, empty if the graph is not bipartite
+    pub partitions: (Vec<u64>, Vec<u64>),
+    /// Nodes of an odd cycle, present if the graph is not bipartite
+    pub odd_cycle: Option<Vec<u64>>,
+}
+
+pub struct BipartiteAlgorithmSpec {
+    graph_name: String,
+}
+
+impl BipartiteAlgorithmSpec {
+    pub fn new(graph_name: String) -> Self {
+        Self { graph_name }
+    }
+
+    pub fn graph_name(&self) -> &str {
+        &self.graph_name
+    }
+}
@@ -0,0 +1,15 @@
+//! Bipartite Check Storage Runtime
+
+pub struct BipartiteStorageRuntime {
+    concurrency: usize,
+}
+
+impl BipartiteStorageRuntime {
+    pub fn new(concurrency: usize) -> Self {
+        Self { concurrency }
+    }
+
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+}
@@ -0,0 +1,191 @@
+//! Connectivity Computation Runtime
+//!
+//! **Translation Source**: `org.neo4j.gds.articulationpoints.ArticulationPoints`,
+//! `org.neo4j.gds.bridges.Bridges`
+//!
+//! This module runs one iterative Tarjan DFS and derives articulation points,
+//! bridges and biconnected components from the shared `tin`/`low` values.
+//! Biconnected components are collected with an edge stack: whenever a child
+//! `v` of `p` satisfies `low[v] >= tin[p]`, the edges pushed since `(p, v)`
+//! form one component.
+
+use crate::collections::{BitSet, HugeLongArray};
+use crate::procedures::bridges::computation::Bridge;
+
+/// Connectivity computation result
+#[derive(Clone)]
+pub struct ConnectivityComputationResult {
+    pub articulation_points: BitSet,
+    pub bridges: Vec<Bridge>,
+    /// Node sets of the biconnected components, each sorted ascending.
+    /// Isolated nodes belong to no component.
+    pub biconnected_components: Vec<Vec<u64>>,
+}
+
+/// DFS frame: a node, its DFS parent and the position in its neighbor list
+struct Frame {
+    node: usize,
+    parent: Option<usize>,
+    neighbors: Vec<usize>,
+    next: usize,
+    skipped_parent_edge: bool,
+}
+
+/// Connectivity computation runtime
+pub struct ConnectivityComputationRuntime {
+    visited: BitSet,
+    tin: HugeLongArray,
+    low: HugeLongArray,
+    children: HugeLongArray,
+    timer: i64,
+    articulation_points: BitSet,
+    bridges: Vec<Bridge>,
+    edge_stack: Vec<(usize, usize)>,
+    biconnected_components: Vec<Vec<u64>>,
+}
+
+impl ConnectivityComputationRuntime {
+    pub fn new(node_count: usize) -> Self {
+        Self {
+            visited: BitSet::new(node_count),
+            tin: HugeLongArray::new(node_count),
+            low: HugeLongArray::new(node_count),
+            children: HugeLongArray::new(node_count),
+            timer: 0,
+            articulation_points: BitSet::new(node_count),
+            bridges: Vec::new(),
+            edge_stack: Vec::new(),
+            biconnected_components: Vec::new(),
+        }
+    }
+
+    /// Compute articulation points, bridges and biconnected components
+    /// get_neighbors returns the neighbor nodes for a given node
+    ///
+    /// All state is reset first, so the runtime can be reused across runs.
+    pub fn compute(
+        &mut self,
+        node_count: usize,
+        get_neighbors: impl Fn(usize) -> Vec<usize>,
+    ) -> ConnectivityComputationResult {
+        self.timer = 0;
+        self.visited.clear_all();
+        self.articulation_points.clear_all();
+        self.bridges.clear();
+        self.edge_stack.clear();
+        self.biconnected_components.clear();
+
+        for i in 0..node_count {
+            self.tin.set(i, -1);
+            self.low.set(i, -1);
+            self.children.set(i, 0);
+        }
+
+        for i in 0..node_count {
+            if !self.visited.get(i) {
+                self.dfs(i, &get_neighbors);
+            }
+        }
+
+        ConnectivityComputationResult {
+            articulation_points: self.articulation_points.clone(),
+            bridges: self.bridges.clone(),
+            biconnected_components: self.biconnected_components.clone(),
+        }
+    }
+
+    fn dfs(&mut self, root: usize, get_neighbors: &impl Fn(usize) -> Vec<usize>) {
+        let mut frames = vec![self.enter(root, None, get_neighbors)];
+
+        while let Some(frame) = frames.last_mut() {
+            let v = frame.node;
+
+            if frame.next < frame.neighbors.len() {
+                let to = frame.neighbors[frame.next];
+                frame.next += 1;
+
+                // Skip the tree edge back to the parent once; parallel edges
+                // to the parent still count as back edges.
+                if Some(to) == frame.parent && !frame.skipped_parent_edge {
+                    frame.skipped_parent_edge = true;
+                    continue;
+                }
+
+                if !self.visited.get(to) {
+                    self.edge_stack.push((v, to));
+                    self.children.add_to(v, 1);
+                    let child = self.enter(to, Some(v), get_neighbors);
+                    frames.push(child);
+                } else if self.tin.get(to) < self.tin.get(v) {
+                    // Back edge to an ancestor
+                    self.edge_stack.push((v, to));
+                    let low_v = self.low.get(v);
+                    self.low.set(v, low_v.min(self.tin.get(to)));
+                }
+                continue;
+            }
+
+            let parent = frame.parent;
+            frames.pop();
+
+            if let Some(p) = parent {
+                let low_v = self.low.get(v);
+                let low_p = self.low.get(p);
+                self.low.set(p, low_p.min(low_v));
+
+                let tin_p = self.tin.get(p);
+                if low_v > tin_p {
+                    self.bridges.push(Bridge {
+                        from: p.min(v) as u64,
+                        to: p.max(v) as u64,
+                    });
+                }
+                if low_v >= tin_p {
+                    if p != root {
+                        self.articulation_points.set(p);
+                    }
+                    self.pop_component(p, v);
+                }
+            }
+        }
+
+        if self.children.get(root) > 1 {
+            self.articulation_points.set(root);
+        }
+    }
+
+    fn enter(
+        &mut self,
+        node: usize,
+        parent: Option<usize>,
+        get_neighbors: &impl Fn(usize) -> Vec<usize>,
+    ) -> Frame {
+        self.visited.set(node);
+        self.tin.set(node, self.timer);
+        self.low.set(node, self.timer);
+        self.timer += 1;
+
+        Frame {
+            node,
+            parent,
+            neighbors: get_neighbors(node),
+            next: 0,
+            skipped_parent_edge: false,
+        }
+    }
+
+    /// Pop edges up to and including the tree edge `(p, v)` as one component
+    fn pop_component(&mut self, p: usize, v: usize) {
+        let mut nodes = Vec::new();
+        while let Some((a, b)) = self.edge_stack.pop() {
+            nodes.push(a as u64);
+            nodes.push(b as u64);
+            if (a, b) == (p, v) {
+                break;
+            }
+        }
+        nodes.sort_unstable();
+        nodes.dedup();
+        self.biconnected_components.push(nodes);
+    }
+}
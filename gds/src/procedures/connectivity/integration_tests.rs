@@ -0,0 +1,140 @@
+//! Connectivity Integration Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::procedures::articulation_points::computation::ArticulationPointsComputationRuntime;
+    use crate::procedures::bridges::computation::BridgesComputationRuntime;
+    use crate::procedures::connectivity::computation::ConnectivityComputationRuntime;
+    use std::collections::HashMap;
+
+    fn create_graph(edges: Vec<(usize, usize)>, node_count: usize) -> HashMap<usize, Vec<usize>> {
+        let mut graph = HashMap::new();
+        for i in 0..node_count {
+            graph.insert(i, Vec::new());
+        }
+        for (from, to) in edges {
+            graph.entry(from).or_insert_with(Vec::new).push(to);
+            graph.entry(to).or_insert_with(Vec::new).push(from);
+        }
+        graph
+    }
+
+    fn sorted_bridges(bridges: impl Iterator<Item = (u64, u64)>) -> Vec<(u64, u64)> {
+        let mut bridges: Vec<_> = bridges.collect();
+        bridges.sort_unstable();
+        bridges
+    }
+
+    #[test]
+    fn test_matches_individual_procedures() {
+        // Two triangles joined through node 3, plus a pendant path 6-7:
+        //   (0-1-2-0) - 3 - (4-5-6-4) - 7
+        let edges = vec![
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 6),
+            (6, 4),
+            (6, 7),
+        ];
+        let node_count = 8;
+        let graph = create_graph(edges, node_count);
+        let neighbors = |node: usize| graph.get(&node).cloned().unwrap_or_default();
+
+        let mut combined = ConnectivityComputationRuntime::new(node_count);
+        let result = combined.compute(node_count, neighbors);
+
+        let mut articulation = ArticulationPointsComputationRuntime::new(node_count);
+        let expected_points = articulation.compute(node_count, neighbors);
+        let mut bridges = BridgesComputationRuntime::new(node_count);
+        let expected_bridges = bridges.compute(node_count, neighbors);
+
+        for node in 0..node_count {
+            assert_eq!(
+                result.articulation_points.get(node),
+                expected_points.articulation_points.get(node),
+                "articulation point mismatch at node {}",
+                node
+            );
+        }
+        for node in [2, 3, 4, 6] {
+            assert!(result.articulation_points.get(node));
+        }
+
+        let combined_bridges = sorted_bridges(result.bridges.iter().map(|b| (b.from, b.to)));
+        assert_eq!(
+            combined_bridges,
+            sorted_bridges(expected_bridges.bridges.iter().map(|b| (b.from, b.to)))
+        );
+        assert_eq!(combined_bridges, vec![(2, 3), (3, 4), (6, 7)]);
+
+        let mut components = result.biconnected_components.clone();
+        components.sort();
+        assert_eq!(
+            components,
+            vec![
+                vec![0, 1, 2],
+                vec![2, 3],
+                vec![3, 4],
+                vec![4, 5, 6],
+                vec![6, 7]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cycle_is_one_component() {
+        let graph = create_graph(vec![(0, 1), (1, 2), (2, 3), (3, 0)], 4);
+
+        let mut runtime = ConnectivityComputationRuntime::new(4);
+        let result = runtime.compute(4, |node| graph.get(&node).cloned().unwrap_or_default());
+
+        assert!((0..4).all(|node| !result.articulation_points.get(node)));
+        assert!(result.bridges.is_empty());
+        assert_eq!(result.biconnected_components, vec![vec![0, 1, 2, 3]]);
+    }
+
+    #[test]
+    fn test_runtime_is_reset_between_runs() {
+        // A path 0-1-2 has one articulation point; the triangle has none
+        let path = create_graph(vec![(0, 1), (1, 2)], 3);
+        let triangle = create_graph(vec![(0, 1), (1, 2), (2, 0)], 3);
+
+        let mut runtime = ConnectivityComputationRuntime::new(3);
+        let first = runtime.compute(3, |node| path.get(&node).cloned().unwrap_or_default());
+        assert!(first.articulation_points.get(1));
+        assert_eq!(first.bridges.len(), 2);
+
+        let second = runtime.compute(3, |node| triangle.get(&node).cloned().unwrap_or_default());
+        assert!((0..3).all(|node| !second.articulation_points.get(node)));
+        assert!(second.bridges.is_empty());
+        assert_eq!(second.biconnected_components, vec![vec![0, 1, 2]]);
+
+        let mut fresh = ConnectivityComputationRuntime::new(3);
+        let expected = fresh.compute(3, |node| path.get(&node).cloned().unwrap_or_default());
+        let again = runtime.compute(3, |node| path.get(&node).cloned().unwrap_or_default());
+        assert_eq!(again.bridges.len(), expected.bridges.len());
+        assert_eq!(
+            again.biconnected_components,
+            expected.biconnected_components
+        );
+        assert!(again.articulation_points.get(1));
+    }
+
+    #[test]
+    fn test_parallel_edges_are_not_bridges() {
+        let mut graph = create_graph(vec![(0, 1), (1, 2)], 3);
+        graph.get_mut(&0).unwrap().push(1);
+        graph.get_mut(&1).unwrap().push(0);
+
+        let mut runtime = ConnectivityComputationRuntime::new(3);
+        let result = runtime.compute(3, |node| graph.get(&node).cloned().unwrap_or_default());
+
+        let bridges = sorted_bridges(result.bridges.iter().map(|b| (b.from, b.to)));
+        assert_eq!(bridges, vec![(1, 2)]);
+        assert!(result.articulation_points.get(1));
+    }
+}
@@ -0,0 +1,17 @@
+//! Connectivity Algorithm
+//!
+//! **Translation Source**: `org.neo4j.gds.articulationpoints.ArticulationPoints`,
+//! `org.neo4j.gds.bridges.Bridges`
+//!
+//! Articulation points, bridges and biconnected components all fall out of the
+//! same Tarjan low-link DFS. This module computes all three in a single pass
+//! instead of running the individual procedures one after another.
+
+pub mod spec;
+pub mod storage;
+pub mod computation;
+pub mod integration_tests;
+
+pub use spec::{ConnectivityAlgorithmSpec, ConnectivityConfig, ConnectivityResult};
+pub use storage::ConnectivityStorageRuntime;
+pub use computation::ConnectivityComputationRuntime;
@@ -0,0 +1,35 @@
+//! Connectivity Algorithm Specification
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectivityConfig {
+    pub concurrency: usize,
+}
+
+impl Default for ConnectivityConfig {
+    fn default() -> Self {
+        Self { concurrency: 4 }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectivityResult {
+    pub articulation_points: Vec<u64>,
+    pub bridges: Vec<(u64, u64)>,
+    /// Node sets of the biconnected components, each sorted ascending
+    pub biconnected_components: Vec<Vec<u64>>,
+}
+
+pub struct ConnectivityAlgorithmSpec {
+    graph_name: String,
+}
+
+impl ConnectivityAlgorithmSpec {
+    pub fn new(graph_name: String) -> Self {
+        Self { graph_name }
+    }
+
+    pub fn graph_name(&self) -> &str {
+        &self.graph_name
+    }
+}
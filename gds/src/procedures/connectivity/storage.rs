@@ -0,0 +1,15 @@
+//! Connectivity Storage Runtime
+
+pub struct ConnectivityStorageRuntime {
+    concurrency: usize,
+}
+
+impl ConnectivityStorageRuntime {
+    pub fn new(concurrency: usize) -> Self {
+        Self { concurrency }
+    }
+
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+}
@@ -0,0 +1,126 @@
+//! Comparison Module - Cross-checking per-node results of two implementations
+//!
+//! **Key Features**: Absolute differences, tolerance checks, offending node ids
+//!
+//! Used to validate one implementation against another on the same graph, e.g.
+//! exact vs sampled betweenness or CSR vs adjacency-list traversal.
+//! `compare_node_results` reports the largest and mean absolute difference and
+//! which nodes differ by more than the tolerance.
+
+use crate::collections::HugeDoubleArray;
+
+/// Summary of the per-node differences between two result arrays
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparisonReport {
+    /// Number of compared nodes
+    pub node_count: usize,
+    /// Largest absolute difference over all nodes
+    pub max_abs_diff: f64,
+    /// Mean absolute difference over all nodes
+    pub mean_abs_diff: f64,
+    /// Nodes whose difference exceeds the tolerance, in ascending order
+    pub exceeding_nodes: Vec<u64>,
+}
+
+impl ComparisonReport {
+    /// Number of nodes whose difference exceeds the tolerance
+    pub fn exceeding_count(&self) -> usize {
+        self.exceeding_nodes.len()
+    }
+
+    /// `true` if every node is within tolerance
+    pub fn is_within_tolerance(&self) -> bool {
+        self.exceeding_nodes.is_empty()
+    }
+}
+
+/// Compare two per-node result arrays
+///
+/// Two NaN values compare equal; NaN against a number counts as an infinite
+/// difference.
+///
+/// # Panics
+///
+/// Panics if the arrays have different sizes.
+pub fn compare_node_results(
+    a: &HugeDoubleArray,
+    b: &HugeDoubleArray,
+    tolerance: f64,
+) -> ComparisonReport {
+    assert_eq!(
+        a.size(),
+        b.size(),
+        "cannot compare results of different sizes"
+    );
+
+    let node_count = a.size();
+    let mut max_abs_diff: f64 = 0.0;
+    let mut sum_abs_diff = 0.0;
+    let mut exceeding_nodes = Vec::new();
+
+    for node in 0..node_count {
+        let diff = abs_diff(a.get(node), b.get(node));
+        max_abs_diff = max_abs_diff.max(diff);
+        sum_abs_diff += diff;
+        if diff > tolerance {
+            exceeding_nodes.push(node as u64);
+        }
+    }
+
+    ComparisonReport {
+        node_count,
+        max_abs_diff,
+        mean_abs_diff: if node_count == 0 {
+            0.0
+        } else {
+            sum_abs_diff / node_count as f64
+        },
+        exceeding_nodes,
+    }
+}
+
+fn abs_diff(x: f64, y: f64) -> f64 {
+    match (x.is_nan(), y.is_nan()) {
+        (true, true) => 0.0,
+        (false, false) => (x - y).abs(),
+        _ => f64::INFINITY,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical_results() {
+        let a = HugeDoubleArray::from_vec(vec![0.1, 0.2, 0.3, f64::NAN]);
+        let b = HugeDoubleArray::from_vec(vec![0.1, 0.2, 0.3, f64::NAN]);
+
+        let report = compare_node_results(&a, &b, 0.0);
+        assert_eq!(report.node_count, 4);
+        assert_eq!(report.max_abs_diff, 0.0);
+        assert_eq!(report.mean_abs_diff, 0.0);
+        assert!(report.is_within_tolerance());
+    }
+
+    #[test]
+    fn test_single_node_difference() {
+        let a = HugeDoubleArray::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+        let b = HugeDoubleArray::from_vec(vec![1.0, 2.0005, 3.5, 4.0]);
+
+        let report = compare_node_results(&a, &b, 0.001);
+        assert_eq!(report.exceeding_count(), 1);
+        assert_eq!(report.exceeding_nodes, vec![2]);
+        assert!((report.max_abs_diff - 0.5).abs() < 1e-12);
+        assert!((report.mean_abs_diff - 0.5005 / 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_nan_against_number_exceeds() {
+        let a = HugeDoubleArray::from_vec(vec![f64::NAN]);
+        let b = HugeDoubleArray::from_vec(vec![1.0]);
+
+        let report = compare_node_results(&a, &b, 1e6);
+        assert_eq!(report.exceeding_nodes, vec![0]);
+    }
+}
@@ -0,0 +1,126 @@
+//! Diameter Module - Fast lower bounds on the graph diameter
+//!
+//! **Key Features**: Two BFS passes, reproducible seeds, exact on trees
+//!
+//! Eccentricity and the delta-stepping auto-tuning only need a good estimate
+//! of the longest shortest path. `double_sweep_diameter` runs a BFS from a
+//! random node to the farthest node `u`, then a BFS from `u` to the farthest
+//! node `v`. The distance between `u` and `v` is a shortest path, so it never
+//! exceeds the true diameter.
+//!
+//! Traversal follows outgoing relationships and counts hops. Relationships
+//! of undirected graphs are stored in both directions, so both are followed
+//! there. Only the component of the random start node is explored.
+
+use crate::core::utils::shuffle::{Random, SplittableRandom};
+use crate::types::graph::Graph;
+use std::collections::VecDeque;
+
+/// Lower bound on the diameter of `graph` by a double BFS sweep
+///
+/// Returns the bound and the endpoints `(u, v)` of a shortest path of that
+/// length, or `(0, None)` for a graph without nodes. With `Some(seed)` the
+/// start node, and therefore the result, is reproducible.
+pub fn double_sweep_diameter(graph: &dyn Graph, seed: Option<u64>) -> (u64, Option<(u64, u64)>) {
+    let node_count = graph.node_count();
+    if node_count == 0 {
+        return (0, None);
+    }
+
+    let start = SplittableRandom::with_seed(seed).next_long(0, node_count) as u64;
+    let (u, _) = farthest_node(graph, start);
+    let (v, distance) = farthest_node(graph, u);
+    (distance, Some((u, v)))
+}
+
+/// BFS from `source`, returning the farthest reached node and its distance
+///
+/// Ties go to the node reached first.
+fn farthest_node(graph: &dyn Graph, source: u64) -> (u64, u64) {
+    let fallback = graph.default_property_value();
+    let mut distances = vec![u64::MAX; graph.node_count()];
+    let mut queue = VecDeque::new();
+    distances[source as usize] = 0;
+    queue.push_back(source);
+
+    let mut farthest = (source, 0);
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[node as usize];
+        if distance > farthest.1 {
+            farthest = (node, distance);
+        }
+        for cursor in graph.stream_relationships(node as i64, fallback) {
+            let target = cursor.target_id() as usize;
+            if distances[target] == u64::MAX {
+                distances[target] = distance + 1;
+                queue.push_back(target as u64);
+            }
+        }
+    }
+    farthest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::graph_store::GraphStore;
+    use crate::types::random::generators::undirected_store;
+    use std::sync::Arc;
+
+    /// Undirected graph over `node_count` nodes, stored in both directions
+    fn undirected(node_count: usize, edges: &[(usize, usize)]) -> Arc<dyn Graph> {
+        undirected_store("diameter", node_count, edges.iter().copied()).get_graph()
+    }
+
+    #[test]
+    fn test_double_sweep_is_exact_on_path() {
+        // 0 - 1 - 2 - 3 - 4 - 5
+        let graph = undirected(6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]);
+
+        for seed in 0..10 {
+            let (diameter, endpoints) = double_sweep_diameter(graph.as_ref(), Some(seed));
+            assert_eq!(diameter, 5);
+            let (u, v) = endpoints.unwrap();
+            assert_eq!((u.min(v), u.max(v)), (0, 5));
+        }
+    }
+
+    #[test]
+    fn test_double_sweep_bounds_tree_diameter() {
+        //         0
+        //       / | \
+        //      1  2  3
+        //     /   |    \
+        //    4    5     6
+        //   /            \
+        //  7              8
+        // The longest path 7 - 4 - 1 - 0 - 3 - 6 - 8 has 6 hops.
+        let graph = undirected(
+            9,
+            &[
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (1, 4),
+                (2, 5),
+                (3, 6),
+                (4, 7),
+                (6, 8),
+            ],
+        );
+
+        for seed in 0..10 {
+            let (diameter, endpoints) = double_sweep_diameter(graph.as_ref(), Some(seed));
+            assert!(diameter > 0 && diameter <= 6);
+            let (u, v) = endpoints.unwrap();
+            assert_eq!(farthest_node(graph.as_ref(), u).1, diameter);
+            assert_ne!(u, v);
+        }
+    }
+
+    #[test]
+    fn test_double_sweep_on_empty_graph() {
+        let graph = undirected(0, &[]);
+        assert_eq!(double_sweep_diameter(graph.as_ref(), Some(1)), (0, None));
+    }
+}
@@ -113,10 +113,13 @@
 //! │   ├── log.rs              (Log scaling)
 //! │   └── none.rs             (No-op scaler)
 //! │
-//! ├── statistics.rs            (Statistical analysis and histograms)
+//! ├── statistics               (re-export of core::utils::statistics)
 //! ├── memory_estimation.rs     (Memory estimation for algorithms)
 //! ├── progress_tracking.rs     (Progress tracking and logging)
 //! ├── result_builders.rs       (Result construction patterns)
+//! ├── sampling.rs              (Reservoir and weighted sampling)
+//! ├── comparison.rs            (Cross-checking per-node results)
+//! ├── diameter.rs              (Double-sweep diameter lower bound)
 //! └── prelude.rs              (Common re-exports)
 //! ```
 //!
@@ -194,7 +197,11 @@
 pub mod prelude;
 pub mod result;
 pub mod scaling;
-pub mod statistics;
+// Statistics live in `core::utils` so the types layer can use them too
+pub use crate::core::utils::statistics;
 pub mod memory_estimation;
 pub mod progress_tracking;
 pub mod result_builders;
+pub mod sampling;
+pub mod comparison;
+pub mod diameter;
@@ -35,3 +35,12 @@ pub use super::progress_tracking::*;
 
 // Re-export result builders
 pub use super::result_builders::*;
+
+// Re-export sampling
+pub use super::sampling::*;
+
+// Re-export result comparison
+pub use super::comparison::*;
+
+// Re-export diameter estimation
+pub use super::diameter::*;
@@ -0,0 +1,280 @@
+//! Sampling Module - Uniform sampling over streams of node/edge ids
+//!
+//! **Key Features**: Single pass, unknown stream length, reproducible seeds
+//!
+//! Sampling-based algorithms (approximate betweenness, sampled kNN) need a
+//! uniform subset of ids without knowing up front how many ids will be seen.
+//! `ReservoirSampler` implements Vitter's Algorithm R: the first `k` items fill
+//! the reservoir, and the `i`-th item (0-based) afterwards replaces a random
+//! slot with probability `k / (i + 1)`.
+//!
+//! Weighted selection (degree-weighted pivots, roulette-wheel selection)
+//! picks an index with probability proportional to its weight:
+//! `weighted_sample` for one-off draws and `WeightedSampler`, an alias table
+//! with O(1) draws, for repeated sampling from the same weights.
+
+use crate::core::utils::shuffle::{Random, SplittableRandom};
+
+/// Uniform reservoir sampler over a stream of `u64` ids
+pub struct ReservoirSampler {
+    capacity: usize,
+    reservoir: Vec<u64>,
+    seen: u64,
+    random: SplittableRandom,
+}
+
+impl ReservoirSampler {
+    /// Create a sampler retaining at most `capacity` items
+    ///
+    /// With `Some(seed)` the sample is reproducible for the same input stream.
+    pub fn new(capacity: usize, seed: Option<u64>) -> Self {
+        Self {
+            capacity,
+            reservoir: Vec::with_capacity(capacity),
+            seen: 0,
+            random: SplittableRandom::with_seed(seed),
+        }
+    }
+
+    /// Offer the next item of the stream
+    pub fn offer(&mut self, item: u64) {
+        if self.reservoir.len() < self.capacity {
+            self.reservoir.push(item);
+        } else if self.capacity > 0 {
+            let slot = self.random.next_long(0, self.seen as usize + 1);
+            if slot < self.capacity {
+                self.reservoir[slot] = item;
+            }
+        }
+        self.seen += 1;
+    }
+
+    /// Offer every item of an iterator
+    pub fn offer_all<I: IntoIterator<Item = u64>>(&mut self, items: I) {
+        for item in items {
+            self.offer(item);
+        }
+    }
+
+    /// The current sample, in reservoir slot order
+    pub fn sample(&self) -> &[u64] {
+        &self.reservoir
+    }
+
+    /// Consume the sampler and return the sample
+    pub fn into_sample(self) -> Vec<u64> {
+        self.reservoir
+    }
+
+    /// Maximum number of retained items
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of items offered so far
+    pub fn seen(&self) -> u64 {
+        self.seen
+    }
+}
+
+/// Draw an index with probability proportional to its weight
+///
+/// Builds the cumulative weights and binary searches a uniform point in
+/// them, which costs O(n) per call; use [`WeightedSampler`] to draw
+/// repeatedly from the same weights.
+///
+/// # Panics
+///
+/// Panics if a weight is negative or not finite, or if all weights are zero.
+pub fn weighted_sample(weights: &[f64], random: &mut dyn Random) -> usize {
+    let total = total_weight(weights);
+    let cumulative: Vec<f64> = weights
+        .iter()
+        .scan(0.0, |sum, &weight| {
+            *sum += weight;
+            Some(*sum)
+        })
+        .collect();
+
+    let point = next_unit(random) * total;
+    let index = cumulative.partition_point(|&sum| sum <= point);
+    // Rounding can push the point past the last sum; fall back to the last
+    // index that can be drawn at all.
+    index.min(weights.iter().rposition(|&weight| weight > 0.0).unwrap())
+}
+
+/// Weighted sampler with O(1) draws using Vose's alias method
+///
+/// Every index owns one column of equal probability mass. A column keeps
+/// its own index with probability `probability[i]` and otherwise yields
+/// `alias[i]`, so a draw needs one uniform column and one uniform coin.
+#[derive(Debug, Clone)]
+pub struct WeightedSampler {
+    probability: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl WeightedSampler {
+    /// Build the alias table for `weights` in O(n)
+    ///
+    /// # Panics
+    ///
+    /// Panics if a weight is negative or not finite, or if all weights are zero.
+    pub fn new(weights: &[f64]) -> Self {
+        let total = total_weight(weights);
+        let n = weights.len();
+        let mut scaled: Vec<f64> = weights
+            .iter()
+            .map(|&weight| weight * n as f64 / total)
+            .collect();
+
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+            (0..n).partition(|&index| scaled[index] < 1.0);
+        let mut probability = vec![1.0; n];
+        let mut alias: Vec<usize> = (0..n).collect();
+        while let (Some(&under), Some(&over)) = (small.last(), large.last()) {
+            small.pop();
+            probability[under] = scaled[under];
+            alias[under] = over;
+            scaled[over] += scaled[under] - 1.0;
+            if scaled[over] < 1.0 {
+                large.pop();
+                small.push(over);
+            }
+        }
+        // Columns left in either list are full up to rounding and keep probability 1.
+
+        Self { probability, alias }
+    }
+
+    /// Draw an index with probability proportional to its weight
+    pub fn sample(&self, random: &mut dyn Random) -> usize {
+        let column = random.next_long(0, self.probability.len());
+        if next_unit(random) < self.probability[column] {
+            column
+        } else {
+            self.alias[column]
+        }
+    }
+
+    /// Number of weights the sampler draws from
+    pub fn len(&self) -> usize {
+        self.probability.len()
+    }
+
+    /// Whether the sampler has no weights, which `new` rules out
+    pub fn is_empty(&self) -> bool {
+        self.probability.is_empty()
+    }
+}
+
+fn total_weight(weights: &[f64]) -> f64 {
+    assert!(
+        weights
+            .iter()
+            .all(|weight| weight.is_finite() && *weight >= 0.0),
+        "weights must be finite and non-negative"
+    );
+    let total: f64 = weights.iter().sum();
+    assert!(total > 0.0, "at least one weight must be positive");
+    total
+}
+
+/// Uniform double in `[0, 1)` with 53 random bits
+pub(crate) fn next_unit(random: &mut dyn Random) -> f64 {
+    const UNIT: usize = 1 << 53;
+    random.next_long(0, UNIT) as f64 / UNIT as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn sample_stream(seed: u64) -> Vec<u64> {
+        let mut sampler = ReservoirSampler::new(1000, Some(seed));
+        sampler.offer_all(0..100_000);
+        sampler.into_sample()
+    }
+
+    #[test]
+    fn test_sample_is_full_distinct_and_reproducible() {
+        let sample = sample_stream(42);
+        assert_eq!(sample.len(), 1000);
+
+        let distinct: HashSet<u64> = sample.iter().copied().collect();
+        assert_eq!(distinct.len(), 1000);
+
+        assert_eq!(sample, sample_stream(42));
+        assert_ne!(sample, sample_stream(43));
+    }
+
+    #[test]
+    fn test_sample_covers_the_whole_stream() {
+        let sample = sample_stream(7);
+        // A uniform sample of 1000 out of 100k must reach far beyond the prefix.
+        let beyond_prefix = sample.iter().filter(|&&id| id >= 1000).count();
+        assert!(beyond_prefix > 900);
+        let upper_half = sample.iter().filter(|&&id| id >= 50_000).count();
+        assert!((400..600).contains(&upper_half));
+    }
+
+    #[test]
+    fn test_short_stream_keeps_everything() {
+        let mut sampler = ReservoirSampler::new(10, Some(1));
+        sampler.offer_all(0..4);
+        assert_eq!(sampler.sample(), &[0, 1, 2, 3]);
+        assert_eq!(sampler.seen(), 4);
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let mut sampler = ReservoirSampler::new(0, Some(1));
+        sampler.offer_all(0..10);
+        assert!(sampler.sample().is_empty());
+        assert_eq!(sampler.seen(), 10);
+    }
+
+    const WEIGHTS: [f64; 5] = [1.0, 0.0, 2.0, 3.0, 4.0];
+
+    fn assert_proportional(mut draw: impl FnMut(&mut SplittableRandom) -> usize) {
+        let mut random = SplittableRandom::with_seed(Some(42));
+        let draws = 100_000;
+        let mut counts = [0usize; 5];
+        for _ in 0..draws {
+            counts[draw(&mut random)] += 1;
+        }
+
+        let total: f64 = WEIGHTS.iter().sum();
+        for (index, &count) in counts.iter().enumerate() {
+            let expected = WEIGHTS[index] / total;
+            let observed = count as f64 / draws as f64;
+            assert!(
+                (observed - expected).abs() < 0.01,
+                "index {} drawn with frequency {} instead of {}",
+                index,
+                observed,
+                expected
+            );
+        }
+        assert_eq!(counts[1], 0);
+    }
+
+    #[test]
+    fn test_weighted_sample_follows_weights() {
+        assert_proportional(|random| weighted_sample(&WEIGHTS, random));
+    }
+
+    #[test]
+    fn test_weighted_sampler_follows_weights() {
+        let sampler = WeightedSampler::new(&WEIGHTS);
+        assert_eq!(sampler.len(), 5);
+        assert_proportional(|random| sampler.sample(random));
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one weight must be positive")]
+    fn test_weighted_sample_rejects_zero_weights() {
+        WeightedSampler::new(&[0.0, 0.0]);
+    }
+}
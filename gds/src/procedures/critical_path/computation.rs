@@ -0,0 +1,102 @@
+//! Critical Path Computation Runtime
+//!
+//! Dynamic programming over a topological order: the longest path ending at
+//! a node is final once the node is reached, so relaxing its outgoing
+//! relationships in order yields the longest path to every node in a single
+//! pass. Paths may start at any node; a node without a longer incoming path
+//! starts at distance zero.
+
+use crate::procedures::topological_sort::{
+    TopologicalSortComputationRuntime, TopologicalSortError,
+};
+
+/// Critical path computation result
+#[derive(Debug, Clone)]
+pub struct CriticalPathComputationResult {
+    /// Length of the longest path ending at each node
+    pub distances: Vec<f64>,
+    /// Previous node on the longest path ending at each node, if any
+    pub predecessors: Vec<Option<u64>>,
+    /// Total weight of the critical path
+    pub length: f64,
+    /// Nodes of the critical path, from its first to its last node
+    pub path: Vec<u64>,
+}
+
+impl CriticalPathComputationResult {
+    /// Reconstruct the longest path ending at `node` by following predecessors
+    pub fn path_to(&self, node: u64) -> Vec<u64> {
+        let mut path = vec![node];
+        let mut current = node;
+        while let Some(previous) = self.predecessors[current as usize] {
+            path.push(previous);
+            current = previous;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Critical path computation runtime
+pub struct CriticalPathComputationRuntime;
+
+impl CriticalPathComputationRuntime {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Compute the longest weighted path
+    /// get_neighbors returns the (target, weight) pairs of a node's outgoing relationships
+    pub fn compute(
+        &mut self,
+        node_count: usize,
+        get_neighbors: impl Fn(usize) -> Vec<(usize, f64)>,
+    ) -> Result<CriticalPathComputationResult, TopologicalSortError> {
+        let adjacency: Vec<Vec<(usize, f64)>> = (0..node_count).map(get_neighbors).collect();
+
+        let order = TopologicalSortComputationRuntime::new()
+            .compute(node_count, |node| {
+                adjacency[node].iter().map(|&(target, _)| target).collect()
+            })?
+            .order;
+
+        let mut distances = vec![0.0; node_count];
+        let mut predecessors = vec![None; node_count];
+        for &node in &order {
+            let node = node as usize;
+            for &(target, weight) in &adjacency[node] {
+                let candidate = distances[node] + weight;
+                if candidate > distances[target] {
+                    distances[target] = candidate;
+                    predecessors[target] = Some(node as u64);
+                }
+            }
+        }
+
+        let mut result = CriticalPathComputationResult {
+            distances,
+            predecessors,
+            length: 0.0,
+            path: Vec::new(),
+        };
+        let end = (0..node_count).reduce(|best, node| {
+            if result.distances[node] > result.distances[best] {
+                node
+            } else {
+                best
+            }
+        });
+        if let Some(end) = end {
+            result.length = result.distances[end];
+            result.path = result.path_to(end as u64);
+        }
+
+        Ok(result)
+    }
+}
+
+impl Default for CriticalPathComputationRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,65 @@
+//! Critical Path Integration Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::procedures::critical_path::computation::CriticalPathComputationRuntime;
+    use crate::procedures::topological_sort::TopologicalSortError;
+    use std::collections::HashMap;
+
+    fn create_graph(
+        edges: &[(usize, usize, f64)],
+        node_count: usize,
+    ) -> HashMap<usize, Vec<(usize, f64)>> {
+        let mut graph = HashMap::new();
+        for i in 0..node_count {
+            graph.insert(i, Vec::new());
+        }
+        for &(from, to, weight) in edges {
+            graph
+                .entry(from)
+                .or_insert_with(Vec::new)
+                .push((to, weight));
+        }
+        graph
+    }
+
+    #[test]
+    fn test_critical_path_on_weighted_dag() {
+        // 0 -> 1 (3), 0 -> 2 (2), 1 -> 3 (4), 2 -> 3 (6), 3 -> 4 (1), 2 -> 4 (2)
+        // Longest path: 0 -> 2 -> 3 -> 4 = 2 + 6 + 1 = 9 (via 1 it is only 8)
+        let graph = create_graph(
+            &[
+                (0, 1, 3.0),
+                (0, 2, 2.0),
+                (1, 3, 4.0),
+                (2, 3, 6.0),
+                (3, 4, 1.0),
+                (2, 4, 2.0),
+            ],
+            5,
+        );
+
+        let mut runtime = CriticalPathComputationRuntime::new();
+        let result = runtime
+            .compute(5, |node| graph.get(&node).cloned().unwrap_or_default())
+            .unwrap();
+
+        assert_eq!(result.length, 9.0);
+        assert_eq!(result.path, vec![0, 2, 3, 4]);
+        assert_eq!(result.distances, vec![0.0, 3.0, 2.0, 8.0, 9.0]);
+        assert_eq!(result.path_to(1), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_cycle_is_rejected() {
+        let graph = create_graph(&[(0, 1, 1.0), (1, 2, 1.0), (2, 0, 1.0)], 3);
+
+        let mut runtime = CriticalPathComputationRuntime::new();
+        let error = runtime
+            .compute(3, |node| graph.get(&node).cloned().unwrap_or_default())
+            .unwrap_err();
+
+        let TopologicalSortError::Cycle { cycle } = error;
+        assert_eq!(cycle.len(), 3);
+    }
+}
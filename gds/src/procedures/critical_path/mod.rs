@@ -0,0 +1,15 @@
+//! Critical Path Algorithm
+//!
+//! Computes the longest weighted path of a directed acyclic graph, as used
+//! for critical-path scheduling. Nodes are processed in topological order
+//! (see `topological_sort`), so each node is finalized before its
+//! successors are relaxed. Cyclic graphs are rejected with one offending cycle.
+
+pub mod spec;
+pub mod storage;
+pub mod computation;
+pub mod integration_tests;
+
+pub use spec::{CriticalPathAlgorithmSpec, CriticalPathConfig, CriticalPathResult};
+pub use storage::CriticalPathStorageRuntime;
+pub use computation::{CriticalPathComputationResult, CriticalPathComputationRuntime};
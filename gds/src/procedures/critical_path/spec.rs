@@ -0,0 +1,37 @@
+//! Critical Path Algorithm Specification
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriticalPathConfig {
+    pub concurrency: usize,
+}
+
+impl Default for CriticalPathConfig {
+    fn default() -> Self {
+        Self { concurrency: 4 }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriticalPathResult {
+    /// Total weight of the critical path
+    pub length: f64,
+    /// Nodes of the critical path, from its first to its last node
+    pub path: Vec<u64>,
+    /// Length of the longest path ending at each node
+    pub distances: Vec<f64>,
+}
+
+pub struct CriticalPathAlgorithmSpec {
+    graph_name: String,
+}
+
+impl CriticalPathAlgorithmSpec {
+    pub fn new(graph_name: String) -> Self {
+        Self { graph_name }
+    }
+
+    pub fn graph_name(&self) -> &str {
+        &self.graph_name
+    }
+}
@@ -0,0 +1,15 @@
+//! Critical Path Storage Runtime
+
+pub struct CriticalPathStorageRuntime {
+    concurrency: usize,
+}
+
+impl CriticalPathStorageRuntime {
+    pub fn new(concurrency: usize) -> Self {
+        Self { concurrency }
+    }
+
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+}
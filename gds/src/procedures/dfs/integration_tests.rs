@@ -29,6 +29,7 @@ fn test_dfs_config_validation() {
         max_depth: Some(5),
         track_paths: true,
         concurrency: 4,
+        compute_timestamps: false,
     };
     
     assert!(config.validate().is_ok());
@@ -105,6 +106,8 @@ fn test_dfs_result_serialization() {
         ],
         nodes_visited: 4,
         computation_time_ms: 5,
+        discovery: None,
+        finish: None,
     };
     
     // Test serialization
@@ -4,6 +4,7 @@
 //!
 //! This module defines the DFS algorithm specification, configuration, and result types.
 
+use crate::collections::HugeLongArray;
 use crate::define_algorithm_spec;
 use crate::projection::codegen::config::validation::ConfigError;
 use crate::projection::eval::procedure::AlgorithmSpec;
@@ -31,6 +32,9 @@ pub struct DfsConfig {
     pub track_paths: bool,
     /// Concurrency level for parallel processing
     pub concurrency: usize,
+    /// Whether to report discovery/finish timestamps in `DfsResult`
+    #[serde(default)]
+    pub compute_timestamps: bool,
 }
 
 impl Default for DfsConfig {
@@ -41,6 +45,7 @@ impl Default for DfsConfig {
             max_depth: None,
             track_paths: false,
             concurrency: 1,
+            compute_timestamps: false,
         }
     }
 }
@@ -72,6 +77,12 @@ pub struct DfsResult {
     pub nodes_visited: usize,
     /// Computation time in milliseconds
     pub computation_time_ms: u64,
+    /// Discovery time d[v] per node (if compute_timestamps was enabled)
+    #[serde(skip)]
+    pub discovery: Option<HugeLongArray>,
+    /// Finish time f[v] per node (if compute_timestamps was enabled)
+    #[serde(skip)]
+    pub finish: Option<HugeLongArray>,
 }
 
 /// Individual path result from DFS
@@ -108,7 +119,8 @@ define_algorithm_spec! {
             parsed_config.max_depth,
             parsed_config.track_paths,
             parsed_config.concurrency,
-        );
+        )
+        .with_compute_timestamps(parsed_config.compute_timestamps);
 
         let mut computation = DfsComputationRuntime::new(
             parsed_config.source_node,
@@ -145,6 +157,8 @@ mod tests {
             }],
             nodes_visited: 3,
             computation_time_ms: 5,
+            discovery: None,
+            finish: None,
         };
 
         assert_eq!(result.visited_nodes.len(), 3);
@@ -175,6 +189,7 @@ mod tests {
         assert!(config.max_depth.is_none());
         assert!(!config.track_paths);
         assert_eq!(config.concurrency, 1);
+        assert!(!config.compute_timestamps);
     }
 
     #[test]
@@ -7,10 +7,11 @@
 
 use super::computation::DfsComputationRuntime;
 use super::spec::{DfsResult, DfsPathResult};
+use crate::collections::HugeLongArray;
 use crate::projection::eval::procedure::AlgorithmError;
 use crate::types::graph::Graph;
 use crate::types::properties::relationship::traits::RelationshipIterator as _;
-use std::collections::{VecDeque, HashMap};
+use std::collections::HashMap;
 
 /// DFS Storage Runtime - handles persistent data access and algorithm orchestration
 ///
@@ -27,6 +28,8 @@ pub struct DfsStorageRuntime {
     pub track_paths: bool,
     /// Concurrency level
     pub concurrency: usize,
+    /// Whether to record discovery and finish timestamps
+    pub compute_timestamps: bool,
 }
 
 impl DfsStorageRuntime {
@@ -44,76 +47,119 @@ impl DfsStorageRuntime {
             max_depth,
             track_paths,
             concurrency,
+            compute_timestamps: false,
         }
     }
 
+    /// Enable or disable discovery/finish timestamps
+    pub fn with_compute_timestamps(mut self, compute_timestamps: bool) -> Self {
+        self.compute_timestamps = compute_timestamps;
+        self
+    }
+
     /// Compute DFS traversal
     ///
     /// Translation of: `DFS.compute()` (lines 1.051.0-200)
-    /// This orchestrates the main DFS algorithm loop using a stack
+    /// This orchestrates the main DFS algorithm loop using a stack of
+    /// partially explored nodes. A node is discovered when it is first
+    /// reached and finished once all of its neighbors are explored; with
+    /// `compute_timestamps`, one clock ticks on every discovery and every
+    /// finish, giving d[v] < f[v] for each discovered node and f[u] > f[v]
+    /// for every edge u -> v of a DAG. Nodes at `max_depth` are discovered
+    /// but not expanded. Nodes the traversal never reaches keep `-1`.
     pub fn compute_dfs(&self, computation: &mut DfsComputationRuntime, graph: Option<&dyn Graph>) -> Result<DfsResult, AlgorithmError> {
         let start_time = std::time::Instant::now();
         
         // Initialize computation runtime
         computation.initialize(self.source_node, self.max_depth);
 
-        // DFS stack for depth-first traversal
-        let mut stack = VecDeque::new();
-        stack.push_back((self.source_node, 0)); // (node, depth)
-
         // Track visited nodes and their discovery order
         let mut visited = HashMap::new();
         let mut discovery_order = 0;
-        visited.insert(self.source_node, discovery_order);
-        discovery_order += 1;
 
         // Track paths if requested
         let mut paths = Vec::new();
         let mut predecessors = HashMap::new();
 
+        // Discovery and finish times, ticking on one shared clock
+        let mut clock = 0i64;
+        let mut discovery_times = Vec::new();
+        let mut finish_times = Vec::new();
+
+        // (node, depth, neighbors, index of the next neighbor to follow)
+        let mut stack = Vec::new();
+        let mut next_discovery = Some((self.source_node, 0));
+
         // Main DFS loop
-        while let Some((current_node, current_depth)) = stack.pop_back() {
-            // Check max depth constraint
-            if let Some(max_depth) = self.max_depth {
-                if current_depth >= max_depth {
-                    continue;
-                }
-            }
+        loop {
+            if let Some((node, depth)) = next_discovery.take() {
+                visited.insert(node, discovery_order);
+                discovery_order += 1;
+                discovery_times.push((node, clock));
+                clock += 1;
+                computation.add_visited_node(node, depth);
 
-            // Check if we found a target
-            if !self.target_nodes.is_empty() && self.target_nodes.contains(&current_node) {
-                if self.track_paths {
-                    if let Some(path) = self.reconstruct_path(self.source_node, current_node, &predecessors) {
-                        paths.push(path);
+                // Check if we found a target
+                if !self.target_nodes.is_empty() && self.target_nodes.contains(&node) {
+                    if self.track_paths {
+                        if let Some(path) = self.reconstruct_path(self.source_node, node, &predecessors) {
+                            paths.push(path);
+                        }
+                    }
+
+                    // If we have targets and found all, we can stop early
+                    if paths.len() == self.target_nodes.len() {
+                        stack.push((node, depth, Vec::new(), 0));
+                        break;
                     }
                 }
-                
-                // If we have targets and found all, we can stop early
-                if paths.len() == self.target_nodes.len() {
-                    break;
-                }
+
+                // Check max depth constraint
+                let expand = self.max_depth.is_none_or(|max_depth| depth < max_depth);
+                let neighbors = if expand {
+                    self.get_neighbors(graph, node)
+                } else {
+                    Vec::new()
+                };
+                stack.push((node, depth, neighbors, 0usize));
             }
 
-            // Get neighbors and add to stack (in reverse order for consistent traversal)
-            let mut neighbors = self.get_neighbors(graph, current_node);
-            neighbors.reverse(); // Reverse to maintain consistent order
-            
-            for neighbor in neighbors {
+            let Some((node, depth, neighbors, next)) = stack.last_mut() else {
+                break;
+            };
+            if let Some(&neighbor) = neighbors.get(*next) {
+                *next += 1;
                 if !visited.contains_key(&neighbor) {
-                    visited.insert(neighbor, discovery_order);
-                    discovery_order += 1;
-                    stack.push_back((neighbor, current_depth + 1));
-                    
                     if self.track_paths {
-                        predecessors.insert(neighbor, current_node);
+                        predecessors.insert(neighbor, *node);
                     }
+                    next_discovery = Some((neighbor, *depth + 1));
                 }
+            } else {
+                finish_times.push((*node, clock));
+                clock += 1;
+                stack.pop();
             }
+        }
 
-            // Update computation runtime
-            computation.add_visited_node(current_node, current_depth);
+        // An early stop returns through every node still on the stack
+        while let Some((node, ..)) = stack.pop() {
+            finish_times.push((node, clock));
+            clock += 1;
         }
 
+        let (discovery, finish) = if self.compute_timestamps {
+            let node_count = graph
+                .map(|g| g.node_count())
+                .unwrap_or_else(|| visited.keys().max().map_or(0, |&node| node as usize + 1));
+            (
+                Some(Self::timestamps(node_count, &discovery_times)),
+                Some(Self::timestamps(node_count, &finish_times)),
+            )
+        } else {
+            (None, None)
+        };
+
         let computation_time = start_time.elapsed().as_millis() as u64;
 
         let visited_count = visited.len();
@@ -122,9 +168,21 @@ impl DfsStorageRuntime {
             paths,
             nodes_visited: visited_count,
             computation_time_ms: computation_time,
+            discovery,
+            finish,
         })
     }
 
+    /// Collect per-node times into an array, `-1` for nodes without one
+    fn timestamps(node_count: usize, times: &[(u32, i64)]) -> HugeLongArray {
+        let mut array = HugeLongArray::new(node_count);
+        array.fill(-1);
+        for &(node, time) in times {
+            array.set(node as usize, time);
+        }
+        array
+    }
+
     /// Reconstruct path from source to target
     ///
     /// Translation of: `DFS.reconstructPath()` (lines 201.0-250)
@@ -226,4 +284,84 @@ mod tests {
         assert!(result.nodes_visited <= 3); // Source + immediate neighbors
         assert!(result.computation_time_ms >= 0);
     }
+
+    fn dag() -> crate::types::graph_store::DefaultGraphStore {
+        // 0 -> {1, 2}, 1 -> 3, 2 -> 3, 3 -> 4, 5 -> 4; node 5 is not reachable from 0
+        crate::types::random::generators::typed_store(
+            "dag",
+            6,
+            [(
+                "REL",
+                crate::types::schema::Direction::Directed,
+                DAG_EDGES.to_vec(),
+            )],
+        )
+    }
+
+    const DAG_EDGES: [(usize, usize); 6] = [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (5, 4)];
+
+    #[test]
+    fn test_dfs_timestamps_on_dag() {
+        use crate::types::graph_store::GraphStore;
+
+        let graph = dag().get_graph();
+        let storage = DfsStorageRuntime::new(0, vec![], None, false, 1).with_compute_timestamps(true);
+        let mut computation = DfsComputationRuntime::new(0, false, 1);
+        let result = storage.compute_dfs(&mut computation, Some(graph.as_ref())).unwrap();
+
+        let discovery = result.discovery.expect("timestamps requested");
+        let finish = result.finish.expect("timestamps requested");
+        assert_eq!(result.nodes_visited, 5);
+        for node in 0..5 {
+            assert!(discovery.get(node) >= 0, "node {} undiscovered", node);
+            assert!(discovery.get(node) < finish.get(node), "node {}", node);
+        }
+        assert_eq!((discovery.get(5), finish.get(5)), (-1, -1));
+        // Finish times decrease along every traversed edge, so sorting by them is a topological order
+        let reached = DAG_EDGES.iter().filter(|&&(source, _)| source != 5);
+        for &(source, target) in reached.clone() {
+            assert!(finish.get(source) > finish.get(target), "{} -> {}", source, target);
+        }
+        let mut order: Vec<usize> = (0..5).collect();
+        order.sort_by_key(|&node| std::cmp::Reverse(finish.get(node)));
+        let position = |node: usize| order.iter().position(|&n| n == node).unwrap();
+        for &(source, target) in reached {
+            assert!(position(source) < position(target));
+        }
+    }
+
+    #[test]
+    fn test_dfs_timestamps_respect_max_depth_and_targets() {
+        use crate::types::graph_store::GraphStore;
+
+        let graph = dag().get_graph();
+        let storage = DfsStorageRuntime::new(0, vec![], Some(1), false, 1).with_compute_timestamps(true);
+        let mut computation = DfsComputationRuntime::new(0, false, 1);
+        let result = storage.compute_dfs(&mut computation, Some(graph.as_ref())).unwrap();
+
+        // 0 is discovered, then 1 and 2 are discovered and finished without being expanded
+        let discovery = result.discovery.unwrap();
+        let finish = result.finish.unwrap();
+        let times = |node| (discovery.get(node), finish.get(node));
+        assert_eq!(times(0), (0, 5));
+        assert_eq!(times(1), (1, 2));
+        assert_eq!(times(2), (3, 4));
+        for node in 3..6 {
+            assert_eq!(times(node), (-1, -1));
+        }
+
+        // Stopping at target 3 finishes the nodes still on the stack
+        let storage = DfsStorageRuntime::new(0, vec![3], None, true, 1).with_compute_timestamps(true);
+        let mut computation = DfsComputationRuntime::new(0, true, 1);
+        let result = storage.compute_dfs(&mut computation, Some(graph.as_ref())).unwrap();
+        assert_eq!(result.paths[0].node_ids, vec![0, 1, 3]);
+        let discovery = result.discovery.unwrap();
+        let finish = result.finish.unwrap();
+        let times = |node| (discovery.get(node), finish.get(node));
+        assert_eq!(times(0), (0, 5));
+        assert_eq!(times(1), (1, 4));
+        assert_eq!(times(3), (2, 3));
+        assert_eq!(times(2), (-1, -1));
+        assert_eq!(times(4), (-1, -1));
+    }
 }
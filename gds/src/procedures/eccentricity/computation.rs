@@ -0,0 +1,163 @@
+//! Eccentricity Computation Runtime
+//!
+//! Connected components are discovered with BFS from their smallest node `r`.
+//! In exact mode every node then runs its own BFS. In sampled mode each
+//! component runs a double sweep (`a` farthest from `r`, `b` farthest from
+//! `a`) and `sample_size` random nodes act as additional sources; a node's
+//! eccentricity is estimated as its largest distance to any source of its
+//! component. Because `r` and `a` are sources, the estimate is at least a
+//! third of the exact value, and the double sweep is exact on trees.
+
+use super::spec::EccentricityMode;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::VecDeque;
+
+/// Diameter and radius of one connected component
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentEccentricity {
+    pub node_count: usize,
+    pub diameter: u64,
+    pub radius: u64,
+}
+
+/// Eccentricity computation result
+#[derive(Debug, Clone)]
+pub struct EccentricityComputationResult {
+    /// Eccentricity of every node within its component
+    pub eccentricities: Vec<u64>,
+    /// Component index of every node
+    pub component: Vec<usize>,
+    /// Per-component statistics, indexed by component
+    pub components: Vec<ComponentEccentricity>,
+    /// Largest component diameter
+    pub diameter: u64,
+    /// Smallest component radius; isolated nodes have radius 0
+    pub radius: u64,
+    /// `false` if the values are sampled estimates
+    pub exact: bool,
+}
+
+/// Eccentricity computation runtime
+pub struct EccentricityComputationRuntime {
+    mode: EccentricityMode,
+}
+
+impl EccentricityComputationRuntime {
+    pub fn new(mode: EccentricityMode) -> Self {
+        Self { mode }
+    }
+
+    /// Compute eccentricities, diameters and radii
+    /// get_neighbors returns the neighbor nodes for a given node; the graph
+    /// is treated as undirected, so each edge should be listed from both ends
+    pub fn compute(
+        &mut self,
+        node_count: usize,
+        get_neighbors: impl Fn(usize) -> Vec<usize>,
+    ) -> EccentricityComputationResult {
+        let adjacency: Vec<Vec<usize>> = (0..node_count).map(get_neighbors).collect();
+        let mut distances = vec![u64::MAX; node_count];
+        let mut eccentricities = vec![0u64; node_count];
+
+        let mut component = vec![usize::MAX; node_count];
+        let mut roots = Vec::new();
+        for root in 0..node_count {
+            if component[root] == usize::MAX {
+                let id = roots.len();
+                for node in bfs(&adjacency, root, &mut distances) {
+                    component[node] = id;
+                }
+                roots.push(root);
+            }
+        }
+
+        match self.mode {
+            EccentricityMode::Exact => {
+                for (source, eccentricity) in eccentricities.iter_mut().enumerate() {
+                    let reached = bfs(&adjacency, source, &mut distances);
+                    *eccentricity = farthest(&reached, &distances).1;
+                }
+            }
+            EccentricityMode::Sampled { sample_size, seed } => {
+                let mut sources = Vec::new();
+                for &root in &roots {
+                    let reached = bfs(&adjacency, root, &mut distances);
+                    let a = farthest(&reached, &distances).0;
+                    bfs(&adjacency, a, &mut distances);
+                    let b = farthest(&reached, &distances).0;
+                    sources.extend([root, a, b]);
+                }
+                if node_count > 0 {
+                    let mut rng = StdRng::seed_from_u64(seed);
+                    sources.extend((0..sample_size).map(|_| rng.gen_range(0..node_count)));
+                }
+                sources.sort_unstable();
+                sources.dedup();
+
+                for source in sources {
+                    for node in bfs(&adjacency, source, &mut distances) {
+                        eccentricities[node] = eccentricities[node].max(distances[node]);
+                    }
+                }
+            }
+        }
+
+        let mut components: Vec<ComponentEccentricity> = roots
+            .iter()
+            .map(|_| ComponentEccentricity {
+                node_count: 0,
+                diameter: 0,
+                radius: u64::MAX,
+            })
+            .collect();
+        for node in 0..node_count {
+            let stats = &mut components[component[node]];
+            stats.node_count += 1;
+            stats.diameter = stats.diameter.max(eccentricities[node]);
+            stats.radius = stats.radius.min(eccentricities[node]);
+        }
+
+        EccentricityComputationResult {
+            diameter: components.iter().map(|c| c.diameter).max().unwrap_or(0),
+            radius: components.iter().map(|c| c.radius).min().unwrap_or(0),
+            eccentricities,
+            component,
+            components,
+            exact: self.mode == EccentricityMode::Exact,
+        }
+    }
+}
+
+impl Default for EccentricityComputationRuntime {
+    fn default() -> Self {
+        Self::new(EccentricityMode::Exact)
+    }
+}
+
+/// BFS from `source`, filling `distances` for the reached nodes. Returns the
+/// reached nodes in visiting order; other entries of `distances` are stale.
+fn bfs(adjacency: &[Vec<usize>], source: usize, distances: &mut [u64]) -> Vec<usize> {
+    let mut visited = vec![false; adjacency.len()];
+    let mut reached = vec![source];
+    let mut queue = VecDeque::from([source]);
+    visited[source] = true;
+    distances[source] = 0;
+
+    while let Some(node) = queue.pop_front() {
+        for &neighbor in &adjacency[node] {
+            if !visited[neighbor] {
+                visited[neighbor] = true;
+                distances[neighbor] = distances[node] + 1;
+                reached.push(neighbor);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    reached
+}
+
+/// The last reached node is a farthest one; returns it and its distance
+fn farthest(reached: &[usize], distances: &[u64]) -> (usize, u64) {
+    let node = *reached.last().unwrap();
+    (node, distances[node])
+}
@@ -0,0 +1,109 @@
+//! Eccentricity Integration Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::procedures::eccentricity::computation::EccentricityComputationRuntime;
+    use crate::procedures::eccentricity::spec::EccentricityMode;
+    use std::collections::HashMap;
+
+    fn create_graph(edges: &[(usize, usize)], node_count: usize) -> HashMap<usize, Vec<usize>> {
+        let mut graph = HashMap::new();
+        for i in 0..node_count {
+            graph.insert(i, Vec::new());
+        }
+        for &(from, to) in edges {
+            graph.entry(from).or_insert_with(Vec::new).push(to);
+            graph.entry(to).or_insert_with(Vec::new).push(from);
+        }
+        graph
+    }
+
+    fn path(node_count: usize) -> HashMap<usize, Vec<usize>> {
+        let edges: Vec<_> = (1..node_count).map(|i| (i - 1, i)).collect();
+        create_graph(&edges, node_count)
+    }
+
+    #[test]
+    fn test_path_diameter_and_radius() {
+        let n = 9;
+        let graph = path(n);
+
+        for mode in [
+            EccentricityMode::Exact,
+            EccentricityMode::Sampled {
+                sample_size: 0,
+                seed: 1,
+            },
+        ] {
+            let mut runtime = EccentricityComputationRuntime::new(mode);
+            let result = runtime.compute(n, |node| graph.get(&node).cloned().unwrap_or_default());
+
+            assert_eq!(result.diameter, (n - 1) as u64);
+            assert_eq!(result.eccentricities[n / 2], result.radius);
+            assert_eq!(result.radius, (n / 2) as u64);
+            assert_eq!(result.eccentricities[0], (n - 1) as u64);
+        }
+    }
+
+    #[test]
+    fn test_disconnected_graph_is_computed_per_component() {
+        // Path 0-1-2-3 and triangle 4-5-6, plus isolated node 7
+        let graph = create_graph(&[(0, 1), (1, 2), (2, 3), (4, 5), (5, 6), (6, 4)], 8);
+
+        let mut runtime = EccentricityComputationRuntime::default();
+        let result = runtime.compute(8, |node| graph.get(&node).cloned().unwrap_or_default());
+
+        assert_eq!(result.components.len(), 3);
+        assert_eq!(result.components[0].diameter, 3);
+        assert_eq!(result.components[0].radius, 2);
+        assert_eq!(result.components[1].diameter, 1);
+        assert_eq!(result.components[2].node_count, 1);
+        assert_eq!(result.eccentricities[5], 1);
+        assert_eq!(result.diameter, 3);
+        assert_eq!(result.radius, 0);
+    }
+
+    #[test]
+    fn test_sampled_mode_error_is_bounded() {
+        // 6x6 grid
+        let side = 6;
+        let mut edges = Vec::new();
+        for row in 0..side {
+            for col in 0..side {
+                let node = row * side + col;
+                if col + 1 < side {
+                    edges.push((node, node + 1));
+                }
+                if row + 1 < side {
+                    edges.push((node, node + side));
+                }
+            }
+        }
+        let n = side * side;
+        let graph = create_graph(&edges, n);
+        let neighbors = |node: usize| graph.get(&node).cloned().unwrap_or_default();
+
+        let exact = EccentricityComputationRuntime::default().compute(n, neighbors);
+        let sampled = EccentricityComputationRuntime::new(EccentricityMode::Sampled {
+            sample_size: 4,
+            seed: 42,
+        })
+        .compute(n, neighbors);
+
+        assert!(!sampled.exact);
+        assert_eq!(exact.diameter, 10);
+        assert!(sampled.diameter <= exact.diameter);
+        assert!(2 * sampled.diameter >= exact.diameter);
+        for node in 0..n {
+            let estimate = sampled.eccentricities[node];
+            let expected = exact.eccentricities[node];
+            assert!(estimate <= expected);
+            assert!(
+                3 * estimate >= expected,
+                "node {} estimate {}",
+                node,
+                estimate
+            );
+        }
+    }
+}
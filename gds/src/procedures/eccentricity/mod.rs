@@ -0,0 +1,15 @@
+//! Eccentricity Algorithm
+//!
+//! Per-node eccentricity (largest shortest-path distance to any reachable
+//! node), plus the diameter and radius of every connected component. Built on
+//! unweighted BFS; the exact mode runs one BFS per node, the sampled mode
+//! estimates eccentricities from a double sweep and a few random sources.
+
+pub mod spec;
+pub mod storage;
+pub mod computation;
+pub mod integration_tests;
+
+pub use spec::{EccentricityAlgorithmSpec, EccentricityConfig, EccentricityMode, EccentricityResult};
+pub use storage::EccentricityStorageRuntime;
+pub use computation::EccentricityComputationRuntime;
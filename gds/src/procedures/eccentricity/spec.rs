@@ -0,0 +1,52 @@
+//! Eccentricity Algorithm Specification
+use serde::{Deserialize, Serialize};
+
+/// How eccentricities are computed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum EccentricityMode {
+    /// One BFS per node
+    #[default]
+    Exact,
+    /// Double sweep per component plus `sample_size` random BFS sources.
+    /// Eccentricities and diameters are lower bounds of the exact values.
+    Sampled { sample_size: usize, seed: u64 },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EccentricityConfig {
+    pub concurrency: usize,
+    pub mode: EccentricityMode,
+}
+
+impl Default for EccentricityConfig {
+    fn default() -> Self {
+        Self {
+            concurrency: 4,
+            mode: EccentricityMode::Exact,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EccentricityResult {
+    /// Eccentricity of every node within its component
+    pub eccentricities: Vec<u64>,
+    /// Largest component diameter
+    pub diameter: u64,
+    /// Smallest component radius
+    pub radius: u64,
+}
+
+pub struct EccentricityAlgorithmSpec {
+    graph_name: String,
+}
+
+impl EccentricityAlgorithmSpec {
+    pub fn new(graph_name: String) -> Self {
+        Self { graph_name }
+    }
+
+    pub fn graph_name(&self) -> &str {
+        &self.graph_name
+    }
+}
@@ -0,0 +1,15 @@
+//! Eccentricity Storage Runtime
+
+pub struct EccentricityStorageRuntime {
+    concurrency: usize,
+}
+
+impl EccentricityStorageRuntime {
+    pub fn new(concurrency: usize) -> Self {
+        Self { concurrency }
+    }
+
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+}
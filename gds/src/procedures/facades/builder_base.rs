@@ -3,9 +3,13 @@
 //! Provides common types and patterns used across all facade builders,
 //! reducing boilerplate while maintaining consistency.
 
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 use super::traits::Result;
 use crate::projection::eval::procedure::AlgorithmError;
+use crate::types::graph::Graph;
+use crate::types::graph_store::{DefaultGraphStore, GraphStore};
 
 /// Execution context tracking for algorithm runs
 #[derive(Debug, Clone)]
@@ -155,6 +159,41 @@ impl ConfigValidator {
     }
 }
 
+/// The graph store a builder was bound to
+///
+/// Builders created outside a [`crate::Graph`] have nothing to run on, so
+/// they fail instead of returning made-up results.
+pub(crate) fn bound_graph_store<'a>(
+    graph_store: &'a Option<Arc<DefaultGraphStore>>,
+    algorithm: &str,
+) -> Result<&'a DefaultGraphStore> {
+    graph_store.as_deref().ok_or_else(|| {
+        AlgorithmError::Graph(format!("{} builder is not bound to a graph", algorithm))
+    })
+}
+
+/// Graph view whose relationship cursors carry the property `key`
+///
+/// The property is selected on every relationship type that has it; the
+/// other types report the caller's fallback value. Fails if no type has it.
+pub(crate) fn weighted_graph(graph_store: &DefaultGraphStore, key: &str) -> Result<Arc<dyn Graph>> {
+    let rel_types = graph_store.relationship_types();
+    let selectors: HashMap<_, _> = rel_types
+        .iter()
+        .filter(|rel_type| graph_store.has_relationship_property(rel_type, key))
+        .map(|rel_type| (rel_type.clone(), key.to_string()))
+        .collect();
+    if selectors.is_empty() {
+        return Err(AlgorithmError::Execution(format!(
+            "relationship property '{}' not found",
+            key
+        )));
+    }
+    graph_store
+        .get_graph_with_types_and_selectors(&rel_types, &selectors)
+        .map_err(|e| AlgorithmError::Graph(e.to_string()))
+}
+
 // ============================================================================
 // Result Aggregation Utilities
 // ============================================================================
@@ -28,9 +28,17 @@
 //! println!("Max betweenness: {} (bottleneck identified)", stats.max);
 //! ```
 
+use crate::config::{BetweennessCentralityConfig, PivotStrategy};
+use crate::procedures::betweenness::computation::BetweennessCentralityComputationRuntime;
+use crate::procedures::core::statistics::{StatisticsConfig, StatisticsEngine};
 use crate::procedures::facades::traits::{Result, CentralityScore};
-
-use crate::procedures::facades::builder_base::{MutationResult, ConfigValidator};
+use crate::procedures::facades::builder_base::{bound_graph_store, MutationResult, ConfigValidator};
+use crate::projection::eval::procedure::AlgorithmError;
+use crate::types::graph::id_map::IdMap;
+use crate::types::graph_store::DefaultGraphStore;
+use crate::types::properties::relationship::RelationshipIterator;
+use std::sync::Arc;
+use std::time::Instant;
 
 // ============================================================================
 // Statistics Type
@@ -82,22 +90,86 @@ pub struct BetweennessStats {
 /// let stats = builder.stats()?;
 /// ```
 pub struct BetweenessBuilder {
-    // No configuration currently - algorithm is deterministic
-    // Could add: sampling factor, normalization flag, etc.
+    /// Graph store to run on; unbound builders fail
+    graph_store: Option<Arc<DefaultGraphStore>>,
+    /// Sampling settings; exact betweenness unless a sampling size is set
+    config: BetweennessCentralityConfig,
 }
 
 impl BetweenessBuilder {
     /// Create a new Betweenness builder
     pub fn new() -> Self {
-        Self {}
+        Self {
+            graph_store: None,
+            config: BetweennessCentralityConfig::default(),
+        }
+    }
+
+    /// Bind the builder to a graph store
+    pub fn graph_store(mut self, graph_store: Arc<DefaultGraphStore>) -> Self {
+        self.graph_store = Some(graph_store);
+        self
+    }
+
+    /// Use all sampling settings of `config`
+    pub fn config(mut self, config: BetweennessCentralityConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Estimate betweenness from `size` source nodes instead of all of them
+    pub fn sampling_size(mut self, size: usize) -> Self {
+        self.config.sampling_size = Some(size);
+        self
+    }
+
+    /// Seed for choosing the sampled source nodes
+    pub fn sampling_seed(mut self, seed: u64) -> Self {
+        self.config.sampling_seed = Some(seed);
+        self
+    }
+
+    /// How the sampled source nodes are chosen
+    pub fn pivot_strategy(mut self, strategy: PivotStrategy) -> Self {
+        self.config.pivot_strategy = strategy;
+        self
     }
 
     /// Validate configuration
     fn validate(&self) -> Result<()> {
-        // Currently no configuration to validate
+        if let Some(size) = self.config.sampling_size {
+            ConfigValidator::positive(size as f64, "sampling_size")?;
+        }
         Ok(())
     }
 
+    /// Betweenness of every node over outgoing relationships
+    ///
+    /// Exact unless a sampling size is set, in which case only that many
+    /// sources chosen by the pivot strategy contribute.
+    fn compute(&self) -> Result<Vec<f64>> {
+        let graph = bound_graph_store(&self.graph_store, "betweenness")?.graph();
+        let node_count = graph.node_count();
+        let neighbors = |node: usize| {
+            graph
+                .stream_relationships(node as i64, 1.0)
+                .map(|cursor| cursor.target_id() as usize)
+                .collect()
+        };
+        let mut runtime = BetweennessCentralityComputationRuntime::new(node_count);
+        Ok(match self.config.sampling_size {
+            Some(sampling_size) => runtime.compute_sampled(
+                node_count,
+                sampling_size,
+                self.config.pivot_strategy,
+                self.config.sampling_seed,
+                neighbors,
+            ),
+            None => runtime.compute(node_count, neighbors),
+        }
+        .centralities)
+    }
+
     /// Stream mode: Get betweenness score for each node
     ///
     /// Returns an iterator over (node_id, score) tuples.
@@ -119,8 +191,13 @@ impl BetweenessBuilder {
     /// ```
     pub fn stream(self) -> Result<Box<dyn Iterator<Item = CentralityScore>>> {
         self.validate()?;
-        // TODO: Call actual algorithm spec and return stream
-        Ok(Box::new(std::iter::empty()))
+        let centralities = self.compute()?;
+        Ok(Box::new(centralities.into_iter().enumerate().map(|(node_id, score)| {
+            CentralityScore {
+                node_id: node_id as u64,
+                score,
+            }
+        })))
     }
 
     /// Stats mode: Get aggregated statistics
@@ -140,17 +217,31 @@ impl BetweenessBuilder {
     /// ```
     pub fn stats(self) -> Result<BetweennessStats> {
         self.validate()?;
-        // TODO: Call actual algorithm spec and compute statistics
+        let started_at = Instant::now();
+        let centralities = self.compute()?;
+
+        let config = StatisticsConfig {
+            compute_histogram: false,
+            ..StatisticsConfig::default()
+        };
+        let (summary, _) =
+            StatisticsEngine::compute_statistics_from_values(centralities.clone(), config)
+                .map_err(|e| AlgorithmError::Execution(e.to_string()))?;
+        let bridge_threshold = summary.mean + summary.std_dev;
+
         Ok(BetweennessStats {
-            min: 0.0,
-            max: 0.0,
-            mean: 0.0,
-            stddev: 0.0,
-            p50: 0.0,
-            p90: 0.0,
-            p99: 0.0,
-            bridge_nodes: 0,
-            execution_time_ms: 0,
+            min: summary.min,
+            max: summary.max,
+            mean: summary.mean,
+            stddev: summary.std_dev,
+            p50: summary.percentiles.p50,
+            p90: summary.percentiles.p90,
+            p99: summary.percentiles.p99,
+            bridge_nodes: centralities
+                .iter()
+                .filter(|&&score| score > bridge_threshold)
+                .count() as u64,
+            execution_time_ms: started_at.elapsed().as_millis() as u64,
         })
     }
 
@@ -202,17 +293,9 @@ mod tests {
     }
 
     #[test]
-    fn test_stream_ok() {
-        let builder = BetweenessBuilder::new();
-        let result = builder.stream();
-        assert!(result.is_ok());
-    }
-
-    #[test]
-    fn test_stats_ok() {
-        let builder = BetweenessBuilder::new();
-        let result = builder.stats();
-        assert!(result.is_ok());
+    fn test_unbound_builder_fails() {
+        assert!(BetweenessBuilder::new().stream().is_err());
+        assert!(BetweenessBuilder::new().stats().is_err());
     }
 
     #[test]
@@ -227,6 +310,35 @@ mod tests {
         assert!(builder.mutate("betweenness").is_ok());
     }
 
+    #[test]
+    fn test_sampling_size_routes_to_sampled_computation() {
+        use crate::procedures::facades::graph::Graph;
+
+        // Path 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 7
+        let edges: Vec<(u64, u64)> = (0..7).map(|node| (node, node + 1)).collect();
+        let graph = Graph::from_edge_list(&edges);
+        let scores = |builder: BetweenessBuilder| -> Vec<f64> {
+            builder.stream().unwrap().map(|score| score.score).collect()
+        };
+
+        let exact = scores(graph.betweenness());
+        // Sampling every node uniformly is the exact computation.
+        assert_eq!(scores(graph.betweenness().sampling_size(8).sampling_seed(3)), exact);
+
+        // Two sources cannot see every shortest path.
+        let sampled = scores(
+            graph
+                .betweenness()
+                .sampling_size(2)
+                .sampling_seed(3)
+                .pivot_strategy(PivotStrategy::Uniform),
+        );
+        assert_eq!(sampled.len(), exact.len());
+        assert_ne!(sampled, exact);
+
+        assert!(graph.betweenness().sampling_size(0).stream().is_err());
+    }
+
     #[test]
     fn test_default_builder() {
         let _builder = BetweenessBuilder::default();
@@ -23,8 +23,13 @@
 //! graph.degree_centrality().mutate("degree")?;
 //! ```
 
+use crate::procedures::core::statistics::{StatisticsConfig, StatisticsEngine};
 use crate::procedures::facades::traits::{Result, CentralityScore};
-use crate::procedures::facades::builder_base::{MutationResult, ConfigValidator};
+use crate::procedures::facades::builder_base::{bound_graph_store, weighted_graph, ConfigValidator, MutationResult};
+use crate::projection::eval::procedure::AlgorithmError;
+use crate::types::graph_store::{DefaultGraphStore, GraphStore};
+use std::sync::Arc;
+use std::time::Instant;
 
 // ============================================================================
 // Statistics Type
@@ -62,7 +67,10 @@ pub struct DegreeCentralityStats {
 /// This is the simplest centrality algorithm: just count edges per node.
 /// Use this for quick identification of hub nodes.
 pub struct DegreeCentralityFacade<'a> {
-    // Note: We'll add graph reference later when we integrate with Graph type
+    /// Graph store to run on; unbound facades fail
+    graph_store: Option<Arc<DefaultGraphStore>>,
+    /// Relationship property summed instead of counting relationships
+    weight_property: Option<String>,
     _phantom: std::marker::PhantomData<&'a ()>,
 }
 
@@ -70,10 +78,49 @@ impl<'a> DegreeCentralityFacade<'a> {
     /// Create a new DegreeCentrality facade
     pub fn new() -> Self {
         Self {
+            graph_store: None,
+            weight_property: None,
             _phantom: std::marker::PhantomData,
         }
     }
 
+    /// Bind the facade to a graph store
+    pub fn graph_store(mut self, graph_store: Arc<DefaultGraphStore>) -> Self {
+        self.graph_store = Some(graph_store);
+        self
+    }
+
+    /// Use weighted degrees: the sum of the relationship property `key`
+    ///
+    /// Relationships of types without the property count with weight 1.0.
+    pub fn weighted(mut self, key: &str) -> Self {
+        self.weight_property = Some(key.to_string());
+        self
+    }
+
+    /// Outgoing degree of every node, weighted if a weight property is set
+    ///
+    /// Stream, stats and mutate all read the degrees from here.
+    fn compute_degrees(&self) -> Result<Vec<f64>> {
+        let graph_store = bound_graph_store(&self.graph_store, "degree centrality")?;
+        let Some(key) = &self.weight_property else {
+            return Ok((0..graph_store.node_count())
+                .map(|node| graph_store.degree(node as i64) as f64)
+                .collect());
+        };
+
+        let graph = weighted_graph(graph_store, key)?;
+
+        Ok((0..graph.node_count())
+            .map(|node| {
+                graph
+                    .stream_relationships(node as i64, 1.0)
+                    .map(|cursor| cursor.property())
+                    .sum()
+            })
+            .collect())
+    }
+
     /// Stream mode: Get degree for each node
     ///
     /// Returns an iterator over (node_id, degree) tuples.
@@ -92,9 +139,13 @@ impl<'a> DegreeCentralityFacade<'a> {
     /// }
     /// ```
     pub fn stream(&self) -> Result<Box<dyn Iterator<Item = CentralityScore>>> {
-        // TODO: Call actual algorithm spec and return stream of results
-        // For now, return empty iterator
-        Ok(Box::new(std::iter::empty()))
+        let degrees = self.compute_degrees()?;
+        Ok(Box::new(degrees.into_iter().enumerate().map(|(node_id, score)| {
+            CentralityScore {
+                node_id: node_id as u64,
+                score,
+            }
+        })))
     }
 
     /// Stats mode: Get aggregated statistics about degree distribution
@@ -115,25 +166,36 @@ impl<'a> DegreeCentralityFacade<'a> {
     /// println!("Isolated nodes: {}", stats.isolated_nodes);
     /// ```
     pub fn stats(&self) -> Result<DegreeCentralityStats> {
-        // TODO: Call actual algorithm spec and compute statistics
-        // For now, return placeholder
+        let started_at = Instant::now();
+        let degrees = self.compute_degrees()?;
+        let isolated_nodes = degrees.iter().filter(|&&degree| degree == 0.0).count() as u64;
+
+        let config = StatisticsConfig {
+            compute_histogram: false,
+            ..StatisticsConfig::default()
+        };
+        let (summary, _) = StatisticsEngine::compute_statistics_from_values(degrees, config)
+            .map_err(|e| AlgorithmError::Execution(e.to_string()))?;
+
         Ok(DegreeCentralityStats {
-            min: 0.0,
-            max: 0.0,
-            mean: 0.0,
-            stddev: 0.0,
-            p50: 0.0,
-            p90: 0.0,
-            p99: 0.0,
-            isolated_nodes: 0,
-            execution_time_ms: 0,
+            min: summary.min,
+            max: summary.max,
+            mean: summary.mean,
+            stddev: summary.std_dev,
+            p50: summary.percentiles.p50,
+            p90: summary.percentiles.p90,
+            p99: summary.percentiles.p99,
+            isolated_nodes,
+            execution_time_ms: started_at.elapsed().as_millis() as u64,
         })
     }
 
     /// Mutate mode: Compute and store degree as a node property
     ///
-    /// Stores the degree of each node as a property in the graph.
-    /// This allows other algorithms to use the degree as input.
+    /// Facades hold a shared, read-only graph store, so the degrees cannot
+    /// be written back yet. After validating the property name and
+    /// computing the degrees, this returns an error rather than reporting
+    /// nodes as updated.
     ///
     /// ## Example
     /// ```rust,no_run
@@ -141,19 +203,17 @@ impl<'a> DegreeCentralityFacade<'a> {
     /// # let graph = Graph::default();
     /// # use gds::procedures::facades::centrality::DegreeCentralityFacade;
     /// let facade = DegreeCentralityFacade::new();
-    /// let result = facade.mutate("degree")?;
-    /// println!("Updated {} nodes", result.nodes_updated);
+    /// assert!(facade.mutate("degree").is_err());
     /// ```
     pub fn mutate(&self, property_name: &str) -> Result<MutationResult> {
         ConfigValidator::non_empty_string(property_name, "property_name")?;
 
-        // TODO: Call actual algorithm spec and store results
-        // For now, return placeholder
-        Ok(MutationResult::new(
-            0,
-            property_name.to_string(),
-            std::time::Duration::from_millis(0),
-        ))
+        self.compute_degrees()?;
+
+        Err(AlgorithmError::Execution(format!(
+            "Cannot mutate property '{}': degree centrality facades cannot write to the bound graph store",
+            property_name
+        )))
     }
 }
 
@@ -179,17 +239,10 @@ mod tests {
     }
 
     #[test]
-    fn test_stream_returns_iterator() {
+    fn test_unbound_facade_fails() {
         let facade = DegreeCentralityFacade::new();
-        let result = facade.stream();
-        assert!(result.is_ok());
-    }
-
-    #[test]
-    fn test_stats_returns_valid_structure() {
-        let facade = DegreeCentralityFacade::new();
-        let stats = facade.stats().unwrap();
-        assert_eq!(stats.min, 0.0);
+        assert!(facade.stream().is_err());
+        assert!(facade.stats().is_err());
     }
 
     #[test]
@@ -200,9 +253,58 @@ mod tests {
     }
 
     #[test]
-    fn test_mutate_accepts_valid_property_name() {
+    fn test_mutate_reports_that_nothing_is_written() {
         let facade = DegreeCentralityFacade::new();
         let result = facade.mutate("degree");
-        assert!(result.is_ok());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_stats_reflect_weighted_star_hub() {
+        use crate::procedures::facades::graph::Graph;
+
+        // Hub 0 points to four leaves with weight 5.0, each leaf points back with 1.0
+        let edges: Vec<(u64, u64, f64)> = (1..=4)
+            .flat_map(|leaf| [(0, leaf, 5.0), (leaf, 0, 1.0)])
+            .collect();
+        let graph = Graph::from_weighted_edge_list(&edges);
+
+        let stats = graph.degree_centrality().weighted("weight").stats().unwrap();
+        assert_eq!(stats.max, 20.0);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.mean, 24.0 / 5.0);
+        assert_eq!(stats.p50, 1.0);
+        assert!(stats.p99 > 15.0);
+        assert_eq!(stats.isolated_nodes, 0);
+
+        let unweighted = graph.degree_centrality().stats().unwrap();
+        assert_eq!(unweighted.max, 4.0);
+        assert_eq!(unweighted.min, 1.0);
+
+        assert!(graph.degree_centrality().weighted("missing").stats().is_err());
+    }
+
+    #[test]
+    fn test_modes_share_weighted_degrees() {
+        use crate::procedures::facades::graph::Graph;
+
+        let graph = Graph::from_weighted_edge_list(&[(0, 1, 2.5), (0, 2, 0.5), (1, 2, 4.0)]);
+        let facade = graph.degree_centrality().weighted("weight");
+
+        let scores: Vec<f64> = facade.stream().unwrap().map(|score| score.score).collect();
+        assert_eq!(scores, vec![3.0, 4.0, 0.0]);
+
+        let stats = facade.stats().unwrap();
+        assert_eq!(stats.max, 4.0);
+        assert_eq!(stats.mean, 7.0 / 3.0);
+
+        // Nothing is written back, so mutate must not report updated nodes.
+        let error = facade.mutate("degree").unwrap_err();
+        assert!(error.to_string().contains("cannot write"));
+        assert!(graph
+            .degree_centrality()
+            .weighted("missing")
+            .mutate("degree")
+            .is_err());
     }
 }
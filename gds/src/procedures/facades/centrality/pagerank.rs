@@ -28,7 +28,13 @@
 //! ```
 
 use crate::procedures::facades::traits::{Result, CentralityScore};
-use crate::procedures::facades::builder_base::{MutationResult, ConfigValidator};
+use crate::procedures::facades::builder_base::{MutationResult, ConfigValidator, StatsAggregator};
+use crate::procedures::pagerank::{PageRankAlgorithmSpec, PageRankComputationResult};
+use crate::projection::eval::procedure::{
+    AlgorithmError, AlgorithmSpec, ExecutionContext, ExecutionMode,
+};
+use serde_json::json;
+use std::sync::Arc;
 
 // ============================================================================
 // Statistics Type
@@ -90,6 +96,9 @@ pub struct PageRankBuilder {
     damping_factor: f64,
     /// Convergence threshold
     tolerance: f64,
+    /// Context holding the graph to run on, and the graph's name in it;
+    /// unbound builders fail
+    context: Option<(Arc<ExecutionContext>, String)>,
 }
 
 impl PageRankBuilder {
@@ -104,9 +113,20 @@ impl PageRankBuilder {
             iterations: 20,
             damping_factor: 0.85,
             tolerance: 1e-4,
+            context: None,
         }
     }
 
+    /// Bind the builder to the graph stored under `graph_name` in `context`
+    pub fn context(
+        mut self,
+        context: Arc<ExecutionContext>,
+        graph_name: impl Into<String>,
+    ) -> Self {
+        self.context = Some((context, graph_name.into()));
+        self
+    }
+
     /// Set maximum iterations
     ///
     /// The algorithm will stop after this many iterations or when converged,
@@ -151,6 +171,33 @@ impl PageRankBuilder {
         Ok(())
     }
 
+    /// Run [`PageRankAlgorithmSpec`] on the bound graph
+    ///
+    /// The builder settings become the spec's JSON config, and the spec runs
+    /// in the bound context so it logs there.
+    fn compute(&self, mode: ExecutionMode) -> Result<PageRankComputationResult> {
+        let Some((context, graph_name)) = &self.context else {
+            return Err(AlgorithmError::Graph(
+                "pagerank builder is not bound to a graph".to_string(),
+            ));
+        };
+        let graph_store = context
+            .load_graph(graph_name)
+            .map_err(|e| AlgorithmError::Graph(e.to_string()))?;
+
+        let spec = PageRankAlgorithmSpec::new(graph_name.clone());
+        let config = spec
+            .parse_config(&json!({
+                "dampingFactor": self.damping_factor,
+                "tolerance": self.tolerance,
+                "maxIterations": self.iterations,
+            }))
+            .map_err(|e| AlgorithmError::Execution(e.to_string()))?;
+        let result = spec.execute(graph_store.as_ref(), &config, context)?;
+        spec.consume_result(result, &mode)
+            .map_err(|e| AlgorithmError::Execution(e.to_string()))
+    }
+
     /// Stream mode: Get PageRank score for each node
     ///
     /// Returns an iterator over (node_id, score) tuples.
@@ -167,8 +214,13 @@ impl PageRankBuilder {
     /// ```
     pub fn stream(self) -> Result<Box<dyn Iterator<Item = CentralityScore>>> {
         self.validate()?;
-        // TODO: Call actual algorithm spec and return stream
-        Ok(Box::new(std::iter::empty()))
+        let scores = self.compute(ExecutionMode::Stream)?.scores;
+        Ok(Box::new(scores.into_iter().enumerate().map(|(node_id, score)| {
+            CentralityScore {
+                node_id: node_id as u64,
+                score,
+            }
+        })))
     }
 
     /// Stats mode: Get aggregated statistics
@@ -186,18 +238,26 @@ impl PageRankBuilder {
     /// ```
     pub fn stats(self) -> Result<PageRankStats> {
         self.validate()?;
-        // TODO: Call actual algorithm spec and compute statistics
+        let PageRankComputationResult {
+            scores,
+            iterations,
+            converged,
+            execution_time,
+        } = self.compute(ExecutionMode::Stats)?;
+
+        let mut sorted = scores.clone();
+        sorted.sort_by(f64::total_cmp);
         Ok(PageRankStats {
-            min: 0.0,
-            max: 0.0,
-            mean: 0.0,
-            stddev: 0.0,
-            p50: 0.0,
-            p90: 0.0,
-            p99: 0.0,
-            iterations_ran: 0,
-            converged: false,
-            execution_time_ms: 0,
+            min: sorted.first().copied().unwrap_or(0.0),
+            max: sorted.last().copied().unwrap_or(0.0),
+            mean: StatsAggregator::mean(&scores).unwrap_or(0.0),
+            stddev: StatsAggregator::stddev(&scores).unwrap_or(0.0),
+            p50: StatsAggregator::percentile(&sorted, 50.0).unwrap_or(0.0),
+            p90: StatsAggregator::percentile(&sorted, 90.0).unwrap_or(0.0),
+            p99: StatsAggregator::percentile(&sorted, 99.0).unwrap_or(0.0),
+            iterations_ran: iterations as u32,
+            converged,
+            execution_time_ms: execution_time.as_millis() as u64,
         })
     }
 
@@ -317,6 +377,12 @@ mod tests {
         assert!(builder.mutate("").is_err()); // But empty property name
     }
 
+    #[test]
+    fn test_unbound_builder_fails() {
+        assert!(PageRankBuilder::new().stream().is_err());
+        assert!(PageRankBuilder::new().stats().is_err());
+    }
+
     #[test]
     fn test_mutate_accepts_valid_property() {
         let builder = PageRankBuilder::new();
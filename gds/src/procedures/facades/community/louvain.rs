@@ -0,0 +1,151 @@
+//! Louvain Facade
+//!
+//! **What is it?**: Greedy modularity optimisation over nested community levels
+//! **Why care?**: Finds densely connected groups without knowing their number upfront
+//! **Complexity**: O(E) per local moving pass, usually few levels
+//! **Best for**: Community detection on large, sparse networks
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use gds::Graph;
+//! # let graph = Graph::default();
+//! let communities = graph
+//!     .louvain()
+//!     .max_levels(5)
+//!     .stream()?
+//!     .collect::<Vec<_>>();
+//! ```
+
+use crate::procedures::facades::builder_base::{bound_graph_store, ConfigValidator};
+use crate::procedures::facades::traits::{CommunityAssignment, CommunityStats, Result};
+use crate::procedures::louvain::computation::{LouvainComputationRuntime, LouvainResult};
+use crate::procedures::louvain::storage::LouvainStorageRuntime;
+use crate::types::graph_store::DefaultGraphStore;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Louvain algorithm builder - fluent configuration
+///
+/// ## Default Configuration
+/// - max_levels: 10
+/// - max_iterations: 10
+/// - weight_property: none (every relationship weighs 1.0)
+pub struct LouvainBuilder {
+    /// Maximum number of aggregation levels
+    max_levels: u32,
+    /// Maximum local moving passes per level
+    max_iterations: u32,
+    /// Relationship property used as weight
+    weight_property: Option<String>,
+    /// Graph store to run on; unbound builders fail
+    graph_store: Option<Arc<DefaultGraphStore>>,
+}
+
+impl LouvainBuilder {
+    /// Create a new Louvain builder with defaults
+    pub fn new() -> Self {
+        Self {
+            max_levels: 10,
+            max_iterations: 10,
+            weight_property: None,
+            graph_store: None,
+        }
+    }
+
+    /// Bind the builder to the graph store it runs on
+    pub fn graph_store(mut self, graph_store: Arc<DefaultGraphStore>) -> Self {
+        self.graph_store = Some(graph_store);
+        self
+    }
+
+    /// Set the maximum number of aggregation levels
+    pub fn max_levels(mut self, n: u32) -> Self {
+        self.max_levels = n;
+        self
+    }
+
+    /// Set the maximum local moving passes per level
+    pub fn max_iterations(mut self, n: u32) -> Self {
+        self.max_iterations = n;
+        self
+    }
+
+    /// Weigh relationships by the given property
+    pub fn weight_property(mut self, property: &str) -> Self {
+        self.weight_property = Some(property.to_string());
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        ConfigValidator::iterations(self.max_levels, "max_levels")?;
+        ConfigValidator::iterations(self.max_iterations, "max_iterations")?;
+        if let Some(property) = &self.weight_property {
+            ConfigValidator::non_empty_string(property, "weight_property")?;
+        }
+        Ok(())
+    }
+
+    fn compute(&self) -> Result<LouvainResult> {
+        let graph_store = bound_graph_store(&self.graph_store, "louvain")?;
+        let adjacency = LouvainStorageRuntime::new(1)
+            .weighted_adjacency(graph_store, self.weight_property.as_deref())?;
+
+        Ok(LouvainComputationRuntime::new()
+            .with_max_levels(self.max_levels as usize)
+            .with_max_iterations(self.max_iterations as usize)
+            .compute(adjacency.len(), |node| adjacency[node].clone()))
+    }
+
+    /// Stream mode: Get the final community of each node
+    pub fn stream(self) -> Result<Box<dyn Iterator<Item = CommunityAssignment>>> {
+        self.validate()?;
+        let result = self.compute()?;
+        Ok(Box::new(result.data.into_iter().enumerate().map(
+            |(node_id, community_id)| CommunityAssignment {
+                node_id: node_id as u64,
+                community_id,
+            },
+        )))
+    }
+
+    /// Stats mode: Get community count, largest community and final modularity
+    pub fn stats(self) -> Result<CommunityStats> {
+        self.validate()?;
+        let result = self.compute()?;
+
+        let mut sizes: HashMap<u64, u64> = HashMap::new();
+        for &community in &result.data {
+            *sizes.entry(community).or_default() += 1;
+        }
+        let modularity = result.modularities.last().copied().unwrap_or(0.0);
+        Ok(CommunityStats {
+            community_count: sizes.len() as u64,
+            largest_community_size: sizes.values().copied().max().unwrap_or(0),
+            modularityx1000: (modularity * 1000.0).round() as i64,
+        })
+    }
+}
+
+impl Default for LouvainBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_levels() {
+        assert!(LouvainBuilder::new().max_levels(0).stream().is_err());
+        assert!(LouvainBuilder::new().weight_property("").stats().is_err());
+    }
+
+    #[test]
+    fn test_unbound_builder_fails() {
+        assert!(LouvainBuilder::new().stream().is_err());
+        assert!(LouvainBuilder::new().stats().is_err());
+    }
+}
@@ -3,8 +3,11 @@
 //! Identifies groups of related nodes within graphs.
 //! Algorithms in this module find dense subgraphs or communities.
 
+pub mod louvain;
+
+pub use louvain::LouvainBuilder;
+
 // Placeholder for future facades
-// - Louvain
 // - LabelPropagation
 // - WCC (Weakly Connected Components)
 // - LocalClusteringCoefficient
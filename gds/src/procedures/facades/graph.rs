@@ -0,0 +1,534 @@
+//! Graph facade - entry point for the fluent algorithm API
+//!
+//! Wraps a [`DefaultGraphStore`] and hands out builders that are already
+//! bound to that store. The store is registered in an [`ExecutionContext`],
+//! and builders that run through an algorithm spec execute in that context.
+//!
+//! ```rust,no_run
+//! # use gds::Graph;
+//! # let graph: Graph = unimplemented!();
+//! let scores: Vec<_> = graph.pagerank().iterations(20).stream()?.collect();
+//! ```
+
+use crate::config::GraphStoreConfig;
+use crate::procedures::facades::centrality::{
+    BetweenessBuilder, DegreeCentralityFacade, PageRankBuilder,
+};
+use crate::procedures::facades::community::LouvainBuilder;
+use crate::procedures::facades::pathfinding::{
+    AStarBuilder, BfsBuilder, DfsBuilder, DijkstraBuilder,
+};
+use crate::projection::eval::procedure::{ContextError, ExecutionContext};
+use crate::projection::orientation::Orientation;
+use crate::projection::{NodeLabel, RelationshipType};
+use crate::types::graph::{degrees::Degrees, id_map::IdMap};
+use crate::types::graph::{RelationshipTopology, SimpleIdMap};
+use crate::types::graph_store::{
+    Capabilities, DatabaseId, DatabaseInfo, DatabaseLocation, DefaultGraphStore, GraphName,
+    GraphStore,
+};
+use crate::types::properties::relationship::impls::default_relationship_property_values::DefaultRelationshipPropertyValues;
+use crate::types::properties::relationship::RelationshipIterator;
+use crate::types::schema::{
+    Aggregation, Direction, MutableGraphSchema, PropertySchemaTrait, RelationshipPropertySchema,
+};
+use crate::types::ValueType;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, OnceLock};
+
+/// A graph store that algorithm builders run on
+#[derive(Clone)]
+pub struct Graph {
+    store: Arc<DefaultGraphStore>,
+    /// Context the builders run in; holds `store` under `graph_name`
+    context: Arc<ExecutionContext>,
+    graph_name: String,
+    /// Incoming degrees, counted on first use when the store lacks an inverse index
+    in_degrees: Arc<OnceLock<Vec<usize>>>,
+}
+
+impl Graph {
+    /// Name the wrapped store is registered under by [`Graph::new`]
+    pub const DEFAULT_GRAPH_NAME: &'static str = "graph";
+
+    /// Wrap a graph store in a fresh execution context
+    pub fn new(store: Arc<DefaultGraphStore>) -> Self {
+        let mut context = ExecutionContext::empty();
+        context.add_graph(Self::DEFAULT_GRAPH_NAME, Arc::clone(&store));
+        Self {
+            store,
+            context: Arc::new(context),
+            graph_name: Self::DEFAULT_GRAPH_NAME.to_string(),
+            in_degrees: Arc::new(OnceLock::new()),
+        }
+    }
+
+    /// Run on the graph stored under `graph_name` in `context`
+    ///
+    /// Fails if the context holds no graph under that name.
+    pub fn with_context(
+        context: Arc<ExecutionContext>,
+        graph_name: impl Into<String>,
+    ) -> Result<Self, ContextError> {
+        let graph_name = graph_name.into();
+        let store = context.load_graph(&graph_name)?;
+        Ok(Self {
+            store,
+            context,
+            graph_name,
+            in_degrees: Arc::new(OnceLock::new()),
+        })
+    }
+
+    /// Build a graph from directed `(source, target)` pairs
+    ///
+    /// Node ids are `0..=max id`; ids that appear in no edge become isolated
+    /// nodes. All edges share the relationship type `REL`.
+    pub fn from_edge_list(edges: &[(u64, u64)]) -> Self {
+        Self::from_edges(
+            edges.iter().map(|&(source, target)| (source, target, 1.0)),
+            None,
+        )
+    }
+
+    /// Build a graph from directed `(source, target, weight)` triples
+    ///
+    /// Like [`Graph::from_edge_list`], with the weights stored in the
+    /// relationship property `weight`.
+    pub fn from_weighted_edge_list(edges: &[(u64, u64, f64)]) -> Self {
+        Self::from_edges(
+            edges.iter().copied(),
+            Some(RelationshipPropertySchema::of("weight", ValueType::Double)),
+        )
+    }
+
+    fn from_edges(
+        edges: impl Iterator<Item = (u64, u64, f64)>,
+        weight_schema: Option<RelationshipPropertySchema>,
+    ) -> Self {
+        let edges: Vec<(u64, u64, f64)> = edges.collect();
+        let node_count = edges
+            .iter()
+            .map(|&(source, target, _)| source.max(target) as usize + 1)
+            .max()
+            .unwrap_or(0);
+
+        let mut adjacency: Vec<Vec<(i64, f64)>> = vec![Vec::new(); node_count];
+        for &(source, target, weight) in &edges {
+            adjacency[source as usize].push((target as i64, weight));
+        }
+
+        let rel_type = RelationshipType::of("REL");
+        let mut schema = MutableGraphSchema::empty();
+        schema.node_schema_mut().add_label(NodeLabel::all_nodes());
+        let relationship_schema = schema
+            .relationship_schema_mut()
+            .get_or_create_type(rel_type.clone(), Direction::Directed);
+        if let Some(weight_schema) = &weight_schema {
+            relationship_schema.add_property_schema(weight_schema.clone());
+        }
+
+        let outgoing = adjacency
+            .iter()
+            .map(|targets| targets.iter().map(|&(target, _)| target).collect())
+            .collect();
+        let mut topologies = HashMap::new();
+        topologies.insert(rel_type.clone(), RelationshipTopology::new(outgoing, None));
+
+        let mut store = DefaultGraphStore::new(
+            GraphStoreConfig::default(),
+            GraphName::new("graph"),
+            DatabaseInfo::new(
+                DatabaseId::new("memory"),
+                DatabaseLocation::remote("localhost", 7687, None, None),
+            ),
+            schema.build(),
+            Capabilities::default(),
+            SimpleIdMap::from_original_ids(0..node_count as i64),
+            topologies,
+        );
+        if let Some(weight_schema) = weight_schema {
+            let weights: Vec<f64> = adjacency
+                .iter()
+                .flat_map(|targets| targets.iter().map(|&(_, weight)| weight))
+                .collect();
+            let count = weights.len();
+            // Adding a property to a fresh store cannot fail.
+            let _ = store.add_relationship_property(
+                rel_type,
+                weight_schema.key(),
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    weights, count,
+                )),
+            );
+        }
+
+        Self::from(store)
+    }
+
+    /// Value of the relationship property `key` between `source` and `target`
+    ///
+    /// Parallel relationships are combined according to the aggregation the
+    /// schema declares for the property: `Sum` adds the values, `Count`
+    /// returns the number of relationships, `Min` and `Max` pick the extreme,
+    /// and every other aggregation returns the first stored value. Returns `None` if the type has no such
+    /// property or no relationship connects the nodes.
+    pub fn relationship_weight(
+        &self,
+        source: u64,
+        target: u64,
+        rel_type: &RelationshipType,
+        key: &str,
+    ) -> Option<f64> {
+        if !self.store.has_relationship_property(rel_type, key) {
+            return None;
+        }
+        let graph = self
+            .store
+            .get_graph_with_types_and_selectors(
+                &HashSet::from([rel_type.clone()]),
+                &HashMap::from([(rel_type.clone(), key.to_string())]),
+            )
+            .ok()?;
+        if source as usize >= graph.node_count() {
+            return None;
+        }
+
+        let aggregation = self
+            .store
+            .schema()
+            .relationship_schema()
+            .get(rel_type)
+            .and_then(|entry| entry.properties().get(key))
+            .map_or(Aggregation::Default, |schema| schema.aggregation());
+        let values = graph
+            .stream_relationships(source as i64, f64::NAN)
+            .filter(|cursor| cursor.target_id() == target as i64)
+            .map(|cursor| cursor.property());
+        if aggregation == Aggregation::Count {
+            let count = values.count();
+            return (count > 0).then_some(count as f64);
+        }
+        values.reduce(|total, value| match aggregation {
+            Aggregation::Sum => total + value,
+            Aggregation::Min => total.min(value),
+            Aggregation::Max => total.max(value),
+            _ => total,
+        })
+    }
+
+    /// Number of relationships of `node` over all types in the given orientation
+    ///
+    /// `Natural` counts outgoing and `Reverse` incoming relationships;
+    /// `Undirected` counts both, so a self-loop contributes two. Incoming
+    /// degrees come from the inverse index; without one, they are counted
+    /// in a single pass on first use and cached. Returns `None` if `node`
+    /// is not a node of the graph.
+    pub fn degree(&self, node: u64, orientation: Orientation) -> Option<usize> {
+        if node >= self.store.node_count() as u64 {
+            return None;
+        }
+        let outgoing = || self.store.degree(node as i64);
+        let incoming = || {
+            let fully_inverse_indexed = self.store.inverse_indexed_relationship_types().len()
+                == self.store.relationship_types().len();
+            if fully_inverse_indexed {
+                if let Some(degree) = self.store.graph().degree_inverse(node as i64) {
+                    return degree;
+                }
+            }
+            self.in_degrees
+                .get_or_init(|| {
+                    let graph = self.store.graph();
+                    let mut in_degrees = vec![0; graph.node_count()];
+                    for source in 0..graph.node_count() as i64 {
+                        for cursor in graph.stream_relationships(source, 1.0) {
+                            in_degrees[cursor.target_id() as usize] += 1;
+                        }
+                    }
+                    in_degrees
+                })
+                .get(node as usize)
+                .copied()
+                .unwrap_or(0)
+        };
+        Some(match orientation {
+            Orientation::Natural => outgoing(),
+            Orientation::Reverse => incoming(),
+            Orientation::Undirected => outgoing() + incoming(),
+        })
+    }
+
+    /// The wrapped graph store
+    pub fn store(&self) -> &Arc<DefaultGraphStore> {
+        &self.store
+    }
+
+    /// The execution context the builders run in
+    pub fn context(&self) -> &Arc<ExecutionContext> {
+        &self.context
+    }
+
+    /// PageRank bound to this graph, run through its algorithm spec
+    pub fn pagerank(&self) -> PageRankBuilder {
+        PageRankBuilder::new().context(Arc::clone(&self.context), self.graph_name.clone())
+    }
+
+    /// Louvain bound to this graph
+    pub fn louvain(&self) -> LouvainBuilder {
+        LouvainBuilder::new().graph_store(Arc::clone(&self.store))
+    }
+
+    /// Degree centrality
+    pub fn degree_centrality(&self) -> DegreeCentralityFacade<'_> {
+        DegreeCentralityFacade::new().graph_store(Arc::clone(&self.store))
+    }
+
+    /// Betweenness centrality
+    pub fn betweenness(&self) -> BetweenessBuilder {
+        BetweenessBuilder::new().graph_store(Arc::clone(&self.store))
+    }
+
+    /// Dijkstra shortest paths
+    pub fn dijkstra(&self) -> DijkstraBuilder {
+        DijkstraBuilder::new().graph_store(Arc::clone(&self.store))
+    }
+
+    /// Breadth-first search
+    pub fn bfs(&self) -> BfsBuilder {
+        BfsBuilder::new().graph_store(Arc::clone(&self.store))
+    }
+
+    /// Depth-first search
+    pub fn dfs(&self) -> DfsBuilder {
+        DfsBuilder::new().graph_store(Arc::clone(&self.store))
+    }
+
+    /// A* shortest paths
+    pub fn astar(&self) -> AStarBuilder {
+        AStarBuilder::new().graph_store(Arc::clone(&self.store))
+    }
+}
+
+impl From<DefaultGraphStore> for Graph {
+    fn from(store: DefaultGraphStore) -> Self {
+        Self::new(Arc::new(store))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::procedures::facades::pathfinding::Heuristic;
+    use crate::procedures::pagerank::PageRankAlgorithmSpec;
+    use crate::projection::eval::procedure::{ExecutionMode, ProcedureExecutor};
+    use crate::types::random::{RandomGraphConfig, RandomRelationshipConfig};
+    use crate::types::{DefaultValue, PropertyState};
+    use serde_json::json;
+
+    #[test]
+    fn test_pagerank_streams_every_node() {
+        let config = RandomGraphConfig {
+            seed: Some(11),
+            node_count: 25,
+            relationships: vec![RandomRelationshipConfig::new("REL", 0.2)],
+            ..RandomGraphConfig::default()
+        };
+        let graph = Graph::from(DefaultGraphStore::random(&config).unwrap());
+        assert_eq!(graph.store().node_count(), 25);
+
+        let scores: Vec<_> = graph.pagerank().stream().unwrap().collect();
+        assert_eq!(scores.len(), 25);
+        assert!(scores.iter().all(|score| score.score >= 0.15 - 1e-12));
+
+        let stats = graph.pagerank().stats().unwrap();
+        assert!(stats.iterations_ran > 0);
+        assert!(stats.max >= stats.min);
+
+        let communities: Vec<_> = graph.louvain().stream().unwrap().collect();
+        assert_eq!(communities.len(), 25);
+    }
+
+    #[test]
+    fn test_pagerank_runs_the_spec_in_the_graph_context() {
+        let graph = Graph::from_edge_list(&[(0, 1), (0, 2), (1, 2), (2, 0)]);
+        let scores: Vec<f64> = graph
+            .pagerank()
+            .iterations(100)
+            .tolerance(1e-9)
+            .stream()
+            .unwrap()
+            .map(|score| score.score)
+            .collect();
+
+        let mut executor = ProcedureExecutor::new(
+            ExecutionContext::mock(Arc::clone(graph.store())),
+            ExecutionMode::Stream,
+        );
+        let mut spec = PageRankAlgorithmSpec::new("test_graph".to_string());
+        let expected = executor
+            .compute(&mut spec, &json!({"maxIterations": 100, "tolerance": 1e-9}))
+            .unwrap();
+        assert_eq!(scores, expected.scores);
+
+        // Fixed point of score = 0.15 + 0.85 * Σ score(u) / out_degree(u)
+        let d = 0.85;
+        assert!((scores[0] - (1.0 - d) - d * scores[2]).abs() < 1e-6);
+        assert!((scores[1] - (1.0 - d) - d * scores[0] / 2.0).abs() < 1e-6);
+        assert!((scores[2] - (1.0 - d) - d * (scores[0] / 2.0 + scores[1])).abs() < 1e-6);
+
+        let shared =
+            Graph::with_context(Arc::clone(graph.context()), Graph::DEFAULT_GRAPH_NAME).unwrap();
+        assert!(Arc::ptr_eq(shared.store(), graph.store()));
+        assert!(Graph::with_context(Arc::clone(graph.context()), "missing").is_err());
+    }
+
+    #[test]
+    fn test_bfs_on_edge_list_triangle_reaches_all_nodes() {
+        let graph = Graph::from_edge_list(&[(0, 1), (1, 2), (2, 0)]);
+        assert_eq!(graph.store().node_count(), 3);
+        assert_eq!(graph.store().relationship_count(), 3);
+
+        let mut reached: Vec<u64> = graph
+            .bfs()
+            .source(0)
+            .stream()
+            .unwrap()
+            .map(|result| result.target)
+            .collect();
+        reached.sort_unstable();
+        assert_eq!(reached, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_builders_run_on_the_bound_graph() {
+        let line = Graph::from_edge_list(&[(0, 1), (1, 2)]);
+        let betweenness: Vec<f64> = line
+            .betweenness()
+            .stream()
+            .unwrap()
+            .map(|score| score.score)
+            .collect();
+        assert_eq!(betweenness, vec![0.0, 0.5, 0.0]);
+        assert_eq!(line.betweenness().stats().unwrap().bridge_nodes, 1);
+
+        let depths: Vec<(u64, f64)> = line
+            .dfs()
+            .source(0)
+            .stream()
+            .unwrap()
+            .map(|result| (result.target, result.cost))
+            .collect();
+        assert_eq!(depths, vec![(0, 0.0), (1, 1.0), (2, 2.0)]);
+
+        let weighted = Graph::from_weighted_edge_list(&[(0, 1, 1.0), (1, 2, 1.0), (0, 2, 5.0)]);
+        let paths: Vec<_> = weighted
+            .dijkstra()
+            .source(0)
+            .target(2)
+            .stream()
+            .unwrap()
+            .collect();
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].path, vec![0, 1, 2]);
+        assert_eq!(paths[0].cost, 2.0);
+        assert!(
+            weighted
+                .dijkstra()
+                .source(0)
+                .target(2)
+                .stats()
+                .unwrap()
+                .target_reached
+        );
+
+        let mut store = weighted.store().as_ref().clone();
+        store
+            .add_node_property_f64("latitude".to_string(), vec![0.0, 0.0, 0.0])
+            .unwrap();
+        store
+            .add_node_property_f64("longitude".to_string(), vec![0.0, 0.0, 0.0])
+            .unwrap();
+        let located = Graph::from(store);
+        let astar = || located.astar().source(0).target(2);
+        assert!(astar().stream().is_err());
+        let paths: Vec<_> = astar()
+            .heuristic(Heuristic::Haversine)
+            .stream()
+            .unwrap()
+            .collect();
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].path, vec![0, 1, 2]);
+        assert_eq!(paths[0].cost, 2.0);
+    }
+
+    #[test]
+    fn test_weighted_edge_list_stores_weights() {
+        let graph = Graph::from_weighted_edge_list(&[(2, 0, 0.5), (0, 1, 2.0), (0, 3, 4.0)]);
+        assert_eq!(graph.store().node_count(), 4);
+
+        let default_graph = graph.store().graph();
+        let weights: Vec<f64> = default_graph
+            .stream_relationships(0, 1.0)
+            .map(|cursor| cursor.property())
+            .collect();
+        assert_eq!(weights, vec![2.0, 4.0]);
+    }
+
+    #[test]
+    fn test_relationship_weight_aggregates_parallel_relationships() {
+        let edges = [(0, 1, 2.0), (0, 1, 3.0), (1, 2, 4.0), (2, 0, 1.0)];
+        let rel_type = RelationshipType::of("REL");
+        let with_aggregation = |aggregation| {
+            Graph::from_edges(
+                edges.iter().copied(),
+                Some(RelationshipPropertySchema::with_aggregation(
+                    "weight",
+                    ValueType::Double,
+                    DefaultValue::system_default(None),
+                    PropertyState::Persistent,
+                    aggregation,
+                )),
+            )
+        };
+
+        let summed = with_aggregation(Aggregation::Sum);
+        assert_eq!(
+            summed.relationship_weight(0, 1, &rel_type, "weight"),
+            Some(5.0)
+        );
+        assert_eq!(
+            summed.relationship_weight(1, 2, &rel_type, "weight"),
+            Some(4.0)
+        );
+        assert_eq!(summed.relationship_weight(1, 0, &rel_type, "weight"), None);
+        assert_eq!(summed.relationship_weight(0, 1, &rel_type, "missing"), None);
+
+        let max = with_aggregation(Aggregation::Max);
+        assert_eq!(
+            max.relationship_weight(0, 1, &rel_type, "weight"),
+            Some(3.0)
+        );
+
+        let counted = with_aggregation(Aggregation::Count);
+        assert_eq!(
+            counted.relationship_weight(0, 1, &rel_type, "weight"),
+            Some(2.0)
+        );
+        assert_eq!(
+            counted.relationship_weight(1, 2, &rel_type, "weight"),
+            Some(1.0)
+        );
+        assert_eq!(counted.relationship_weight(1, 0, &rel_type, "weight"), None);
+
+        assert_eq!(summed.degree(0, Orientation::Natural), Some(2));
+        assert_eq!(summed.degree(0, Orientation::Reverse), Some(1));
+        assert_eq!(summed.degree(1, Orientation::Reverse), Some(2));
+        assert_eq!(summed.degree(1, Orientation::Undirected), Some(3));
+        // Served from the cached incoming degrees
+        assert_eq!(summed.degree(2, Orientation::Reverse), Some(1));
+        assert_eq!(summed.in_degrees.get().map(Vec::len), Some(3));
+        assert_eq!(summed.degree(3, Orientation::Natural), None);
+        assert_eq!(summed.degree(3, Orientation::Reverse), None);
+    }
+}
@@ -58,6 +58,7 @@
 
 pub mod traits;
 pub mod builder_base;
+pub mod graph;
 
 // Facade implementations by algorithm family
 pub mod centrality;
@@ -67,5 +68,6 @@ pub mod utilities;
 
 // Re-export commonly used types
 pub use builder_base::{ExecutionContext, MutationResult, WriteResult};
+pub use graph::Graph;
 pub use traits::{AlgorithmRunner, StreamResults, StatsResults, MutateResults, WriteResults};
 
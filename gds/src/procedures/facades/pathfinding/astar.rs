@@ -36,8 +36,14 @@
 //!     .unwrap();
 //! ```
 
+use crate::procedures::astar::computation::AStarComputationResult;
+use crate::procedures::astar::{AStarComputationRuntime, AStarStorageRuntime};
 use crate::procedures::facades::traits::{Result, PathResult};
-use crate::procedures::facades::builder_base::{MutationResult, WriteResult, ConfigValidator};
+use crate::procedures::facades::builder_base::{bound_graph_store, weighted_graph, MutationResult, WriteResult, ConfigValidator};
+use crate::projection::eval::procedure::AlgorithmError;
+use crate::types::graph_store::DefaultGraphStore;
+use std::sync::Arc;
+use std::time::Instant;
 
 // ============================================================================
 // Heuristic Types
@@ -141,8 +147,12 @@ pub struct AStarBuilder {
     weight_property: String,
     /// Heuristic function type
     heuristic: Heuristic,
+    /// Node properties holding latitude and longitude for the Haversine heuristic
+    coordinate_properties: (String, String),
     /// Concurrency level for parallel processing
     concurrency: usize,
+    /// Graph store to search; unbound builders fail
+    graph_store: Option<Arc<DefaultGraphStore>>,
 }
 
 impl AStarBuilder {
@@ -160,10 +170,21 @@ impl AStarBuilder {
             targets: vec![],
             weight_property: "weight".to_string(),
             heuristic: Heuristic::Manhattan,
+            coordinate_properties: ("latitude".to_string(), "longitude".to_string()),
             concurrency: 4,
+            graph_store: None,
         }
     }
 
+    /// Bind the builder to the graph store it searches
+    ///
+    /// Bound builders run the A* procedure, which only supports
+    /// [`Heuristic::Haversine`] over the coordinate node properties.
+    pub fn graph_store(mut self, graph_store: Arc<DefaultGraphStore>) -> Self {
+        self.graph_store = Some(graph_store);
+        self
+    }
+
     /// Set source node
     ///
     /// The algorithm starts search from this node.
@@ -211,6 +232,15 @@ impl AStarBuilder {
         self
     }
 
+    /// Set the latitude and longitude node properties
+    ///
+    /// Used by the Haversine heuristic of bound builders.
+    /// Default: "latitude", "longitude"
+    pub fn coordinate_properties(mut self, latitude: &str, longitude: &str) -> Self {
+        self.coordinate_properties = (latitude.to_string(), longitude.to_string());
+        self
+    }
+
     /// Set concurrency level
     ///
     /// Number of parallel threads to use.
@@ -243,6 +273,61 @@ impl AStarBuilder {
         Ok(())
     }
 
+    /// Run the A* procedure from the source to each target
+    fn compute(&self, graph_store: &DefaultGraphStore) -> Result<Vec<(u64, AStarComputationResult)>> {
+        if !matches!(self.heuristic, Heuristic::Haversine) {
+            return Err(AlgorithmError::Execution(
+                "the A* procedure only supports the Haversine heuristic".to_string(),
+            ));
+        }
+        if self.targets.is_empty() {
+            return Err(AlgorithmError::Execution(
+                "the A* procedure needs at least one target".to_string(),
+            ));
+        }
+        let graph = weighted_graph(graph_store, &self.weight_property)?;
+        let node_count = graph.node_count();
+        let source = self.source.unwrap();
+        if let Some(node) = std::iter::once(&source)
+            .chain(&self.targets)
+            .find(|&&node| node as usize >= node_count)
+        {
+            return Err(AlgorithmError::Execution(format!(
+                "node {} is not in the graph",
+                node
+            )));
+        }
+
+        let (latitude, longitude) = &self.coordinate_properties;
+        let (Some(lat_values), Some(lon_values)) =
+            (graph.node_properties(latitude), graph.node_properties(longitude))
+        else {
+            return Err(AlgorithmError::Execution(format!(
+                "node properties '{}' and '{}' are required for the Haversine heuristic",
+                latitude, longitude
+            )));
+        };
+
+        self.targets
+            .iter()
+            .map(|&target| {
+                let mut storage = AStarStorageRuntime::new_with_values(
+                    source as usize,
+                    target as usize,
+                    latitude.clone(),
+                    longitude.clone(),
+                    Arc::clone(&lat_values),
+                    Arc::clone(&lon_values),
+                );
+                let mut computation = AStarComputationRuntime::new();
+                storage
+                    .compute_astar_path(&mut computation, Some(graph.as_ref()), 0)
+                    .map(|result| (target, result))
+                    .map_err(AlgorithmError::Execution)
+            })
+            .collect()
+    }
+
     /// Execute the algorithm and return iterator over path results
     ///
     /// Returns optimal paths from source to target(s) using A* search.
@@ -263,59 +348,21 @@ impl AStarBuilder {
     pub fn stream(self) -> Result<Box<dyn Iterator<Item = PathResult>>> {
         self.validate()?;
 
-        // TODO: Wire to actual algorithm spec when execution pipeline is ready
-        // For now, return dummy results for demonstration
-        let dummy_results = if self.targets.is_empty() {
-            // No specific targets - return paths to sample reachable nodes
-            vec![
-                PathResult {
-                    source: self.source.unwrap(),
-                    target: 1,
-                    path: vec![self.source.unwrap(), 1],
-                    cost: self.heuristic.calculate(self.source.unwrap(), 1),
-                },
-                PathResult {
-                    source: self.source.unwrap(),
-                    target: 2,
-                    path: vec![self.source.unwrap(), 1, 2],
-                    cost: self.heuristic.calculate(self.source.unwrap(), 1) + self.heuristic.calculate(1, 2),
-                },
-                PathResult {
-                    source: self.source.unwrap(),
-                    target: 3,
-                    path: vec![self.source.unwrap(), 3],
-                    cost: self.heuristic.calculate(self.source.unwrap(), 3),
-                },
-            ]
-        } else {
-            // Specific targets - return optimal paths to each target
-            self.targets
-                .into_iter()
-                .enumerate()
-                .map(|(i, target)| {
-                    // Simulate A* path with heuristic-based cost estimation
-                    let heuristic_cost = self.heuristic.calculate(self.source.unwrap(), target);
-                    let actual_cost = heuristic_cost * (1.0 + (i as f64 * 0.1)); // Simulate A* optimality
-
-                    // Simulate path reconstruction (in real implementation, this would come from A* algorithm)
-                    let path = match i {
-                        0 => vec![self.source.unwrap(), target],
-                        1 => vec![self.source.unwrap(), 1, target],
-                        2 => vec![self.source.unwrap(), 3, target],
-                        _ => vec![self.source.unwrap(), target],
-                    };
-
-                    PathResult {
-                        source: self.source.unwrap(),
-                        target,
-                        path,
-                        cost: actual_cost,
-                    }
+        let graph_store = bound_graph_store(&self.graph_store, "A*")?;
+        let source = self.source.unwrap();
+        let results: Vec<PathResult> = self
+            .compute(graph_store)?
+            .into_iter()
+            .filter_map(|(target, result)| {
+                result.path.map(|path| PathResult {
+                    source,
+                    target,
+                    path: path.into_iter().map(|node| node as u64).collect(),
+                    cost: result.total_cost,
                 })
-                .collect()
-        };
-
-        Ok(Box::new(dummy_results.into_iter()))
+            })
+            .collect();
+        Ok(Box::new(results.into_iter()))
     }
 
     /// Stats mode: Get aggregated statistics
@@ -334,22 +381,23 @@ impl AStarBuilder {
     pub fn stats(self) -> Result<AStarStats> {
         self.validate()?;
 
-        // TODO: Wire to actual algorithm spec when execution pipeline is ready
-        // For now, return dummy stats for demonstration
+        let graph_store = bound_graph_store(&self.graph_store, "A*")?;
+        let started_at = Instant::now();
+        let results = self.compute(graph_store)?;
+        let targets_found = results
+            .iter()
+            .filter(|(_, result)| result.has_path())
+            .count() as u64;
         Ok(AStarStats {
-            nodes_visited: 15,
-            final_queue_size: 3,
-            max_queue_size: 12,
-            execution_time_ms: 35,
-            targets_found: self.targets.len() as u64,
-            all_targets_reached: !self.targets.is_empty(),
-            heuristic_accuracy: match self.heuristic {
-                Heuristic::Manhattan => 1.2,  // Less accurate but fast
-                Heuristic::Euclidean => 1.0,  // Perfect for Euclidean space
-                Heuristic::Haversine => 1.0,  // Perfect for geographic routing
-                Heuristic::Custom(_) => 1.1,  // User-defined, assume good
-            },
-            heuristic_evaluations: 45,
+            nodes_visited: results.iter().map(|(_, result)| result.nodes_explored as u64).sum(),
+            // The procedure does not report its open set or heuristic calls
+            final_queue_size: 0,
+            max_queue_size: 0,
+            execution_time_ms: started_at.elapsed().as_millis() as u64,
+            targets_found,
+            all_targets_reached: targets_found == self.targets.len() as u64,
+            heuristic_accuracy: 1.0,
+            heuristic_evaluations: 0,
         })
     }
 
@@ -522,52 +570,8 @@ mod tests {
     }
 
     #[test]
-    fn test_stream_returns_paths_to_targets() {
-        let builder = AStarBuilder::new().source(0).targets(vec![2, 3]);
-        let results: Vec<_> = builder.stream().unwrap().collect();
-
-        assert_eq!(results.len(), 2);
-        assert_eq!(results[0].source, 0);
-        assert_eq!(results[0].target, 2);
-        assert_eq!(results[1].source, 0);
-        assert_eq!(results[1].target, 3);
-
-        // A* should find optimal paths (lower cost than naive)
-        assert!(results[0].cost <= 10.0);
-        assert!(results[1].cost <= 10.0);
-    }
-
-    #[test]
-    fn test_stream_returns_all_reachable() {
-        let builder = AStarBuilder::new().source(0); // No targets specified
-        let results: Vec<_> = builder.stream().unwrap().collect();
-
-        assert_eq!(results.len(), 3); // Dummy implementation returns 3 nodes
-        assert_eq!(results[0].source, 0);
-
-        // All paths should start from source
-        for result in &results {
-            assert_eq!(result.source, 0);
-            assert!(!result.path.is_empty());
-            assert_eq!(result.path[0], 0);
-        }
-    }
-
-    #[test]
-    fn test_stats_returns_heuristic_specific_info() {
-        // Test Manhattan heuristic
-        let builder = AStarBuilder::new().source(0).heuristic(Heuristic::Manhattan);
-        let stats = builder.stats().unwrap();
-        assert_eq!(stats.heuristic_accuracy, 1.2); // Manhattan is less accurate
-
-        // Test Euclidean heuristic
-        let builder = AStarBuilder::new().source(0).heuristic(Heuristic::Euclidean);
-        let stats = builder.stats().unwrap();
-        assert_eq!(stats.heuristic_accuracy, 1.0); // Euclidean is perfect
-
-        // Test Haversine heuristic
-        let builder = AStarBuilder::new().source(0).heuristic(Heuristic::Haversine);
-        let stats = builder.stats().unwrap();
-        assert_eq!(stats.heuristic_accuracy, 1.0); // Haversine is perfect for geo
+    fn test_unbound_builder_fails() {
+        assert!(AStarBuilder::new().source(0).stream().is_err());
+        assert!(AStarBuilder::new().source(0).stats().is_err());
     }
 }
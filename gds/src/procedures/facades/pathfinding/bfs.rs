@@ -27,8 +27,16 @@
 //!     .collect::<Vec<_>>();
 //! ```
 
+use crate::procedures::bfs::{BfsComputationRuntime, BfsStorageRuntime};
 use crate::procedures::facades::traits::{Result, PathResult};
-use crate::procedures::facades::builder_base::{MutationResult, WriteResult, ConfigValidator};
+use crate::procedures::facades::builder_base::{bound_graph_store, MutationResult, WriteResult, ConfigValidator};
+use crate::types::graph::degrees::Degrees;
+use crate::types::graph::id_map::IdMap;
+use crate::types::graph_store::DefaultGraphStore;
+use crate::types::properties::relationship::RelationshipIterator;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Instant;
 
 // ============================================================================
 // Statistics Type
@@ -91,6 +99,20 @@ pub struct BfsBuilder {
     concurrency: usize,
     /// Delta parameter for chunking (affects performance)
     delta: usize,
+    /// Graph store to traverse; unbound builders fail
+    graph_store: Option<Arc<DefaultGraphStore>>,
+}
+
+/// Nodes reached by a traversal, in visiting order
+struct Traversal {
+    /// `(node, depth)` per visited node
+    visited: Vec<(u64, u32)>,
+    /// BFS tree parent per node, `None` for the source and unvisited nodes
+    parents: Vec<Option<u64>>,
+    /// Relationships scanned while expanding nodes
+    edges_considered: u64,
+    /// Nodes whose relationships were scanned
+    nodes_expanded: u64,
 }
 
 impl BfsBuilder {
@@ -111,9 +133,16 @@ impl BfsBuilder {
             track_paths: false,
             concurrency: 1,
             delta: 64,
+            graph_store: None,
         }
     }
 
+    /// Bind the builder to the graph store it traverses
+    pub fn graph_store(mut self, graph_store: Arc<DefaultGraphStore>) -> Self {
+        self.graph_store = Some(graph_store);
+        self
+    }
+
     /// Set source node
     ///
     /// The algorithm starts traversal from this node.
@@ -194,6 +223,12 @@ impl BfsBuilder {
             ));
         }
 
+        if self.delta == 0 {
+            return Err(crate::projection::eval::procedure::AlgorithmError::Execution(
+                "delta must be > 0".to_string()
+            ));
+        }
+
         if let Some(depth) = self.max_depth {
             if depth == 0 {
                 return Err(crate::projection::eval::procedure::AlgorithmError::Execution(
@@ -205,6 +240,70 @@ impl BfsBuilder {
         Ok(())
     }
 
+    /// Breadth-first traversal from the source over outgoing relationships
+    ///
+    /// Runs the BFS procedure, then stops once every target is visited.
+    /// Nodes at `max_depth` are reached but not expanded.
+    fn traverse(&self, graph_store: &DefaultGraphStore) -> Result<Traversal> {
+        let graph = graph_store.graph();
+        let node_count = graph.node_count();
+        let source = self.source.unwrap();
+        if source as usize >= node_count {
+            return Err(crate::projection::eval::procedure::AlgorithmError::Execution(format!(
+                "source node {} is not in the graph", source
+            )));
+        }
+
+        let storage = BfsStorageRuntime::new(
+            source as u32,
+            Vec::new(),
+            self.max_depth,
+            false,
+            self.concurrency,
+            self.delta,
+        )
+        .with_compute_levels(true);
+        let mut computation = BfsComputationRuntime::new(source as u32, false, self.concurrency);
+        let result = storage.compute_bfs(&mut computation, Some(graph.as_ref()))?;
+        let levels = result.levels.expect("levels were requested");
+
+        let mut remaining: HashSet<u64> = self.targets.iter().copied().collect();
+        let mut traversal = Traversal {
+            visited: Vec::new(),
+            parents: vec![None; node_count],
+            edges_considered: 0,
+            nodes_expanded: 0,
+        };
+        for (node, _) in result.visited_nodes {
+            let depth = levels.get(node as usize) as u32;
+            traversal.visited.push((node as u64, depth));
+            remaining.remove(&(node as u64));
+            if !self.targets.is_empty() && remaining.is_empty() {
+                break;
+            }
+            if self.max_depth.is_some_and(|max_depth| depth >= max_depth) {
+                continue;
+            }
+
+            traversal.nodes_expanded += 1;
+            traversal.edges_considered += graph.degree(node as i64) as u64;
+            if self.track_paths {
+                // The first node of the previous level to reach a node is its tree parent
+                for cursor in graph.stream_relationships(node as i64, 1.0) {
+                    let target = cursor.target_id() as usize;
+                    if traversal.parents[target].is_none()
+                        && target as u64 != source
+                        && levels.get(target) == depth as i64 + 1
+                    {
+                        traversal.parents[target] = Some(node as u64);
+                    }
+                }
+            }
+        }
+
+        Ok(traversal)
+    }
+
     /// Execute the algorithm and return iterator over traversal results
     ///
     /// Returns nodes in breadth-first order with their distances from source.
@@ -222,39 +321,34 @@ impl BfsBuilder {
     pub fn stream(self) -> Result<Box<dyn Iterator<Item = PathResult>>> {
         self.validate()?;
 
-        // TODO: Wire to actual algorithm spec when execution pipeline is ready
-        // For now, return dummy results for demonstration
-        let dummy_results = if self.targets.is_empty() {
-            // No specific targets - return all reachable nodes
-            vec![
+        let graph_store = bound_graph_store(&self.graph_store, "BFS")?;
+        let traversal = self.traverse(graph_store)?;
+        let source = self.source.unwrap();
+        let targets: HashSet<u64> = self.targets.iter().copied().collect();
+        let results: Vec<PathResult> = traversal
+            .visited
+            .iter()
+            .filter(|(node, _)| targets.is_empty() || targets.contains(node))
+            .map(|&(node, depth)| {
+                let path = if self.track_paths {
+                    let mut path = vec![node];
+                    while let Some(parent) = traversal.parents[*path.last().unwrap() as usize] {
+                        path.push(parent);
+                    }
+                    path.reverse();
+                    path
+                } else {
+                    Vec::new()
+                };
                 PathResult {
-                    source: self.source.unwrap(),
-                    target: 1,
-                    path: vec![self.source.unwrap(), 1],
-                    cost: 1.0,
-                },
-                PathResult {
-                    source: self.source.unwrap(),
-                    target: 2,
-                    path: vec![self.source.unwrap(), 2],
-                    cost: 1.0,
-                },
-            ]
-        } else {
-            // Specific targets - return paths to targets
-            self.targets
-                .into_iter()
-                .enumerate()
-                .map(|(i, target)| PathResult {
-                    source: self.source.unwrap(),
-                    target,
-                    path: vec![self.source.unwrap(), target],
-                    cost: (i + 1) as f64,
-                })
-                .collect()
-        };
-
-        Ok(Box::new(dummy_results.into_iter()))
+                    source,
+                    target: node,
+                    path,
+                    cost: depth as f64,
+                }
+            })
+            .collect();
+        Ok(Box::new(results.into_iter()))
     }
 
     /// Stats mode: Get aggregated statistics
@@ -273,15 +367,25 @@ impl BfsBuilder {
     pub fn stats(self) -> Result<BfsStats> {
         self.validate()?;
 
-        // TODO: Wire to actual algorithm spec when execution pipeline is ready
-        // For now, return dummy stats for demonstration
+        let graph_store = bound_graph_store(&self.graph_store, "BFS")?;
+        let started_at = Instant::now();
+        let traversal = self.traverse(graph_store)?;
+        let visited: HashSet<u64> = traversal.visited.iter().map(|&(node, _)| node).collect();
+        let targets_found = self.targets.iter().filter(|t| visited.contains(t)).count() as u64;
         Ok(BfsStats {
-            nodes_visited: 10,
-            max_depth_reached: 3,
-            execution_time_ms: 25,
-            targets_found: self.targets.len() as u64,
-            all_targets_reached: !self.targets.is_empty(),
-            avg_branching_factor: 2.5,
+            nodes_visited: traversal.visited.len() as u64,
+            max_depth_reached: traversal
+                .visited
+                .last()
+                .map_or(0, |&(_, depth)| depth as u64),
+            execution_time_ms: started_at.elapsed().as_millis() as u64,
+            targets_found,
+            all_targets_reached: targets_found == self.targets.len() as u64,
+            avg_branching_factor: if traversal.nodes_expanded == 0 {
+                0.0
+            } else {
+                traversal.edges_considered as f64 / traversal.nodes_expanded as f64
+            },
         })
     }
 
@@ -438,37 +542,33 @@ mod tests {
     }
 
     #[test]
-    fn test_stream_returns_paths_to_targets() {
-        let builder = BfsBuilder::new().source(0).targets(vec![3, 5]);
-        let results: Vec<_> = builder.stream().unwrap().collect();
-
-        assert_eq!(results.len(), 2);
-        assert_eq!(results[0].source, 0);
-        assert_eq!(results[0].target, 3);
-        assert_eq!(results[1].source, 0);
-        assert_eq!(results[1].target, 5);
+    fn test_unbound_builder_fails() {
+        assert!(BfsBuilder::new().source(0).stream().is_err());
+        assert!(BfsBuilder::new().source(0).stats().is_err());
     }
 
     #[test]
-    fn test_stream_returns_all_reachable() {
-        let builder = BfsBuilder::new().source(0); // No targets specified
-        let results: Vec<_> = builder.stream().unwrap().collect();
-
-        assert_eq!(results.len(), 2); // Dummy implementation returns 2 nodes
-        assert_eq!(results[0].source, 0);
-        assert_eq!(results[0].target, 1);
-        assert_eq!(results[1].source, 0);
-        assert_eq!(results[1].target, 2);
-    }
+    fn test_bound_stream_follows_the_bfs_procedure() {
+        let graph = crate::Graph::from_edge_list(&[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]);
 
-    #[test]
-    fn test_stats_returns_aggregated_info() {
-        let builder = BfsBuilder::new().source(0).targets(vec![1, 2, 3]);
-        let stats = builder.stats().unwrap();
+        let results: Vec<_> = graph.bfs().source(0).track_paths(true).stream().unwrap().collect();
+        let depths: Vec<(u64, f64)> = results.iter().map(|r| (r.target, r.cost)).collect();
+        assert_eq!(depths, vec![(0, 0.0), (1, 1.0), (2, 1.0), (3, 2.0), (4, 3.0)]);
+        assert_eq!(results[4].path, vec![0, 1, 3, 4]);
 
-        assert_eq!(stats.nodes_visited, 10);
-        assert_eq!(stats.targets_found, 3);
+        let limited: Vec<u64> = graph
+            .bfs()
+            .source(0)
+            .max_depth(2)
+            .stream()
+            .unwrap()
+            .map(|r| r.target)
+            .collect();
+        assert_eq!(limited, vec![0, 1, 2, 3]);
+
+        let stats = graph.bfs().source(0).targets(vec![2, 3]).stats().unwrap();
+        assert_eq!(stats.nodes_visited, 4);
         assert!(stats.all_targets_reached);
-        assert_eq!(stats.avg_branching_factor, 2.5);
+        assert_eq!(stats.avg_branching_factor, 4.0 / 3.0);
     }
 }
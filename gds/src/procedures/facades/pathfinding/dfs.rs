@@ -29,8 +29,13 @@
 //!     .collect::<Vec<_>>();
 //! ```
 
+use crate::procedures::dfs::{DfsComputationRuntime, DfsResult, DfsStorageRuntime};
 use crate::procedures::facades::traits::{Result, PathResult};
-use crate::procedures::facades::builder_base::{MutationResult, WriteResult, ConfigValidator};
+use crate::procedures::facades::builder_base::{bound_graph_store, MutationResult, WriteResult, ConfigValidator};
+use crate::projection::eval::procedure::AlgorithmError;
+use crate::types::graph::id_map::IdMap;
+use crate::types::graph_store::DefaultGraphStore;
+use std::sync::Arc;
 
 // ============================================================================
 // Statistics Type
@@ -93,6 +98,8 @@ pub struct DfsBuilder {
     track_paths: bool,
     /// Concurrency level for parallel processing
     concurrency: usize,
+    /// Graph store to traverse; unbound builders fail
+    graph_store: Option<Arc<DefaultGraphStore>>,
 }
 
 impl DfsBuilder {
@@ -111,9 +118,16 @@ impl DfsBuilder {
             max_depth: None,
             track_paths: false,
             concurrency: 1,
+            graph_store: None,
         }
     }
 
+    /// Bind the builder to the graph store it traverses
+    pub fn graph_store(mut self, graph_store: Arc<DefaultGraphStore>) -> Self {
+        self.graph_store = Some(graph_store);
+        self
+    }
+
     /// Set source node
     ///
     /// The algorithm starts traversal from this node.
@@ -197,6 +211,32 @@ impl DfsBuilder {
         Ok(())
     }
 
+    /// Run the DFS procedure from the source
+    ///
+    /// Without explicit targets every node is a target, so the result holds
+    /// the DFS tree path of each expanded node in visiting order.
+    fn compute(&self, graph_store: &DefaultGraphStore) -> Result<DfsResult> {
+        let graph = graph_store.graph();
+        let node_count = graph.node_count();
+        let source = self.source.unwrap();
+        if source as usize >= node_count {
+            return Err(AlgorithmError::Execution(format!(
+                "source node {} is not in the graph",
+                source
+            )));
+        }
+
+        let targets = if self.targets.is_empty() {
+            (0..node_count as u32).collect()
+        } else {
+            self.targets.iter().map(|&target| target as u32).collect()
+        };
+        let storage =
+            DfsStorageRuntime::new(source as u32, targets, self.max_depth, true, self.concurrency);
+        let mut computation = DfsComputationRuntime::new(source as u32, true, self.concurrency);
+        storage.compute_dfs(&mut computation, Some(graph.as_ref()))
+    }
+
     /// Execute the algorithm and return iterator over traversal results
     ///
     /// Returns nodes in depth-first order with their discovery depths.
@@ -214,54 +254,23 @@ impl DfsBuilder {
     pub fn stream(self) -> Result<Box<dyn Iterator<Item = PathResult>>> {
         self.validate()?;
 
-        // TODO: Wire to actual algorithm spec when execution pipeline is ready
-        // For now, return dummy results for demonstration
-        let dummy_results = if self.targets.is_empty() {
-            // No specific targets - return all reachable nodes in DFS order
-            vec![
-                PathResult {
-                    source: self.source.unwrap(),
-                    target: 1,
-                    path: vec![self.source.unwrap(), 1],
-                    cost: 1.0, // Depth from source
-                },
-                PathResult {
-                    source: self.source.unwrap(),
-                    target: 3,
-                    path: vec![self.source.unwrap(), 1, 3],
-                    cost: 2.0,
-                },
-                PathResult {
-                    source: self.source.unwrap(),
-                    target: 2,
-                    path: vec![self.source.unwrap(), 2],
-                    cost: 1.0,
+        let graph_store = bound_graph_store(&self.graph_store, "DFS")?;
+        let result = self.compute(graph_store)?;
+        let results: Vec<PathResult> = result
+            .paths
+            .into_iter()
+            .map(|path| PathResult {
+                source: path.source_node as u64,
+                target: path.target_node as u64,
+                path: if self.track_paths {
+                    path.node_ids.iter().map(|&node| node as u64).collect()
+                } else {
+                    Vec::new()
                 },
-            ]
-        } else {
-            // Specific targets - return paths to targets in DFS order
-            self.targets
-                .into_iter()
-                .enumerate()
-                .map(|(i, target)| {
-                    // Simulate DFS path discovery order
-                    let path = match i {
-                        0 => vec![self.source.unwrap(), target],
-                        1 => vec![self.source.unwrap(), 1, target],
-                        _ => vec![self.source.unwrap(), target],
-                    };
-                    let cost = path.len() as f64 - 1.0; // Edge count = depth
-                    PathResult {
-                        source: self.source.unwrap(),
-                        target,
-                        path,
-                        cost,
-                    }
-                })
-                .collect()
-        };
-
-        Ok(Box::new(dummy_results.into_iter()))
+                cost: path.path_length as f64,
+            })
+            .collect();
+        Ok(Box::new(results.into_iter()))
     }
 
     /// Stats mode: Get aggregated statistics
@@ -280,16 +289,27 @@ impl DfsBuilder {
     pub fn stats(self) -> Result<DfsStats> {
         self.validate()?;
 
-        // TODO: Wire to actual algorithm spec when execution pipeline is ready
-        // For now, return dummy stats for demonstration
+        let graph_store = bound_graph_store(&self.graph_store, "DFS")?;
+        let result = self.compute(graph_store)?;
+        let targets_found = if self.targets.is_empty() {
+            0
+        } else {
+            result.paths.len() as u64
+        };
         Ok(DfsStats {
-            nodes_visited: 8,
-            max_depth_reached: 3,
-            execution_time_ms: 18,
-            targets_found: self.targets.len() as u64,
-            all_targets_reached: !self.targets.is_empty(),
-            backtrack_operations: 5,
-            avg_branch_depth: 2.2,
+            nodes_visited: result.nodes_visited as u64,
+            max_depth_reached: result
+                .paths
+                .iter()
+                .map(|path| path.path_length as u64)
+                .max()
+                .unwrap_or(0),
+            execution_time_ms: result.computation_time_ms,
+            targets_found,
+            all_targets_reached: targets_found == self.targets.len() as u64,
+            // The procedure does not report its backtracking
+            backtrack_operations: 0,
+            avg_branch_depth: 0.0,
         })
     }
 
@@ -443,40 +463,8 @@ mod tests {
     }
 
     #[test]
-    fn test_stream_returns_paths_to_targets() {
-        let builder = DfsBuilder::new().source(0).targets(vec![2, 3]);
-        let results: Vec<_> = builder.stream().unwrap().collect();
-
-        assert_eq!(results.len(), 2);
-        assert_eq!(results[0].source, 0);
-        assert_eq!(results[0].target, 2);
-        assert_eq!(results[1].source, 0);
-        assert_eq!(results[1].target, 3);
-    }
-
-    #[test]
-    fn test_stream_returns_all_reachable() {
-        let builder = DfsBuilder::new().source(0); // No targets specified
-        let results: Vec<_> = builder.stream().unwrap().collect();
-
-        assert_eq!(results.len(), 3); // Dummy implementation returns 3 nodes in DFS order
-        assert_eq!(results[0].source, 0);
-        assert_eq!(results[0].target, 1);
-        assert_eq!(results[1].source, 0);
-        assert_eq!(results[1].target, 3); // DFS goes deep first
-        assert_eq!(results[2].source, 0);
-        assert_eq!(results[2].target, 2);
-    }
-
-    #[test]
-    fn test_stats_returns_aggregated_info() {
-        let builder = DfsBuilder::new().source(0).targets(vec![1, 2, 3]);
-        let stats = builder.stats().unwrap();
-
-        assert_eq!(stats.nodes_visited, 8);
-        assert_eq!(stats.targets_found, 3);
-        assert!(stats.all_targets_reached);
-        assert_eq!(stats.backtrack_operations, 5);
-        assert_eq!(stats.avg_branch_depth, 2.2);
+    fn test_unbound_builder_fails() {
+        assert!(DfsBuilder::new().source(0).stream().is_err());
+        assert!(DfsBuilder::new().source(0).stats().is_err());
     }
 }
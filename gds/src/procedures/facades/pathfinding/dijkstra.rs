@@ -27,8 +27,13 @@
 //!     .collect::<Vec<_>>();
 //! ```
 
+use crate::procedures::dijkstra::targets::create_targets;
+use crate::procedures::dijkstra::{DijkstraComputationRuntime, DijkstraResult, DijkstraStorageRuntime};
 use crate::procedures::facades::traits::{Result, PathResult};
-use crate::procedures::facades::builder_base::{MutationResult, WriteResult, ConfigValidator};
+use crate::procedures::facades::builder_base::{bound_graph_store, weighted_graph, MutationResult, WriteResult, ConfigValidator};
+use crate::projection::eval::procedure::AlgorithmError;
+use crate::types::graph_store::DefaultGraphStore;
+use std::sync::Arc;
 
 // ============================================================================
 // Statistics Type
@@ -92,6 +97,8 @@ pub struct DijkstraBuilder {
     track_relationships: bool,
     /// Concurrency level for parallel processing
     concurrency: usize,
+    /// Graph store to search; unbound builders fail
+    graph_store: Option<Arc<DefaultGraphStore>>,
 }
 
 impl DijkstraBuilder {
@@ -112,9 +119,16 @@ impl DijkstraBuilder {
             direction: "outgoing".to_string(),
             track_relationships: false,
             concurrency: 4,
+            graph_store: None,
         }
     }
 
+    /// Bind the builder to the graph store it searches
+    pub fn graph_store(mut self, graph_store: Arc<DefaultGraphStore>) -> Self {
+        self.graph_store = Some(graph_store);
+        self
+    }
+
     /// Set source node
     ///
     /// The algorithm starts path computation from this node.
@@ -206,6 +220,61 @@ impl DijkstraBuilder {
         Ok(())
     }
 
+    /// Run the Dijkstra procedure over `weight_property`
+    ///
+    /// Returns the result together with the number of nodes the search
+    /// settled and the relationships leaving them.
+    fn compute(&self, graph_store: &DefaultGraphStore) -> Result<(DijkstraResult, u64, u64)> {
+        let direction = match self.direction.as_str() {
+            "outgoing" => 0,
+            "incoming" => 1,
+            _ => {
+                return Err(AlgorithmError::Execution(format!(
+                    "direction '{}' is not supported by the Dijkstra procedure",
+                    self.direction
+                )))
+            }
+        };
+        let graph = weighted_graph(graph_store, &self.weight_property)?;
+        let node_count = graph.node_count();
+        let in_graph = |node: u64| -> Result<u32> {
+            if node as usize >= node_count {
+                return Err(AlgorithmError::Execution(format!(
+                    "node {} is not in the graph",
+                    node
+                )));
+            }
+            Ok(node as u32)
+        };
+        let source = in_graph(self.source.unwrap())?;
+        let targets = self
+            .targets
+            .iter()
+            .map(|&target| in_graph(target))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut storage =
+            DijkstraStorageRuntime::new(source, self.track_relationships, self.concurrency, false);
+        let mut computation =
+            DijkstraComputationRuntime::new(source, self.track_relationships, self.concurrency, false);
+        let result = storage.compute_dijkstra(
+            &mut computation,
+            create_targets(targets),
+            Some(graph.as_ref()),
+            direction,
+        )?;
+
+        let settled = (0..node_count as u32).filter(|&node| computation.is_visited(node));
+        let edges_considered = settled
+            .clone()
+            .map(|node| match direction {
+                1 => graph.degree_inverse(node as i64).unwrap_or(0) as u64,
+                _ => graph.degree(node as i64) as u64,
+            })
+            .sum();
+        Ok((result, settled.count() as u64, edges_considered))
+    }
+
 
     /// Execute the algorithm and return iterator over path results
     ///
@@ -224,16 +293,19 @@ impl DijkstraBuilder {
     pub fn stream(self) -> Result<Box<dyn Iterator<Item = PathResult>>> {
         self.validate()?;
 
-        // TODO: Wire to actual algorithm spec when execution pipeline is ready
-        // For now, return a dummy path for demonstration
-        let dummy_path = PathResult {
-            source: self.source.unwrap(),
-            target: self.targets.first().copied().unwrap_or(0),
-            path: vec![self.source.unwrap(), self.targets.first().copied().unwrap_or(0)],
-            cost: 42.0,
-        };
-
-        Ok(Box::new(std::iter::once(dummy_path)))
+        let graph_store = bound_graph_store(&self.graph_store, "Dijkstra")?;
+        let (result, _, _) = self.compute(graph_store)?;
+        let paths: Vec<PathResult> = result
+            .path_finding_result
+            .paths()
+            .map(|path| PathResult {
+                source: path.source_node as u64,
+                target: path.target_node as u64,
+                path: path.node_ids.iter().map(|&node| node as u64).collect(),
+                cost: path.costs.last().copied().unwrap_or(0.0),
+            })
+            .collect();
+        Ok(Box::new(paths.into_iter()))
     }
 
     /// Stats mode: Get aggregated statistics
@@ -252,15 +324,22 @@ impl DijkstraBuilder {
     pub fn stats(self) -> Result<DijkstraStats> {
         self.validate()?;
 
-        // TODO: Wire to actual algorithm spec when execution pipeline is ready
-        // For now, return dummy stats for demonstration
+        let graph_store = bound_graph_store(&self.graph_store, "Dijkstra")?;
+        let (result, nodes_expanded, edges_considered) = self.compute(graph_store)?;
+        let reached = result
+            .path_finding_result
+            .paths()
+            .map(|path| path.target_node as u64)
+            .collect::<std::collections::HashSet<_>>();
         Ok(DijkstraStats {
-            paths_found: 1,
-            execution_time_ms: 42,
-            nodes_expanded: 10,
-            edges_considered: 25,
-            max_queue_size: 8,
-            target_reached: !self.targets.is_empty(),
+            paths_found: result.path_finding_result.path_count() as u64,
+            execution_time_ms: result.computation_time_ms,
+            nodes_expanded,
+            edges_considered,
+            // The procedure does not track its queue size
+            max_queue_size: 0,
+            target_reached: !self.targets.is_empty()
+                && self.targets.iter().all(|target| reached.contains(target)),
         })
     }
 
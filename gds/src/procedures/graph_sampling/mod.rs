@@ -0,0 +1,17 @@
+//! Graph Sampling - Manageable subgraphs of large graphs
+//!
+//! Both samplers select a set of nodes and return the subgraph they induce
+//! as a new [`DefaultGraphStore`](crate::types::graph_store::DefaultGraphStore),
+//! renumbered densely in ascending order of the original node ids:
+//!
+//! - [`RandomNodeSample`] keeps every node with a fixed probability. Cheap,
+//!   but the sampled subgraph is much sparser than the original.
+//! - [`RandomWalkSample`] keeps the nodes visited by random walks with
+//!   restart from a set of start nodes, which preserves local structure
+//!   around them much better.
+
+pub mod random_node;
+pub mod random_walk;
+
+pub use random_node::RandomNodeSample;
+pub use random_walk::RandomWalkSample;
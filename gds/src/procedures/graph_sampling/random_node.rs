@@ -0,0 +1,135 @@
+//! Random node sampling
+//!
+//! Keeps every node independently with probability `fraction`, together
+//! with the relationships between kept nodes.
+
+use crate::core::utils::shuffle::SplittableRandom;
+use crate::procedures::core::sampling::next_unit;
+use crate::types::graph::id_map::MappedNodeId;
+use crate::types::graph_store::DefaultGraphStore;
+use crate::types::prelude::GraphStore;
+
+/// Samples each node independently with probability `fraction`
+///
+/// The sample depends only on the graph and `seed`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RandomNodeSample {
+    /// Probability of keeping a node, within `[0, 1]`
+    pub fraction: f64,
+    pub seed: u64,
+}
+
+impl RandomNodeSample {
+    pub fn new(fraction: f64, seed: u64) -> Self {
+        Self { fraction, seed }
+    }
+
+    /// Subgraph induced by the sampled nodes
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fraction` is not within `[0, 1]`.
+    pub fn sample(&self, graph_store: &DefaultGraphStore) -> DefaultGraphStore {
+        assert!(
+            (0.0..=1.0).contains(&self.fraction),
+            "sample fraction must be within [0, 1], got {}",
+            self.fraction
+        );
+
+        let mut random = SplittableRandom::with_seed(Some(self.seed));
+        let nodes: Vec<MappedNodeId> = (0..graph_store.node_count() as MappedNodeId)
+            .filter(|_| next_unit(&mut random) < self.fraction)
+            .collect();
+        graph_store.induced_subgraph(&nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projection::RelationshipType;
+    use crate::types::prelude::RandomGraphConfig;
+    use crate::types::random::RandomRelationshipConfig;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_sample_keeps_requested_fraction_of_nodes() {
+        let config = RandomGraphConfig {
+            node_count: 10_000,
+            relationships: Vec::new(),
+            ..RandomGraphConfig::default()
+        }
+        .with_seed(3);
+        let store = DefaultGraphStore::random(&config).unwrap();
+
+        let sample = RandomNodeSample::new(0.25, 11).sample(&store);
+
+        let fraction = sample.node_count() as f64 / store.node_count() as f64;
+        assert!(
+            (fraction - 0.25).abs() < 0.02,
+            "sampled fraction {}",
+            fraction
+        );
+        assert_eq!(
+            RandomNodeSample::new(0.25, 11).sample(&store).node_count(),
+            sample.node_count()
+        );
+        assert!(sample.has_node_property("random_score"));
+    }
+
+    #[test]
+    fn test_sampled_relationships_are_induced_by_original() {
+        let config = RandomGraphConfig {
+            node_count: 200,
+            relationships: vec![RandomRelationshipConfig::new("RELATES", 0.05)],
+            ..RandomGraphConfig::default()
+        }
+        .with_seed(5);
+        let store = DefaultGraphStore::random(&config).unwrap();
+        let sample = RandomNodeSample::new(0.5, 1).sample(&store);
+
+        let original_nodes = store.nodes();
+        let original_graph = store.get_graph();
+        let mut original = HashSet::new();
+        for node in 0..store.node_count() as MappedNodeId {
+            for cursor in original_graph.stream_relationships(node, 0.0) {
+                original.insert((
+                    original_nodes.to_original_node_id(node).unwrap(),
+                    original_nodes
+                        .to_original_node_id(cursor.target_id())
+                        .unwrap(),
+                ));
+            }
+        }
+
+        let sampled_nodes = sample.nodes();
+        let sampled_graph = sample.get_graph();
+        let sampled_originals: HashSet<_> = (0..sample.node_count() as MappedNodeId)
+            .map(|node| sampled_nodes.to_original_node_id(node).unwrap())
+            .collect();
+        let mut sampled = HashSet::new();
+        for node in 0..sample.node_count() as MappedNodeId {
+            for cursor in sampled_graph.stream_relationships(node, 0.0) {
+                sampled.insert((
+                    sampled_nodes.to_original_node_id(node).unwrap(),
+                    sampled_nodes
+                        .to_original_node_id(cursor.target_id())
+                        .unwrap(),
+                ));
+            }
+        }
+
+        let induced: HashSet<_> = original
+            .into_iter()
+            .filter(|(source, target)| {
+                sampled_originals.contains(source) && sampled_originals.contains(target)
+            })
+            .collect();
+        assert!(!sampled.is_empty());
+        assert_eq!(sampled, induced);
+        assert_eq!(
+            sample.relationship_count_for_type(&RelationshipType::of("RELATES")),
+            sampled.len()
+        );
+    }
+}
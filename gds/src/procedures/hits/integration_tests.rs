@@ -127,4 +127,57 @@ mod tests {
         assert_eq!(runtime.hub_scores[0], 1.0);
         assert_eq!(runtime.hub_scores[1], 0.0);
     }
+
+    #[test]
+    fn test_hits_mutate_writes_hub_and_authority() {
+        use crate::procedures::hits::HITSAlgorithmSpec;
+        use crate::projection::eval::procedure::{
+            mutate_multiple_node_properties, AlgorithmSpec, ExecutionContext, ExecutionMode,
+            MutateMode, ProcedureExecutor,
+        };
+        use crate::types::prelude::{DefaultGraphStore, GraphStore, RandomGraphConfig};
+        use std::sync::Arc;
+
+        let config = RandomGraphConfig {
+            node_count: 6,
+            ..RandomGraphConfig::default()
+        }
+        .with_seed(2);
+        let store = Arc::new(DefaultGraphStore::random(&config).unwrap());
+        let mut executor = ProcedureExecutor::new(
+            ExecutionContext::mock(Arc::clone(&store)),
+            ExecutionMode::MutateNodeProperty,
+        );
+        let mut algorithm = HITSAlgorithmSpec::new("test_graph".to_string());
+        let result = executor
+            .compute(
+                &mut algorithm,
+                &serde_json::json!({"tolerance": 1e-6, "max_iterations": 100}),
+            )
+            .unwrap();
+
+        let mutated = executor.context().load_graph("test_graph").unwrap();
+        assert!(!store.has_node_property("hub"));
+
+        let mut store = Arc::unwrap_or_clone(store);
+        let written = mutate_multiple_node_properties(
+            &mut store,
+            algorithm.node_property_outputs(&result),
+            MutateMode::Replace,
+        )
+        .unwrap();
+        assert_eq!(written, 12);
+
+        for graph in [mutated.as_ref(), &store] {
+            let hubs = graph.node_property_values("hub").unwrap();
+            let authorities = graph.node_property_values("authority").unwrap();
+            for node_id in 0..6u64 {
+                assert_eq!(hubs.double_value(node_id).unwrap(), result.hub_scores[node_id as usize]);
+                assert_eq!(
+                    authorities.double_value(node_id).unwrap(),
+                    result.authority_scores[node_id as usize]
+                );
+            }
+        }
+    }
 }
@@ -4,6 +4,7 @@
 
 use crate::define_algorithm_spec;
 use crate::projection::eval::procedure::*;
+use crate::types::ValueType;
 use std::time::Duration;
 
 use super::storage::HitsStorageRuntime;
@@ -50,6 +51,16 @@ pub struct HitsResult {
     pub execution_time: Duration,
 }
 
+/// Scores as a `Double` node property output for mutate mode
+fn score_output(property_key: &str, scores: &[f64]) -> NodePropertyOutput {
+    NodePropertyOutput {
+        property_key: property_key.to_string(),
+        value_type: ValueType::Double,
+        values: result_to_node_property_values(scores.to_vec().into(), ValueType::Double)
+            .expect("Double scores convert to Double node properties"),
+    }
+}
+
 // ============================================================================
 // Algorithm Spec (Using macro for boilerplate)
 // ============================================================================
@@ -58,8 +69,14 @@ define_algorithm_spec! {
     name: "hits",
     output_type: HitsResult,
     projection_hint: Dense,
-    modes: [Stream, Stats],
-    
+    modes: [Stream, Stats, MutateNodeProperty],
+    node_property_outputs: |result| {
+        vec![
+            score_output("hub", &result.hub_scores),
+            score_output("authority", &result.authority_scores),
+        ]
+    },
+
     execute: |self, graph_store, config, context| {
         // Parse configuration
         let _parsed_config: HitsConfig = serde_json::from_value(config.clone())
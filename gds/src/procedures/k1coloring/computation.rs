@@ -13,6 +13,9 @@ pub struct K1ColoringComputationResult {
     pub colors: Vec<u64>,
     pub ran_iterations: u64,
     pub did_converge: bool,
+    /// Size of the largest clique found greedily, if requested with
+    /// `with_lower_bound`; no coloring uses fewer colors
+    pub chromatic_lower_bound: Option<u64>,
 }
 
 /// K1Coloring computation runtime
@@ -29,6 +32,8 @@ pub struct K1ColoringComputationRuntime {
     ran_iterations: u64,
     /// Forbidden colors for current node being colored
     forbidden_colors: BitSet,
+    /// Whether to search for a clique bounding the chromatic number
+    compute_lower_bound: bool,
 }
 
 const INITIAL_COLOR: u64 = 1000;
@@ -43,9 +48,16 @@ impl K1ColoringComputationRuntime {
             max_iterations,
             ran_iterations: 0,
             forbidden_colors: BitSet::new(INITIAL_FORBIDDEN_COLORS),
+            compute_lower_bound: false,
         }
     }
 
+    /// Also compute `greedy_clique_lower_bound` after coloring
+    pub fn with_lower_bound(mut self, compute_lower_bound: bool) -> Self {
+        self.compute_lower_bound = compute_lower_bound;
+        self
+    }
+
     /// Run K1Coloring algorithm
     pub fn compute(
         &mut self,
@@ -82,6 +94,9 @@ impl K1ColoringComputationRuntime {
             colors: self.colors.clone(),
             ran_iterations: self.ran_iterations,
             did_converge,
+            chromatic_lower_bound: self
+                .compute_lower_bound
+                .then(|| greedy_clique_lower_bound(node_count, &get_neighbors)),
         }
     }
 
@@ -150,6 +165,53 @@ impl K1ColoringComputationRuntime {
     }
 }
 
+/// Lower bound on the chromatic number from a greedily grown clique
+///
+/// Every node seeds a clique, grown from its neighbors in descending degree
+/// order whenever the candidate is adjacent to all members so far. The
+/// largest clique found is returned: a clique of size k needs k colors, so
+/// the coloring is optimal when it uses this many. Self-loops are ignored.
+///
+/// This reads the whole adjacency, so the runtime only calls it when
+/// enabled with `with_lower_bound`.
+pub fn greedy_clique_lower_bound(
+    node_count: usize,
+    get_neighbors: impl Fn(usize) -> Vec<usize>,
+) -> u64 {
+    let adjacency: Vec<Vec<usize>> = (0..node_count)
+        .map(|node| {
+            let mut neighbors = get_neighbors(node);
+            neighbors.retain(|&neighbor| neighbor != node);
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            neighbors
+        })
+        .collect();
+
+    let mut best = 0;
+    for seed in 0..node_count {
+        // A clique around `seed` cannot exceed its degree + 1
+        if adjacency[seed].len() < best {
+            continue;
+        }
+        let mut candidates = adjacency[seed].clone();
+        candidates.sort_unstable_by_key(|&node| (std::cmp::Reverse(adjacency[node].len()), node));
+
+        let mut clique = vec![seed];
+        for candidate in candidates {
+            if clique
+                .iter()
+                .all(|member| adjacency[candidate].binary_search(member).is_ok())
+            {
+                clique.push(candidate);
+            }
+        }
+        best = best.max(clique.len());
+    }
+
+    best as u64
+}
+
 /// Helper trait for BitSet operations
 trait BitSetExt {
     fn clear_all(&mut self);
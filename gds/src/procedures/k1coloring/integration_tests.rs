@@ -3,6 +3,7 @@
 #[cfg(test)]
 mod tests {
     use crate::procedures::k1coloring::computation::K1ColoringComputationRuntime;
+    use crate::procedures::k1coloring::K1ColoringResult;
     use std::collections::HashMap;
 
     fn create_graph(edges: Vec<(usize, usize)>, node_count: usize) -> HashMap<usize, Vec<usize>> {
@@ -176,4 +177,40 @@ mod tests {
         let unique_colors: std::collections::HashSet<_> = result.colors.iter().collect();
         assert!(unique_colors.len() <= 2);
     }
+
+    #[test]
+    fn test_chromatic_lower_bound_complete_graph_k4() {
+        let edges = vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+        let graph = create_graph(edges, 4);
+        let mut runtime = K1ColoringComputationRuntime::new(4, 20).with_lower_bound(true);
+        let result: K1ColoringResult = runtime
+            .compute(4, |node| graph.get(&node).cloned().unwrap_or_default())
+            .into();
+
+        assert_eq!(result.chromatic_lower_bound(), Some(4));
+        assert_eq!(result.colors_used(), 4);
+    }
+
+    #[test]
+    fn test_chromatic_lower_bound_path() {
+        let edges = vec![(0, 1), (1, 2), (2, 3), (3, 4)];
+        let graph = create_graph(edges, 5);
+        let mut runtime = K1ColoringComputationRuntime::new(5, 10).with_lower_bound(true);
+        let result: K1ColoringResult = runtime
+            .compute(5, |node| graph.get(&node).cloned().unwrap_or_default())
+            .into();
+
+        assert_eq!(result.chromatic_lower_bound(), Some(2));
+        assert!(result.colors_used() >= 2);
+    }
+
+    #[test]
+    fn test_chromatic_lower_bound_is_opt_in() {
+        let edges = vec![(0, 1), (1, 2), (0, 2)];
+        let graph = create_graph(edges, 3);
+        let mut runtime = K1ColoringComputationRuntime::new(3, 10);
+        let result = runtime.compute(3, |node| graph.get(&node).cloned().unwrap_or_default());
+
+        assert_eq!(result.chromatic_lower_bound, None);
+    }
 }
@@ -1,10 +1,14 @@
 //! K1Coloring Specification
+use super::computation::K1ColoringComputationResult;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct K1ColoringConfig {
     pub concurrency: usize,
     pub max_iterations: u64,
+    /// Search for a clique bounding the chromatic number from below
+    #[serde(default)]
+    pub compute_lower_bound: bool,
 }
 
 impl Default for K1ColoringConfig {
@@ -12,6 +16,7 @@ impl Default for K1ColoringConfig {
         Self {
             concurrency: 4,
             max_iterations: 10,
+            compute_lower_bound: false,
         }
     }
 }
@@ -21,6 +26,37 @@ pub struct K1ColoringResult {
     pub colors: Vec<u64>,
     pub ran_iterations: u64,
     pub did_converge: bool,
+    #[serde(default)]
+    pub chromatic_lower_bound: Option<u64>,
+}
+
+impl K1ColoringResult {
+    /// Number of distinct colors in the coloring
+    pub fn colors_used(&self) -> u64 {
+        self.colors
+            .iter()
+            .collect::<std::collections::HashSet<_>>()
+            .len() as u64
+    }
+
+    /// Greedy lower bound on the chromatic number (largest clique found)
+    ///
+    /// The coloring is optimal when `colors_used()` equals this bound.
+    /// `None` unless the runtime was built `with_lower_bound(true)`.
+    pub fn chromatic_lower_bound(&self) -> Option<u64> {
+        self.chromatic_lower_bound
+    }
+}
+
+impl From<K1ColoringComputationResult> for K1ColoringResult {
+    fn from(result: K1ColoringComputationResult) -> Self {
+        Self {
+            colors: result.colors,
+            ran_iterations: result.ran_iterations,
+            did_converge: result.did_converge,
+            chromatic_lower_bound: result.chromatic_lower_bound,
+        }
+    }
 }
 
 pub struct K1ColoringAlgorithmSpec {
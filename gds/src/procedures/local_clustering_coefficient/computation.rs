@@ -2,6 +2,8 @@
 //!
 //! This implements the core clustering coefficient formula:
 //! C(v) = 2 * triangles(v) / (degree(v) * (degree(v) - 1))
+//! and the global clustering coefficient (transitivity):
+//! C = 3 * triangles / connected triples
 
 /// Computation Runtime for Local Clustering Coefficient
 ///
@@ -13,6 +15,8 @@ pub struct LocalClusteringCoefficientComputationRuntime {
     pub local_clustering_coefficients: Vec<f64>,
     /// Average clustering coefficient across all nodes
     pub average_clustering_coefficient: f64,
+    /// Global clustering coefficient (transitivity) of the whole graph
+    pub global_clustering_coefficient: f64,
     /// Number of nodes processed
     pub node_count: usize,
 }
@@ -23,6 +27,7 @@ impl LocalClusteringCoefficientComputationRuntime {
         Self {
             local_clustering_coefficients: vec![0.0; node_count],
             average_clustering_coefficient: 0.0,
+            global_clustering_coefficient: 0.0,
             node_count,
         }
     }
@@ -61,6 +66,8 @@ impl LocalClusteringCoefficientComputationRuntime {
 
         // Calculate average clustering coefficient
         self.average_clustering_coefficient = sum / (self.node_count as f64);
+        self.global_clustering_coefficient =
+            global_clustering_coefficient(triangle_counts, degrees);
     }
 
     /// Get all local clustering coefficients
@@ -72,6 +79,32 @@ impl LocalClusteringCoefficientComputationRuntime {
     pub fn get_average(&self) -> f64 {
         self.average_clustering_coefficient
     }
+
+    /// Get global clustering coefficient
+    pub fn get_global(&self) -> f64 {
+        self.global_clustering_coefficient
+    }
+}
+
+/// Global clustering coefficient (transitivity) from per-node triangle counts
+///
+/// Formula: C = 3 * triangles / triples = sum(triangles(v)) / sum(d(v) * (d(v) - 1) / 2)
+///
+/// Every triangle is counted at each of its three nodes, so the per-node sum
+/// already equals `3 * triangles`. A graph without connected triples has
+/// coefficient 0.0.
+pub fn global_clustering_coefficient(triangle_counts: &[u64], degrees: &[i32]) -> f64 {
+    let closed: f64 = triangle_counts.iter().map(|&t| t as f64).sum();
+    let triples: f64 = degrees
+        .iter()
+        .filter(|&&d| d >= 2)
+        .map(|&d| (d as f64) * ((d - 1) as f64) / 2.0)
+        .sum();
+    if triples == 0.0 {
+        0.0
+    } else {
+        closed / triples
+    }
 }
 
 impl Default for LocalClusteringCoefficientComputationRuntime {
@@ -192,4 +192,20 @@ mod tests {
         }
         assert_eq!(runtime.average_clustering_coefficient, 0.0);
     }
+
+    #[test]
+    fn test_global_clustering_coefficient() {
+        use crate::procedures::local_clustering_coefficient::global_clustering_coefficient;
+
+        // Triangle 0-1-2 with pendant 2-3
+        // 1 triangle, triples: 1 + 1 + 3 + 0 = 5 → C = 3*1/5 = 0.6
+        let mut runtime = LocalClusteringCoefficientComputationRuntime::new(4);
+        runtime.compute(&[1, 1, 1, 0], &[2, 2, 3, 1]);
+        assert!((runtime.global_clustering_coefficient - 0.6).abs() < 1e-12);
+
+        // Tree: 0-1, 0-2, 1-3, 1-4 has triples but no triangles
+        assert_eq!(global_clustering_coefficient(&[0; 5], &[2, 3, 1, 1, 1]), 0.0);
+        // No triples at all
+        assert_eq!(global_clustering_coefficient(&[0, 0], &[1, 1]), 0.0);
+    }
 }
@@ -39,4 +39,4 @@ pub use spec::{
     LocalClusteringCoefficientResult,
 };
 pub use storage::LocalClusteringCoefficientStorageRuntime;
-pub use computation::LocalClusteringCoefficientComputationRuntime;
+pub use computation::{global_clustering_coefficient, LocalClusteringCoefficientComputationRuntime};
@@ -39,6 +39,9 @@ pub struct LocalClusteringCoefficientResult {
     pub local_clustering_coefficients: Vec<f64>,
     /// Average clustering coefficient across all nodes
     pub average_clustering_coefficient: f64,
+    /// Global clustering coefficient (transitivity): 3 * triangles / connected triples
+    #[serde(default)]
+    pub global_clustering_coefficient: f64,
     /// Execution time
     pub execution_time: Duration,
 }
@@ -101,6 +104,7 @@ define_algorithm_spec! {
         Ok(LocalClusteringCoefficientResult {
             local_clustering_coefficients: computation.get_coefficients().clone(),
             average_clustering_coefficient: computation.get_average(),
+            global_clustering_coefficient: computation.get_global(),
             execution_time: Duration::from_millis(50),
         })
     }
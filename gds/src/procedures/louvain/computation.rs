@@ -1,23 +1,206 @@
+//! Louvain Computation Runtime
+//!
+//! **Translation Source**: `org.neo4j.gds.louvain.Louvain`
+//!
+//! Greedy modularity optimisation. Each level runs local moving (every node
+//! joins the neighbouring community with the largest modularity gain) and then
+//! aggregates communities into super-nodes, until no node changes community.
+//!
+//! All sums are weighted: `get_neighbors` yields `(neighbor, weight)` pairs and
+//! degrees, `m` and intra-community sums are computed from those weights. Pass
+//! `1.0` for every edge to get unweighted Louvain. The adjacency is treated as
+//! undirected, so each edge should be listed from both endpoints.
+
+use std::collections::HashMap;
+
 #[derive(Clone)]
 pub struct LouvainResult {
+    /// Final community per node
     pub data: Vec<u64>,
+    /// Modularity after each level
+    pub modularities: Vec<f64>,
+    pub ran_levels: usize,
 }
 
 pub struct LouvainComputationRuntime {
+    max_levels: usize,
+    max_iterations: usize,
 }
 
 impl LouvainComputationRuntime {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            max_levels: 10,
+            max_iterations: 10,
+        }
+    }
+
+    pub fn with_max_levels(mut self, max_levels: usize) -> Self {
+        self.max_levels = max_levels;
+        self
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
     }
 
     pub fn compute(
         &mut self,
         node_count: usize,
-        get_neighbors: impl Fn(usize) -> Vec<usize>,
+        get_neighbors: impl Fn(usize) -> Vec<(usize, f64)>,
     ) -> LouvainResult {
+        let mut adjacency: Vec<Vec<(usize, f64)>> = (0..node_count).map(&get_neighbors).collect();
+        let mut communities: Vec<usize> = (0..node_count).collect();
+        let mut modularities = Vec::new();
+
+        let total_weight: f64 = adjacency.iter().flatten().map(|(_, w)| w).sum();
+        if total_weight == 0.0 {
+            return LouvainResult {
+                data: communities.into_iter().map(|c| c as u64).collect(),
+                modularities,
+                ran_levels: 0,
+            };
+        }
+
+        for _ in 0..self.max_levels {
+            let (level_communities, moved) = self.local_moving(&adjacency, total_weight);
+            if !moved {
+                break;
+            }
+
+            let (renumbered, community_count) = renumber(&level_communities);
+            for community in communities.iter_mut() {
+                *community = renumbered[*community];
+            }
+
+            adjacency = aggregate(&adjacency, &renumbered, community_count);
+            modularities.push(level_modularity(&adjacency, total_weight));
+        }
+
         LouvainResult {
-            data: vec![0u64; node_count],
+            data: communities.into_iter().map(|c| c as u64).collect(),
+            ran_levels: modularities.len(),
+            modularities,
+        }
+    }
+
+    /// Move nodes between communities until no move improves modularity.
+    /// Returns the community of every node and whether any node moved.
+    fn local_moving(&self, adjacency: &[Vec<(usize, f64)>], total_weight: f64) -> (Vec<usize>, bool) {
+        let node_count = adjacency.len();
+        let degrees: Vec<f64> = adjacency
+            .iter()
+            .map(|neighbors| neighbors.iter().map(|(_, w)| w).sum())
+            .collect();
+
+        let mut community: Vec<usize> = (0..node_count).collect();
+        let mut community_total = degrees.clone();
+        let mut moved_any = false;
+
+        for _ in 0..self.max_iterations {
+            let mut moved = false;
+
+            for node in 0..node_count {
+                let current = community[node];
+                let degree = degrees[node];
+                community_total[current] -= degree;
+
+                let mut weight_to: HashMap<usize, f64> = HashMap::new();
+                weight_to.insert(current, 0.0);
+                for &(neighbor, weight) in &adjacency[node] {
+                    if neighbor != node {
+                        *weight_to.entry(community[neighbor]).or_insert(0.0) += weight;
+                    }
+                }
+
+                let gain = |c: usize, w: f64| w - community_total[c] * degree / total_weight;
+                let mut best = current;
+                let mut best_gain = gain(current, weight_to[&current]);
+                for (&candidate, &weight) in &weight_to {
+                    let candidate_gain = gain(candidate, weight);
+                    if candidate_gain > best_gain
+                        || (candidate_gain == best_gain && candidate < best && best != current)
+                    {
+                        best = candidate;
+                        best_gain = candidate_gain;
+                    }
+                }
+
+                community_total[best] += degree;
+                if best != current {
+                    community[node] = best;
+                    moved = true;
+                }
+            }
+
+            if !moved {
+                break;
+            }
+            moved_any = true;
+        }
+
+        (community, moved_any)
+    }
+}
+
+impl Default for LouvainComputationRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Map community ids to `0..count`, in order of first appearance
+fn renumber(communities: &[usize]) -> (Vec<usize>, usize) {
+    let mut ids = HashMap::new();
+    let renumbered = communities
+        .iter()
+        .map(|c| {
+            let next = ids.len();
+            *ids.entry(*c).or_insert(next)
+        })
+        .collect();
+    (renumbered, ids.len())
+}
+
+/// Collapse each community into a super-node. Intra-community weights become
+/// a self loop, so degrees and the total weight are preserved.
+fn aggregate(
+    adjacency: &[Vec<(usize, f64)>],
+    communities: &[usize],
+    community_count: usize,
+) -> Vec<Vec<(usize, f64)>> {
+    let mut weights: Vec<HashMap<usize, f64>> = vec![HashMap::new(); community_count];
+    for (node, neighbors) in adjacency.iter().enumerate() {
+        for &(neighbor, weight) in neighbors {
+            *weights[communities[node]]
+                .entry(communities[neighbor])
+                .or_insert(0.0) += weight;
         }
     }
+    weights
+        .into_iter()
+        .map(|targets| {
+            let mut targets: Vec<_> = targets.into_iter().collect();
+            targets.sort_by_key(|(target, _)| *target);
+            targets
+        })
+        .collect()
+}
+
+/// Modularity of the singleton partition of an aggregated graph
+fn level_modularity(adjacency: &[Vec<(usize, f64)>], total_weight: f64) -> f64 {
+    adjacency
+        .iter()
+        .enumerate()
+        .map(|(node, neighbors)| {
+            let internal: f64 = neighbors
+                .iter()
+                .filter(|(target, _)| *target == node)
+                .map(|(_, w)| w)
+                .sum();
+            let degree: f64 = neighbors.iter().map(|(_, w)| w).sum();
+            internal / total_weight - (degree / total_weight).powi(2)
+        })
+        .sum()
 }
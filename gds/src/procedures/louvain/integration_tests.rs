@@ -1,5 +1,132 @@
 #[cfg(test)]
 mod tests {
+    use crate::procedures::louvain::computation::LouvainComputationRuntime;
+    use crate::procedures::louvain::storage::LouvainStorageRuntime;
+    use crate::projection::RelationshipType;
+    use crate::types::graph_store::{DefaultGraphStore, GraphStore};
+    use crate::types::properties::relationship::impls::default_relationship_property_values::DefaultRelationshipPropertyValues;
+    use crate::types::random::{RandomGraphConfig, RandomRelationshipConfig};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    fn create_weighted_graph(
+        edges: &[(usize, usize, f64)],
+        node_count: usize,
+    ) -> HashMap<usize, Vec<(usize, f64)>> {
+        let mut graph = HashMap::new();
+        for i in 0..node_count {
+            graph.insert(i, Vec::new());
+        }
+        for &(from, to, weight) in edges {
+            graph
+                .entry(from)
+                .or_insert_with(Vec::new)
+                .push((to, weight));
+            graph
+                .entry(to)
+                .or_insert_with(Vec::new)
+                .push((from, weight));
+        }
+        graph
+    }
+
+    /// Two 4-cliques {0,1,2,3} and {4,5,6,7} joined by the matching i - i+4.
+    /// Clique edges weigh 1.0, matching edges weigh `matching_weight`.
+    fn cliques_with_matching(matching_weight: f64) -> HashMap<usize, Vec<(usize, f64)>> {
+        let mut edges = Vec::new();
+        for offset in [0, 4] {
+            for a in 0..4 {
+                for b in (a + 1)..4 {
+                    edges.push((offset + a, offset + b, 1.0));
+                }
+            }
+        }
+        for i in 0..4 {
+            edges.push((i, i + 4, matching_weight));
+        }
+        create_weighted_graph(&edges, 8)
+    }
+
+    fn run(graph: &HashMap<usize, Vec<(usize, f64)>>, node_count: usize) -> Vec<u64> {
+        let mut runtime = LouvainComputationRuntime::new();
+        runtime
+            .compute(node_count, |node| {
+                graph.get(&node).cloned().unwrap_or_default()
+            })
+            .data
+    }
+
     #[test]
-    fn test_placeholder() {}
+    fn test_unweighted_finds_cliques() {
+        let communities = run(&cliques_with_matching(1.0), 8);
+
+        assert!((0..4).all(|n| communities[n] == communities[0]));
+        assert!((4..8).all(|n| communities[n] == communities[4]));
+        assert_ne!(communities[0], communities[4]);
+    }
+
+    #[test]
+    fn test_heavy_weights_change_communities() {
+        let communities = run(&cliques_with_matching(10.0), 8);
+
+        // The heavy matching edges now dominate: each pair i, i+4 is a community.
+        for i in 0..4 {
+            assert_eq!(communities[i], communities[i + 4]);
+        }
+        for i in 1..4 {
+            assert_ne!(communities[0], communities[i]);
+        }
+    }
+
+    #[test]
+    fn test_modularity_is_tracked_per_level() {
+        let graph = cliques_with_matching(1.0);
+        let mut runtime = LouvainComputationRuntime::new();
+        let result = runtime.compute(8, |node| graph.get(&node).cloned().unwrap_or_default());
+
+        assert_eq!(result.ran_levels, result.modularities.len());
+        assert!((result.modularities.last().unwrap() - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_no_edges_keeps_singletons() {
+        let graph = create_weighted_graph(&[], 3);
+        assert_eq!(run(&graph, 3), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_storage_reads_relationship_weights() {
+        let config = RandomGraphConfig {
+            seed: Some(3),
+            node_count: 12,
+            relationships: vec![RandomRelationshipConfig::new("REL", 0.3)],
+            ..RandomGraphConfig::default()
+        };
+        let mut store = DefaultGraphStore::random(&config).unwrap();
+        let rel_type = RelationshipType::of("REL");
+        let count = store.relationship_count();
+        store
+            .add_relationship_property(
+                rel_type,
+                "weight",
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    vec![2.5; count],
+                    count,
+                )),
+            )
+            .unwrap();
+
+        let storage = LouvainStorageRuntime::new(1);
+        let total = |adjacency: Vec<Vec<(usize, f64)>>| -> f64 {
+            adjacency.iter().flatten().map(|(_, w)| w).sum()
+        };
+
+        let unweighted = storage.weighted_adjacency(&store, None).unwrap();
+        assert_eq!(total(unweighted), 2.0 * count as f64);
+
+        let weighted = storage.weighted_adjacency(&store, Some("weight")).unwrap();
+        assert_eq!(total(weighted), 5.0 * count as f64);
+
+        assert!(storage.weighted_adjacency(&store, Some("missing")).is_err());
+    }
 }
@@ -3,11 +3,16 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LouvainConfig {
     pub concurrency: usize,
+    /// Relationship property used as edge weight; `None` treats every edge as weight 1.0
+    pub relationship_weight_property: Option<String>,
 }
 
 impl Default for LouvainConfig {
     fn default() -> Self {
-        Self { concurrency: 4 }
+        Self {
+            concurrency: 4,
+            relationship_weight_property: None,
+        }
     }
 }
 
@@ -1,3 +1,7 @@
+use crate::procedures::algorithms::community::relationship_weighted_graph;
+use crate::projection::eval::procedure::AlgorithmError;
+use crate::types::prelude::GraphStore;
+
 pub struct LouvainStorageRuntime {
     concurrency: usize,
 }
@@ -6,4 +10,36 @@ impl LouvainStorageRuntime {
     pub fn new(concurrency: usize) -> Self {
         Self { concurrency }
     }
+
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Load the undirected weighted adjacency `(neighbor, weight)` per node.
+    ///
+    /// With `relationship_weight_property` unset every edge has weight 1.0.
+    /// Directed graphs are symmetrized by adding each edge from both endpoints.
+    pub fn weighted_adjacency<G: GraphStore>(
+        &self,
+        graph_store: &G,
+        relationship_weight_property: Option<&str>,
+    ) -> Result<Vec<Vec<(usize, f64)>>, AlgorithmError> {
+        let graph = relationship_weighted_graph(graph_store, relationship_weight_property)?;
+        let weighted = relationship_weight_property.is_some();
+        let node_count = graph.node_count();
+        let symmetrize = graph.characteristics().is_directed();
+
+        let mut adjacency = vec![Vec::new(); node_count];
+        for node in 0..node_count {
+            for cursor in graph.stream_relationships(node as i64, 1.0) {
+                let target = cursor.target_id() as usize;
+                let weight = if weighted { cursor.property() } else { 1.0 };
+                adjacency[node].push((target, weight));
+                if symmetrize {
+                    adjacency[target].push((node, weight));
+                }
+            }
+        }
+        Ok(adjacency)
+    }
 }
@@ -112,6 +112,10 @@ pub mod spanning_tree;
 // pub mod scc;  // TODO: Fix trait bounds and private module issues
 pub mod articulation_points;
 pub mod bridges;
+pub mod connectivity;
+pub mod topological_sort;
+pub mod eccentricity;
+pub mod bipartite;
 pub mod wcc;
 pub mod msbfs;
 pub mod harmonic;
@@ -125,6 +129,9 @@ pub mod k1coloring;
 pub mod kspanningtree;
 pub mod local_clustering_coefficient;
 pub mod hits;
+pub mod threshold_count;
+pub mod graph_sampling;
+pub mod critical_path;
 
 
 // Future modules (to be implemented)
@@ -147,12 +154,16 @@ pub use spanning_tree::{SPANNING_TREEAlgorithmSpec, SpanningTreeConfig, Spanning
 // pub use scc::{SCCAlgorithmSpec, SccConfig, SccResult, SccStorageRuntime, SccComputationRuntime};
 pub use articulation_points::{ArticulationPointsAlgorithmSpec, ArticulationPointsConfig, ArticulationPointsResult, ArticulationPointsStorageRuntime, ArticulationPointsComputationRuntime};
 pub use bridges::{BridgesAlgorithmSpec, BridgesConfig, BridgesResult, BridgesStorageRuntime, BridgesComputationRuntime};
+pub use connectivity::{ConnectivityAlgorithmSpec, ConnectivityConfig, ConnectivityResult, ConnectivityStorageRuntime, ConnectivityComputationRuntime};
+pub use topological_sort::{TopologicalSortAlgorithmSpec, TopologicalSortConfig, TopologicalSortResult, TopologicalSortStorageRuntime, TopologicalSortComputationRuntime, TopologicalSortError};
+pub use eccentricity::{EccentricityAlgorithmSpec, EccentricityConfig, EccentricityMode, EccentricityResult, EccentricityStorageRuntime, EccentricityComputationRuntime};
+pub use bipartite::{BipartiteAlgorithmSpec, BipartiteConfig, BipartiteResult, BipartiteStorageRuntime, BipartiteComputationRuntime};
 pub use wcc::{WccAlgorithmSpec, WccConfig, WccResult, WccStorageRuntime, WccComputationRuntime};
 pub use msbfs::SimpleMSBFS;
 pub use harmonic::{HarmonicAlgorithmSpec, HarmonicConfig, HarmonicResult, HarmonicStorageRuntime, HarmonicComputationRuntime};
 pub use closeness::{ClosenessCentralityAlgorithmSpec, ClosenessCentralityConfig, ClosenessCentralityResult, ClosenessCentralityStorageRuntime, ClosenessCentralityComputationRuntime};
-pub use betweenness::{BetweennessCentralityAlgorithmSpec, BetweennessCentralityConfig, BetweennessCentralityResult, BetweennessCentralityStorageRuntime, BetweennessCentralityComputationRuntime};
-pub use triangle_count::{TriangleCountAlgorithmSpec, TriangleCountConfig, TriangleCountResult, TriangleCountStorageRuntime, TriangleCountComputationRuntime};
+pub use betweenness::{BetweennessCentralityAlgorithmSpec, BetweennessCentralityConfig, BetweennessCentralityResult, BetweennessCentralityStorageRuntime, BetweennessCentralityComputationRuntime, PivotStrategy};
+pub use triangle_count::{TriangleCountAlgorithmSpec, TriangleCountConfig, TriangleCountResult, TriangleWeightCombination, TriangleCountStorageRuntime, TriangleCountComputationRuntime};
 pub use louvain::{LouvainAlgorithmSpec, LouvainConfig, LouvainResult, LouvainStorageRuntime, LouvainComputationRuntime};
 pub use label_propagation::{LabelPropAlgorithmSpec, LabelPropConfig, LabelPropResult, LabelPropStorageRuntime, LabelPropComputationRuntime};
 pub use kcore::{KCoreAlgorithmSpec, KCoreConfig, KCoreResult, KCoreStorageRuntime, KCoreDecompositionRuntime};
@@ -160,6 +171,9 @@ pub use k1coloring::{K1ColoringAlgorithmSpec, K1ColoringConfig, K1ColoringResult
 pub use kspanningtree::{KSpanningTreeAlgorithmSpec, KSpanningTreeConfig, KSpanningTreeResult, KSpanningTreeStorageRuntime, KSpanningTreeComputationRuntime};
 pub use local_clustering_coefficient::{LOCAL_CLUSTERING_COEFFICIENTAlgorithmSpec, LocalClusteringCoefficientConfig, LocalClusteringCoefficientResult, LocalClusteringCoefficientStorageRuntime, LocalClusteringCoefficientComputationRuntime};
 pub use hits::{HITSAlgorithmSpec, HitsConfig, HitsResult, HitsStorageRuntime, HitsComputationRuntime};
+pub use threshold_count::{ThresholdCountAlgorithmSpec, ThresholdCountConfig};
+pub use graph_sampling::{RandomNodeSample, RandomWalkSample};
+pub use critical_path::{CriticalPathAlgorithmSpec, CriticalPathConfig, CriticalPathResult, CriticalPathStorageRuntime, CriticalPathComputationRuntime};
 
 // pub use algorithms::*;
 pub use core::*;
@@ -4,6 +4,11 @@
 //! Each bit represents whether a source node has reached a particular target node
 //! at the current BFS depth.
 
+use crate::projection::RelationshipType;
+use crate::types::graph::GraphResult;
+use crate::types::graph_store::GraphStore;
+use std::collections::HashSet;
+
 /// Simplified Multi-Source BFS using bit-packed u64 masks
 ///
 /// **OMEGA = 64**: Can simultaneously process up to 64 source nodes
@@ -140,6 +145,33 @@ impl SimpleMSBFS {
         }
     }
 
+    /// Run MSBFS over the relationships of the given types only
+    ///
+    /// Neighbors come from the store's graph view filtered to
+    /// `relationship_types`, so on a heterogeneous graph each call follows a
+    /// single meta-path step. Fails if a type is unknown to the store.
+    pub fn compute_with_types<S, F>(
+        &mut self,
+        graph_store: &S,
+        relationship_types: &HashSet<RelationshipType>,
+        source_nodes: &[usize],
+        on_node: F,
+    ) -> GraphResult<()>
+    where
+        S: GraphStore + ?Sized,
+        F: FnMut(usize, u32, u64),
+    {
+        let graph = graph_store.get_graph_with_types(relationship_types)?;
+        let fallback = graph.default_property_value();
+        self.compute(source_nodes, on_node, |node| {
+            graph
+                .stream_relationships(node as i64, fallback)
+                .map(|cursor| cursor.target_id() as usize)
+                .collect()
+        });
+        Ok(())
+    }
+
     /// Get the seen_set: which sources reached each node
     pub fn seen_set(&self) -> &[u64] {
         &self.seen_set
@@ -308,4 +340,37 @@ mod tests {
             |_| vec![],
         );
     }
+
+    #[test]
+    fn test_compute_with_types_follows_only_selected_types() {
+        use crate::projection::RelationshipType;
+        use crate::types::random::generators::typed_store;
+        use crate::types::schema::Direction;
+
+        let store = typed_store(
+            "msbfs",
+            5,
+            [
+                ("REL", Direction::Directed, vec![(0, 3), (3, 4)]),
+                ("A", Direction::Directed, vec![(0, 1), (1, 2)]),
+            ],
+        );
+
+        let reached = |rel_type: &str| {
+            let mut msbfs = SimpleMSBFS::new(5);
+            let mut nodes = Vec::new();
+            msbfs
+                .compute_with_types(
+                    &store,
+                    &HashSet::from([RelationshipType::of(rel_type)]),
+                    &[0],
+                    |node_id, _, _| nodes.push(node_id),
+                )
+                .unwrap();
+            nodes
+        };
+
+        assert_eq!(reached("A"), vec![0, 1, 2]);
+        assert_eq!(reached("REL"), vec![0, 3, 4]);
+    }
 }
@@ -4,8 +4,9 @@
 //! It is the **Species** manifestation of the abstract **Genus** (PageRank principle).
 
 use crate::projection::eval::procedure::{
-    AlgorithmError, AlgorithmSpec, ComputationResult, ConfigError, ConsumerError, ExecutionContext,
-    ExecutionMode, LogLevel, ProjectionHint, ValidationConfiguration,
+    get_optional_param, parse_config, AlgorithmConfig, AlgorithmError, AlgorithmSpec,
+    ComputationResult, ConfigError, ConsumerError, ExecutionContext, ExecutionMode, LogLevel,
+    ProjectionHint, RangeValidator, ValidationConfiguration,
 };
 use crate::types::prelude::GraphStore;
 use crate::config::PageRankConfig;
@@ -92,8 +93,8 @@ impl AlgorithmSpec for PageRankAlgorithmSpec {
 
     /// Parse JSON configuration
     ///
-    /// Uses the config system's `PageRankConfig` builder pattern for validation.
-    /// The config system handles validation (damping factor range, positive values, etc.).
+    /// Delegates to `parse_config::<PageRankConfig>`, which runs the JSON-level
+    /// validators and then the config system's builder validation.
     ///
     /// **Input JSON Format**:
     /// ```json
@@ -106,34 +107,7 @@ impl AlgorithmSpec for PageRankAlgorithmSpec {
     /// }
     /// ```
     fn parse_config(&self, input: &JsonValue) -> Result<JsonValue, ConfigError> {
-        // Extract fields manually (since PageRankConfig doesn't derive Deserialize from define_config!)
-        let mut builder = PageRankConfig::builder();
-        
-        if let Some(df) = input.get("dampingFactor").and_then(|v| v.as_f64()) {
-            builder = builder.damping_factor(df);
-        }
-        
-        if let Some(tol) = input.get("tolerance").and_then(|v| v.as_f64()) {
-            builder = builder.tolerance(tol);
-        }
-        
-        if let Some(max_iter) = input.get("maxIterations").and_then(|v| v.as_u64()) {
-            builder = builder.max_iterations(max_iter as usize);
-        }
-        
-        if let Some(src_nodes) = input.get("sourceNodes").and_then(|v| v.as_array()) {
-            let nodes: Vec<String> = src_nodes
-                .iter()
-                .filter_map(|v| v.as_str().map(|s| s.to_string()))
-                .collect();
-            builder = builder.source_nodes(Some(nodes));
-        }
-
-        // Build and validate using config system
-        let config = builder.build().map_err(|e| ConfigError::InvalidValue {
-            param: "config".to_string(),
-            message: format!("Config validation failed: {}", e),
-        })?;
+        let config: PageRankConfig = parse_config(input)?;
 
         // Return validated config as JSON (with weight_property included)
         // Note: weight_property is not in PageRankConfig yet, extract separately
@@ -257,17 +231,27 @@ impl AlgorithmSpec for PageRankAlgorithmSpec {
             source_nodes,
         );
 
-        // Run PageRank iterations
+        // Run PageRank iterations: score = (1 - d) + d * Σ score(u) / out_degree(u)
+        let teleport = 1.0 - damping_factor;
         for iteration in 0..max_iterations {
             context.log(
                 LogLevel::Debug,
                 &format!("PageRank iteration {}", iteration + 1),
             );
 
-            // Advance to next iteration
             computation.advance_iteration();
 
-            // Check convergence
+            let incoming = storage.incoming_scores(|node| computation.get_score(node));
+            for (node, sum) in incoming.into_iter().enumerate() {
+                let base = if storage.is_source_node(node as u64) {
+                    teleport
+                } else {
+                    0.0
+                };
+                computation.set_score(node as u64, base + damping_factor * sum);
+            }
+
+            computation.check_convergence();
             if computation.converged() {
                 context.log(
                     LogLevel::Info,
@@ -275,14 +259,6 @@ impl AlgorithmSpec for PageRankAlgorithmSpec {
                 );
                 break;
             }
-
-            // TODO: Implement actual PageRank message passing
-            // For now, this is a placeholder that simulates the algorithm
-            // In a real implementation, this would:
-            // 1. For each node, compute outgoing messages
-            // 2. Distribute messages to neighbors
-            // 3. Accumulate incoming messages
-            // 4. Update scores
         }
 
         let elapsed = timer.elapsed();
@@ -298,8 +274,11 @@ impl AlgorithmSpec for PageRankAlgorithmSpec {
         );
 
         // Create result
+        let scores = (0..storage.node_count() as u64)
+            .map(|node| computation.get_score(node))
+            .collect();
         let result = PageRankComputationResult {
-            scores: computation.get_all_scores(),
+            scores,
             iterations: computation.iteration(),
             converged: computation.converged(),
             execution_time: elapsed,
@@ -337,6 +316,44 @@ impl AlgorithmSpec for PageRankAlgorithmSpec {
     }
 }
 
+// ============================================================================
+// Typed Config Parsing
+// ============================================================================
+
+impl AlgorithmConfig for PageRankConfig {
+    fn validation() -> ValidationConfiguration {
+        ValidationConfiguration::new()
+            .add_before_load(RangeValidator::new("dampingFactor", 0.0, 1.0))
+    }
+
+    /// Builds the config via `PageRankConfig::builder()`, which applies the
+    /// config system's own validation on top of the JSON-level checks.
+    fn from_json(json: &JsonValue) -> Result<Self, ConfigError> {
+        let mut builder = PageRankConfig::builder();
+
+        if let Some(df) = get_optional_param::<Option<f64>>(json, "dampingFactor", None)? {
+            builder = builder.damping_factor(df);
+        }
+
+        if let Some(tol) = get_optional_param::<Option<f64>>(json, "tolerance", None)? {
+            builder = builder.tolerance(tol);
+        }
+
+        if let Some(max_iter) = get_optional_param::<Option<usize>>(json, "maxIterations", None)? {
+            builder = builder.max_iterations(max_iter);
+        }
+
+        if let Some(nodes) = get_optional_param::<Option<Vec<String>>>(json, "sourceNodes", None)? {
+            builder = builder.source_nodes(Some(nodes));
+        }
+
+        builder.build().map_err(|e| ConfigError::InvalidValue {
+            param: "config".to_string(),
+            message: format!("Config validation failed: {}", e),
+        })
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -409,4 +426,25 @@ mod tests {
         assert_eq!(config.get("tolerance").unwrap().as_f64().unwrap(), 0.0000001);
         assert_eq!(config.get("maxIterations").unwrap().as_u64().unwrap(), 20);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_typed_pagerank_config() {
+        let config: PageRankConfig =
+            parse_config(&json!({"maxIterations": 20, "dampingFactor": 0.85})).unwrap();
+        assert_eq!(config.max_iterations, 20);
+        assert_eq!(config.damping_factor, 0.85);
+    }
+
+    #[test]
+    fn test_parse_typed_pagerank_config_out_of_range_damping_factor() {
+        let result = parse_config::<PageRankConfig>(&json!({"dampingFactor": 1.5}));
+        match result {
+            Err(ConfigError::InvalidValue { param, message }) => {
+                assert_eq!(param, "dampingFactor");
+                assert!(message.contains("1.5"), "unexpected message: {}", message);
+                assert!(message.contains("out of range"), "unexpected message: {}", message);
+            }
+            other => panic!("expected InvalidValue error, got {:?}", other),
+        }
+    }
+}
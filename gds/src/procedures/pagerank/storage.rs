@@ -129,6 +129,29 @@ impl<'a, G: GraphStore> PageRankStorageRuntime<'a, G> {
         Ok(neighbors)
     }
     
+    /// Sum the shares every node receives over its incoming relationships
+    ///
+    /// Each node splits `score_of(node)` evenly across its outgoing
+    /// relationships. Nodes are addressed by mapped id.
+    pub fn incoming_scores(&self, score_of: impl Fn(u64) -> f64) -> Vec<f64> {
+        let node_count = self.graph.node_count();
+        let mut incoming = vec![0.0; node_count];
+        for node in 0..node_count as i64 {
+            let degree = self.graph.degree(node);
+            if degree == 0 {
+                continue;
+            }
+            let share = score_of(node as u64) / degree as f64;
+            for cursor in self
+                .graph
+                .stream_relationships(node, self.graph.default_property_value())
+            {
+                incoming[cursor.target_id() as usize] += share;
+            }
+        }
+        incoming
+    }
+
     /// Check if a node is a source node
     pub fn is_source_node(&self, node_id: u64) -> bool {
         self.source_nodes
@@ -0,0 +1,27 @@
+//! Threshold Count - Reference `AlgorithmSpec` implementation
+//!
+//! Counts the nodes whose value for a numeric node property is strictly
+//! greater than a threshold. The computation is deliberately trivial: this
+//! module is the template for writing a new algorithm against the
+//! `ProcedureExecutor` contract, covering every step the executor drives.
+//!
+//! ## The Contract, Step by Step
+//!
+//! | Executor step          | Hook                             | Here                                 |
+//! |------------------------|----------------------------------|--------------------------------------|
+//! | parse configuration    | `AlgorithmSpec::parse_config`    | typed `ThresholdCountConfig`         |
+//! | validate before load   | `AlgorithmConfig::validation`    | required `property_key`, `threshold` |
+//! | validate after load    | `AlgorithmSpec::validation_config` | property exists on the graph       |
+//! | execute                | `AlgorithmSpec::execute`         | scan property values                 |
+//! | consume                | `AlgorithmSpec::consume_result`  | Stream and Stats return the count    |
+//!
+//! ## Example
+//!
+//! `spec::tests::test_example_counts_scores_above_threshold` runs the spec
+//! end to end: it adds a `score` property to a four-node store, registers
+//! the store in a mock `ExecutionContext` and counts the scores above 0.5
+//! through the `ProcedureExecutor`.
+
+pub mod spec;
+
+pub use spec::{ThresholdCountAlgorithmSpec, ThresholdCountConfig};
@@ -0,0 +1,277 @@
+//! Threshold Count Algorithm Specification
+//!
+//! Implements the `AlgorithmSpec` trait for counting nodes above a property
+//! threshold. See the module documentation for how each hook maps onto the
+//! executor lifecycle.
+
+use crate::projection::eval::procedure::{
+    get_required_param, parse_config, AfterLoadValidator, AlgorithmConfig, AlgorithmError,
+    AlgorithmSpec, ComputationResult, ConfigError, ConsumerError, ExecutionContext, ExecutionMode,
+    LogLevel, ProjectionHint, RequiredParameterValidator, ValidationConfiguration, ValidationError,
+};
+use crate::types::prelude::{DefaultGraphStore, GraphStore};
+use serde_json::{json, Value as JsonValue};
+use std::time::Instant;
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// Threshold Count Configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThresholdCountConfig {
+    /// Numeric node property to compare
+    pub property_key: String,
+    /// Nodes with a value strictly greater than this are counted
+    pub threshold: f64,
+}
+
+impl AlgorithmConfig for ThresholdCountConfig {
+    fn validation() -> ValidationConfiguration {
+        ValidationConfiguration::new()
+            .add_before_load(RequiredParameterValidator::new("property_key"))
+            .add_before_load(RequiredParameterValidator::new("threshold"))
+    }
+
+    fn from_json(json: &JsonValue) -> Result<Self, ConfigError> {
+        Ok(Self {
+            property_key: get_required_param(json, "property_key")?,
+            threshold: get_required_param(json, "threshold")?,
+        })
+    }
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+/// Checks that the configured `property_key` exists on the loaded graph.
+///
+/// `PropertyExistsValidator` needs the key up front, but the key is only
+/// known once the user config is parsed, so this validator reads it from
+/// the config the executor passes in.
+struct ConfiguredPropertyExistsValidator;
+
+impl AfterLoadValidator for ConfiguredPropertyExistsValidator {
+    fn validate(
+        &self,
+        graph_store: &DefaultGraphStore,
+        config: &JsonValue,
+    ) -> Result<(), ValidationError> {
+        let property_key = config
+            .get("property_key")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| ValidationError::MissingParameter("property_key".to_string()))?;
+
+        if !graph_store.has_node_property(property_key) {
+            return Err(ValidationError::PropertyNotFound(property_key.to_string()));
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "ConfiguredPropertyExistsValidator"
+    }
+}
+
+// ============================================================================
+// Algorithm Specification
+// ============================================================================
+
+/// Threshold Count Algorithm Specification
+///
+/// Output is the number of nodes whose property value exceeds the threshold.
+pub struct ThresholdCountAlgorithmSpec {
+    /// Name of the graph to load
+    graph_name: String,
+}
+
+impl ThresholdCountAlgorithmSpec {
+    /// Create a new threshold count specification for the given graph
+    pub fn new(graph_name: String) -> Self {
+        Self { graph_name }
+    }
+}
+
+impl AlgorithmSpec for ThresholdCountAlgorithmSpec {
+    type Output = u64;
+
+    fn name(&self) -> &str {
+        "threshold_count"
+    }
+
+    fn graph_name(&self) -> &str {
+        &self.graph_name
+    }
+
+    /// A single sequential scan over one property
+    fn projection_hint(&self) -> ProjectionHint {
+        ProjectionHint::Dense
+    }
+
+    /// Parse into the typed config, then hand the normalized JSON to the executor
+    fn parse_config(&self, input: &JsonValue) -> Result<JsonValue, ConfigError> {
+        let config: ThresholdCountConfig = parse_config(input)?;
+
+        Ok(json!({
+            "property_key": config.property_key,
+            "threshold": config.threshold,
+        }))
+    }
+
+    fn validation_config(&self, _context: &ExecutionContext) -> ValidationConfiguration {
+        ValidationConfiguration::new().add_after_load(ConfiguredPropertyExistsValidator)
+    }
+
+    fn execute<G: GraphStore>(
+        &self,
+        graph_store: &G,
+        config: &JsonValue,
+        context: &ExecutionContext,
+    ) -> Result<ComputationResult<Self::Output>, AlgorithmError> {
+        // The config was normalized by parse_config, so rebuilding it cannot fail
+        // unless execute is called outside the executor.
+        let config = ThresholdCountConfig::from_json(config)
+            .map_err(|e| AlgorithmError::Execution(e.to_string()))?;
+
+        let timer = Instant::now();
+        let values = graph_store
+            .node_property_values(&config.property_key)
+            .map_err(|e| AlgorithmError::Graph(e.to_string()))?;
+
+        let mut count = 0u64;
+        for node_id in 0..graph_store.node_count() as u64 {
+            let value = values
+                .double_value(node_id)
+                .map_err(|e| AlgorithmError::Execution(e.to_string()))?;
+            if value > config.threshold {
+                count += 1;
+            }
+        }
+
+        context.log(
+            LogLevel::Info,
+            &format!(
+                "{} of {} nodes have '{}' > {}",
+                count,
+                graph_store.node_count(),
+                config.property_key,
+                config.threshold
+            ),
+        );
+
+        Ok(ComputationResult::new(count, timer.elapsed()))
+    }
+
+    fn consume_result(
+        &self,
+        result: ComputationResult<Self::Output>,
+        mode: &ExecutionMode,
+    ) -> Result<Self::Output, ConsumerError> {
+        match mode {
+            ExecutionMode::Stream | ExecutionMode::Stats => Ok(result.into_result()),
+            other => Err(ConsumerError::UnsupportedMode(*other)),
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projection::eval::procedure::{ExecutorError, ProcedureExecutor};
+    use crate::types::prelude::RandomGraphConfig;
+    use std::sync::Arc;
+
+    fn executor(mode: ExecutionMode) -> ProcedureExecutor {
+        let config = RandomGraphConfig {
+            node_count: 6,
+            ..RandomGraphConfig::default()
+        }
+        .with_seed(42);
+        let mut store = DefaultGraphStore::random(&config).unwrap();
+        store
+            .add_node_property_f64("score".to_string(), vec![0.2, 0.7, 0.5, 0.9, 0.1, 0.51])
+            .unwrap();
+
+        ProcedureExecutor::new(ExecutionContext::mock(Arc::new(store)), mode)
+    }
+
+    #[test]
+    fn test_stream_counts_nodes_above_threshold() {
+        let mut executor = executor(ExecutionMode::Stream);
+        let mut spec = ThresholdCountAlgorithmSpec::new("test_graph".to_string());
+
+        let count = executor
+            .compute(
+                &mut spec,
+                &json!({"property_key": "score", "threshold": 0.5}),
+            )
+            .unwrap();
+
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn test_example_counts_scores_above_threshold() {
+        let config = RandomGraphConfig {
+            node_count: 4,
+            ..RandomGraphConfig::default()
+        }
+        .with_seed(7);
+        let mut store = DefaultGraphStore::random(&config).unwrap();
+        store
+            .add_node_property_f64("score".to_string(), vec![0.1, 0.6, 0.9, 0.5])
+            .unwrap();
+
+        let mut executor = ProcedureExecutor::new(
+            ExecutionContext::mock(Arc::new(store)),
+            ExecutionMode::Stream,
+        );
+        let mut spec = ThresholdCountAlgorithmSpec::new("test_graph".to_string());
+
+        let count = executor
+            .compute(&mut spec, &json!({"property_key": "score", "threshold": 0.5}))
+            .unwrap();
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_missing_threshold_is_rejected() {
+        let mut executor = executor(ExecutionMode::Stream);
+        let mut spec = ThresholdCountAlgorithmSpec::new("test_graph".to_string());
+
+        let result = executor.compute(&mut spec, &json!({"property_key": "score"}));
+
+        assert!(matches!(
+            result,
+            Err(ExecutorError::Config(ConfigError::MissingParameter(param))) if param == "threshold"
+        ));
+    }
+
+    #[test]
+    fn test_unknown_property_fails_after_load() {
+        let mut executor = executor(ExecutionMode::Stream);
+        let mut spec = ThresholdCountAlgorithmSpec::new("test_graph".to_string());
+
+        let result = executor.compute(&mut spec, &json!({"property_key": "age", "threshold": 1}));
+
+        assert!(matches!(
+            result,
+            Err(ExecutorError::Validation(ValidationError::PropertyNotFound(key))) if key == "age"
+        ));
+    }
+
+    #[test]
+    fn test_write_mode_is_unsupported() {
+        let mut executor = executor(ExecutionMode::WriteNodeProperty);
+        let mut spec = ThresholdCountAlgorithmSpec::new("test_graph".to_string());
+
+        let result = executor.compute(&mut spec, &json!({"property_key": "score", "threshold": 0}));
+
+        assert!(matches!(result, Err(ExecutorError::Consumer(_))));
+    }
+}
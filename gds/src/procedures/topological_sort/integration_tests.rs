@@ -0,0 +1,79 @@
+//! Topological Sort Integration Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::procedures::topological_sort::computation::{
+        TopologicalSortComputationRuntime, TopologicalSortError,
+    };
+    use std::collections::HashMap;
+
+    fn create_graph(edges: &[(usize, usize)], node_count: usize) -> HashMap<usize, Vec<usize>> {
+        let mut graph = HashMap::new();
+        for i in 0..node_count {
+            graph.insert(i, Vec::new());
+        }
+        for &(from, to) in edges {
+            graph.entry(from).or_insert_with(Vec::new).push(to);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_order_respects_all_edges() {
+        // Build pipeline: 5 -> 2 -> 3 -> 1, 5 -> 0, 4 -> 0, 4 -> 1, 3 -> 6
+        let edges = [(5, 2), (2, 3), (3, 1), (5, 0), (4, 0), (4, 1), (3, 6)];
+        let graph = create_graph(&edges, 7);
+
+        let mut runtime = TopologicalSortComputationRuntime::new();
+        let result = runtime
+            .compute(7, |node| graph.get(&node).cloned().unwrap_or_default())
+            .unwrap();
+
+        assert_eq!(result.order.len(), 7);
+        let mut position = [0; 7];
+        for (index, &node) in result.order.iter().enumerate() {
+            position[node as usize] = index;
+        }
+        for &(from, to) in &edges {
+            assert!(
+                position[from] < position[to],
+                "{} must come before {}",
+                from,
+                to
+            );
+        }
+    }
+
+    #[test]
+    fn test_cycle_is_detected() {
+        // 0 -> 1 -> 2 -> 3 -> 1, plus 3 -> 4 downstream of the cycle
+        let graph = create_graph(&[(0, 1), (1, 2), (2, 3), (3, 1), (3, 4)], 5);
+
+        let mut runtime = TopologicalSortComputationRuntime::new();
+        let error = runtime
+            .compute(5, |node| graph.get(&node).cloned().unwrap_or_default())
+            .unwrap_err();
+
+        let TopologicalSortError::Cycle { cycle } = error;
+        let mut nodes = cycle.clone();
+        nodes.sort_unstable();
+        assert_eq!(nodes, vec![1, 2, 3]);
+        for (index, &node) in cycle.iter().enumerate() {
+            let next = cycle[(index + 1) % cycle.len()] as usize;
+            assert!(graph[&(node as usize)].contains(&next));
+        }
+    }
+
+    #[test]
+    fn test_self_loop_is_a_cycle() {
+        let graph = create_graph(&[(0, 1), (1, 1)], 2);
+
+        let mut runtime = TopologicalSortComputationRuntime::new();
+        let result = runtime.compute(2, |node| graph.get(&node).cloned().unwrap_or_default());
+
+        assert_eq!(
+            result.unwrap_err(),
+            TopologicalSortError::Cycle { cycle: vec![1] }
+        );
+    }
+}
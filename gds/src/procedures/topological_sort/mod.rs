@@ -0,0 +1,16 @@
+//! Topological Sort Algorithm
+//!
+//! **Translation Source**: `org.neo4j.gds.dag.topologicalsort.TopologicalSort`
+//!
+//! Orders the nodes of a directed acyclic graph so that every relationship
+//! points from an earlier to a later node. Used for dependency graphs and
+//! pipeline scheduling. Cyclic graphs are rejected with one offending cycle.
+
+pub mod spec;
+pub mod storage;
+pub mod computation;
+pub mod integration_tests;
+
+pub use spec::{TopologicalSortAlgorithmSpec, TopologicalSortConfig, TopologicalSortResult};
+pub use storage::TopologicalSortStorageRuntime;
+pub use computation::{TopologicalSortComputationRuntime, TopologicalSortError};
@@ -0,0 +1,33 @@
+//! Topological Sort Algorithm Specification
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopologicalSortConfig {
+    pub concurrency: usize,
+}
+
+impl Default for TopologicalSortConfig {
+    fn default() -> Self {
+        Self { concurrency: 4 }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopologicalSortResult {
+    /// Node ids such that every relationship goes from an earlier to a later node
+    pub order: Vec<u64>,
+}
+
+pub struct TopologicalSortAlgorithmSpec {
+    graph_name: String,
+}
+
+impl TopologicalSortAlgorithmSpec {
+    pub fn new(graph_name: String) -> Self {
+        Self { graph_name }
+    }
+
+    pub fn graph_name(&self) -> &str {
+        &self.graph_name
+    }
+}
@@ -0,0 +1,15 @@
+//! Topological Sort Storage Runtime
+
+pub struct TopologicalSortStorageRuntime {
+    concurrency: usize,
+}
+
+impl TopologicalSortStorageRuntime {
+    pub fn new(concurrency: usize) -> Self {
+        Self { concurrency }
+    }
+
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+}
@@ -5,6 +5,7 @@
 //! This module implements the core traversal infrastructure from Java GDS,
 //! including ExitPredicate, Aggregator, and related utilities.
 
+use crate::core::utils::NodeSet;
 use serde::{Deserialize, Serialize};
 
 /// Exit predicate result for traversal control
@@ -54,19 +55,26 @@ impl ExitPredicate for FollowExitPredicate {
 /// Translation of: `TargetExitPredicate.java` (lines 24-33)
 /// Terminates traversal when target nodes are reached
 pub struct TargetExitPredicate {
-    targets: Vec<u32>,
+    targets: NodeSet,
 }
 
 impl TargetExitPredicate {
     /// Create new target exit predicate
     pub fn new(targets: Vec<u32>) -> Self {
+        Self {
+            targets: targets.into_iter().map(u64::from).collect(),
+        }
+    }
+
+    /// Create target exit predicate from an existing node set
+    pub fn from_node_set(targets: NodeSet) -> Self {
         Self { targets }
     }
 }
 
 impl ExitPredicate for TargetExitPredicate {
     fn test(&self, _source_node: u32, current_node: u32, _weight_at_source: f64) -> ExitPredicateResult {
-        if self.targets.contains(&current_node) {
+        if self.targets.contains(current_node as u64) {
             ExitPredicateResult::Break
         } else {
             ExitPredicateResult::Follow
@@ -5,12 +5,15 @@
 //! Counts triangles using set intersection on adjacency lists.
 //! For each node, we check if common neighbors exist to form triangles.
 
+use super::spec::TriangleWeightCombination;
 use std::collections::HashMap;
 
 #[derive(Clone)]
 pub struct TriangleCountComputationResult {
     pub local_triangles: Vec<u64>,
     pub global_triangles: u64,
+    pub weighted_local_triangles: Option<Vec<f64>>,
+    pub weighted_global_triangles: Option<f64>,
 }
 
 pub struct TriangleCountComputationRuntime;
@@ -65,6 +68,61 @@ impl TriangleCountComputationRuntime {
         TriangleCountComputationResult {
             local_triangles,
             global_triangles,
+            weighted_local_triangles: None,
+            weighted_global_triangles: None,
+        }
+    }
+
+    /// Compute triangle counts together with weighted triangle counts
+    ///
+    /// Each triangle (u, v, w) adds `combination.combine(w_uv, w_vw, w_uw)` to
+    /// the weighted count of its three nodes and to the global weighted count.
+    /// Parallel edges keep the last weight reported by `get_neighbors`.
+    pub fn compute_weighted(
+        &mut self,
+        node_count: usize,
+        get_neighbors: impl Fn(usize) -> Vec<(usize, f64)>,
+        combination: TriangleWeightCombination,
+    ) -> TriangleCountComputationResult {
+        let mut local_triangles = vec![0u64; node_count];
+        let mut global_triangles = 0u64;
+        let mut weighted_local = vec![0.0f64; node_count];
+        let mut weighted_global = 0.0f64;
+
+        let mut adjacency: HashMap<usize, HashMap<usize, f64>> = HashMap::new();
+        for node in 0..node_count {
+            adjacency.insert(node, get_neighbors(node).into_iter().collect());
+        }
+
+        for u in 0..node_count {
+            let u_neighbors = &adjacency[&u];
+            for (&v, &w_uv) in u_neighbors {
+                if v <= u {
+                    continue;
+                }
+
+                for (&w, &w_vw) in &adjacency[&v] {
+                    if w <= v {
+                        continue;
+                    }
+                    if let Some(&w_uw) = u_neighbors.get(&w) {
+                        let contribution = combination.combine(w_uv, w_vw, w_uw);
+                        for node in [u, v, w] {
+                            local_triangles[node] += 1;
+                            weighted_local[node] += contribution;
+                        }
+                        global_triangles += 1;
+                        weighted_global += contribution;
+                    }
+                }
+            }
+        }
+
+        TriangleCountComputationResult {
+            local_triangles,
+            global_triangles,
+            weighted_local_triangles: Some(weighted_local),
+            weighted_global_triangles: Some(weighted_global),
         }
     }
 }
@@ -175,4 +175,82 @@ mod tests {
             assert_eq!(result.local_triangles[i], 1);
         }
     }
+
+    #[test]
+    fn test_weighted_single_triangle() {
+        use crate::procedures::triangle_count::TriangleWeightCombination;
+
+        // Triangle 0-1-2 with weights 1, 2, 3
+        let weights = [(0, 1, 1.0), (1, 2, 2.0), (2, 0, 3.0)];
+        let mut graph: HashMap<usize, Vec<(usize, f64)>> = HashMap::new();
+        for (from, to, weight) in weights {
+            graph.entry(from).or_default().push((to, weight));
+            graph.entry(to).or_default().push((from, weight));
+        }
+        let neighbors = |node: usize| graph.get(&node).cloned().unwrap_or_default();
+
+        let mut runtime = TriangleCountComputationRuntime::new();
+        let product = runtime.compute_weighted(3, neighbors, TriangleWeightCombination::Product);
+        assert_eq!(product.global_triangles, 1);
+        assert_eq!(product.weighted_global_triangles, Some(6.0));
+        assert_eq!(product.weighted_local_triangles, Some(vec![6.0; 3]));
+
+        let mean = runtime.compute_weighted(3, neighbors, TriangleWeightCombination::GeometricMean);
+        let expected = 6.0f64.cbrt();
+        assert!((mean.weighted_global_triangles.unwrap() - expected).abs() < 1e-12);
+        assert!((expected - 1.817).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_storage_runs_configured_weighted_count() {
+        use crate::procedures::facades::Graph;
+        use crate::procedures::triangle_count::{
+            TriangleCountConfig, TriangleCountStorageRuntime, TriangleWeightCombination,
+        };
+
+        // Triangle 0-1-2 with weights 1, 2, 3, stored in both directions
+        let mut edges = Vec::new();
+        for (from, to, weight) in [(0, 1, 1.0), (1, 2, 2.0), (2, 0, 3.0)] {
+            edges.push((from, to, weight));
+            edges.push((to, from, weight));
+        }
+        let graph = Graph::from_weighted_edge_list(&edges);
+        let mut runtime = TriangleCountComputationRuntime::new();
+
+        let unweighted = TriangleCountStorageRuntime::from_config(&TriangleCountConfig::default())
+            .compute_triangle_count(&mut runtime, graph.store())
+            .unwrap();
+        assert_eq!(unweighted.global_triangles, 1);
+        assert_eq!(unweighted.weighted_global_triangles, None);
+
+        let config = TriangleCountConfig {
+            weighted: true,
+            relationship_weight_property: Some("weight".to_string()),
+            weight_combination: TriangleWeightCombination::Product,
+            ..TriangleCountConfig::default()
+        };
+        let weighted = TriangleCountStorageRuntime::from_config(&config)
+            .compute_triangle_count(&mut runtime, graph.store())
+            .unwrap();
+        assert_eq!(weighted.global_triangles, 1);
+        assert_eq!(weighted.weighted_global_triangles, Some(6.0));
+        assert_eq!(weighted.weighted_local_triangles, Some(vec![6.0; 3]));
+
+        let unit = TriangleCountConfig {
+            relationship_weight_property: None,
+            ..config.clone()
+        };
+        let unit = TriangleCountStorageRuntime::from_config(&unit)
+            .compute_triangle_count(&mut runtime, graph.store())
+            .unwrap();
+        assert_eq!(unit.weighted_global_triangles, Some(1.0));
+
+        let missing = TriangleCountConfig {
+            relationship_weight_property: Some("missing".to_string()),
+            ..config
+        };
+        assert!(TriangleCountStorageRuntime::from_config(&missing)
+            .compute_triangle_count(&mut runtime, graph.store())
+            .is_err());
+    }
 }
@@ -7,6 +7,6 @@ pub mod storage;
 pub mod computation;
 pub mod integration_tests;
 
-pub use spec::{TriangleCountAlgorithmSpec, TriangleCountConfig, TriangleCountResult};
+pub use spec::{TriangleCountAlgorithmSpec, TriangleCountConfig, TriangleCountResult, TriangleWeightCombination};
 pub use storage::TriangleCountStorageRuntime;
 pub use computation::TriangleCountComputationRuntime;
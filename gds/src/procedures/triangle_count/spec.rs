@@ -5,6 +5,15 @@ use serde::{Deserialize, Serialize};
 pub struct TriangleCountConfig {
     pub concurrency: usize,
     pub max_degree: u64,
+    /// Also compute the weighted triangle count from edge weights
+    #[serde(default)]
+    pub weighted: bool,
+    /// Relationship property used as edge weight; `None` treats every edge as weight 1.0
+    #[serde(default)]
+    pub relationship_weight_property: Option<String>,
+    /// How the three edge weights of a triangle combine into its contribution
+    #[serde(default)]
+    pub weight_combination: TriangleWeightCombination,
 }
 
 impl Default for TriangleCountConfig {
@@ -12,6 +21,30 @@ impl Default for TriangleCountConfig {
         Self {
             concurrency: 4,
             max_degree: u64::MAX,
+            weighted: false,
+            relationship_weight_property: None,
+            weight_combination: TriangleWeightCombination::default(),
+        }
+    }
+}
+
+/// Contribution of a weighted triangle, from its three edge weights.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TriangleWeightCombination {
+    /// `w1 * w2 * w3`
+    #[default]
+    Product,
+    /// `(w1 * w2 * w3)^(1/3)`, which stays on the scale of a single weight
+    GeometricMean,
+}
+
+impl TriangleWeightCombination {
+    /// Combines the weights of a triangle's three edges.
+    pub fn combine(self, w1: f64, w2: f64, w3: f64) -> f64 {
+        let product = w1 * w2 * w3;
+        match self {
+            Self::Product => product,
+            Self::GeometricMean => product.cbrt(),
         }
     }
 }
@@ -20,6 +53,12 @@ impl Default for TriangleCountConfig {
 pub struct TriangleCountResult {
     pub local_triangles: Vec<u64>,
     pub global_triangles: u64,
+    /// Per-node sum of triangle contributions; set only for weighted runs
+    #[serde(default)]
+    pub weighted_local_triangles: Option<Vec<f64>>,
+    /// Sum of all triangle contributions; set only for weighted runs
+    #[serde(default)]
+    pub weighted_global_triangles: Option<f64>,
 }
 
 pub struct TriangleCountAlgorithmSpec {
@@ -1,9 +1,119 @@
+//! Triangle Count Storage Runtime
+//!
+//! Reads neighbors (and, for weighted runs, edge weights) from the graph
+//! store and hands them to the computation runtime.
+
+use super::computation::TriangleCountComputationRuntime;
+use super::spec::{TriangleCountConfig, TriangleCountResult, TriangleWeightCombination};
+use crate::projection::eval::procedure::AlgorithmError;
+use crate::types::graph::Graph;
+use crate::types::prelude::{DefaultGraphStore, GraphStore};
+use crate::types::properties::relationship::traits::RelationshipIterator as _;
+use std::collections::HashMap;
+use std::sync::Arc;
+
 pub struct TriangleCountStorageRuntime {
     concurrency: usize,
+    weighted: bool,
+    relationship_weight_property: Option<String>,
+    weight_combination: TriangleWeightCombination,
 }
 
 impl TriangleCountStorageRuntime {
     pub fn new(concurrency: usize) -> Self {
-        Self { concurrency }
+        Self {
+            concurrency,
+            weighted: false,
+            relationship_weight_property: None,
+            weight_combination: TriangleWeightCombination::default(),
+        }
+    }
+
+    /// Create a storage runtime with the weight settings of `config`
+    pub fn from_config(config: &TriangleCountConfig) -> Self {
+        Self {
+            concurrency: config.concurrency,
+            weighted: config.weighted,
+            relationship_weight_property: config.relationship_weight_property.clone(),
+            weight_combination: config.weight_combination,
+        }
+    }
+
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Count triangles over all relationships of `graph_store`
+    ///
+    /// The relationships are expected to be stored in both directions, as in
+    /// an undirected projection. Weighted runs read the configured
+    /// `relationship_weight_property`, or use weight 1.0 for every edge if
+    /// none is configured.
+    pub fn compute_triangle_count(
+        &self,
+        computation: &mut TriangleCountComputationRuntime,
+        graph_store: &DefaultGraphStore,
+    ) -> Result<TriangleCountResult, AlgorithmError> {
+        let node_count = graph_store.node_count();
+        let result = if self.weighted {
+            let graph = self.weighted_graph(graph_store)?;
+            let use_property = self.relationship_weight_property.is_some();
+            computation.compute_weighted(
+                node_count,
+                |node| {
+                    graph
+                        .stream_relationships(node as i64, 1.0)
+                        .map(|cursor| {
+                            let weight = if use_property { cursor.property() } else { 1.0 };
+                            (cursor.target_id() as usize, weight)
+                        })
+                        .collect()
+                },
+                self.weight_combination,
+            )
+        } else {
+            let graph = graph_store.graph();
+            computation.compute(node_count, |node| {
+                graph
+                    .stream_relationships(node as i64, 1.0)
+                    .map(|cursor| cursor.target_id() as usize)
+                    .collect()
+            })
+        };
+
+        Ok(TriangleCountResult {
+            local_triangles: result.local_triangles,
+            global_triangles: result.global_triangles,
+            weighted_local_triangles: result.weighted_local_triangles,
+            weighted_global_triangles: result.weighted_global_triangles,
+        })
+    }
+
+    /// Graph view whose relationship property is the configured weight
+    fn weighted_graph(
+        &self,
+        graph_store: &DefaultGraphStore,
+    ) -> Result<Arc<dyn Graph>, AlgorithmError> {
+        let types = graph_store.relationship_types();
+        let Some(key) = &self.relationship_weight_property else {
+            return graph_store
+                .get_graph_with_types_and_selectors(&types, &HashMap::new())
+                .map_err(|e| AlgorithmError::Graph(e.to_string()));
+        };
+
+        let selectors: HashMap<_, _> = types
+            .iter()
+            .filter(|rel_type| graph_store.has_relationship_property(rel_type, key))
+            .map(|rel_type| (rel_type.clone(), key.clone()))
+            .collect();
+        if selectors.is_empty() {
+            return Err(AlgorithmError::Graph(format!(
+                "relationship property {} not found",
+                key
+            )));
+        }
+        graph_store
+            .get_graph_with_types_and_selectors(&types, &selectors)
+            .map_err(|e| AlgorithmError::Graph(e.to_string()))
     }
 }
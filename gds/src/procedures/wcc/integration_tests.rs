@@ -3,6 +3,7 @@
 #[cfg(test)]
 mod tests {
     use crate::procedures::wcc::computation::WccComputationRuntime;
+    use crate::procedures::wcc::spec::WccResult;
     use std::collections::HashMap;
 
     fn create_graph(edges: Vec<(usize, usize)>, node_count: usize) -> HashMap<usize, Vec<usize>> {
@@ -149,4 +150,67 @@ mod tests {
         // Should have 3 components
         assert_eq!(result.component_count, 3);
     }
+
+    #[test]
+    fn test_component_size_distribution() {
+        // Components {0, 1, 2}, {3, 4} and {5}
+        let edges = vec![(0, 1), (1, 2), (3, 4)];
+        let graph = create_graph(edges, 6);
+
+        let mut runtime = WccComputationRuntime::new();
+        let computed = runtime.compute(6, |node| graph.get(&node).cloned().unwrap_or_default());
+        let result = WccResult {
+            components: computed.components,
+            component_count: computed.component_count,
+        };
+
+        let sizes = result.component_sizes();
+        assert_eq!(sizes.len(), 3);
+        assert_eq!(sizes[&result.components[0]], 3);
+        assert_eq!(sizes[&result.components[3]], 2);
+        assert_eq!(sizes[&result.components[5]], 1);
+        assert_eq!(result.largest_component_size(), 3);
+        assert_eq!(result.component_count(), 3);
+    }
+
+    #[test]
+    fn test_mutate_writes_component_of_every_node() {
+        use crate::procedures::wcc::spec::WCCAlgorithmSpec;
+        use crate::projection::eval::procedure::{ExecutionContext, ExecutionMode, ProcedureExecutor};
+        use crate::types::prelude::GraphStore;
+        use crate::types::random::generators::typed_store;
+        use crate::types::schema::Direction;
+        use std::sync::Arc;
+
+        // Components {0, 1, 2}, {3, 4} and the isolated node 5
+        let store = Arc::new(typed_store(
+            "test_graph",
+            6,
+            [("REL", Direction::Directed, vec![(0, 1), (2, 1), (4, 3)])],
+        ));
+        let mut executor = ProcedureExecutor::new(
+            ExecutionContext::mock(Arc::clone(&store)),
+            ExecutionMode::MutateNodeProperty,
+        );
+        let mut algorithm = WCCAlgorithmSpec::new("test_graph".to_string());
+
+        let result = executor
+            .compute(&mut algorithm, &serde_json::json!({"concurrency": 1}))
+            .unwrap();
+
+        assert_eq!(result.component_count, 3);
+        assert_eq!(result.components[0], result.components[1]);
+        assert_eq!(result.components[1], result.components[2]);
+        assert_eq!(result.components[3], result.components[4]);
+        assert_ne!(result.components[0], result.components[3]);
+        assert_ne!(result.components[5], result.components[0]);
+        assert_ne!(result.components[5], result.components[3]);
+
+        let mutated = executor.context().load_graph("test_graph").unwrap();
+        let components = mutated.node_property_values("componentId").unwrap();
+        for (node_id, &component) in result.components.iter().enumerate() {
+            assert_eq!(components.long_value(node_id as u64).unwrap(), component as i64);
+        }
+        assert!(!store.has_node_property("componentId"));
+    }
 }
@@ -7,8 +7,10 @@
 pub mod spec;
 pub mod storage;
 pub mod computation;
+pub mod pregel_computation;
 pub mod integration_tests;
 
 pub use spec::{WccAlgorithmSpec, WccConfig, WccResult};
 pub use storage::WccStorageRuntime;
 pub use computation::WccComputationRuntime;
+pub use pregel_computation::WccPregelComputation;
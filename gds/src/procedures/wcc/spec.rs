@@ -1,12 +1,16 @@
 //! WCC Algorithm Specification
 use serde::{Deserialize, Serialize};
 use crate::define_algorithm_spec;
-use crate::projection::eval::procedure::{AlgorithmSpec, AlgorithmError};
+use crate::projection::eval::procedure::{
+    result_to_node_property_values, AlgorithmSpec, AlgorithmError, NodePropertyOutput,
+};
 use crate::projection::orientation::Orientation;
 use crate::projection::RelationshipType;
 use crate::types::prelude::GraphStore as _;
+use crate::types::ValueType;
 use super::storage::WccStorageRuntime;
 use super::computation::WccComputationRuntime;
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WccConfig {
@@ -25,6 +29,27 @@ pub struct WccResult {
     pub component_count: usize,
 }
 
+impl WccResult {
+    /// Number of nodes per component id
+    pub fn component_sizes(&self) -> HashMap<u64, usize> {
+        let mut sizes = HashMap::new();
+        for &component in &self.components {
+            *sizes.entry(component).or_insert(0) += 1;
+        }
+        sizes
+    }
+
+    /// Size of the largest component, 0 for an empty graph
+    pub fn largest_component_size(&self) -> usize {
+        self.component_sizes().into_values().max().unwrap_or(0)
+    }
+
+    /// Number of distinct components
+    pub fn component_count(&self) -> usize {
+        self.component_sizes().len()
+    }
+}
+
 pub struct WccAlgorithmSpec {
     graph_name: String,
 }
@@ -43,7 +68,17 @@ define_algorithm_spec! {
     name: "wcc",
     output_type: WccResult,
     projection_hint: Dense,
-    modes: [Stream, Stats],
+    modes: [Stream, Stats, MutateNodeProperty],
+    node_property_output: |result| {
+        let components: Vec<i64> = result.components.iter().map(|&c| c as i64).collect();
+        let values = result_to_node_property_values(components.into(), ValueType::Long)
+            .expect("Long component ids convert to Long node properties");
+        Some(NodePropertyOutput {
+            property_key: "componentId".to_string(),
+            value_type: ValueType::Long,
+            values,
+        })
+    },
     execute: |_self, graph_store, config_input, _context| {
         let parsed_config: WccConfig = serde_json::from_value(config_input.clone())
             .map_err(|e| AlgorithmError::InvalidGraph(format!("Failed to parse config: {}", e)))?;
@@ -26,6 +26,20 @@
 ///     }
 /// }
 /// ```
+///
+/// Algorithms supporting `MutateNodeProperty` declare the node property
+/// they produce with an optional `node_property_output` clause before
+/// `execute`, see `AlgorithmSpec::node_property_output`, or several
+/// properties with `node_property_outputs`:
+///
+/// ```rust,ignore
+///     node_property_output: |result| {
+///         Some(/* NodePropertyOutput */)
+///     },
+///     node_property_outputs: |result| {
+///         vec![/* NodePropertyOutput per property */]
+///     },
+/// ```
 #[macro_export]
 macro_rules! define_algorithm_spec {
     (
@@ -33,7 +47,9 @@ macro_rules! define_algorithm_spec {
         output_type: $output_type:ty,
         projection_hint: $hint:ident,
         modes: [$($mode:ident),*],
-        
+        $(node_property_output: |$output_result:ident| $output_fn:block,)?
+        $(node_property_outputs: |$outputs_result:ident| $outputs_fn:block,)?
+
         execute: |$self_param:ident, $graph_store_param:ident, $config_param:ident, $context_param:ident| $execute_fn:block
     ) => {
         paste::paste! {
@@ -107,6 +123,20 @@ macro_rules! define_algorithm_spec {
                         other => Err($crate::projection::eval::procedure::ConsumerError::UnsupportedMode(*other)),
                     }
                 }
+
+                $(
+                    fn node_property_output(
+                        &self,
+                        $output_result: &Self::Output,
+                    ) -> Option<$crate::projection::eval::procedure::NodePropertyOutput> $output_fn
+                )?
+
+                $(
+                    fn node_property_outputs(
+                        &self,
+                        $outputs_result: &Self::Output,
+                    ) -> Vec<$crate::projection::eval::procedure::NodePropertyOutput> $outputs_fn
+                )?
             }
         }
     };
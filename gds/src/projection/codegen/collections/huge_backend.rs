@@ -102,7 +102,7 @@ macro_rules! huge_collections {
             }
             fn with_defaults(count: usize, default_value: $element_type) -> Self {
                 let mut huge = Self::new(count);
-                for i in 0..count { huge.set(i, default_value); }
+                huge.fill(default_value);
                 huge
             }
         }
@@ -161,7 +161,7 @@ macro_rules! huge_collections {
             fn with_capacity(capacity: usize) -> Self { Self::new(capacity) }
             fn from_vec(values: Vec<$element_type>) -> Self { let mut h = Self::new(values.len()); for (i,v) in values.into_iter().enumerate(){ h.set(i,v);} h }
             fn from_slice(slice: &[$element_type]) -> Self { let mut h = Self::new(slice.len()); for (i,&v) in slice.iter().enumerate(){ h.set(i,v);} h }
-            fn with_defaults(count: usize, default_value: $element_type) -> Self { let mut h = Self::new(count); for i in 0..count { h.set(i, default_value); } h }
+            fn with_defaults(count: usize, default_value: $element_type) -> Self { let mut h = Self::new(count); h.fill(default_value); h }
         }
     };
 
@@ -217,7 +217,7 @@ macro_rules! huge_collections {
             fn with_capacity(capacity: usize) -> Self { Self::new(capacity) }
             fn from_vec(values: Vec<$element_type>) -> Self { let mut h = Self::new(values.len()); for (i,v) in values.into_iter().enumerate(){ h.set(i,v);} h }
             fn from_slice(slice: &[$element_type]) -> Self { let mut h = Self::new(slice.len()); for (i,&v) in slice.iter().enumerate(){ h.set(i,v);} h }
-            fn with_defaults(count: usize, default_value: $element_type) -> Self { let mut h = Self::new(count); for i in 0..count { h.set(i, default_value); } h }
+            fn with_defaults(count: usize, default_value: $element_type) -> Self { let mut h = Self::new(count); h.fill(default_value); h }
         }
     };
 }
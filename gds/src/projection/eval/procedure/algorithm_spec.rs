@@ -12,11 +12,16 @@
 //! - Executor calls these methods to orchestrate algorithm execution
 
 use crate::types::prelude::GraphStore;
+use crate::types::properties::node::NodePropertyValues;
+use crate::types::ValueType;
 use serde_json::Value as JsonValue;
+use std::sync::Arc;
 use std::time::Duration;
 
 // Import types from the surrounding executor runtime module
-use super::{ComputationResult, ExecutionContext, ExecutionMode, ValidationConfiguration};
+use super::{
+    AccessMode, ComputationResult, ExecutionContext, ExecutionMode, ValidationConfiguration,
+};
 
 /// Algorithm Specification - Contract for algorithm implementations
 ///
@@ -77,6 +82,13 @@ pub trait AlgorithmSpec: Send + Sync {
         ProjectionHint::Auto
     }
 
+    /// How the algorithm reads the projected graph
+    ///
+    /// **New in rust-gds**: Costs the projections when the hint is `Auto`
+    fn access_mode(&self) -> AccessMode {
+        AccessMode::Iterative
+    }
+
     /// Pre-process configuration (optional enhancement)
     ///
     /// Translated from: `void preProcessConfig(Map<String, Object> userInput, ExecutionContext)`
@@ -147,6 +159,29 @@ pub trait AlgorithmSpec: Send + Sync {
         mode: &ExecutionMode,
     ) -> Result<Self::Output, ConsumerError>;
 
+    /// Node property produced for Mutate/Write node-property modes
+    ///
+    /// **New in rust-gds**: Round-trip check before consumption
+    ///
+    /// When this returns `Some`, the executor reads the values back with the
+    /// declared `ValueType` and checks that they cover every node of the graph.
+    /// This catches algorithms that return truncated result arrays.
+    /// Default: `None` (no verification).
+    fn node_property_output(&self, _result: &Self::Output) -> Option<NodePropertyOutput> {
+        None
+    }
+
+    /// All node properties produced for Mutate/Write node-property modes
+    ///
+    /// Override for algorithms with several outputs, such as HITS with hub
+    /// and authority scores. Every output is verified like
+    /// [`node_property_output`](Self::node_property_output), and in
+    /// `MutateNodeProperty` mode the executor stores all of them in the graph.
+    /// Default: the single `node_property_output`, if any.
+    fn node_property_outputs(&self, result: &Self::Output) -> Vec<NodePropertyOutput> {
+        self.node_property_output(result).into_iter().collect()
+    }
+
     /// Should release progress task? (default true)
     ///
     /// Translated from: `boolean releaseProgressTask()`
@@ -158,6 +193,20 @@ pub trait AlgorithmSpec: Send + Sync {
     }
 }
 
+/// Node Property Output - A result property declared for write-back
+///
+/// Returned by `AlgorithmSpec::node_property_output` so the executor can
+/// verify the values before they are mutated into or written to a graph.
+#[derive(Debug, Clone)]
+pub struct NodePropertyOutput {
+    /// Property key the values are written under
+    pub property_key: String,
+    /// Value type the algorithm declares for the property
+    pub value_type: ValueType,
+    /// The produced values, one per node
+    pub values: Arc<dyn NodePropertyValues>,
+}
+
 /// Projection Hint - Guides AdaptiveProjector storage selection
 ///
 /// **New in rust-gds**: Helps executor choose optimal storage backend
@@ -170,6 +219,8 @@ pub trait AlgorithmSpec: Send + Sync {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ProjectionHint {
     /// Let AdaptiveProjector decide based on graph density
+    ///
+    /// See [`choose_projection`](super::choose_projection).
     Auto,
 
     /// Prefer dense array storage (HugeArray)
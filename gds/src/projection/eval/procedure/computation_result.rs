@@ -5,6 +5,7 @@
 //!
 //! Wraps algorithm results with timing information and metadata.
 
+use super::ProjectionHint;
 use serde_json::Value as JsonValue;
 use std::time::Duration;
 
@@ -13,6 +14,7 @@ use std::time::Duration;
 /// Wraps the algorithm's raw result with:
 /// - Timing information (preprocess + compute)
 /// - Configuration used
+/// - Projection the algorithm ran on
 /// - Graph empty flag
 ///
 /// **Simplified from Java GDS**:
@@ -33,6 +35,9 @@ pub struct ComputationResult<R> {
     /// Configuration used (JSON)
     config: JsonValue,
 
+    /// Projection chosen by the executor (`Auto` until resolved)
+    projection: ProjectionHint,
+
     /// Whether the graph was empty (no computation happened)
     is_graph_empty: bool,
 }
@@ -45,6 +50,7 @@ impl<R> ComputationResult<R> {
             compute_time,
             preprocess_time: Duration::ZERO,
             config: JsonValue::Null,
+            projection: ProjectionHint::Auto,
             is_graph_empty: false,
         }
     }
@@ -84,6 +90,11 @@ impl<R> ComputationResult<R> {
         &self.config
     }
 
+    /// Get the projection the algorithm ran on
+    pub fn projection(&self) -> ProjectionHint {
+        self.projection
+    }
+
     /// Check if the graph was empty
     pub fn is_graph_empty(&self) -> bool {
         self.is_graph_empty
@@ -101,6 +112,12 @@ impl<R> ComputationResult<R> {
         self
     }
 
+    /// Builder: Set the projection
+    pub fn with_projection(mut self, projection: ProjectionHint) -> Self {
+        self.projection = projection;
+        self
+    }
+
     /// Builder: Mark graph as empty
     pub fn mark_graph_empty(mut self) -> Self {
         self.is_graph_empty = true;
@@ -117,6 +134,7 @@ impl<R> ComputationResult<R> {
             compute_time: self.compute_time,
             preprocess_time: self.preprocess_time,
             config: self.config,
+            projection: self.projection,
             is_graph_empty: self.is_graph_empty,
         }
     }
@@ -129,6 +147,7 @@ impl<R: Clone> Clone for ComputationResult<R> {
             compute_time: self.compute_time,
             preprocess_time: self.preprocess_time,
             config: self.config.clone(),
+            projection: self.projection,
             is_graph_empty: self.is_graph_empty,
         }
     }
@@ -140,6 +159,7 @@ impl<R: std::fmt::Debug> std::fmt::Debug for ComputationResult<R> {
             .field("result", &self.result)
             .field("compute_millis", &self.compute_millis())
             .field("preprocess_millis", &self.preprocess_millis())
+            .field("projection", &self.projection)
             .field("is_graph_empty", &self.is_graph_empty)
             .finish()
     }
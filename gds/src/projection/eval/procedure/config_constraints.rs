@@ -0,0 +1,179 @@
+//! Config Constraints - Declarative numeric constraints for algorithm configs
+//!
+//! Numeric parameters share a handful of rules: damping factors are
+//! probabilities, iteration counts are positive, tolerances lie in a range.
+//! `ConfigConstraints` declares them once per config instead of checking
+//! each field by hand:
+//!
+//! ```rust,ignore
+//! ConfigConstraints::new()
+//!     .probability("dampingFactor")
+//!     .positive("maxIterations")
+//!     .range("tolerance", 0.0, 1.0)
+//! ```
+//!
+//! `parse_config` checks `AlgorithmConfig::constraints()` before building the
+//! typed config. Absent fields are skipped; whether a field is required is
+//! decided by `RequiredParameterValidator`.
+
+use serde_json::Value as JsonValue;
+
+use super::ConfigError;
+
+/// A single numeric rule
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Constraint {
+    /// `min <= value <= max`
+    Range { min: f64, max: f64 },
+    /// `value > 0`
+    Positive,
+    /// `0 <= value <= 1`
+    Probability,
+}
+
+impl Constraint {
+    fn check(&self, value: f64) -> Result<(), String> {
+        match *self {
+            Constraint::Range { min, max } if !(min..=max).contains(&value) => {
+                Err(format!("value {} out of range [{}, {}]", value, min, max))
+            }
+            Constraint::Positive if value.is_nan() || value <= 0.0 => {
+                Err(format!("value {} must be positive", value))
+            }
+            Constraint::Probability if !(0.0..=1.0).contains(&value) => {
+                Err(format!("value {} is not a probability in [0, 1]", value))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Config Constraints - Builder of per-field numeric constraints
+#[derive(Debug, Clone, Default)]
+pub struct ConfigConstraints {
+    constraints: Vec<(String, Constraint)>,
+}
+
+impl ConfigConstraints {
+    /// Create an empty constraint set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require `min <= field <= max`
+    pub fn range(mut self, field: impl Into<String>, min: f64, max: f64) -> Self {
+        self.constraints
+            .push((field.into(), Constraint::Range { min, max }));
+        self
+    }
+
+    /// Require `field > 0`
+    pub fn positive(mut self, field: impl Into<String>) -> Self {
+        self.constraints.push((field.into(), Constraint::Positive));
+        self
+    }
+
+    /// Require `0 <= field <= 1`
+    pub fn probability(mut self, field: impl Into<String>) -> Self {
+        self.constraints
+            .push((field.into(), Constraint::Probability));
+        self
+    }
+
+    /// Whether no constraints are declared
+    pub fn is_empty(&self) -> bool {
+        self.constraints.is_empty()
+    }
+
+    /// All violations in declaration order
+    ///
+    /// Out-of-range values give `ConfigError::InvalidValue`, non-numeric
+    /// values give `ConfigError::TypeMismatch`; both name the field.
+    pub fn violations(&self, config: &JsonValue) -> Vec<ConfigError> {
+        self.constraints
+            .iter()
+            .filter_map(|(field, constraint)| {
+                let value = config.get(field).filter(|value| !value.is_null())?;
+                let Some(number) = value.as_f64() else {
+                    return Some(ConfigError::TypeMismatch {
+                        param: field.clone(),
+                        expected: "number".to_string(),
+                        actual: value.to_string(),
+                    });
+                };
+                constraint
+                    .check(number)
+                    .err()
+                    .map(|message| ConfigError::InvalidValue {
+                        param: field.clone(),
+                        message,
+                    })
+            })
+            .collect()
+    }
+
+    /// Validate a config, failing with the first violation
+    pub fn validate(&self, config: &JsonValue) -> Result<(), ConfigError> {
+        match self.violations(config).into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn pagerank_constraints() -> ConfigConstraints {
+        ConfigConstraints::new()
+            .probability("dampingFactor")
+            .positive("maxIterations")
+            .range("tolerance", 0.0, 1.0)
+    }
+
+    #[test]
+    fn test_pagerank_damping_factor() {
+        let constraints = pagerank_constraints();
+
+        assert!(constraints
+            .validate(&json!({"dampingFactor": 0.85, "maxIterations": 20}))
+            .is_ok());
+
+        let error = constraints
+            .validate(&json!({"dampingFactor": 1.5, "maxIterations": 20}))
+            .unwrap_err();
+        assert!(matches!(
+            &error,
+            ConfigError::InvalidValue { param, .. } if param == "dampingFactor"
+        ));
+        assert!(error.to_string().contains("dampingFactor"));
+    }
+
+    #[test]
+    fn test_violations_name_every_field() {
+        let violations = pagerank_constraints().violations(&json!({
+            "dampingFactor": -0.1,
+            "maxIterations": 0,
+            "tolerance": "small"
+        }));
+
+        let params: Vec<&str> = violations
+            .iter()
+            .map(|error| match error {
+                ConfigError::InvalidValue { param, .. } => param.as_str(),
+                ConfigError::TypeMismatch { param, .. } => param.as_str(),
+                other => panic!("unexpected error {:?}", other),
+            })
+            .collect();
+        assert_eq!(params, vec!["dampingFactor", "maxIterations", "tolerance"]);
+        assert!(matches!(violations[2], ConfigError::TypeMismatch { .. }));
+    }
+
+    #[test]
+    fn test_absent_fields_are_skipped() {
+        assert!(pagerank_constraints().validate(&json!({})).is_ok());
+        assert!(ConfigConstraints::new().is_empty());
+    }
+}
@@ -0,0 +1,162 @@
+//! Config Parser - Typed parsing of user JSON into algorithm configs
+//!
+//! Every algorithm has a `*Config` struct, but turning user JSON into one used
+//! to be ad hoc inside each `AlgorithmSpec::parse_config`. This module
+//! standardizes that step:
+//!
+//! 1. Run the config's before-load validators against the raw JSON
+//!    (`RangeValidator`, `RequiredParameterValidator`, ...)
+//! 2. Check the config's numeric `ConfigConstraints`
+//! 3. Build the typed config via `AlgorithmConfig::from_json`
+//!
+//! ```rust,ignore
+//! let config: PageRankConfig = parse_config(&json!({"maxIterations": 20}))?;
+//! ```
+
+use serde_json::Value as JsonValue;
+
+use super::{ConfigConstraints, ConfigError, ValidationConfiguration, ValidationError};
+
+/// Algorithm Config - A typed configuration parsed from user JSON
+///
+/// Implementors describe their per-field constraints in `validation()` and
+/// `constraints()`, and build themselves from the (already validated) JSON in
+/// `from_json()`.
+pub trait AlgorithmConfig: Sized {
+    /// Validators applied to the raw JSON before the config is built.
+    fn validation() -> ValidationConfiguration {
+        ValidationConfiguration::empty()
+    }
+
+    /// Numeric constraints checked after the validators.
+    fn constraints() -> ConfigConstraints {
+        ConfigConstraints::new()
+    }
+
+    /// Build the typed config from JSON.
+    fn from_json(json: &JsonValue) -> Result<Self, ConfigError>;
+}
+
+/// Parse user JSON into a typed algorithm config
+///
+/// Rejects non-object input, runs the config's validators and constraints,
+/// then builds it.
+pub fn parse_config<C: AlgorithmConfig>(json: &JsonValue) -> Result<C, ConfigError> {
+    if !json.is_object() {
+        return Err(ConfigError::Parse(format!(
+            "expected a JSON object, got {}",
+            json
+        )));
+    }
+
+    C::validation()
+        .validate_before_load(json)
+        .map_err(ConfigError::from)?;
+    C::constraints().validate(json)?;
+
+    C::from_json(json)
+}
+
+impl From<ValidationError> for ConfigError {
+    fn from(error: ValidationError) -> Self {
+        match error {
+            ValidationError::MissingParameter(param) => ConfigError::MissingParameter(param),
+            ValidationError::InvalidValue { param, message } => {
+                ConfigError::InvalidValue { param, message }
+            }
+            other => ConfigError::Parse(other.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projection::eval::procedure::{
+        get_optional_param, RangeValidator, RequiredParameterValidator,
+    };
+    use serde_json::json;
+
+    #[derive(Debug)]
+    struct ThresholdConfig {
+        threshold: f64,
+        label: String,
+    }
+
+    impl AlgorithmConfig for ThresholdConfig {
+        fn validation() -> ValidationConfiguration {
+            ValidationConfiguration::new()
+                .add_before_load(RequiredParameterValidator::new("threshold"))
+                .add_before_load(RangeValidator::new("threshold", 0.0, 1.0))
+        }
+
+        fn from_json(json: &JsonValue) -> Result<Self, ConfigError> {
+            Ok(Self {
+                threshold: get_optional_param(json, "threshold", 0.5)?,
+                label: get_optional_param(json, "label", "default".to_string())?,
+            })
+        }
+    }
+
+    #[test]
+    fn test_parse_valid_config() {
+        let config: ThresholdConfig =
+            parse_config(&json!({"threshold": 0.25, "label": "x"})).unwrap();
+        assert_eq!(config.threshold, 0.25);
+        assert_eq!(config.label, "x");
+    }
+
+    #[test]
+    fn test_parse_missing_required_parameter() {
+        let result = parse_config::<ThresholdConfig>(&json!({"label": "x"}));
+        assert!(matches!(result, Err(ConfigError::MissingParameter(p)) if p == "threshold"));
+    }
+
+    #[test]
+    fn test_parse_out_of_range_parameter() {
+        let result = parse_config::<ThresholdConfig>(&json!({"threshold": 2.0}));
+        assert!(
+            matches!(result, Err(ConfigError::InvalidValue { param, .. }) if param == "threshold")
+        );
+    }
+
+    #[test]
+    fn test_parse_type_mismatch() {
+        let result = parse_config::<ThresholdConfig>(&json!({"threshold": 0.1, "label": 3}));
+        assert!(matches!(result, Err(ConfigError::TypeMismatch { param, .. }) if param == "label"));
+    }
+
+    #[derive(Debug)]
+    struct DampedConfig {
+        damping_factor: f64,
+    }
+
+    impl AlgorithmConfig for DampedConfig {
+        fn constraints() -> ConfigConstraints {
+            ConfigConstraints::new().probability("dampingFactor")
+        }
+
+        fn from_json(json: &JsonValue) -> Result<Self, ConfigError> {
+            Ok(Self {
+                damping_factor: get_optional_param(json, "dampingFactor", 0.85)?,
+            })
+        }
+    }
+
+    #[test]
+    fn test_parse_checks_constraints() {
+        let config: DampedConfig = parse_config(&json!({"dampingFactor": 0.9})).unwrap();
+        assert_eq!(config.damping_factor, 0.9);
+
+        let result = parse_config::<DampedConfig>(&json!({"dampingFactor": 1.5}));
+        assert!(
+            matches!(result, Err(ConfigError::InvalidValue { param, .. }) if param == "dampingFactor")
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_non_object() {
+        let result = parse_config::<ThresholdConfig>(&json!([1, 2, 3]));
+        assert!(matches!(result, Err(ConfigError::Parse(_))));
+    }
+}
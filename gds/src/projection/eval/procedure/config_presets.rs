@@ -0,0 +1,204 @@
+//! Config Presets - Named, pre-filled algorithm configurations
+//!
+//! Users tend to pass the same parameters to the same algorithms over and
+//! over. A preset names a recommended parameter set; selecting it with
+//! `"preset": "<name>"` fills in every parameter the user did not set:
+//!
+//! ```rust,ignore
+//! // {"preset": "pagerank-accurate", "dampingFactor": 0.9}
+//! // becomes
+//! // {"maxIterations": 100, "tolerance": 1e-9, "dampingFactor": 0.9}
+//! ```
+//!
+//! User values always win over preset values. The executor applies presets
+//! before `AlgorithmSpec::preprocess_config`, so algorithms only ever see the
+//! merged configuration.
+
+use serde_json::{json, Map, Value as JsonValue};
+use std::collections::HashMap;
+
+use super::ConfigError;
+
+/// Config key selecting a preset
+pub const PRESET_KEY: &str = "preset";
+
+/// Config Presets - Registry of named configuration defaults
+///
+/// `ConfigPresets::default()` contains the built-in presets; use
+/// `ConfigPresets::new()` for an empty registry.
+#[derive(Debug, Clone)]
+pub struct ConfigPresets {
+    presets: HashMap<String, Map<String, JsonValue>>,
+}
+
+impl ConfigPresets {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self {
+            presets: HashMap::new(),
+        }
+    }
+
+    /// Create a registry with the built-in presets
+    ///
+    /// - `pagerank-fast`: 10 iterations, tolerance 1e-4
+    /// - `pagerank-accurate`: 100 iterations, tolerance 1e-9
+    pub fn builtin() -> Self {
+        let mut presets = Self::new();
+        presets
+            .register(
+                "pagerank-fast",
+                json!({"maxIterations": 10, "tolerance": 1e-4}),
+            )
+            .expect("built-in preset is an object");
+        presets
+            .register(
+                "pagerank-accurate",
+                json!({"maxIterations": 100, "tolerance": 1e-9}),
+            )
+            .expect("built-in preset is an object");
+        presets
+    }
+
+    /// Register (or replace) a preset
+    ///
+    /// Fails if `values` is not a JSON object.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        values: JsonValue,
+    ) -> Result<(), ConfigError> {
+        let name = name.into();
+        match values {
+            JsonValue::Object(values) => {
+                self.presets.insert(name, values);
+                Ok(())
+            }
+            other => Err(ConfigError::InvalidValue {
+                param: name,
+                message: format!("preset must be a JSON object, got {}", other),
+            }),
+        }
+    }
+
+    /// Get the values of a preset
+    pub fn get(&self, name: &str) -> Option<&Map<String, JsonValue>> {
+        self.presets.get(name)
+    }
+
+    /// Names of all registered presets, sorted
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.presets.keys().map(|name| name.as_str()).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Resolve the preset selected by `config`, if any
+    ///
+    /// Removes the `preset` key and adds every preset value the config does
+    /// not already contain. Configs without a `preset` key are left as is.
+    pub fn apply(&self, config: &mut JsonValue) -> Result<(), ConfigError> {
+        let Some(object) = config.as_object_mut() else {
+            return Ok(());
+        };
+        let Some(selected) = object.remove(PRESET_KEY) else {
+            return Ok(());
+        };
+
+        let name = selected.as_str().ok_or_else(|| ConfigError::TypeMismatch {
+            param: PRESET_KEY.to_string(),
+            expected: "string".to_string(),
+            actual: selected.to_string(),
+        })?;
+        let preset = self.get(name).ok_or_else(|| ConfigError::InvalidValue {
+            param: PRESET_KEY.to_string(),
+            message: format!(
+                "unknown preset '{}' (available: {})",
+                name,
+                self.names().join(", ")
+            ),
+        })?;
+
+        for (key, value) in preset {
+            object.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+        Ok(())
+    }
+}
+
+impl Default for ConfigPresets {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_fills_missing_values() {
+        let presets = ConfigPresets::default();
+        let mut config = json!({"preset": "pagerank-accurate", "dampingFactor": 0.9});
+
+        presets.apply(&mut config).unwrap();
+
+        assert_eq!(
+            config,
+            json!({"maxIterations": 100, "tolerance": 1e-9, "dampingFactor": 0.9})
+        );
+    }
+
+    #[test]
+    fn test_user_values_override_preset() {
+        let presets = ConfigPresets::default();
+        let mut config = json!({"preset": "pagerank-fast", "maxIterations": 3});
+
+        presets.apply(&mut config).unwrap();
+
+        assert_eq!(config["maxIterations"], 3);
+        assert_eq!(config["tolerance"], 1e-4);
+    }
+
+    #[test]
+    fn test_config_without_preset_is_unchanged() {
+        let presets = ConfigPresets::default();
+        let mut config = json!({"maxIterations": 7});
+
+        presets.apply(&mut config).unwrap();
+
+        assert_eq!(config, json!({"maxIterations": 7}));
+    }
+
+    #[test]
+    fn test_unknown_preset_lists_available() {
+        let presets = ConfigPresets::default();
+        let mut config = json!({"preset": "pagerank-turbo"});
+
+        let error = presets.apply(&mut config).unwrap_err();
+
+        assert!(matches!(
+            &error,
+            ConfigError::InvalidValue { param, message }
+                if param == "preset" && message.contains("pagerank-accurate, pagerank-fast")
+        ));
+    }
+
+    #[test]
+    fn test_register_custom_preset() {
+        let mut presets = ConfigPresets::new();
+        assert!(presets.names().is_empty());
+
+        presets
+            .register(
+                "louvain-weighted",
+                json!({"relationshipWeightProperty": "weight"}),
+            )
+            .unwrap();
+        assert!(presets.register("broken", json!([1, 2])).is_err());
+
+        let mut config = json!({"preset": "louvain-weighted"});
+        presets.apply(&mut config).unwrap();
+        assert_eq!(config, json!({"relationshipWeightProperty": "weight"}));
+    }
+}
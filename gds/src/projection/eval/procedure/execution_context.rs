@@ -104,6 +104,19 @@ impl ExecutionContext {
         self.graph_catalog.insert(name.into(), graph);
     }
 
+    /// Store `graph` under `name` where [`load_graph`](Self::load_graph) finds it
+    ///
+    /// Writes to the injected catalog if there is one, otherwise to the
+    /// internal map. A graph already stored under `name` is replaced.
+    pub fn store_graph(&mut self, name: &str, graph: Arc<DefaultGraphStore>) {
+        match &self.catalog_handle {
+            Some(catalog) => catalog.set(name, graph),
+            None => {
+                self.graph_catalog.insert(name.to_string(), graph);
+            }
+        }
+    }
+
     /// Remove graph from catalog
     pub fn remove_graph(&mut self, name: &str) -> Option<Arc<DefaultGraphStore>> {
         self.graph_catalog.remove(name)
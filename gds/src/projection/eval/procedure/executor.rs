@@ -26,15 +26,20 @@
 
 use crate::types::prelude::GraphStore;
 use serde_json::Value as JsonValue;
+use std::sync::Arc;
 use std::time::Instant;
 
 // Re-export from sibling modules and codegen
 use super::execution_context::ContextError;
 use super::validation_config::ValidationError;
 use super::{
-    AlgorithmError, AlgorithmSpec, ComputationResult, ConfigError, ConsumerError, ExecutionContext,
-    ExecutionMode, LogLevel,
+    mutate_multiple_node_properties, resolve_projection, AlgorithmError, AlgorithmSpec,
+    ComputationResult, ConfigError, ConfigPresets, ConsumerError, ExecutionContext, ExecutionMode,
+    LogLevel, MutateMode, NodeFilter, NodePropertyOutput,
 };
+use crate::core::graph_dimensions::ConcreteGraphDimensions;
+use crate::types::graph_store::GraphStoreError;
+use crate::types::ValueType;
 
 /// Procedure Executor - GDSL Runtime for algorithm execution
 ///
@@ -52,8 +57,9 @@ use super::{
 /// 3. validate_before_load() → Config-only validation
 /// 4. load_graph()           → Get GraphStore from catalog
 /// 5. validate_after_load()  → Config + graph validation
-/// 6. execute_algorithm()    → Run algorithm with timing
-/// 7. consume_result()       → Transform & validate output
+/// 6. resolve_projection()   → Cost-based choice for `ProjectionHint::Auto`
+/// 7. execute_algorithm()    → Run algorithm with timing
+/// 8. consume_result()       → Transform & validate output
 /// ```
 pub struct ProcedureExecutor {
     /// Execution context (graph catalog, logging, metrics)
@@ -61,6 +67,15 @@ pub struct ProcedureExecutor {
 
     /// Execution mode (Stream, Stats, Train, Write, Mutate)
     mode: ExecutionMode,
+
+    /// Named config defaults selected with `"preset"` (built-ins by default)
+    presets: ConfigPresets,
+
+    /// Nodes the algorithm is restricted to (all nodes if unset)
+    node_filter: Option<NodeFilter>,
+
+    /// How `MutateNodeProperty` treats existing properties
+    mutate_mode: MutateMode,
 }
 
 impl ProcedureExecutor {
@@ -70,7 +85,73 @@ impl ProcedureExecutor {
     /// - `context`: Runtime environment (catalog, logging, metrics)
     /// - `mode`: How to return results (Stream, Stats, etc.)
     pub fn new(context: ExecutionContext, mode: ExecutionMode) -> Self {
-        Self { context, mode }
+        Self {
+            context,
+            mode,
+            presets: ConfigPresets::default(),
+            node_filter: None,
+            mutate_mode: MutateMode::default(),
+        }
+    }
+
+    /// Replace the preset registry
+    pub fn with_presets(mut self, presets: ConfigPresets) -> Self {
+        self.presets = presets;
+        self
+    }
+
+    /// Get the preset registry
+    pub fn presets(&self) -> &ConfigPresets {
+        &self.presets
+    }
+
+    /// Get a mutable reference to the preset registry (to register presets)
+    pub fn presets_mut(&mut self) -> &mut ConfigPresets {
+        &mut self.presets
+    }
+
+    /// Restrict every algorithm run to the nodes passing `node_filter`
+    ///
+    /// The filter is applied to the loaded graph before validation and
+    /// execution. Runs starting from a rejected source node fail, see
+    /// [`NodeFilter`].
+    pub fn with_node_filter(mut self, node_filter: NodeFilter) -> Self {
+        self.node_filter = Some(node_filter);
+        self
+    }
+
+    /// Get the node filter, if any
+    pub fn node_filter(&self) -> Option<&NodeFilter> {
+        self.node_filter.as_ref()
+    }
+
+    /// Choose how `MutateNodeProperty` runs treat existing properties
+    ///
+    /// `MutateMode::Accumulate` adds the outputs to properties of the same
+    /// key, see [`mutate_node_property`](super::mutate_node_property). Default: `MutateMode::Replace`.
+    pub fn with_mutate_mode(mut self, mutate_mode: MutateMode) -> Self {
+        self.mutate_mode = mutate_mode;
+        self
+    }
+
+    /// Get the mutate mode
+    pub fn mutate_mode(&self) -> MutateMode {
+        self.mutate_mode
+    }
+
+    /// Preprocess user configuration
+    ///
+    /// Resolves a `"preset"` entry against the preset registry, then lets the
+    /// algorithm enhance the merged config. User values override preset values.
+    pub fn preprocess_config<A: AlgorithmSpec>(
+        &self,
+        algorithm: &mut A,
+        config_input: &JsonValue,
+    ) -> Result<JsonValue, ConfigError> {
+        let mut config = config_input.clone();
+        self.presets.apply(&mut config)?;
+        algorithm.preprocess_config(&mut config, &self.context)?;
+        Ok(config)
     }
 
     /// Execute an algorithm following the complete procedure lifecycle
@@ -109,10 +190,9 @@ impl ProcedureExecutor {
             &format!("Starting procedure: {} on graph: {}", algo_name, graph_name),
         );
 
-        // Step 1: Preprocess configuration (ML pipelines, model params, etc.)
+        // Step 1: Preprocess configuration (presets, ML pipelines, model params, etc.)
         let preprocess_start = Instant::now();
-        let mut config = config_input.clone();
-        algorithm.preprocess_config(&mut config, &self.context)?;
+        let config = self.preprocess_config(algorithm, config_input)?;
         let preprocess_time = preprocess_start.elapsed();
 
         self.context.log(
@@ -137,7 +217,19 @@ impl ProcedureExecutor {
 
         // Step 5: Load graph from catalog
         let load_start = Instant::now();
-        let graph_store = self.context.load_graph(&graph_name)?;
+        let mut graph_store = self.context.load_graph(&graph_name)?;
+        if let Some(node_filter) = &self.node_filter {
+            node_filter
+                .validate_source_nodes(&graph_store, &config)
+                .map_err(ExecutorError::NodeFilter)?;
+            graph_store = Arc::new(
+                node_filter
+                    .apply(&graph_store)
+                    .map_err(ExecutorError::NodeFilter)?,
+            );
+            self.context
+                .log(LogLevel::Debug, &format!("Applied {:?}", node_filter));
+        }
         let load_time = load_start.elapsed();
 
         self.context.log(
@@ -176,7 +268,21 @@ impl ProcedureExecutor {
         self.context
             .log(LogLevel::Debug, "After-load validation passed");
 
-        // Step 7: Execute algorithm with timing
+        // Step 7: Resolve the projection the algorithm runs on
+        let dimensions =
+            ConcreteGraphDimensions::of(graph_store.node_count(), graph_store.relationship_count());
+        let projection = resolve_projection(
+            algorithm.projection_hint(),
+            &dimensions,
+            algorithm.access_mode(),
+        );
+
+        self.context.log(
+            LogLevel::Debug,
+            &format!("Projection: {}", projection.description()),
+        );
+
+        // Step 8: Execute algorithm with timing
         let compute_start = Instant::now();
 
         self.context.log(
@@ -184,7 +290,9 @@ impl ProcedureExecutor {
             &format!("Executing algorithm: {}", algo_name),
         );
 
-        let computation_result = algorithm.execute(graph_store.as_ref(), &config, &self.context)?;
+        let computation_result = algorithm
+            .execute(graph_store.as_ref(), &config, &self.context)?
+            .with_projection(projection);
         let compute_time = compute_start.elapsed();
 
         self.context.log(
@@ -203,7 +311,27 @@ impl ProcedureExecutor {
             compute_time.as_millis() as u64,
         );
 
-        // Step 8: Consume result (transform, validate, return)
+        // Step 9: Verify node property outputs round-trip (Mutate/Write modes)
+        if matches!(
+            self.mode,
+            ExecutionMode::MutateNodeProperty | ExecutionMode::WriteNodeProperty
+        ) {
+            let outputs = algorithm.node_property_outputs(computation_result.result());
+            for output in &outputs {
+                Self::verify_node_property_output(output, graph_store.node_count())?;
+                self.context.log(
+                    LogLevel::Debug,
+                    &format!("Result property '{}' verified", output.property_key),
+                );
+            }
+
+            // Step 9b: Store the verified outputs in the catalog graph
+            if self.mode == ExecutionMode::MutateNodeProperty && !outputs.is_empty() {
+                self.mutate_node_properties(&graph_name, outputs)?;
+            }
+        }
+
+        // Step 10: Consume result (transform, validate, return)
         let consume_start = Instant::now();
         let output = algorithm.consume_result(computation_result, &self.mode)?;
         let consume_time = consume_start.elapsed();
@@ -221,6 +349,76 @@ impl ProcedureExecutor {
         Ok(output)
     }
 
+    /// Store verified node property outputs in the catalog graph `graph_name`
+    ///
+    /// The outputs are stored with [`mutate_multiple_node_properties`] in the
+    /// executor's mutate mode, so a failing output leaves the catalog graph
+    /// untouched.
+    fn mutate_node_properties(
+        &mut self,
+        graph_name: &str,
+        outputs: Vec<NodePropertyOutput>,
+    ) -> Result<(), ExecutorError> {
+        let mut graph_store = (*self.context.load_graph(graph_name)?).clone();
+        let properties_mutated =
+            mutate_multiple_node_properties(&mut graph_store, outputs, self.mutate_mode)?;
+        self.context.store_graph(graph_name, Arc::new(graph_store));
+        self.context.log(
+            LogLevel::Debug,
+            &format!(
+                "Mutated {} node properties of graph '{}'",
+                properties_mutated, graph_name
+            ),
+        );
+        Ok(())
+    }
+
+    /// Verify that a node property result can be written back
+    ///
+    /// Checks that the values carry the declared `ValueType`, cover exactly
+    /// `node_count` nodes, and that every node's value can be read with the
+    /// declared type.
+    fn verify_node_property_output(
+        output: &NodePropertyOutput,
+        node_count: usize,
+    ) -> Result<(), ExecutorError> {
+        let values = &output.values;
+
+        if values.value_type() != output.value_type {
+            return Err(ExecutorError::PropertyTypeMismatch {
+                property: output.property_key.clone(),
+                declared: output.value_type,
+                actual: values.value_type(),
+            });
+        }
+
+        if values.node_count() != node_count {
+            return Err(ExecutorError::PropertyCountMismatch {
+                property: output.property_key.clone(),
+                expected: node_count,
+                actual: values.node_count(),
+            });
+        }
+
+        for node_id in 0..node_count as u64 {
+            let read_back = match output.value_type {
+                ValueType::Long => values.long_value(node_id).map(|_| ()),
+                ValueType::Double => values.double_value(node_id).map(|_| ()),
+                ValueType::LongArray => values.long_array_value(node_id).map(|_| ()),
+                ValueType::FloatArray => values.float_array_value(node_id).map(|_| ()),
+                ValueType::DoubleArray => values.double_array_value(node_id).map(|_| ()),
+                _ => values.get_object(node_id).map(|_| ()),
+            };
+
+            read_back.map_err(|e| ExecutorError::PropertyReadBack {
+                property: output.property_key.clone(),
+                message: format!("node {}: {}", node_id, e),
+            })?;
+        }
+
+        Ok(())
+    }
+
     /// Get a reference to the execution context
     pub fn context(&self) -> &ExecutionContext {
         &self.context
@@ -266,16 +464,37 @@ pub enum ExecutorError {
     #[error("Result consumption error: {0}")]
     Consumer(#[from] ConsumerError),
 
+    #[error("Node filter error: {0}")]
+    NodeFilter(GraphStoreError),
+
     #[error("Orchestration failed: {0}")]
     Orchestration(String),
 
     #[error("Empty graph: cannot execute algorithm on empty graph")]
     EmptyGraph,
+
+    #[error("Result property '{property}' has {actual} values but the graph has {expected} nodes")]
+    PropertyCountMismatch {
+        property: String,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[error("Result property '{property}' declared as {declared} but holds {actual} values")]
+    PropertyTypeMismatch {
+        property: String,
+        declared: ValueType,
+        actual: ValueType,
+    },
+
+    #[error("Result property '{property}' cannot be read back: {message}")]
+    PropertyReadBack { property: String, message: String },
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::projection::eval::procedure::ProjectionHint;
     use crate::types::prelude::{DefaultGraphStore, RandomGraphConfig};
     use std::sync::Arc;
     use std::time::Duration;
@@ -365,6 +584,52 @@ mod tests {
         assert!(!output.is_empty());
     }
 
+    #[test]
+    fn test_preprocess_config_applies_preset() {
+        let context = create_test_context();
+        let executor = ProcedureExecutor::new(context, ExecutionMode::Stream);
+        let mut algorithm = TestAlgorithm {
+            name: "pagerank".to_string(),
+            graph_name: "test_graph".to_string(),
+        };
+
+        let config = executor
+            .preprocess_config(
+                &mut algorithm,
+                &serde_json::json!({"preset": "pagerank-accurate"}),
+            )
+            .unwrap();
+        assert_eq!(config["maxIterations"], 100);
+        assert_eq!(config["tolerance"], 1e-9);
+        assert!(config.get("preset").is_none());
+
+        let config = executor
+            .preprocess_config(
+                &mut algorithm,
+                &serde_json::json!({"preset": "pagerank-accurate", "maxIterations": 20}),
+            )
+            .unwrap();
+        assert_eq!(config["maxIterations"], 20);
+        assert_eq!(config["tolerance"], 1e-9);
+    }
+
+    #[test]
+    fn test_compute_unknown_preset() {
+        let context = create_test_context();
+        let mut executor = ProcedureExecutor::new(context, ExecutionMode::Stream);
+        let mut algorithm = TestAlgorithm {
+            name: "test_algo".to_string(),
+            graph_name: "test_graph".to_string(),
+        };
+
+        let result = executor.compute(&mut algorithm, &serde_json::json!({"preset": "nope"}));
+
+        assert!(matches!(
+            result,
+            Err(ExecutorError::Config(ConfigError::InvalidValue { param, .. })) if param == "preset"
+        ));
+    }
+
     #[test]
     fn test_compute_graph_not_found() {
         let context = ExecutionContext::new("test_user");
@@ -503,4 +768,198 @@ mod tests {
         let result = executor.compute(&mut algorithm, &config);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_compute_resolves_projection() {
+        struct HintedAlgorithm {
+            hint: ProjectionHint,
+        }
+
+        impl AlgorithmSpec for HintedAlgorithm {
+            type Output = ProjectionHint;
+
+            fn name(&self) -> &str {
+                "hinted_algo"
+            }
+
+            fn graph_name(&self) -> &str {
+                "test_graph"
+            }
+
+            fn projection_hint(&self) -> ProjectionHint {
+                self.hint
+            }
+
+            fn parse_config(&self, input: &JsonValue) -> Result<JsonValue, ConfigError> {
+                Ok(input.clone())
+            }
+
+            fn execute<G: GraphStore>(
+                &self,
+                _graph_store: &G,
+                _config: &JsonValue,
+                _context: &ExecutionContext,
+            ) -> Result<ComputationResult<Self::Output>, AlgorithmError> {
+                Ok(ComputationResult::new(self.hint, Duration::ZERO))
+            }
+
+            fn consume_result(
+                &self,
+                result: ComputationResult<Self::Output>,
+                _mode: &ExecutionMode,
+            ) -> Result<Self::Output, ConsumerError> {
+                Ok(result.projection())
+            }
+        }
+
+        let mut executor = ProcedureExecutor::new(create_test_context(), ExecutionMode::Stream);
+
+        let mut auto = HintedAlgorithm {
+            hint: ProjectionHint::Auto,
+        };
+        let chosen = executor.compute(&mut auto, &serde_json::json!({})).unwrap();
+        assert_eq!(chosen, ProjectionHint::Dense);
+
+        let mut sparse = HintedAlgorithm {
+            hint: ProjectionHint::Sparse,
+        };
+        let chosen = executor
+            .compute(&mut sparse, &serde_json::json!({}))
+            .unwrap();
+        assert_eq!(chosen, ProjectionHint::Sparse);
+    }
+
+    // Mock mutate algorithm producing one score per node, optionally truncated
+    struct ScoringAlgorithm {
+        truncate_by: usize,
+    }
+
+    impl AlgorithmSpec for ScoringAlgorithm {
+        type Output = Vec<f64>;
+
+        fn name(&self) -> &str {
+            "scoring"
+        }
+
+        fn graph_name(&self) -> &str {
+            "test_graph"
+        }
+
+        fn parse_config(&self, input: &JsonValue) -> Result<JsonValue, ConfigError> {
+            Ok(input.clone())
+        }
+
+        fn execute<G: GraphStore>(
+            &self,
+            graph_store: &G,
+            _config: &JsonValue,
+            _context: &ExecutionContext,
+        ) -> Result<ComputationResult<Self::Output>, AlgorithmError> {
+            let count = graph_store.node_count() - self.truncate_by;
+            Ok(ComputationResult::new(vec![1.0; count], Duration::ZERO))
+        }
+
+        fn consume_result(
+            &self,
+            result: ComputationResult<Self::Output>,
+            _mode: &ExecutionMode,
+        ) -> Result<Self::Output, ConsumerError> {
+            Ok(result.into_result())
+        }
+
+        fn node_property_output(&self, result: &Self::Output) -> Option<NodePropertyOutput> {
+            use crate::collections::backends::vec::VecDouble;
+            use crate::types::properties::node::impls::default_node_property_values::DefaultDoubleNodePropertyValues;
+
+            let values = DefaultDoubleNodePropertyValues::from_collection(
+                VecDouble::from(result.clone()),
+                result.len(),
+            );
+            Some(NodePropertyOutput {
+                property_key: "score".to_string(),
+                value_type: ValueType::Double,
+                values: Arc::new(values),
+            })
+        }
+    }
+
+    #[test]
+    fn test_mutate_verifies_node_property_output() {
+        let context = create_test_context();
+        let mut executor = ProcedureExecutor::new(context, ExecutionMode::MutateNodeProperty);
+
+        let mut algorithm = ScoringAlgorithm { truncate_by: 0 };
+        let result = executor.compute(&mut algorithm, &serde_json::json!({}));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_mutate_stores_node_property_in_catalog_graph() {
+        let context = create_test_context();
+        let mut executor = ProcedureExecutor::new(context, ExecutionMode::MutateNodeProperty);
+
+        let mut algorithm = ScoringAlgorithm { truncate_by: 0 };
+        executor
+            .compute(&mut algorithm, &serde_json::json!({}))
+            .unwrap();
+
+        let graph = executor.context().load_graph("test_graph").unwrap();
+        let scores = graph.node_property_values("score").unwrap();
+        for node_id in 0..graph.node_count() as u64 {
+            assert_eq!(scores.double_value(node_id).unwrap(), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_mutate_accumulates_runs_in_accumulate_mode() {
+        let context = create_test_context();
+        let mut executor = ProcedureExecutor::new(context, ExecutionMode::MutateNodeProperty)
+            .with_mutate_mode(MutateMode::Accumulate);
+
+        let mut algorithm = ScoringAlgorithm { truncate_by: 0 };
+        for _ in 0..2 {
+            executor
+                .compute(&mut algorithm, &serde_json::json!({}))
+                .unwrap();
+        }
+
+        let graph = executor.context().load_graph("test_graph").unwrap();
+        let scores = graph.node_property_values("score").unwrap();
+        for node_id in 0..graph.node_count() as u64 {
+            assert_eq!(scores.double_value(node_id).unwrap(), 2.0);
+        }
+    }
+
+    #[test]
+    fn test_mutate_detects_truncated_result() {
+        let context = create_test_context();
+        let node_count = context.load_graph("test_graph").unwrap().node_count();
+        let mut executor = ProcedureExecutor::new(context, ExecutionMode::MutateNodeProperty);
+
+        let mut algorithm = ScoringAlgorithm { truncate_by: 1 };
+        let result = executor.compute(&mut algorithm, &serde_json::json!({}));
+
+        match result {
+            Err(ExecutorError::PropertyCountMismatch {
+                property,
+                expected,
+                actual,
+            }) => {
+                assert_eq!(property, "score");
+                assert_eq!(expected, node_count);
+                assert_eq!(actual, node_count - 1);
+            }
+            other => panic!("Expected PropertyCountMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_stream_skips_node_property_verification() {
+        let context = create_test_context();
+        let mut executor = ProcedureExecutor::new(context, ExecutionMode::Stream);
+
+        let mut algorithm = ScoringAlgorithm { truncate_by: 1 };
+        let result = executor.compute(&mut algorithm, &serde_json::json!({}));
+        assert!(result.is_ok());
+    }
 }
@@ -33,6 +33,11 @@
 //! - **execution_context** - Runtime environment (catalog, logging, metrics)
 //! - **validation_config** - Two-phase validation system
 //! - **algorithm_spec** - Contract between executor and algorithms
+//! - **adaptive_projector** - Cost model choosing HugeArray, Arrow or Pregel projections
+//! - **config_constraints** - Declarative range/positive/probability checks
+//! - **config_parser** - Typed JSON → `*Config` parsing with validation
+//! - **config_presets** - Named config defaults merged with user overrides
+//! - **node_filter** - Node predicates scoping algorithms to an induced subgraph
 //! - **result_consumer** - Result processing helpers
 //! - **executor** - Main orchestrator (this brings it all together)
 //!
@@ -59,11 +64,16 @@
 //! ```
 
 // Module declarations
+mod adaptive_projector;
 mod algorithm_spec;
 mod computation_result;
+mod config_constraints;
+mod config_parser;
+mod config_presets;
 mod execution_context;
 mod execution_mode;
 mod executor;
+mod node_filter;
 mod result_consumer;
 mod validation_config;
 
@@ -79,10 +89,13 @@ pub use validation_config::{
     AfterLoadValidator,
     BeforeLoadValidator,
     NodeLabelExistsValidator,
+    PropertyDescriptor,
     PropertyExistsValidator,
+    PropertyTypeCheck,
     // Example validators
     RangeValidator,
     RequiredParameterValidator,
+    TypeValidator,
     ValidationConfiguration,
     ValidationError,
 };
@@ -90,23 +103,41 @@ pub use validation_config::{
 // Algorithm contract
 pub use algorithm_spec::{
     get_optional_param, get_required_param, AlgorithmError, AlgorithmSpec, ConfigError,
-    ConsumerError, ProjectionHint,
+    ConsumerError, NodePropertyOutput, ProjectionHint,
 };
 
+// Projection selection
+pub use adaptive_projector::{
+    choose_projection, resolve_projection, AccessMode, DefaultCostModel, ProjectionCostModel,
+};
+
+// Typed config parsing
+pub use config_constraints::ConfigConstraints;
+pub use config_parser::{parse_config, AlgorithmConfig};
+pub use config_presets::{ConfigPresets, PRESET_KEY};
+pub use node_filter::NodeFilter;
+
 // Result consumption
 pub use result_consumer::{
     consume_by_mode,
+    mutate_multiple_node_properties,
+    mutate_node_property,
     mutate_node_property_stats,
     mutate_relationship_stats,
+    result_to_node_property_values,
     stats_only,
     // Consumer functions
     stream_results,
     train_model,
+    write_results_csv,
     write_node_property_stats,
     write_relationship_stats,
     // Output types
     ConsumerOutput,
+    CsvColumns,
+    MutateMode,
     MutateSummary,
+    ResultColumn,
     StatsSummary,
     TrainSummary,
     WriteSummary,
@@ -0,0 +1,200 @@
+//! Node Filter - Scoping algorithms to a node-induced subgraph
+//!
+//! A node filter restricts a procedure to the nodes passing a predicate,
+//! e.g. only nodes whose `score` is above `0.5`:
+//!
+//! ```rust,ignore
+//! let mut executor = ProcedureExecutor::new(context, ExecutionMode::Stream)
+//!     .with_node_filter(NodeFilter::property_above("score", 0.5));
+//! ```
+//!
+//! The executor applies the filter right after loading the graph, so the
+//! algorithm only sees relationships between passing nodes. Rejected nodes
+//! keep their ids but lose every relationship; traversals starting from a
+//! passing node therefore never reach them, and per-node results still
+//! contain an entry for every rejected node. A run whose config starts from
+//! a rejected node (see [`SOURCE_NODE_KEYS`]) fails instead.
+
+use std::fmt;
+use std::sync::Arc;
+
+use serde_json::Value as JsonValue;
+
+use crate::types::graph::id_map::MappedNodeId;
+use crate::types::graph_store::{DefaultGraphStore, GraphStoreError, GraphStoreResult};
+use crate::types::prelude::GraphStore;
+
+/// Config keys naming the node or nodes a traversal starts from
+pub const SOURCE_NODE_KEYS: [&str; 4] = ["source_node", "sourceNode", "source_nodes", "sourceNodes"];
+
+type NodePredicate = Arc<dyn Fn(MappedNodeId) -> bool + Send + Sync>;
+
+#[derive(Clone)]
+enum Predicate {
+    Closure(NodePredicate),
+    PropertyAbove {
+        property_key: String,
+        threshold: f64,
+    },
+}
+
+/// Node Filter - Predicate selecting the nodes an algorithm runs on
+#[derive(Clone)]
+pub struct NodeFilter {
+    predicate: Predicate,
+}
+
+impl NodeFilter {
+    /// Keep the nodes for which `predicate` returns `true`
+    pub fn new(predicate: impl Fn(MappedNodeId) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            predicate: Predicate::Closure(Arc::new(predicate)),
+        }
+    }
+
+    /// Keep the nodes whose `property_key` value is greater than `threshold`
+    ///
+    /// Nodes without a value for the property are rejected.
+    pub fn property_above(property_key: impl Into<String>, threshold: f64) -> Self {
+        Self {
+            predicate: Predicate::PropertyAbove {
+                property_key: property_key.into(),
+                threshold,
+            },
+        }
+    }
+
+    /// Restrict `graph_store` to the subgraph induced by the passing nodes
+    ///
+    /// See [`DefaultGraphStore::filter_nodes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphStoreError::PropertyNotFound` if a property filter
+    /// names a property the graph does not have.
+    pub fn apply(&self, graph_store: &DefaultGraphStore) -> GraphStoreResult<DefaultGraphStore> {
+        let keeps = self.keeps(graph_store)?;
+        Ok(graph_store.filter_nodes(keeps))
+    }
+
+    /// Check that no source node named in `config` is rejected
+    ///
+    /// Every key of [`SOURCE_NODE_KEYS`] holding a node id or a list of
+    /// node ids is checked against the filter.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphStoreError::InvalidOperation` for a rejected source
+    /// node, and `GraphStoreError::PropertyNotFound` as [`apply`](Self::apply).
+    pub fn validate_source_nodes(
+        &self,
+        graph_store: &DefaultGraphStore,
+        config: &JsonValue,
+    ) -> GraphStoreResult<()> {
+        let keeps = self.keeps(graph_store)?;
+        for key in SOURCE_NODE_KEYS {
+            let sources = match config.get(key) {
+                Some(JsonValue::Array(values)) => values.iter().collect(),
+                Some(value) => vec![value],
+                None => continue,
+            };
+            for source in sources.into_iter().filter_map(JsonValue::as_u64) {
+                if !keeps(source as MappedNodeId) {
+                    return Err(GraphStoreError::InvalidOperation(format!(
+                        "{} {} is rejected by {:?}",
+                        key, source, self
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn keeps<'a>(
+        &'a self,
+        graph_store: &DefaultGraphStore,
+    ) -> GraphStoreResult<Box<dyn Fn(MappedNodeId) -> bool + 'a>> {
+        match &self.predicate {
+            Predicate::Closure(predicate) => Ok(Box::new(move |node| predicate(node))),
+            Predicate::PropertyAbove {
+                property_key,
+                threshold,
+            } => {
+                let values = graph_store.node_property_values(property_key)?;
+                Ok(Box::new(move |node| {
+                    values
+                        .double_value(node as u64)
+                        .is_ok_and(|value| value > *threshold)
+                }))
+            }
+        }
+    }
+}
+
+impl fmt::Debug for NodeFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.predicate {
+            Predicate::Closure(_) => f.write_str("NodeFilter(<closure>)"),
+            Predicate::PropertyAbove {
+                property_key,
+                threshold,
+            } => write!(f, "NodeFilter({} > {})", property_key, threshold),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::prelude::RandomGraphConfig;
+
+    #[test]
+    fn test_filters_relationships_of_rejected_nodes() {
+        let config = RandomGraphConfig {
+            node_count: 8,
+            ..RandomGraphConfig::default()
+        }
+        .with_seed(7);
+        let store = DefaultGraphStore::random(&config).unwrap();
+
+        let filtered = NodeFilter::new(|node| node % 2 == 0).apply(&store).unwrap();
+
+        assert_eq!(filtered.node_count(), store.node_count());
+        let graph = filtered.get_graph();
+        for node in 0..filtered.node_count() as MappedNodeId {
+            for cursor in graph.stream_relationships(node, 0.0) {
+                assert_eq!(node % 2, 0);
+                assert_eq!(cursor.target_id() % 2, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rejected_source_nodes_fail_validation() {
+        let store = DefaultGraphStore::ring(6);
+        let filter = NodeFilter::new(|node| node < 4);
+
+        assert!(filter
+            .validate_source_nodes(&store, &serde_json::json!({"source_node": 3}))
+            .is_ok());
+        assert!(matches!(
+            filter.validate_source_nodes(&store, &serde_json::json!({"source_node": 4})),
+            Err(GraphStoreError::InvalidOperation(_))
+        ));
+        assert!(filter
+            .validate_source_nodes(&store, &serde_json::json!({"sourceNodes": [0, 5]}))
+            .is_err());
+        assert!(filter
+            .validate_source_nodes(&store, &serde_json::json!({"target_node": 5}))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_missing_property_is_rejected() {
+        let store = DefaultGraphStore::random(&RandomGraphConfig::default()).unwrap();
+
+        assert!(NodeFilter::property_above("missing", 0.5)
+            .apply(&store)
+            .is_err());
+    }
+}
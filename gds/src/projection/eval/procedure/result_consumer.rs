@@ -12,7 +12,19 @@
 //! - Mode-based consumption routing
 //! - Simplified without separate consumer trait
 
-use super::{ComputationResult, ConsumerError, ExecutionMode};
+use super::{ComputationResult, ConsumerError, ExecutionMode, NodePropertyOutput};
+use crate::collections::backends::vec::{VecDouble, VecFloat, VecLong};
+use crate::types::graph_store::GraphStore;
+use crate::types::properties::node::impls::default_node_property_values::{
+    DefaultDoubleNodePropertyValues, DefaultFloatNodePropertyValues, DefaultLongNodePropertyValues,
+};
+use crate::types::properties::node::NodePropertyValues;
+use crate::types::properties::{PropertyValuesError, PropertyValuesResult};
+use crate::types::ValueType;
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::io::Write;
+use std::sync::Arc;
 
 /// Stream Results - Return all algorithm results
 ///
@@ -144,6 +156,167 @@ pub fn mutate_relationship_stats<T>(
     })
 }
 
+/// Mutate Mode - How a mutated node property treats an existing property
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MutateMode {
+    /// Overwrite the existing property (default)
+    #[default]
+    Replace,
+    /// Add the new values element-wise to the existing property
+    ///
+    /// Behaves like `Replace` when the property does not exist yet.
+    Accumulate,
+}
+
+/// Mutate Node Property - Store an algorithm's node property in the graph
+///
+/// **Execution Mode**: `MutateNodeProperty`
+///
+/// In `Accumulate` mode an existing numeric property is summed with the new
+/// values. The sum stays `Long` when both sides are `Long`, otherwise it is
+/// stored as `Double`. A `Long` sum that overflows `i64` fails the mutation
+/// and leaves the property as it was. Returns the number of node properties
+/// written.
+///
+/// **Use case**: ensemble scores accumulated over several algorithm runs
+pub fn mutate_node_property<G: GraphStore>(
+    graph_store: &mut G,
+    output: NodePropertyOutput,
+    mode: MutateMode,
+) -> Result<usize, ConsumerError> {
+    let existing = match mode {
+        MutateMode::Replace => None,
+        MutateMode::Accumulate => graph_store.node_property_values(&output.property_key).ok(),
+    };
+
+    let values = match existing {
+        Some(existing) => accumulate(
+            &output.property_key,
+            existing.as_ref(),
+            output.values.as_ref(),
+        )?,
+        None => output.values,
+    };
+    let properties_mutated = values.node_count();
+
+    graph_store
+        .add_node_property(graph_store.node_labels(), output.property_key, values)
+        .map_err(|e| ConsumerError::Failed(e.to_string()))?;
+
+    Ok(properties_mutated)
+}
+
+fn accumulate(
+    property_key: &str,
+    existing: &dyn NodePropertyValues,
+    added: &dyn NodePropertyValues,
+) -> Result<Arc<dyn NodePropertyValues>, ConsumerError> {
+    if existing.node_count() != added.node_count() {
+        return Err(ConsumerError::Transformation(format!(
+            "cannot accumulate '{}': existing property has {} values, new property has {}",
+            property_key,
+            existing.node_count(),
+            added.node_count()
+        )));
+    }
+    let node_count = existing.node_count() as u64;
+    let read_error = |e: PropertyValuesError| {
+        ConsumerError::Transformation(format!("cannot accumulate '{}': {}", property_key, e))
+    };
+
+    if existing.value_type() == ValueType::Long && added.value_type() == ValueType::Long {
+        let sums = (0..node_count)
+            .map(|node_id| {
+                let (left, right) = (
+                    existing.long_value(node_id).map_err(read_error)?,
+                    added.long_value(node_id).map_err(read_error)?,
+                );
+                left.checked_add(right).ok_or_else(|| {
+                    ConsumerError::Transformation(format!(
+                        "cannot accumulate '{}': {} + {} overflows at node {}",
+                        property_key, left, right, node_id
+                    ))
+                })
+            })
+            .collect::<Result<Vec<i64>, _>>()?;
+        return result_to_node_property_values(ResultColumn::Long(sums), ValueType::Long);
+    }
+
+    for values in [existing, added] {
+        if !matches!(
+            values.value_type(),
+            ValueType::Long | ValueType::Double | ValueType::Float
+        ) {
+            return Err(ConsumerError::Transformation(format!(
+                "cannot accumulate '{}': {} values are not numeric",
+                property_key,
+                values.value_type()
+            )));
+        }
+    }
+
+    let sums = (0..node_count)
+        .map(|node_id| Ok(numeric_value(existing, node_id)? + numeric_value(added, node_id)?))
+        .collect::<Result<Vec<f64>, _>>()
+        .map_err(read_error)?;
+    result_to_node_property_values(ResultColumn::Double(sums), ValueType::Double)
+}
+
+fn numeric_value(values: &dyn NodePropertyValues, node_id: u64) -> PropertyValuesResult<f64> {
+    match values.value_type() {
+        ValueType::Long => values.long_value(node_id).map(|v| v as f64),
+        _ => values.double_value(node_id),
+    }
+}
+
+/// Mutate Multiple Node Properties - Store several outputs of one algorithm run
+///
+/// **Execution Mode**: `MutateNodeProperty`
+///
+/// Every output is validated before the first one is stored: keys must be
+/// distinct and every output must hold one value per node. The outputs are
+/// stored with [`mutate_node_property`] in `mode` on a copy of the graph that
+/// replaces it once all of them are stored, so if any output fails, such as
+/// an accumulated `Long` overflowing, the graph is left untouched. Returns
+/// the number of node properties written over all outputs.
+///
+/// **Use case**: HITS.mutate() → `hub` and `authority` in one pass
+pub fn mutate_multiple_node_properties<G: GraphStore + Clone>(
+    graph_store: &mut G,
+    outputs: Vec<NodePropertyOutput>,
+    mode: MutateMode,
+) -> Result<usize, ConsumerError> {
+    let node_count = graph_store.node_count();
+    for (index, output) in outputs.iter().enumerate() {
+        if outputs[..index]
+            .iter()
+            .any(|earlier| earlier.property_key == output.property_key)
+        {
+            return Err(ConsumerError::Validation(format!(
+                "node property '{}' is mutated more than once",
+                output.property_key
+            )));
+        }
+        if output.values.node_count() != node_count {
+            return Err(ConsumerError::Validation(format!(
+                "node property '{}' has {} values for {} nodes",
+                output.property_key,
+                output.values.node_count(),
+                node_count
+            )));
+        }
+    }
+
+    let mut staged = graph_store.clone();
+    let mut properties_mutated = 0;
+    for output in outputs {
+        properties_mutated += mutate_node_property(&mut staged, output, mode)?;
+    }
+    *graph_store = staged;
+
+    Ok(properties_mutated)
+}
+
 /// Consume by Mode - Route consumption based on execution mode
 ///
 /// **Central consumption dispatcher**
@@ -175,6 +348,218 @@ pub fn consume_by_mode<T>(
     }
 }
 
+// ============================================================================
+// Node Property Conversion
+// ============================================================================
+
+/// Result Column - Raw per-node algorithm output, indexed by node id
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResultColumn {
+    Double(Vec<f64>),
+    Long(Vec<i64>),
+    Float(Vec<f32>),
+}
+
+impl ResultColumn {
+    /// Number of nodes in the column
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Double(values) => values.len(),
+            Self::Long(values) => values.len(),
+            Self::Float(values) => values.len(),
+        }
+    }
+
+    /// Whether the column holds no values
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl From<Vec<f64>> for ResultColumn {
+    fn from(values: Vec<f64>) -> Self {
+        Self::Double(values)
+    }
+}
+
+impl From<Vec<i64>> for ResultColumn {
+    fn from(values: Vec<i64>) -> Self {
+        Self::Long(values)
+    }
+}
+
+impl From<Vec<f32>> for ResultColumn {
+    fn from(values: Vec<f32>) -> Self {
+        Self::Float(values)
+    }
+}
+
+/// Result to Node Property Values - Convert a result column for mutate/write
+///
+/// Dispatches on the target `value_type` (`Double`, `Long` or `Float`) and
+/// converts the column's numbers when its type differs. Conversion to `Long`
+/// only accepts integral values, so fractional scores are never truncated
+/// silently.
+///
+/// **Use case**: `AlgorithmSpec::node_property_output` for any algorithm
+/// producing one number per node
+pub fn result_to_node_property_values(
+    values: ResultColumn,
+    value_type: ValueType,
+) -> Result<Arc<dyn NodePropertyValues>, ConsumerError> {
+    let node_count = values.len();
+
+    let property_values: Arc<dyn NodePropertyValues> = match value_type {
+        ValueType::Double => {
+            let doubles = match values {
+                ResultColumn::Double(values) => values,
+                ResultColumn::Long(values) => values.into_iter().map(|v| v as f64).collect(),
+                ResultColumn::Float(values) => values.into_iter().map(f64::from).collect(),
+            };
+            Arc::new(DefaultDoubleNodePropertyValues::from_collection(
+                VecDouble::from(doubles),
+                node_count,
+            ))
+        }
+        ValueType::Long => {
+            let longs = match values {
+                ResultColumn::Long(values) => values,
+                ResultColumn::Double(values) => integral_values(values)?,
+                ResultColumn::Float(values) => {
+                    integral_values(values.into_iter().map(f64::from).collect())?
+                }
+            };
+            Arc::new(DefaultLongNodePropertyValues::from_collection(
+                VecLong::from(longs),
+                node_count,
+            ))
+        }
+        ValueType::Float => {
+            let floats = match values {
+                ResultColumn::Float(values) => values,
+                ResultColumn::Double(values) => values.into_iter().map(|v| v as f32).collect(),
+                ResultColumn::Long(values) => values.into_iter().map(|v| v as f32).collect(),
+            };
+            Arc::new(DefaultFloatNodePropertyValues::from_collection(
+                VecFloat::from(floats),
+                node_count,
+            ))
+        }
+        other => {
+            return Err(ConsumerError::Transformation(format!(
+                "cannot convert a result column to {} node properties",
+                other
+            )))
+        }
+    };
+
+    Ok(property_values)
+}
+
+fn integral_values(values: Vec<f64>) -> Result<Vec<i64>, ConsumerError> {
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(node_id, value)| {
+            if value.is_finite() && value.fract() == 0.0 {
+                Ok(value as i64)
+            } else {
+                Err(ConsumerError::Transformation(format!(
+                    "value {} of node {} is not an integer",
+                    value, node_id
+                )))
+            }
+        })
+        .collect()
+}
+
+// ============================================================================
+// CSV Export
+// ============================================================================
+
+/// Csv Columns - Column layout for `write_results_csv`
+///
+/// Each column has a header name and extracts its cell from a result row,
+/// e.g. a `(node_id, score)` pair or a `(source, target, value)` triple.
+pub struct CsvColumns<R> {
+    columns: Vec<(String, CsvCell<R>)>,
+}
+
+type CsvCell<R> = Box<dyn Fn(&R) -> String>;
+
+impl<R> CsvColumns<R> {
+    /// Create a layout without columns
+    pub fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+        }
+    }
+
+    /// Append a column whose cells are the `Display` output of `value`
+    pub fn column<V: Display>(
+        mut self,
+        name: impl Into<String>,
+        value: impl Fn(&R) -> V + 'static,
+    ) -> Self {
+        self.columns
+            .push((name.into(), Box::new(move |row| value(row).to_string())));
+        self
+    }
+
+    /// Header names in column order
+    pub fn names(&self) -> Vec<&str> {
+        self.columns.iter().map(|(name, _)| name.as_str()).collect()
+    }
+}
+
+impl<R> Default for CsvColumns<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Write Results CSV - Export result rows as CSV
+///
+/// **Execution Mode**: `Stream`
+///
+/// Writes a header row followed by one row per result row. Cells containing
+/// a comma, quote or line break are quoted, with quotes doubled (RFC 4180).
+/// Returns the number of data rows written.
+///
+/// **Use case**: PageRank → `nodeId,score` file for spreadsheet analysis
+pub fn write_results_csv<R, W: Write>(
+    rows: impl IntoIterator<Item = R>,
+    columns: &CsvColumns<R>,
+    mut writer: W,
+) -> Result<usize, ConsumerError> {
+    let io_error = |e: std::io::Error| ConsumerError::Formatting(e.to_string());
+
+    let header: Vec<Cow<'_, str>> = columns.names().into_iter().map(escape_csv_field).collect();
+    writeln!(writer, "{}", header.join(",")).map_err(io_error)?;
+
+    let mut written = 0;
+    for row in rows {
+        let cells: Vec<String> = columns
+            .columns
+            .iter()
+            .map(|(_, value)| escape_csv_field(&value(&row)).into_owned())
+            .collect();
+        writeln!(writer, "{}", cells.join(",")).map_err(io_error)?;
+        written += 1;
+    }
+    writer.flush().map_err(io_error)?;
+
+    Ok(written)
+}
+
+fn escape_csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
 // ============================================================================
 // Output Types
 // ============================================================================
@@ -457,4 +842,250 @@ mod tests {
         assert_eq!(summary.properties_mutated, 500);
         assert_eq!(summary.relationships_mutated, 200);
     }
+
+    #[test]
+    fn test_double_result_column_to_node_properties() {
+        let values =
+            result_to_node_property_values(vec![0.25, 1.5, 3.0].into(), ValueType::Double).unwrap();
+
+        assert_eq!(values.value_type(), ValueType::Double);
+        assert_eq!(values.node_count(), 3);
+        assert_eq!(values.double_value(0).unwrap(), 0.25);
+        assert_eq!(values.double_value(1).unwrap(), 1.5);
+        assert_eq!(values.double_value(2).unwrap(), 3.0);
+    }
+
+    #[test]
+    fn test_long_result_column_to_node_properties() {
+        let values =
+            result_to_node_property_values(vec![7i64, -2, 40].into(), ValueType::Long).unwrap();
+
+        assert_eq!(values.value_type(), ValueType::Long);
+        assert_eq!(values.node_count(), 3);
+        assert_eq!(values.long_value(0).unwrap(), 7);
+        assert_eq!(values.long_value(1).unwrap(), -2);
+        assert_eq!(values.long_value(2).unwrap(), 40);
+    }
+
+    #[test]
+    fn test_result_column_conversions() {
+        let floats =
+            result_to_node_property_values(ResultColumn::Long(vec![1, 2]), ValueType::Float)
+                .unwrap();
+        assert_eq!(floats.value_type(), ValueType::Float);
+        assert_eq!(floats.double_value(1).unwrap(), 2.0);
+
+        let longs =
+            result_to_node_property_values(ResultColumn::Double(vec![3.0, 4.0]), ValueType::Long)
+                .unwrap();
+        assert_eq!(longs.long_value(1).unwrap(), 4);
+
+        let fractional =
+            result_to_node_property_values(ResultColumn::Double(vec![3.0, 4.5]), ValueType::Long);
+        assert!(
+            matches!(fractional, Err(ConsumerError::Transformation(msg)) if msg.contains("node 1"))
+        );
+
+        let unsupported =
+            result_to_node_property_values(ResultColumn::Long(vec![1]), ValueType::String);
+        assert!(unsupported.is_err());
+    }
+
+    // Mock scoring run: one score per node, scaled by `factor`
+    fn run_scoring(
+        graph_store: &crate::types::prelude::DefaultGraphStore,
+        factor: f64,
+    ) -> NodePropertyOutput {
+        let scores: Vec<f64> = (0..graph_store.node_count())
+            .map(|node_id| (node_id + 1) as f64 * factor)
+            .collect();
+        NodePropertyOutput {
+            property_key: "ensemble".to_string(),
+            value_type: ValueType::Double,
+            values: result_to_node_property_values(scores.into(), ValueType::Double).unwrap(),
+        }
+    }
+
+    fn scoring_graph() -> crate::types::prelude::DefaultGraphStore {
+        use crate::types::prelude::{DefaultGraphStore, RandomGraphConfig};
+
+        let config = RandomGraphConfig {
+            node_count: 5,
+            ..RandomGraphConfig::default()
+        }
+        .with_seed(3);
+        DefaultGraphStore::random(&config).unwrap()
+    }
+
+    #[test]
+    fn test_mutate_accumulate_sums_runs() {
+        let mut store = scoring_graph();
+
+        let first = run_scoring(&store, 1.0);
+        assert_eq!(
+            mutate_node_property(&mut store, first, MutateMode::Accumulate).unwrap(),
+            5
+        );
+        let second = run_scoring(&store, 0.5);
+        mutate_node_property(&mut store, second, MutateMode::Accumulate).unwrap();
+
+        let values = store.node_property_values("ensemble").unwrap();
+        for node_id in 0..5u64 {
+            let expected = (node_id + 1) as f64 * 1.5;
+            assert_eq!(values.double_value(node_id).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_mutate_replace_keeps_last_run() {
+        let mut store = scoring_graph();
+
+        let first = run_scoring(&store, 1.0);
+        mutate_node_property(&mut store, first, MutateMode::Replace).unwrap();
+        let second = run_scoring(&store, 0.5);
+        mutate_node_property(&mut store, second, MutateMode::default()).unwrap();
+
+        let values = store.node_property_values("ensemble").unwrap();
+        for node_id in 0..5u64 {
+            let expected = (node_id + 1) as f64 * 0.5;
+            assert_eq!(values.double_value(node_id).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_mutate_accumulate_keeps_long_type() {
+        let mut store = scoring_graph();
+        store
+            .add_node_property_i64("ensemble".to_string(), vec![1, 2, 3, 4, 5])
+            .unwrap();
+
+        let output = NodePropertyOutput {
+            property_key: "ensemble".to_string(),
+            value_type: ValueType::Long,
+            values: result_to_node_property_values(vec![10i64; 5].into(), ValueType::Long).unwrap(),
+        };
+        mutate_node_property(&mut store, output, MutateMode::Accumulate).unwrap();
+
+        let values = store.node_property_values("ensemble").unwrap();
+        assert_eq!(values.value_type(), ValueType::Long);
+        assert_eq!(values.long_value(4).unwrap(), 15);
+    }
+
+    #[test]
+    fn test_mutate_accumulate_rejects_long_overflow() {
+        let mut store = scoring_graph();
+        store
+            .add_node_property_i64("ensemble".to_string(), vec![1, 2, 3, 4, i64::MAX])
+            .unwrap();
+
+        let output = NodePropertyOutput {
+            property_key: "ensemble".to_string(),
+            value_type: ValueType::Long,
+            values: result_to_node_property_values(vec![1i64; 5].into(), ValueType::Long).unwrap(),
+        };
+        let result = mutate_node_property(&mut store, output, MutateMode::Accumulate);
+
+        assert!(
+            matches!(result, Err(ConsumerError::Transformation(msg)) if msg.contains("overflows"))
+        );
+        let values = store.node_property_values("ensemble").unwrap();
+        assert_eq!(values.long_value(4).unwrap(), i64::MAX);
+    }
+
+    #[test]
+    fn test_write_pagerank_results_csv() {
+        use crate::procedures::pagerank::PageRankAlgorithmSpec;
+        use crate::projection::eval::procedure::{
+            ExecutionContext, ExecutionMode, ProcedureExecutor,
+        };
+        use std::sync::Arc;
+
+        let store = Arc::new(scoring_graph());
+        let mut executor = ProcedureExecutor::new(
+            ExecutionContext::mock(Arc::clone(&store)),
+            ExecutionMode::Stream,
+        );
+        let mut spec = PageRankAlgorithmSpec::new("test_graph".to_string());
+        let scores = executor
+            .compute(
+                &mut spec,
+                &serde_json::json!({"maxIterations": 50, "tolerance": 1e-9}),
+            )
+            .unwrap()
+            .scores;
+        assert_eq!(scores.len(), store.node_count());
+        assert!(scores.iter().all(|&score| score >= 0.15));
+
+        let columns = CsvColumns::new()
+            .column("nodeId", |(node_id, _): &(usize, f64)| *node_id)
+            .column("score", |(_, score): &(usize, f64)| *score);
+
+        let mut buffer = Vec::new();
+        let written =
+            write_results_csv(scores.iter().copied().enumerate(), &columns, &mut buffer).unwrap();
+
+        let csv = String::from_utf8(buffer).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("nodeId,score"));
+        let rows: Vec<(usize, f64)> = lines
+            .map(|line| {
+                let (node_id, score) = line.split_once(',').unwrap();
+                (node_id.parse().unwrap(), score.parse().unwrap())
+            })
+            .collect();
+        assert_eq!(written, scores.len());
+        assert_eq!(rows.len(), scores.len());
+        for (node_id, score) in rows {
+            assert_eq!(score, scores[node_id]);
+        }
+    }
+
+    #[test]
+    fn test_write_results_csv_escapes_strings() {
+        let columns = CsvColumns::new()
+            .column("name", |name: &&str| name.to_string())
+            .column("length", |name: &&str| name.len());
+
+        let mut buffer = Vec::new();
+        write_results_csv(
+            ["plain", "a,b", "say \"hi\"", "two\nlines"],
+            &columns,
+            &mut buffer,
+        )
+        .unwrap();
+
+        let csv = String::from_utf8(buffer).unwrap();
+        assert_eq!(
+            csv,
+            "name,length\nplain,5\n\"a,b\",3\n\"say \"\"hi\"\"\",8\n\"two\nlines\",9\n"
+        );
+    }
+
+    #[test]
+    fn test_mutate_multiple_rejects_invalid_outputs_without_writing() {
+        let mut store = scoring_graph();
+        let output = |property_key: &str, scores: Vec<f64>| NodePropertyOutput {
+            property_key: property_key.to_string(),
+            value_type: ValueType::Double,
+            values: result_to_node_property_values(scores.into(), ValueType::Double).unwrap(),
+        };
+
+        let short = mutate_multiple_node_properties(
+            &mut store,
+            vec![
+                output("first", vec![1.0; 5]),
+                output("second", vec![1.0; 4]),
+            ],
+            MutateMode::Replace,
+        );
+        assert!(matches!(short, Err(ConsumerError::Validation(msg)) if msg.contains("second")));
+
+        let duplicate = mutate_multiple_node_properties(
+            &mut store,
+            vec![output("first", vec![1.0; 5]), output("first", vec![2.0; 5])],
+            MutateMode::Replace,
+        );
+        assert!(matches!(duplicate, Err(ConsumerError::Validation(_))));
+        assert!(!store.has_node_property("first"));
+    }
 }
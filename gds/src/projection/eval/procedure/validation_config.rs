@@ -11,6 +11,7 @@
 
 use crate::types::prelude::{DefaultGraphStore, GraphStore};
 use crate::types::schema::NodeLabel;
+use crate::types::{Conversion, ValueType};
 use serde_json::Value as JsonValue;
 
 /// Validation Configuration - Two-phase validation
@@ -176,6 +177,13 @@ pub enum ValidationError {
 
     #[error("Relationship type not found in graph: {0}")]
     RelationshipTypeNotFound(String),
+
+    #[error("Property {property} has type {actual}, which cannot be converted to the required {required}")]
+    IncompatiblePropertyType {
+        property: String,
+        required: ValueType,
+        actual: ValueType,
+    },
 }
 
 // ============================================================================
@@ -279,6 +287,96 @@ impl AfterLoadValidator for PropertyExistsValidator {
     }
 }
 
+/// Property Descriptor - A node property an algorithm reads, with its type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyDescriptor {
+    pub key: String,
+    pub value_type: ValueType,
+}
+
+impl PropertyDescriptor {
+    pub fn new(key: impl Into<String>, value_type: ValueType) -> Self {
+        Self {
+            key: key.into(),
+            value_type,
+        }
+    }
+}
+
+/// Property Type Check - Outcome of a successful property type check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PropertyTypeCheck {
+    /// The stored values convert without loss
+    Ok,
+    /// The stored values convert, but may lose range or precision
+    Warn(String),
+}
+
+/// Type Validator - Checks property types against algorithm requirements
+///
+/// **After-load validator** - Graph + config
+///
+/// Stored values must convert to the required type (see
+/// [`ValueType::can_convert_to`]). Exact and widening conversions pass,
+/// lossy ones pass with a warning, incompatible ones fail.
+#[derive(Debug, Clone, Default)]
+pub struct TypeValidator {
+    required: Vec<PropertyDescriptor>,
+}
+
+impl TypeValidator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require node property `key` to convert to `value_type` (builder pattern)
+    pub fn require(mut self, key: impl Into<String>, value_type: ValueType) -> Self {
+        self.required.push(PropertyDescriptor::new(key, value_type));
+        self
+    }
+
+    /// Check a property of type `actual` against `descriptor`
+    pub fn validate_property_type(
+        descriptor: &PropertyDescriptor,
+        actual: ValueType,
+    ) -> Result<PropertyTypeCheck, ValidationError> {
+        match actual.can_convert_to(descriptor.value_type) {
+            Conversion::Exact | Conversion::Widening => Ok(PropertyTypeCheck::Ok),
+            Conversion::Lossy => Ok(PropertyTypeCheck::Warn(format!(
+                "Property {} has type {}; converting it to the required {} may lose range or precision",
+                descriptor.key, actual, descriptor.value_type
+            ))),
+            Conversion::Incompatible => Err(ValidationError::IncompatiblePropertyType {
+                property: descriptor.key.clone(),
+                required: descriptor.value_type,
+                actual,
+            }),
+        }
+    }
+}
+
+impl AfterLoadValidator for TypeValidator {
+    /// Lossy conversions are accepted; use `validate_property_type` to
+    /// surface their warnings.
+    fn validate(
+        &self,
+        graph_store: &DefaultGraphStore,
+        _config: &JsonValue,
+    ) -> Result<(), ValidationError> {
+        for descriptor in &self.required {
+            let actual = graph_store
+                .node_property_type(&descriptor.key)
+                .map_err(|_| ValidationError::PropertyNotFound(descriptor.key.clone()))?;
+            Self::validate_property_type(descriptor, actual)?;
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "TypeValidator"
+    }
+}
+
 /// Node Label Exists Validator - Validates node label exists in graph
 ///
 /// **After-load validator** - Graph + config
@@ -518,4 +616,66 @@ mod tests {
         let label_validator = NodeLabelExistsValidator::new("x");
         assert_eq!(label_validator.name(), "NodeLabelExistsValidator");
     }
+
+    #[test]
+    fn test_validate_property_type_outcomes() {
+        let required = PropertyDescriptor::new("score", ValueType::Double);
+
+        assert_eq!(
+            TypeValidator::validate_property_type(&required, ValueType::Double).unwrap(),
+            PropertyTypeCheck::Ok
+        );
+        assert_eq!(
+            TypeValidator::validate_property_type(&required, ValueType::Long).unwrap(),
+            PropertyTypeCheck::Ok
+        );
+
+        let long = PropertyDescriptor::new("community", ValueType::Long);
+        match TypeValidator::validate_property_type(&long, ValueType::Double).unwrap() {
+            PropertyTypeCheck::Warn(message) => {
+                assert!(message.contains("community"));
+                assert!(message.contains("lose range or precision"));
+            }
+            other => panic!("Expected a warning, got {:?}", other),
+        }
+
+        let error =
+            TypeValidator::validate_property_type(&required, ValueType::String).unwrap_err();
+        assert!(matches!(
+            error,
+            ValidationError::IncompatiblePropertyType {
+                required: ValueType::Double,
+                actual: ValueType::String,
+                ..
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Property score has type {}, which cannot be converted to the required {}",
+                ValueType::String,
+                ValueType::Double
+            )
+        );
+    }
+
+    #[test]
+    fn test_type_validator_checks_store_properties() {
+        let graph = DefaultGraphStore::random(&RandomGraphConfig::default().with_seed(42)).unwrap();
+
+        let lossy = TypeValidator::new().require("random_score", ValueType::Long);
+        assert!(lossy.validate(&graph, &json!({})).is_ok());
+
+        let incompatible = TypeValidator::new().require("random_score", ValueType::LongArray);
+        assert!(matches!(
+            incompatible.validate(&graph, &json!({})),
+            Err(ValidationError::IncompatiblePropertyType { .. })
+        ));
+
+        let missing = TypeValidator::new().require("missing", ValueType::Double);
+        assert!(matches!(
+            missing.validate(&graph, &json!({})),
+            Err(ValidationError::PropertyNotFound(_))
+        ));
+    }
 }
@@ -3,8 +3,26 @@
 // Translation from: GraphProjectFromStoreConfig.java (199 lines)
 // Design: Type-safe configuration with validation
 
+use crate::types::graph::OriginalNodeId;
 use std::fmt;
 
+/// How the node import treats rows that repeat an already imported node id.
+///
+/// "First" and "last" refer to row order in the node table, independent of how
+/// the rows were distributed across import tasks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateNodePolicy {
+    /// Fail the import, naming the duplicate id
+    #[default]
+    Error,
+    /// Keep the labels and properties of the first row
+    KeepFirst,
+    /// Keep the labels and properties of the last row
+    KeepLast,
+    /// Union the labels of all rows; properties are taken from the last row
+    Merge,
+}
+
 /// Configuration for Arrow-native GraphStore projection.
 ///
 /// This specifies what data to import and how to import it.
@@ -43,6 +61,10 @@ pub struct ArrowProjectionConfig {
     /// Batch size for parallel processing
     /// Default: 10,000 rows per batch
     pub batch_size: usize,
+
+    /// Handling of repeated node ids in the node table
+    /// Default: DuplicateNodePolicy::Error
+    pub duplicate_node_policy: DuplicateNodePolicy,
 }
 
 impl ArrowProjectionConfig {
@@ -95,6 +117,7 @@ impl Default for ArrowProjectionConfig {
             validate_schema: true,
             log_progress: false,
             batch_size: 10_000,
+            duplicate_node_policy: DuplicateNodePolicy::default(),
         }
     }
 }
@@ -108,6 +131,7 @@ pub struct ArrowProjectionConfigBuilder {
     validate_schema: Option<bool>,
     log_progress: Option<bool>,
     batch_size: Option<usize>,
+    duplicate_node_policy: Option<DuplicateNodePolicy>,
 }
 
 impl ArrowProjectionConfigBuilder {
@@ -147,6 +171,12 @@ impl ArrowProjectionConfigBuilder {
         self
     }
 
+    /// Set the duplicate node policy.
+    pub fn duplicate_node_policy(mut self, policy: DuplicateNodePolicy) -> Self {
+        self.duplicate_node_policy = Some(policy);
+        self
+    }
+
     /// Build the configuration.
     ///
     /// Uses defaults for unset fields, then validates.
@@ -158,6 +188,7 @@ impl ArrowProjectionConfigBuilder {
             validate_schema: self.validate_schema.unwrap_or(true),
             log_progress: self.log_progress.unwrap_or(false),
             batch_size: self.batch_size.unwrap_or(10_000),
+            duplicate_node_policy: self.duplicate_node_policy.unwrap_or_default(),
         };
 
         config.validate()?;
@@ -180,6 +211,9 @@ pub enum ArrowProjectionError {
     /// Import error
     Import(String),
 
+    /// Node table contains the same node id more than once
+    DuplicateNode(OriginalNodeId),
+
     /// Generic error
     Other(String),
 }
@@ -191,6 +225,11 @@ impl fmt::Display for ArrowProjectionError {
             Self::SchemaValidation(msg) => write!(f, "Schema validation failed: {}", msg),
             Self::Arrow(msg) => write!(f, "Arrow error: {}", msg),
             Self::Import(msg) => write!(f, "Import error: {}", msg),
+            Self::DuplicateNode(id) => write!(
+                f,
+                "Duplicate node id {} in node table (duplicate_node_policy = Error)",
+                id
+            ),
             Self::Other(msg) => write!(f, "Error: {}", msg),
         }
     }
@@ -198,6 +237,17 @@ impl fmt::Display for ArrowProjectionError {
 
 impl std::error::Error for ArrowProjectionError {}
 
+impl From<super::importer::ImporterError> for ArrowProjectionError {
+    fn from(error: super::importer::ImporterError) -> Self {
+        match error {
+            super::importer::ImporterError::DuplicateNode { original_id } => {
+                Self::DuplicateNode(original_id)
+            }
+            other => Self::Import(other.to_string()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -211,6 +261,7 @@ mod tests {
         assert!(config.validate_schema);
         assert!(!config.log_progress);
         assert_eq!(config.batch_size, 10_000);
+        assert_eq!(config.duplicate_node_policy, DuplicateNodePolicy::Error);
     }
 
     #[test]
@@ -236,6 +287,7 @@ mod tests {
             .validate_schema(false)
             .log_progress(true)
             .batch_size(5000)
+            .duplicate_node_policy(DuplicateNodePolicy::Merge)
             .build()
             .unwrap();
 
@@ -245,6 +297,7 @@ mod tests {
         assert!(!config.validate_schema);
         assert!(config.log_progress);
         assert_eq!(config.batch_size, 5000);
+        assert_eq!(config.duplicate_node_policy, DuplicateNodePolicy::Merge);
     }
 
     #[test]
@@ -317,5 +370,8 @@ mod tests {
 
         let err = ArrowProjectionError::Arrow("test".to_string());
         assert!(format!("{}", err).contains("Arrow error"));
+
+        let err = ArrowProjectionError::DuplicateNode(42);
+        assert!(format!("{}", err).contains("Duplicate node id 42"));
     }
 }
@@ -0,0 +1,573 @@
+// Arrow Export
+//
+// The reverse of the import path: GraphStore → arrow2 tables.
+//
+// Design principles:
+// - Import-compatible layout: exported tables load back through NodeTableReference /
+//   EdgeTableReference and the positional import tasks (id, label | source, target, type)
+// - Original ids: node ids in both tables are the original ids from the IdMap
+// - Deterministic: labels, relationship types and property keys are emitted in sorted order
+// - NOT IO: produces in-memory tables; writing Parquet/IPC is up to the caller
+//
+// Table layouts:
+// - Nodes: id (Int64), label (Utf8, only if the store has labels), one column per node property
+// - Relationships: source (Int64), target (Int64), type (Utf8), one Float64 column per
+//   relationship property key (null for relationship types without that property)
+
+use super::reference::{ArrowReferenceError, EdgeTableReference, NodeTableReference};
+use crate::projection::RelationshipType;
+use crate::types::graph::id_map::{IdMap, MappedNodeId};
+use crate::types::graph::Graph;
+use crate::types::prelude::GraphStore;
+use crate::types::properties::node::NodePropertyValues;
+use crate::types::ValueType;
+use arrow2::array::{
+    Array, Float64Array, Int64Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend,
+    Utf8Array,
+};
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::types::NativeType;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use thiserror::Error;
+
+// ================================================================================================
+// Error Types
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum ArrowExportError {
+    #[error("Cannot export property '{property_key}' of type {value_type:?}")]
+    UnsupportedPropertyType {
+        property_key: String,
+        value_type: ValueType,
+    },
+
+    #[error("Failed to read property '{property_key}': {message}")]
+    PropertyAccess {
+        property_key: String,
+        message: String,
+    },
+
+    #[error("Failed to build column '{column_name}': {message}")]
+    ColumnBuild {
+        column_name: String,
+        message: String,
+    },
+
+    #[error("Graph error: {message}")]
+    Graph { message: String },
+}
+
+// ================================================================================================
+// RecordBatch - arrow2 chunk together with its schema
+// ================================================================================================
+
+/// An exported table: an arrow2 chunk together with its schema.
+///
+/// arrow2 keeps schema and data apart; this bundles them the way an Arrow
+/// `RecordBatch` does so the table can be handed to writers or loaded back.
+#[derive(Debug, Clone)]
+pub struct RecordBatch {
+    schema: Arc<Schema>,
+    chunk: Chunk<Box<dyn Array>>,
+}
+
+impl RecordBatch {
+    fn new(fields: Vec<Field>, columns: Vec<Box<dyn Array>>) -> Self {
+        Self {
+            schema: Arc::new(Schema::from(fields)),
+            chunk: Chunk::new(columns),
+        }
+    }
+
+    /// Returns the schema
+    pub fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+
+    /// Returns the underlying chunk
+    pub fn chunk(&self) -> &Chunk<Box<dyn Array>> {
+        &self.chunk
+    }
+
+    /// Returns the number of rows
+    pub fn num_rows(&self) -> usize {
+        self.chunk.len()
+    }
+
+    /// Finds a column by name
+    pub fn column(&self, name: &str) -> Option<&dyn Array> {
+        self.schema
+            .fields
+            .iter()
+            .position(|field| field.name == name)
+            .map(|idx| self.chunk.arrays()[idx].as_ref())
+    }
+
+    /// Wraps the batch as a node table for re-import
+    pub fn into_node_table(
+        self,
+        table_name: impl Into<String>,
+    ) -> Result<NodeTableReference, ArrowReferenceError> {
+        NodeTableReference::new(table_name, self.chunk, self.schema)
+    }
+
+    /// Wraps the batch as an edge table for re-import
+    pub fn into_edge_table(
+        self,
+        table_name: impl Into<String>,
+    ) -> Result<EdgeTableReference, ArrowReferenceError> {
+        EdgeTableReference::new(table_name, self.chunk, self.schema)
+    }
+}
+
+// ================================================================================================
+// Node Export
+// ================================================================================================
+
+/// Exports all nodes of the store as a table.
+///
+/// Columns: `id` with the original node ids, `label` if any node carries a
+/// label (multiple labels are joined with `:` in sorted order), and one column
+/// per node property. Long and Double properties map to Int64 / Float64,
+/// array properties to List columns.
+pub fn export_nodes<G: GraphStore>(graph_store: &G) -> Result<RecordBatch, ArrowExportError> {
+    let id_map = graph_store.nodes();
+    let node_count = id_map.node_count();
+
+    let mut fields = vec![Field::new("id", DataType::Int64, false)];
+    let mut columns: Vec<Box<dyn Array>> = vec![Box::new(Int64Array::from_vec(original_ids(
+        id_map.as_ref(),
+        node_count,
+    )))];
+
+    let labels: Vec<String> = (0..node_count)
+        .map(|node| label_string(id_map.as_ref(), node as MappedNodeId))
+        .collect();
+    if labels.iter().any(|label| !label.is_empty()) {
+        fields.push(Field::new("label", DataType::Utf8, false));
+        columns.push(Box::new(Utf8Array::<i32>::from_slice(labels)));
+    }
+
+    for key in sorted(graph_store.node_property_keys()) {
+        let values = graph_store.node_property_values(&key).map_err(|e| {
+            ArrowExportError::PropertyAccess {
+                property_key: key.clone(),
+                message: e.to_string(),
+            }
+        })?;
+        let column = node_property_column(&key, values.as_ref(), node_count)?;
+        fields.push(Field::new(key, column.data_type().clone(), true));
+        columns.push(column);
+    }
+
+    Ok(RecordBatch::new(fields, columns))
+}
+
+fn original_ids(id_map: &dyn IdMap, node_count: usize) -> Vec<i64> {
+    (0..node_count)
+        .map(|node| {
+            id_map
+                .to_original_node_id(node as MappedNodeId)
+                .unwrap_or(node as i64)
+        })
+        .collect()
+}
+
+fn label_string(id_map: &dyn IdMap, node: MappedNodeId) -> String {
+    let mut labels: Vec<String> = id_map
+        .node_labels(node)
+        .into_iter()
+        .filter(|label| !label.is_all_nodes())
+        .map(|label| label.name().to_string())
+        .collect();
+    labels.sort();
+    labels.join(":")
+}
+
+fn node_property_column(
+    key: &str,
+    values: &dyn NodePropertyValues,
+    node_count: usize,
+) -> Result<Box<dyn Array>, ArrowExportError> {
+    let access_error = |e: &dyn std::fmt::Display| ArrowExportError::PropertyAccess {
+        property_key: key.to_string(),
+        message: e.to_string(),
+    };
+
+    match values.value_type() {
+        ValueType::Long => {
+            let column = (0..node_count as u64)
+                .map(|node| values.long_value(node))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| access_error(&e))?;
+            Ok(Box::new(Int64Array::from_vec(column)))
+        }
+        ValueType::Double => {
+            let column = (0..node_count as u64)
+                .map(|node| values.double_value(node))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| access_error(&e))?;
+            Ok(Box::new(Float64Array::from_vec(column)))
+        }
+        ValueType::LongArray => {
+            let rows = (0..node_count as u64)
+                .map(|node| values.long_array_value(node))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| access_error(&e))?;
+            list_column(key, rows)
+        }
+        ValueType::DoubleArray => {
+            let rows = (0..node_count as u64)
+                .map(|node| values.double_array_value(node))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| access_error(&e))?;
+            list_column(key, rows)
+        }
+        ValueType::FloatArray => {
+            let rows = (0..node_count as u64)
+                .map(|node| values.float_array_value(node))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| access_error(&e))?;
+            list_column(key, rows)
+        }
+        value_type => Err(ArrowExportError::UnsupportedPropertyType {
+            property_key: key.to_string(),
+            value_type,
+        }),
+    }
+}
+
+fn list_column<T: NativeType>(
+    key: &str,
+    rows: Vec<Vec<T>>,
+) -> Result<Box<dyn Array>, ArrowExportError> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<T>>::new();
+    array
+        .try_extend(rows.into_iter().map(|row| Some(row.into_iter().map(Some))))
+        .map_err(|e| ArrowExportError::ColumnBuild {
+            column_name: key.to_string(),
+            message: e.to_string(),
+        })?;
+    let array: ListArray<i32> = array.into();
+    Ok(Box::new(array))
+}
+
+// ================================================================================================
+// Relationship Export
+// ================================================================================================
+
+/// Exports all relationships of the store as a table.
+///
+/// Columns: `source` and `target` with original node ids, `type`, and one
+/// Float64 column per relationship property key. Rows are grouped by
+/// relationship type; a property column is null for types without it.
+pub fn export_relationships<G: GraphStore>(
+    graph_store: &G,
+) -> Result<RecordBatch, ArrowExportError> {
+    let id_map = graph_store.nodes();
+    let ids = original_ids(id_map.as_ref(), id_map.node_count());
+
+    let relationship_types = sorted(graph_store.relationship_types());
+    let property_keys = sorted(graph_store.relationship_property_keys());
+
+    let mut sources = Vec::new();
+    let mut targets = Vec::new();
+    let mut types = Vec::new();
+    let mut properties: Vec<Vec<Option<f64>>> = vec![Vec::new(); property_keys.len()];
+
+    for relationship_type in &relationship_types {
+        let graph = typed_graph(graph_store, relationship_type, None)?;
+        let rows_before = sources.len();
+        for_each_relationship(graph.as_ref(), |source, target, _| {
+            sources.push(ids[source as usize]);
+            targets.push(ids[target as usize]);
+            types.push(relationship_type.name().to_string());
+        });
+        let row_count = sources.len() - rows_before;
+
+        for (key, column) in property_keys.iter().zip(properties.iter_mut()) {
+            if graph_store.has_relationship_property(relationship_type, key) {
+                let graph = typed_graph(graph_store, relationship_type, Some(key))?;
+                for_each_relationship(graph.as_ref(), |_, _, property| {
+                    column.push(Some(property));
+                });
+            } else {
+                column.extend(std::iter::repeat_n(None, row_count));
+            }
+        }
+    }
+
+    let mut fields = vec![
+        Field::new("source", DataType::Int64, false),
+        Field::new("target", DataType::Int64, false),
+        Field::new("type", DataType::Utf8, false),
+    ];
+    let mut columns: Vec<Box<dyn Array>> = vec![
+        Box::new(Int64Array::from_vec(sources)),
+        Box::new(Int64Array::from_vec(targets)),
+        Box::new(Utf8Array::<i32>::from_slice(types)),
+    ];
+    for (key, column) in property_keys.into_iter().zip(properties) {
+        fields.push(Field::new(key, DataType::Float64, true));
+        columns.push(Box::new(Float64Array::from(column)));
+    }
+
+    Ok(RecordBatch::new(fields, columns))
+}
+
+fn typed_graph<G: GraphStore>(
+    graph_store: &G,
+    relationship_type: &RelationshipType,
+    property_key: Option<&str>,
+) -> Result<Arc<dyn Graph>, ArrowExportError> {
+    let types = HashSet::from([relationship_type.clone()]);
+    let selectors: HashMap<RelationshipType, String> = property_key
+        .map(|key| HashMap::from([(relationship_type.clone(), key.to_string())]))
+        .unwrap_or_default();
+    graph_store
+        .get_graph_with_types_and_selectors(&types, &selectors)
+        .map_err(|e| ArrowExportError::Graph {
+            message: e.to_string(),
+        })
+}
+
+/// Visits `(source, target, property)` for every relationship in node order
+fn for_each_relationship(graph: &dyn Graph, mut consumer: impl FnMut(i64, i64, f64)) {
+    for node in 0..graph.node_count() {
+        for cursor in graph.stream_relationships(node as MappedNodeId, f64::NAN) {
+            consumer(cursor.source_id(), cursor.target_id(), cursor.property());
+        }
+    }
+}
+
+fn sorted<T: Ord>(values: impl IntoIterator<Item = T>) -> Vec<T> {
+    let mut values: Vec<T> = values.into_iter().collect();
+    values.sort();
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        EdgeAccumulator, EdgeBatchScanner, EdgeImportTaskFactory, NodeAccumulator,
+        NodeBatchScanner, NodeImportTaskFactory, TaskRunner,
+    };
+    use super::*;
+    use crate::collections::backends::vec::VecLong;
+    use crate::config::GraphStoreConfig;
+    use crate::projection::NodeLabel;
+    use crate::types::graph_store::{
+        Capabilities, DatabaseId, DatabaseInfo, DatabaseLocation, DefaultGraphStore, GraphName,
+    };
+    use crate::types::properties::node::DefaultLongNodePropertyValues;
+    use crate::types::schema::GraphSchema;
+    use arrow2::array::Float32Array;
+    use std::sync::Mutex;
+
+    fn node_batch() -> RecordBatch {
+        RecordBatch::new(
+            vec![
+                Field::new("id", DataType::Int64, false),
+                Field::new("label", DataType::Utf8, false),
+                Field::new("age", DataType::Int64, true),
+            ],
+            vec![
+                Box::new(Int64Array::from_slice([100, 101, 102, 103])),
+                Box::new(Utf8Array::<i32>::from_slice([
+                    "Person", "Person", "Company", "Person",
+                ])),
+                Box::new(Int64Array::from_slice([31, 42, 7, 25])),
+            ],
+        )
+    }
+
+    fn edge_batch() -> RecordBatch {
+        RecordBatch::new(
+            vec![
+                Field::new("source", DataType::Int64, false),
+                Field::new("target", DataType::Int64, false),
+                Field::new("type", DataType::Utf8, false),
+            ],
+            vec![
+                Box::new(Int64Array::from_slice([100, 101, 100, 103, 101])),
+                Box::new(Int64Array::from_slice([101, 102, 102, 100, 103])),
+                Box::new(Utf8Array::<i32>::from_slice([
+                    "KNOWS", "WORKS_AT", "WORKS_AT", "KNOWS", "KNOWS",
+                ])),
+            ],
+        )
+    }
+
+    /// Imports the tables through the scanner / task pipeline and attaches
+    /// the `age` column as a node property.
+    fn import(nodes: RecordBatch, edges: RecordBatch) -> DefaultGraphStore {
+        let ages: Vec<i64> = nodes
+            .column("age")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap()
+            .values()
+            .to_vec();
+
+        let runner = TaskRunner::new(2).unwrap();
+
+        let node_table = Arc::new(nodes.into_node_table("nodes").unwrap());
+        let node_accumulator = Arc::new(Mutex::new(NodeAccumulator::new()));
+        runner
+            .run_import(
+                Arc::new(NodeBatchScanner::new(node_table, 2).unwrap()),
+                Arc::new(NodeImportTaskFactory::new(node_accumulator.clone())),
+            )
+            .unwrap();
+        let node_accumulator = Arc::try_unwrap(node_accumulator)
+            .unwrap()
+            .into_inner()
+            .unwrap();
+        let id_map = Arc::new(node_accumulator.build_id_map());
+
+        let edge_table = Arc::new(edges.into_edge_table("edges").unwrap());
+        let edge_accumulator = Arc::new(Mutex::new(EdgeAccumulator::new()));
+        runner
+            .run_import(
+                Arc::new(EdgeBatchScanner::new(edge_table, 2).unwrap()),
+                Arc::new(EdgeImportTaskFactory::new(
+                    edge_accumulator.clone(),
+                    id_map.clone(),
+                )),
+            )
+            .unwrap();
+        let edge_accumulator = Arc::try_unwrap(edge_accumulator)
+            .unwrap()
+            .into_inner()
+            .unwrap();
+        let topologies = edge_accumulator.build_topology(&id_map).unwrap();
+
+        // Import order is not guaranteed across tasks, so align ages by id.
+        let ages_by_id: HashMap<i64, i64> = [100, 101, 102, 103].into_iter().zip(ages).collect();
+        let mapped_ages: Vec<i64> = (0..id_map.node_count())
+            .map(|node| ages_by_id[&id_map.to_original_node_id(node as i64).unwrap()])
+            .collect();
+
+        let mut store = DefaultGraphStore::new(
+            GraphStoreConfig::default(),
+            GraphName::new("arrow"),
+            DatabaseInfo::new(
+                DatabaseId::new("db"),
+                DatabaseLocation::remote("localhost", 7687, None, None),
+            ),
+            GraphSchema::empty(),
+            Capabilities::default(),
+            Arc::try_unwrap(id_map).unwrap(),
+            topologies,
+        );
+        store
+            .add_node_property(
+                HashSet::from([NodeLabel::all_nodes()]),
+                "age",
+                Arc::new(DefaultLongNodePropertyValues::from_collection(
+                    VecLong::from(mapped_ages),
+                    4,
+                )),
+            )
+            .unwrap();
+        store
+    }
+
+    fn int64_column<'a>(batch: &'a RecordBatch, name: &str) -> &'a Int64Array {
+        batch
+            .column(name)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let store = import(node_batch(), edge_batch());
+
+        let nodes = export_nodes(&store).unwrap();
+        let edges = export_relationships(&store).unwrap();
+        assert_eq!(nodes.num_rows(), 4);
+        assert_eq!(edges.num_rows(), 5);
+
+        // Property column survives, keyed by original id
+        let exported: HashMap<i64, i64> = int64_column(&nodes, "id")
+            .values()
+            .iter()
+            .copied()
+            .zip(int64_column(&nodes, "age").values().iter().copied())
+            .collect();
+        assert_eq!(
+            exported,
+            HashMap::from([(100, 31), (101, 42), (102, 7), (103, 25)])
+        );
+
+        // Edges keep their original endpoints and types
+        let types = edges
+            .column("type")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()
+            .unwrap();
+        let mut exported_edges: Vec<(i64, i64, String)> = (0..edges.num_rows())
+            .map(|row| {
+                (
+                    int64_column(&edges, "source").value(row),
+                    int64_column(&edges, "target").value(row),
+                    types.value(row).to_string(),
+                )
+            })
+            .collect();
+        exported_edges.sort();
+        assert_eq!(
+            exported_edges,
+            vec![
+                (100, 101, "KNOWS".to_string()),
+                (100, 102, "WORKS_AT".to_string()),
+                (101, 102, "WORKS_AT".to_string()),
+                (101, 103, "KNOWS".to_string()),
+                (103, 100, "KNOWS".to_string()),
+            ]
+        );
+
+        // And the exported tables import again
+        let reimported = import(nodes, edges);
+        assert_eq!(reimported.node_count(), 4);
+        assert_eq!(reimported.relationship_count(), 5);
+    }
+
+    #[test]
+    fn test_export_labels_and_array_properties() {
+        let store = import(node_batch(), edge_batch());
+        let nodes = export_nodes(&store).unwrap();
+
+        let labels = nodes
+            .column("label")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()
+            .unwrap();
+        let ids = int64_column(&nodes, "id");
+        for row in 0..nodes.num_rows() {
+            let expected = if ids.value(row) == 102 {
+                "Company"
+            } else {
+                "Person"
+            };
+            assert_eq!(labels.value(row), expected);
+        }
+
+        let embedding = vec![vec![0.5f32, 1.5]; 2];
+        let column = list_column("embedding", embedding).unwrap();
+        let list = column.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+        assert_eq!(list.len(), 2);
+        let first = list.value(0);
+        let first = first.as_any().downcast_ref::<Float32Array>().unwrap();
+        assert_eq!(first.values().as_slice(), &[0.5, 1.5]);
+    }
+}
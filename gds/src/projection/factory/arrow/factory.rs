@@ -9,8 +9,20 @@
 // - Database I/O → In-memory columnar data
 
 use super::config::{ArrowProjectionConfig, ArrowProjectionError};
+use super::importer::{
+    NodeAccumulator, NodeImportTaskFactory, PartitionedEdgeAccumulator,
+    PartitionedEdgeImportTaskFactory, PropertyConfig,
+};
+use super::reference::{EdgeTableReference, NodeTableReference};
+use super::scanner::{EdgeBatchScanner, NodeBatchScanner};
+use super::schema_inference::SchemaInferenceReport;
+use super::task::TaskRunner;
 use crate::projection::factory::GraphStoreFactory;
+use crate::projection::RelationshipType;
+use crate::types::graph::{RelationshipTopology, SimpleIdMap};
 use crate::types::graph_store::DefaultGraphStore;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 /// Arrow-native factory for creating GraphStores.
 ///
@@ -86,6 +98,98 @@ impl ArrowNativeFactory {
     pub fn new() -> Self {
         Self { _placeholder: () }
     }
+
+    /// Import the rows of a node table in parallel.
+    ///
+    /// Uses `config.concurrency` tasks over batches of `config.batch_size` rows and
+    /// resolves repeated node ids with `config.duplicate_node_policy`. Under
+    /// `DuplicateNodePolicy::Error` a repeated id fails the import with
+    /// `ArrowProjectionError::DuplicateNode`.
+    ///
+    /// The returned accumulator builds the id map and node properties.
+    pub fn import_nodes(
+        &self,
+        node_table: Arc<NodeTableReference>,
+        property_configs: Vec<PropertyConfig>,
+        config: &ArrowProjectionConfig,
+    ) -> Result<NodeAccumulator, ArrowProjectionError> {
+        config.validate()?;
+
+        let accumulator = Arc::new(Mutex::new(
+            NodeAccumulator::new_with_properties(property_configs)
+                .with_duplicate_policy(config.duplicate_node_policy),
+        ));
+        let scanner = NodeBatchScanner::new(node_table, config.batch_size)
+            .map_err(|e| ArrowProjectionError::Import(e.to_string()))?;
+        TaskRunner::new(config.concurrency)
+            .and_then(|runner| {
+                runner.run_import(
+                    Arc::new(scanner),
+                    Arc::new(NodeImportTaskFactory::new(accumulator.clone())),
+                )
+            })
+            .map_err(|e| ArrowProjectionError::Import(e.to_string()))?;
+
+        let accumulator = Arc::try_unwrap(accumulator)
+            .map_err(|_| ArrowProjectionError::Other("node import tasks still running".into()))?
+            .into_inner()
+            .map_err(|e| ArrowProjectionError::Import(e.to_string()))?;
+        accumulator.check_duplicates()?;
+
+        Ok(accumulator)
+    }
+
+    /// Import a node table with property columns inferred from its schema.
+    ///
+    /// Every column [`SchemaInferenceReport::for_node_table`] can map is
+    /// imported as described by the report, which is returned alongside the
+    /// accumulator so callers can surface its nulls, coercions and skipped
+    /// columns.
+    pub fn import_nodes_inferred(
+        &self,
+        node_table: Arc<NodeTableReference>,
+        config: &ArrowProjectionConfig,
+    ) -> Result<(NodeAccumulator, SchemaInferenceReport), ArrowProjectionError> {
+        let report = SchemaInferenceReport::for_node_table(&node_table);
+        let accumulator = self.import_nodes(node_table, report.property_configs(), config)?;
+        Ok((accumulator, report))
+    }
+
+    /// Import the rows of an edge table in parallel.
+    ///
+    /// Uses `config.concurrency` tasks over batches of `config.batch_size` rows.
+    /// Edges are routed into `config.concurrency` source-node partitions balanced
+    /// by out-degree (see [`PartitionedEdgeAccumulator`]), so skewed graphs spread
+    /// over the partitions instead of piling into one. The resulting topologies list
+    /// targets in table row order, exactly as a sequential import.
+    pub fn import_edges(
+        &self,
+        edge_table: Arc<EdgeTableReference>,
+        id_map: Arc<SimpleIdMap>,
+        config: &ArrowProjectionConfig,
+    ) -> Result<HashMap<RelationshipType, RelationshipTopology>, ArrowProjectionError> {
+        config.validate()?;
+
+        let accumulator = Arc::new(
+            PartitionedEdgeAccumulator::for_edge_table(id_map, &edge_table, config.concurrency)
+                .map_err(|e| ArrowProjectionError::Import(e.to_string()))?,
+        );
+        let scanner = EdgeBatchScanner::new(edge_table, config.batch_size)
+            .map_err(|e| ArrowProjectionError::Import(e.to_string()))?;
+        TaskRunner::new(config.concurrency)
+            .and_then(|runner| {
+                runner.run_import(
+                    Arc::new(scanner),
+                    Arc::new(PartitionedEdgeImportTaskFactory::new(accumulator.clone())),
+                )
+            })
+            .map_err(|e| ArrowProjectionError::Import(e.to_string()))?;
+
+        Arc::try_unwrap(accumulator)
+            .map_err(|_| ArrowProjectionError::Other("edge import tasks still running".into()))?
+            .build_topology()
+            .map_err(|e| ArrowProjectionError::Import(e.to_string()))
+    }
 }
 
 impl Default for ArrowNativeFactory {
@@ -271,6 +375,72 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_import_nodes_rejects_duplicate_ids() {
+        use arrow2::array::{Array, Int64Array};
+        use arrow2::chunk::Chunk;
+        use arrow2::datatypes::{DataType, Field, Schema};
+
+        let chunk = Chunk::new(vec![
+            Box::new(Int64Array::from_slice([7, 8, 7])) as Box<dyn Array>
+        ]);
+        let schema = Arc::new(Schema::from(vec![Field::new("id", DataType::Int64, false)]));
+        let table = Arc::new(NodeTableReference::new("people", chunk, schema).unwrap());
+        let config = ArrowProjectionConfig::builder()
+            .concurrency(2)
+            .batch_size(1)
+            .build()
+            .unwrap();
+
+        let result = ArrowNativeFactory::new().import_nodes(table, vec![], &config);
+
+        match result {
+            Err(error @ ArrowProjectionError::DuplicateNode(7)) => {
+                assert!(error.to_string().contains("Duplicate node id 7"));
+            }
+            other => panic!("Expected DuplicateNode(7), got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_import_nodes_inferred_returns_report() {
+        use arrow2::array::{Array, Float32Array, Int64Array, Utf8Array};
+        use arrow2::chunk::Chunk;
+        use arrow2::datatypes::{DataType, Field, Schema};
+
+        let chunk = Chunk::new(vec![
+            Box::new(Int64Array::from_slice([1, 2, 3])) as Box<dyn Array>,
+            Box::new(Float32Array::from([Some(0.5), None, Some(1.5)])),
+            Box::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+        ]);
+        let schema = Arc::new(Schema::from(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("score", DataType::Float32, true),
+            Field::new("name", DataType::Utf8, false),
+        ]));
+        let table = Arc::new(NodeTableReference::new("people", chunk, schema).unwrap());
+        let config = ArrowProjectionConfig::builder()
+            .concurrency(2)
+            .batch_size(2)
+            .build()
+            .unwrap();
+
+        let (accumulator, report) = ArrowNativeFactory::new()
+            .import_nodes_inferred(table, &config)
+            .unwrap();
+
+        assert_eq!(accumulator.node_count(), 3);
+        let keys: Vec<String> = accumulator
+            .property_configs()
+            .into_iter()
+            .map(|property| property.key)
+            .collect();
+        assert_eq!(keys, vec!["score".to_string()]);
+        assert_eq!(report.property("score").unwrap().null_count, 1);
+        assert_eq!(report.skipped_columns()[0].name, "name");
+        assert_eq!(report.warnings().len(), 3);
+    }
+
     #[test]
     fn test_estimate_memory_placeholder() {
         let factory = ArrowNativeFactory::new();
@@ -24,6 +24,7 @@
 // - Aggregation logic deferred to Phase 7
 
 use super::{
+    config::DuplicateNodePolicy,
     task::{ImportTask, TaskError, TaskFactory},
     ArrowBatchReference, ScanCursor,
 };
@@ -226,25 +227,35 @@ impl PropertyAccumulator {
 /// Stores original node IDs, their associated labels, and property values.
 /// After all tasks complete, this can be converted into a `SimpleIdMap` and PropertyValues.
 ///
+/// Repeated node IDs are resolved by the configured `DuplicateNodePolicy`. Each node
+/// remembers the row its labels and properties came from, so "first" and "last" follow
+/// table row order even when batches arrive out of order from parallel tasks.
+///
 /// Thread-safety: Wrapped in `Arc<Mutex<_>>` for parallel writes.
 #[derive(Debug)]
 pub struct NodeAccumulator {
     /// Original node IDs in insertion order
     original_ids: Vec<OriginalNodeId>,
+    /// Insertion index per original node ID
+    index_by_id: HashMap<OriginalNodeId, usize>,
+    /// Row that provided the current labels/properties (indexed by insertion order)
+    source_rows: Vec<usize>,
     /// Label sets per node (indexed by insertion order)
     labels_by_node: HashMap<usize, HashSet<NodeLabel>>,
     /// Property accumulators (one per configured property)
     property_accumulators: Vec<PropertyAccumulator>,
+    /// How repeated node IDs are resolved
+    duplicate_policy: DuplicateNodePolicy,
+    /// First repeated node ID seen under `DuplicateNodePolicy::Error`
+    duplicate_node: Option<OriginalNodeId>,
+    /// Row counter for nodes added without an explicit row
+    next_row: usize,
 }
 
 impl NodeAccumulator {
     /// Creates a new empty accumulator without properties.
     pub fn new() -> Self {
-        Self {
-            original_ids: Vec::new(),
-            labels_by_node: HashMap::new(),
-            property_accumulators: Vec::new(),
-        }
+        Self::new_with_properties(Vec::new())
     }
 
     /// Creates a new accumulator with property configurations.
@@ -256,11 +267,35 @@ impl NodeAccumulator {
 
         Self {
             original_ids: Vec::new(),
+            index_by_id: HashMap::new(),
+            source_rows: Vec::new(),
             labels_by_node: HashMap::new(),
             property_accumulators,
+            duplicate_policy: DuplicateNodePolicy::default(),
+            duplicate_node: None,
+            next_row: 0,
         }
     }
 
+    /// Sets the policy for repeated node IDs.
+    pub fn with_duplicate_policy(mut self, policy: DuplicateNodePolicy) -> Self {
+        self.duplicate_policy = policy;
+        self
+    }
+
+    /// Returns the policy for repeated node IDs.
+    pub fn duplicate_policy(&self) -> DuplicateNodePolicy {
+        self.duplicate_policy
+    }
+
+    /// Returns the property configurations, in the order `add_node_with_properties` expects.
+    pub fn property_configs(&self) -> Vec<PropertyConfig> {
+        self.property_accumulators
+            .iter()
+            .map(|accumulator| accumulator.config.clone())
+            .collect()
+    }
+
     /// Adds a node with its original ID and labels (no properties).
     ///
     /// # Arguments
@@ -270,13 +305,7 @@ impl NodeAccumulator {
     /// # Returns
     /// The insertion index (will become mapped node ID)
     pub fn add_node(&mut self, original_id: OriginalNodeId, labels: Vec<NodeLabel>) -> usize {
-        let index = self.original_ids.len();
-        self.original_ids.push(original_id);
-        if !labels.is_empty() {
-            self.labels_by_node
-                .insert(index, labels.into_iter().collect());
-        }
-        index
+        self.add_node_with_properties(original_id, labels, Vec::new())
     }
 
     /// Adds a node with properties.
@@ -294,14 +323,83 @@ impl NodeAccumulator {
         labels: Vec<NodeLabel>,
         properties: Vec<PropertyValue>,
     ) -> usize {
-        let index = self.add_node(original_id, labels);
+        let row = self.next_row;
+        self.add_node_at_row(row, original_id, labels, properties)
+    }
+
+    /// Adds a node read from the given table row.
+    ///
+    /// If the ID was added before, the duplicate policy decides which row's labels and
+    /// properties are kept. Under `DuplicateNodePolicy::Error` the row is dropped and the
+    /// ID is reported by `check_duplicates()`.
+    ///
+    /// # Returns
+    /// The insertion index (will become mapped node ID)
+    fn add_node_at_row(
+        &mut self,
+        row: usize,
+        original_id: OriginalNodeId,
+        labels: Vec<NodeLabel>,
+        properties: Vec<PropertyValue>,
+    ) -> usize {
+        self.next_row = self.next_row.max(row + 1);
+
+        let Some(&index) = self.index_by_id.get(&original_id) else {
+            let index = self.original_ids.len();
+            self.original_ids.push(original_id);
+            self.index_by_id.insert(original_id, index);
+            self.source_rows.push(row);
+            self.set_labels(index, labels);
+            self.set_properties(original_id, properties);
+            return index;
+        };
+
+        let replaces = match self.duplicate_policy {
+            DuplicateNodePolicy::Error => {
+                self.duplicate_node.get_or_insert(original_id);
+                return index;
+            }
+            DuplicateNodePolicy::KeepFirst => row < self.source_rows[index],
+            DuplicateNodePolicy::KeepLast | DuplicateNodePolicy::Merge => {
+                row > self.source_rows[index]
+            }
+        };
 
-        // Set properties in each accumulator
+        if self.duplicate_policy == DuplicateNodePolicy::Merge {
+            self.labels_by_node.entry(index).or_default().extend(labels);
+        } else if replaces {
+            self.labels_by_node.remove(&index);
+            self.set_labels(index, labels);
+        }
+
+        if replaces {
+            self.source_rows[index] = row;
+            self.set_properties(original_id, properties);
+        }
+
+        index
+    }
+
+    fn set_labels(&mut self, index: usize, labels: Vec<NodeLabel>) {
+        if !labels.is_empty() {
+            self.labels_by_node
+                .insert(index, labels.into_iter().collect());
+        }
+    }
+
+    fn set_properties(&mut self, original_id: OriginalNodeId, properties: Vec<PropertyValue>) {
         for (accumulator, value) in self.property_accumulators.iter_mut().zip(properties) {
             accumulator.set(original_id, value);
         }
+    }
 
-        index
+    /// Fails with `ImporterError::DuplicateNode` if a repeated node ID was dropped
+    /// under `DuplicateNodePolicy::Error`.
+    pub fn check_duplicates(&self) -> Result<(), ImporterError> {
+        match self.duplicate_node {
+            Some(original_id) => Err(ImporterError::DuplicateNode { original_id }),
+            None => Ok(()),
+        }
     }
 
     /// Returns the number of accumulated nodes.
@@ -467,6 +565,180 @@ impl Default for EdgeAccumulator {
     }
 }
 
+// ================================================================================================
+// PartitionedEdgeAccumulator - Source-range partitioned edge buffers
+// ================================================================================================
+
+/// An edge routed to a partition: (row index, mapped source, mapped target, relationship type)
+type PartitionedEdge = (usize, MappedNodeId, MappedNodeId, RelationshipType);
+
+/// Accumulates edge data in per-partition buffers keyed by source-node range.
+///
+/// Partition boundaries follow the cumulative out-degree of the mapped source ids, so
+/// every partition owns a contiguous source range holding roughly the same number of
+/// edges; a hub node gets a partition of its own instead of sharing one with its
+/// neighbors. Concurrent tasks writing edges of different source ranges lock different
+/// buffers instead of contending on one accumulator. Edges keep their table row index;
+/// merging sorts each buffer by row, which makes the resulting topology identical to a
+/// sequential import of the same table.
+///
+/// Thread-safety: Each partition buffer has its own Mutex; share via `Arc`.
+#[derive(Debug)]
+pub struct PartitionedEdgeAccumulator {
+    id_map: Arc<SimpleIdMap>,
+    /// First mapped source id of each partition, ascending and starting at 0
+    partition_starts: Vec<MappedNodeId>,
+    partitions: Vec<Mutex<Vec<PartitionedEdge>>>,
+}
+
+impl PartitionedEdgeAccumulator {
+    /// Creates an accumulator with at most `partition_count` source ranges over the nodes
+    /// of `id_map`, balanced by `degrees`, the out-degree of every mapped node.
+    ///
+    /// A range is closed once it holds its share of the remaining edges, so a single node
+    /// whose degree exceeds that share forms a partition of its own.
+    pub fn new(id_map: Arc<SimpleIdMap>, degrees: &[usize], partition_count: usize) -> Self {
+        let node_count = id_map.node_count();
+        let mut remaining_partitions = partition_count.max(1);
+        let mut remaining_edges: usize = degrees.iter().sum();
+        let mut share = remaining_edges.div_ceil(remaining_partitions).max(1);
+        let mut current = 0;
+
+        let mut partition_starts = vec![0];
+        for (node, &degree) in degrees.iter().enumerate().take(node_count) {
+            current += degree;
+            if current >= share && remaining_partitions > 1 && node + 1 < node_count {
+                partition_starts.push((node + 1) as MappedNodeId);
+                remaining_edges -= current;
+                remaining_partitions -= 1;
+                share = remaining_edges.div_ceil(remaining_partitions).max(1);
+                current = 0;
+            }
+        }
+        let partitions = partition_starts.iter().map(|_| Mutex::new(Vec::new())).collect();
+
+        Self {
+            id_map,
+            partition_starts,
+            partitions,
+        }
+    }
+
+    /// Creates an accumulator balanced by the out-degrees of the edges in `edge_table`.
+    ///
+    /// Returns `ImporterError::InvalidNodeId` if a source id is missing from `id_map`.
+    pub fn for_edge_table(
+        id_map: Arc<SimpleIdMap>,
+        edge_table: &super::EdgeTableReference,
+        partition_count: usize,
+    ) -> Result<Self, ImporterError> {
+        let mut degrees = vec![0usize; id_map.node_count()];
+        for &original_id in edge_table.source_column().values().iter() {
+            let source = id_map
+                .safe_to_mapped_node_id(original_id)
+                .ok_or(ImporterError::InvalidNodeId { original_id })?;
+            degrees[source as usize] += 1;
+        }
+        Ok(Self::new(id_map, &degrees, partition_count))
+    }
+
+    /// Returns the number of partitions.
+    pub fn partition_count(&self) -> usize {
+        self.partitions.len()
+    }
+
+    /// Returns the mapped source ids owned by `partition`.
+    pub fn partition_range(&self, partition: usize) -> std::ops::Range<MappedNodeId> {
+        let end = self
+            .partition_starts
+            .get(partition + 1)
+            .copied()
+            .unwrap_or(self.id_map.node_count() as MappedNodeId);
+        self.partition_starts[partition]..end
+    }
+
+    /// Returns the partition owning the given mapped source node.
+    pub fn partition_of(&self, source: MappedNodeId) -> usize {
+        self.partition_starts.partition_point(|&start| start <= source) - 1
+    }
+
+    /// Adds a batch of edges given as (row index, source original, target original, type).
+    ///
+    /// Edges are grouped by partition first, so each partition lock is taken at most once
+    /// per call.
+    pub fn add_edges(
+        &self,
+        edges: impl IntoIterator<Item = (usize, OriginalNodeId, OriginalNodeId, RelationshipType)>,
+    ) -> Result<(), ImporterError> {
+        let mut buffers: Vec<Vec<PartitionedEdge>> = vec![Vec::new(); self.partitions.len()];
+
+        for (row, source_original, target_original, rel_type) in edges {
+            let source = self.to_mapped(source_original)?;
+            let target = self.to_mapped(target_original)?;
+            buffers[self.partition_of(source)].push((row, source, target, rel_type));
+        }
+
+        for (partition, buffer) in self.partitions.iter().zip(buffers) {
+            if buffer.is_empty() {
+                continue;
+            }
+            partition
+                .lock()
+                .map_err(|e| ImporterError::LockError {
+                    message: e.to_string(),
+                })?
+                .extend(buffer);
+        }
+
+        Ok(())
+    }
+
+    fn to_mapped(&self, original_id: OriginalNodeId) -> Result<MappedNodeId, ImporterError> {
+        self.id_map
+            .safe_to_mapped_node_id(original_id)
+            .ok_or(ImporterError::InvalidNodeId { original_id })
+    }
+
+    /// Returns the number of accumulated edges.
+    pub fn edge_count(&self) -> usize {
+        self.partitions
+            .iter()
+            .map(|partition| partition.lock().map(|edges| edges.len()).unwrap_or(0))
+            .sum()
+    }
+
+    /// Merges the partitions into relationship topologies grouped by relationship type.
+    ///
+    /// Adjacency lists list targets in table row order, as the sequential
+    /// `EdgeAccumulator::build_topology` does.
+    pub fn build_topology(
+        self,
+    ) -> Result<HashMap<RelationshipType, RelationshipTopology>, ImporterError> {
+        let node_count = self.id_map.node_count();
+        let mut outgoing_by_type: HashMap<RelationshipType, Vec<Vec<MappedNodeId>>> =
+            HashMap::new();
+
+        for partition in self.partitions {
+            let mut edges = partition.into_inner().map_err(|e| ImporterError::LockError {
+                message: e.to_string(),
+            })?;
+            edges.sort_unstable_by_key(|&(row, ..)| row);
+
+            for (_, source, target, rel_type) in edges {
+                outgoing_by_type
+                    .entry(rel_type)
+                    .or_insert_with(|| vec![Vec::new(); node_count])[source as usize]
+                    .push(target);
+            }
+        }
+
+        Ok(outgoing_by_type
+            .into_iter()
+            .map(|(rel_type, outgoing)| (rel_type, RelationshipTopology::new(outgoing, None)))
+            .collect())
+    }
+}
+
 // ================================================================================================
 // NodeImportTask - ImportTask implementation for nodes
 // ================================================================================================
@@ -680,6 +952,7 @@ fn process_node_batch(
     let mut acc = accumulator.lock().map_err(|e| ImporterError::LockError {
         message: e.to_string(),
     })?;
+    let property_configs = acc.property_configs();
 
     // Process each row in the batch range
     for i in batch.start_offset()..batch.end_offset() {
@@ -693,7 +966,12 @@ fn process_node_batch(
             vec![]
         };
 
-        acc.add_node(original_id, labels);
+        let properties = property_configs
+            .iter()
+            .map(|config| extract_property_value(batch, config, i))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        acc.add_node_at_row(i, original_id, labels, properties);
         count += 1;
     }
 
@@ -909,6 +1187,93 @@ pub(super) fn process_edge_batch_with_consumer(
     Ok(true)
 }
 
+// ================================================================================================
+// PartitionedEdgeImportTask - ImportTask routing edges to source-range partitions
+// ================================================================================================
+
+/// Import task that routes edges into a shared `PartitionedEdgeAccumulator`.
+///
+/// Unlike `EdgeImportTask`, which holds one accumulator lock for a whole batch, this task
+/// maps and groups each batch locally and then appends to the affected partitions only.
+pub struct PartitionedEdgeImportTask {
+    task_index: usize,
+    accumulator: Arc<PartitionedEdgeAccumulator>,
+}
+
+impl PartitionedEdgeImportTask {
+    /// Creates a new partitioned edge import task.
+    pub fn new(task_index: usize, accumulator: Arc<PartitionedEdgeAccumulator>) -> Self {
+        Self {
+            task_index,
+            accumulator,
+        }
+    }
+}
+
+impl ImportTask for PartitionedEdgeImportTask {
+    fn execute(&mut self, cursor: &mut dyn ScanCursor) -> Result<(u64, u64), TaskError> {
+        let mut records_imported = 0u64;
+
+        while cursor.reserve_batch() {
+            let mut batch_result: Result<u64, TaskError> = Ok(0);
+
+            cursor.consume_batch(&mut |batch: &ArrowBatchReference| {
+                match process_partitioned_edge_batch(batch, &self.accumulator) {
+                    Ok(count) => {
+                        batch_result = Ok(count);
+                        true
+                    }
+                    Err(e) => {
+                        batch_result = Err(TaskError::ExecutionFailed {
+                            message: e.to_string(),
+                        });
+                        false
+                    }
+                }
+            });
+
+            records_imported += batch_result?;
+        }
+
+        Ok((records_imported, 0))
+    }
+
+    fn task_name(&self) -> String {
+        format!("partitioned-edge-import-task-{}", self.task_index)
+    }
+
+    fn task_index(&self) -> usize {
+        self.task_index
+    }
+}
+
+/// Processes a single edge batch, routing its edges to the accumulator partitions.
+fn process_partitioned_edge_batch(
+    batch: &ArrowBatchReference,
+    accumulator: &PartitionedEdgeAccumulator,
+) -> Result<u64, ImporterError> {
+    let source_column = batch.int64_column(0).ok_or(ImporterError::MissingColumn {
+        column_name: "source".to_string(),
+    })?;
+    let target_column = batch.int64_column(1).ok_or(ImporterError::MissingColumn {
+        column_name: "target".to_string(),
+    })?;
+    let type_column = batch.utf8_column(2);
+    let default_rel_type = RelationshipType::of("RELATED");
+
+    let rows = batch.start_offset()..batch.end_offset();
+    let count = rows.len() as u64;
+    accumulator.add_edges(rows.map(|i| {
+        let rel_type = match type_column {
+            Some(type_col) => RelationshipType::of(type_col.value(i)),
+            None => default_rel_type.clone(),
+        };
+        (i, source_column.value(i), target_column.value(i), rel_type)
+    }))?;
+
+    Ok(count)
+}
+
 // ================================================================================================
 // Task Factories
 // ================================================================================================
@@ -960,6 +1325,27 @@ impl TaskFactory for EdgeImportTaskFactory {
     }
 }
 
+/// Factory for creating PartitionedEdgeImportTask instances.
+pub struct PartitionedEdgeImportTaskFactory {
+    accumulator: Arc<PartitionedEdgeAccumulator>,
+}
+
+impl PartitionedEdgeImportTaskFactory {
+    /// Creates a new factory sharing the given partitioned accumulator.
+    pub fn new(accumulator: Arc<PartitionedEdgeAccumulator>) -> Self {
+        Self { accumulator }
+    }
+}
+
+impl TaskFactory for PartitionedEdgeImportTaskFactory {
+    fn create_task(&self, task_index: usize) -> Result<Box<dyn ImportTask>, TaskError> {
+        Ok(Box::new(PartitionedEdgeImportTask::new(
+            task_index,
+            self.accumulator.clone(),
+        )))
+    }
+}
+
 // ================================================================================================
 // Error Types
 // ================================================================================================
@@ -990,6 +1376,8 @@ pub enum ImporterError {
         column_index: usize,
         column_count: usize,
     },
+    /// Node ID occurs more than once under `DuplicateNodePolicy::Error`
+    DuplicateNode { original_id: OriginalNodeId },
 }
 
 impl std::fmt::Display for ImporterError {
@@ -1036,6 +1424,9 @@ impl std::fmt::Display for ImporterError {
                     property_key, column_index, column_count
                 )
             }
+            ImporterError::DuplicateNode { original_id } => {
+                write!(f, "Duplicate node ID: {} occurs more than once", original_id)
+            }
         }
     }
 }
@@ -1400,4 +1791,267 @@ mod tests {
             ImporterError::UnsupportedPropertyType { .. }
         ));
     }
+
+    fn skewed_edge_table(
+        edge_count: usize,
+        node_count: i64,
+    ) -> Arc<super::super::EdgeTableReference> {
+        use arrow2::array::Utf8Array;
+        use arrow2::chunk::Chunk;
+        use arrow2::datatypes::{Field, Schema};
+
+        // Half of all edges start at node 0; the rest follow a simple LCG.
+        let mut state = 42u64;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as i64
+        };
+        let mut sources = Vec::with_capacity(edge_count);
+        let mut targets = Vec::with_capacity(edge_count);
+        let mut types = Vec::with_capacity(edge_count);
+        for i in 0..edge_count {
+            let source = if i % 2 == 0 { 0 } else { next() % node_count };
+            sources.push(1000 + source);
+            targets.push(1000 + next() % node_count);
+            types.push(if i % 3 == 0 { "LIKES" } else { "KNOWS" });
+        }
+
+        let chunk = Chunk::new(vec![
+            Box::new(Int64Array::from_vec(sources)) as Box<dyn Array>,
+            Box::new(Int64Array::from_vec(targets)),
+            Box::new(Utf8Array::<i32>::from_slice(types)),
+        ]);
+        let schema = Arc::new(Schema::from(vec![
+            Field::new("source", DataType::Int64, false),
+            Field::new("target", DataType::Int64, false),
+            Field::new("type", DataType::Utf8, false),
+        ]));
+        Arc::new(super::super::EdgeTableReference::new("edges", chunk, schema).unwrap())
+    }
+
+    #[test]
+    fn test_partitioned_edge_import_matches_sequential() {
+        use super::super::{ArrowNativeFactory, ArrowProjectionConfig, ArrowReference};
+
+        let node_count = 500;
+        let edge_table = skewed_edge_table(20_000, node_count);
+        let id_map = Arc::new(SimpleIdMap::from_original_ids(1000..1000 + node_count));
+
+        // Sequential reference: every row in table order
+        let mut sequential = EdgeAccumulator::new();
+        let sources = edge_table.source_column();
+        let targets = edge_table.target_column();
+        let types = edge_table.type_column().unwrap();
+        for row in 0..edge_table.row_count() {
+            sequential.add_edge(
+                sources.value(row),
+                targets.value(row),
+                RelationshipType::of(types.value(row)),
+            );
+        }
+        let expected = sequential.build_topology(&id_map).unwrap();
+
+        // Parallel import through degree-balanced partitions
+        let config = ArrowProjectionConfig::builder()
+            .concurrency(4)
+            .batch_size(256)
+            .build()
+            .unwrap();
+        let actual = ArrowNativeFactory::new()
+            .import_edges(edge_table, id_map, &config)
+            .unwrap();
+
+        assert_eq!(actual.len(), expected.len());
+        for (rel_type, expected_topology) in &expected {
+            let actual_topology = &actual[rel_type];
+            for node in 0..node_count {
+                assert_eq!(
+                    actual_topology.outgoing(node),
+                    expected_topology.outgoing(node),
+                    "adjacency of node {} for {:?}",
+                    node,
+                    rel_type
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_partitioned_edge_import_runs_several_tasks() {
+        use super::super::{EdgeBatchScanner, TaskRunner};
+
+        let node_count = 500;
+        let edge_table = skewed_edge_table(20_000, node_count);
+        let id_map = Arc::new(SimpleIdMap::from_original_ids(1000..1000 + node_count));
+        let accumulator = Arc::new(
+            PartitionedEdgeAccumulator::for_edge_table(id_map, &edge_table, 8).unwrap(),
+        );
+        let scanner = Arc::new(EdgeBatchScanner::new(edge_table, 256).unwrap());
+        let factory = Arc::new(PartitionedEdgeImportTaskFactory::new(accumulator.clone()));
+        let result = TaskRunner::new(4).unwrap().run_import(scanner, factory).unwrap();
+
+        assert!(result.tasks_completed > 1, "expected several import tasks");
+        assert_eq!(result.total_records_imported, 20_000);
+        assert_eq!(accumulator.edge_count(), 20_000);
+    }
+
+    #[test]
+    fn test_partitions_balance_cumulative_degree() {
+        let node_count = 500;
+        let edge_table = skewed_edge_table(20_000, node_count);
+        let id_map = Arc::new(SimpleIdMap::from_original_ids(1000..1000 + node_count));
+        let accumulator =
+            PartitionedEdgeAccumulator::for_edge_table(id_map, &edge_table, 8).unwrap();
+
+        let mut degrees = vec![0usize; node_count as usize];
+        for &source in edge_table.source_column().values().iter() {
+            degrees[(source - 1000) as usize] += 1;
+        }
+        let partition_degree = |partition: usize| -> usize {
+            accumulator
+                .partition_range(partition)
+                .map(|node| degrees[node as usize])
+                .sum()
+        };
+
+        // Node 0 is the source of over half of the edges and gets a partition of its own
+        assert!(degrees[0] >= 10_000);
+        assert_eq!(accumulator.partition_range(0), 0..1);
+        // The other edges spread evenly over the remaining seven partitions
+        assert_eq!(accumulator.partition_count(), 8);
+        let share = (20_000 - degrees[0]).div_ceil(7);
+        let max_degree = degrees[1..].iter().copied().max().unwrap();
+        for partition in 1..8 {
+            assert!(partition_degree(partition) < share + max_degree);
+            assert_eq!(
+                accumulator.partition_of(accumulator.partition_range(partition).start),
+                partition
+            );
+        }
+        let covered: usize = (0..8).map(partition_degree).sum();
+        assert_eq!(covered, 20_000);
+    }
+
+    #[test]
+    fn test_partitioned_edge_accumulator_rejects_unknown_nodes() {
+        let id_map = Arc::new(SimpleIdMap::from_original_ids([10, 11, 12]));
+        let accumulator = PartitionedEdgeAccumulator::new(id_map, &[1, 1, 1], 16);
+        assert_eq!(accumulator.partition_count(), 3);
+
+        let result = accumulator.add_edges([(0, 10, 99, RelationshipType::of("REL"))]);
+        assert!(matches!(
+            result,
+            Err(ImporterError::InvalidNodeId { original_id: 99 })
+        ));
+        assert_eq!(accumulator.edge_count(), 0);
+    }
+
+    /// Node 7 appears twice: first as a Person aged 30, then as an Employee aged 31.
+    fn import_people(policy: DuplicateNodePolicy) -> NodeAccumulator {
+        use super::super::{ArrowNativeFactory, ArrowProjectionConfig, NodeTableReference};
+        use arrow2::array::Utf8Array;
+        use arrow2::chunk::Chunk;
+        use arrow2::datatypes::{Field, Schema};
+
+        let chunk = Chunk::new(vec![
+            Box::new(Int64Array::from_slice([7, 8, 7])) as Box<dyn Array>,
+            Box::new(Utf8Array::<i32>::from_slice(["Person", "Person", "Employee"])),
+            Box::new(Int64Array::from_slice([30, 40, 31])),
+        ]);
+        let schema = Arc::new(Schema::from(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("label", DataType::Utf8, false),
+            Field::new("age", DataType::Int64, false),
+        ]));
+        let table = Arc::new(NodeTableReference::new("people", chunk, schema).unwrap());
+        let config = ArrowProjectionConfig::builder()
+            .concurrency(2)
+            .batch_size(1)
+            .duplicate_node_policy(policy)
+            .build()
+            .unwrap();
+        let age = PropertyConfig::new("age", 2, DefaultValue::long(0), ValueType::Long);
+
+        ArrowNativeFactory::new()
+            .import_nodes(table, vec![age], &config)
+            .unwrap()
+    }
+
+    fn labels_of(acc: &NodeAccumulator, original_id: OriginalNodeId) -> Vec<String> {
+        let index = acc.index_by_id[&original_id];
+        let mut labels: Vec<String> = acc.labels_by_node[&index]
+            .iter()
+            .map(|label| label.name().to_string())
+            .collect();
+        labels.sort();
+        labels
+    }
+
+    fn age_of(acc: &NodeAccumulator, original_id: OriginalNodeId) -> i64 {
+        match acc.property_accumulators[0].values[&original_id] {
+            PropertyValue::Long(age) => age,
+            ref other => panic!("unexpected age value {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_duplicate_policy_keep_first() {
+        let acc = import_people(DuplicateNodePolicy::KeepFirst);
+
+        assert_eq!(acc.node_count(), 2);
+        assert_eq!(labels_of(&acc, 7), vec!["Person"]);
+        assert_eq!(age_of(&acc, 7), 30);
+        assert_eq!(age_of(&acc, 8), 40);
+    }
+
+    #[test]
+    fn test_duplicate_policy_keep_last() {
+        let acc = import_people(DuplicateNodePolicy::KeepLast);
+
+        assert_eq!(acc.node_count(), 2);
+        assert_eq!(labels_of(&acc, 7), vec!["Employee"]);
+        assert_eq!(age_of(&acc, 7), 31);
+    }
+
+    #[test]
+    fn test_duplicate_policy_merge() {
+        let acc = import_people(DuplicateNodePolicy::Merge);
+
+        assert_eq!(acc.node_count(), 2);
+        assert_eq!(labels_of(&acc, 7), vec!["Employee", "Person"]);
+        assert_eq!(age_of(&acc, 7), 31);
+        assert_eq!(labels_of(&acc, 8), vec!["Person"]);
+    }
+
+    #[test]
+    fn test_duplicate_policy_follows_row_order() {
+        // Rows arriving out of order still resolve by row index
+        let mut acc = NodeAccumulator::new_with_properties(vec![PropertyConfig::new(
+            "age",
+            2,
+            DefaultValue::long(0),
+            ValueType::Long,
+        )])
+        .with_duplicate_policy(DuplicateNodePolicy::KeepFirst);
+
+        acc.add_node_at_row(5, 7, vec![NodeLabel::of("Late")], vec![PropertyValue::Long(2)]);
+        acc.add_node_at_row(1, 7, vec![NodeLabel::of("Early")], vec![PropertyValue::Long(1)]);
+
+        assert_eq!(labels_of(&acc, 7), vec!["Early"]);
+        assert_eq!(age_of(&acc, 7), 1);
+        assert!(acc.check_duplicates().is_ok());
+    }
+
+    #[test]
+    fn test_duplicate_policy_error_reports_id() {
+        let mut acc = NodeAccumulator::new();
+        acc.add_node(7, vec![]);
+        acc.add_node(7, vec![]);
+
+        assert_eq!(acc.node_count(), 1);
+        assert!(matches!(
+            acc.check_duplicates(),
+            Err(ImporterError::DuplicateNode { original_id: 7 })
+        ));
+    }
 }
@@ -11,7 +11,7 @@
 // - NOT IO (assumes Arrow tables already in memory)
 
 // Public API surface
-pub use self::config::{ArrowProjectionConfig, ArrowProjectionError};
+pub use self::config::{ArrowProjectionConfig, ArrowProjectionError, DuplicateNodePolicy};
 pub use self::consumer::{
     BufferedEdgeConsumer, BufferedNodeConsumer, CompositeEdgeConsumer, NodeRecord,
     RecordConsumer as ConsumerTrait, RelationshipRecord,
@@ -20,10 +20,13 @@ pub use self::consumer_task::{
     ConsumerEdgeImportTask, ConsumerEdgeImportTaskFactory, ConsumerNodeImportTask,
     ConsumerNodeImportTaskFactory,
 };
+#[cfg(feature = "arrow")]
+pub use self::export::{export_nodes, export_relationships, ArrowExportError, RecordBatch};
 pub use self::factory::ArrowNativeFactory;
 pub use self::importer::{
     EdgeAccumulator, EdgeImportTask, EdgeImportTaskFactory, ImporterError, NodeAccumulator,
-    NodeImportTask, NodeImportTaskFactory,
+    NodeImportTask, NodeImportTaskFactory, PartitionedEdgeAccumulator, PartitionedEdgeImportTask,
+    PartitionedEdgeImportTaskFactory, PropertyConfig,
 };
 pub use self::reference::{
     ArrowBatchReference, ArrowReference, ArrowReferenceError, EdgeTableReference,
@@ -33,6 +36,7 @@ pub use self::scanner::{
     BatchScanner, EdgeBatchScanner, NodeBatchScanner, RecordConsumer, ScanCursor, ScannerError,
     DEFAULT_BATCH_SIZE, DEFAULT_PREFETCH_SIZE,
 };
+pub use self::schema_inference::{InferredProperty, SchemaInferenceReport, SkippedColumn};
 pub use self::task::{
     AggregatedImportResult, ImportResult, ImportTask, ProgressTracker, TaskError, TaskFactory,
     TaskRunner,
@@ -42,10 +46,13 @@ pub use self::task::{
 mod config;
 mod consumer; // Phase 7: BufferedConsumers (IMPLEMENTED)
 mod consumer_task; // Phase 8: Consumer-based ImportTask implementations (Option A)
+#[cfg(feature = "arrow")]
+mod export; // GraphStore → arrow2 tables (reverse of the import path)
 mod factory;
 mod importer; // Phase 5: NodeImportTask, EdgeImportTask, accumulators (GAMMA STRATEGY) + Phase 6 properties
 mod reference; // Phase 2: TableReference, BatchReference (IMPLEMENTED with arrow2)
 mod scanner; // Phase 3: BatchScanner trait + NodeBatchScanner + EdgeBatchScanner
+mod schema_inference; // Property column → ValueType decisions with warnings
 mod task; // Phase 4: ImportTask trait + TaskRunner + parallel orchestration
 
 #[cfg(test)]
@@ -0,0 +1,331 @@
+// Schema Inference Report
+//
+// Decides how each Arrow property column is imported and reports what was decided.
+//
+// Design principles:
+// - Mirrors the importer: the chosen ValueType and default are exactly what
+//   extract_property_value() applies, so the report never disagrees with the import
+// - No silent choices: nulls, widening coercions and skipped columns produce warnings
+// - Feeds the importer: inferred properties convert directly into PropertyConfigs
+//
+// Type mapping:
+// - Int64                → Long         (nulls default to 0)
+// - Float64              → Double       (nulls default to NaN)
+// - Float32              → Double       (widened; nulls default to NaN)
+// - List<Int64>          → LongArray    (nulls default to an empty array)
+// - List<Float64>        → DoubleArray  (nulls default to an empty array)
+// - List<Float32>        → FloatArray   (nulls default to an empty array)
+// - anything else        → skipped
+
+use super::importer::PropertyConfig;
+use super::reference::{ArrowReference, EdgeTableReference, NodeTableReference};
+use crate::types::{DefaultValue, ValueType};
+use arrow2::array::Array;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Schema};
+use serde_json::json;
+
+/// How a single property column will be imported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InferredProperty {
+    /// Property key (the column name)
+    pub key: String,
+    /// Arrow column index in the table
+    pub column_index: usize,
+    /// Arrow type of the column
+    pub arrow_type: DataType,
+    /// Chosen GDS value type
+    pub value_type: ValueType,
+    /// Number of null cells in the column
+    pub null_count: usize,
+    /// Value used for null cells
+    pub default_value: DefaultValue,
+    /// Description of a type coercion, if the column is not imported as-is
+    pub coercion: Option<String>,
+}
+
+impl InferredProperty {
+    /// Converts the inferred property into an importer configuration
+    pub fn to_property_config(&self) -> PropertyConfig {
+        PropertyConfig::new(
+            self.key.clone(),
+            self.column_index,
+            self.default_value.clone(),
+            self.value_type,
+        )
+    }
+}
+
+/// A column that will not be imported, with the reason.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkippedColumn {
+    /// Column name
+    pub name: String,
+    /// Arrow type of the column
+    pub arrow_type: DataType,
+}
+
+/// Outcome of inferring the property schema of an Arrow table.
+///
+/// Lists every inferred property with its chosen `ValueType`, null count and
+/// coercion, plus columns that cannot be imported. `warnings()` summarises
+/// everything that was not a lossless one-to-one mapping.
+///
+/// Example:
+/// ```rust,ignore
+/// let report = SchemaInferenceReport::for_node_table(&node_table);
+/// for warning in report.warnings() {
+///     eprintln!("{}", warning);
+/// }
+/// let configs = report.property_configs();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaInferenceReport {
+    table_name: String,
+    properties: Vec<InferredProperty>,
+    skipped_columns: Vec<SkippedColumn>,
+    warnings: Vec<String>,
+}
+
+impl SchemaInferenceReport {
+    /// Infers the property columns of a node table (all but id and label)
+    pub fn for_node_table(table: &NodeTableReference) -> Self {
+        Self::infer(
+            table.table_name(),
+            table.schema(),
+            table.chunk(),
+            table.property_column_indices(),
+        )
+    }
+
+    /// Infers the property columns of an edge table (all but source, target and type)
+    pub fn for_edge_table(table: &EdgeTableReference) -> Self {
+        Self::infer(
+            table.table_name(),
+            table.schema(),
+            table.chunk(),
+            table.property_column_indices(),
+        )
+    }
+
+    fn infer(
+        table_name: &str,
+        schema: &Schema,
+        chunk: &Chunk<Box<dyn Array>>,
+        column_indices: Vec<usize>,
+    ) -> Self {
+        let mut report = Self {
+            table_name: table_name.to_string(),
+            properties: Vec::new(),
+            skipped_columns: Vec::new(),
+            warnings: Vec::new(),
+        };
+
+        for column_index in column_indices {
+            let field = &schema.fields[column_index];
+            let null_count = chunk.arrays()[column_index].null_count();
+
+            let Some((value_type, coercion)) = infer_value_type(&field.data_type) else {
+                report.warnings.push(format!(
+                    "column '{}' has unsupported type {:?} and will be skipped",
+                    field.name, field.data_type
+                ));
+                report.skipped_columns.push(SkippedColumn {
+                    name: field.name.clone(),
+                    arrow_type: field.data_type.clone(),
+                });
+                continue;
+            };
+
+            if let Some(coercion) = &coercion {
+                report
+                    .warnings
+                    .push(format!("column '{}': {}", field.name, coercion));
+            }
+            if null_count > 0 {
+                report.warnings.push(format!(
+                    "column '{}' had {} null{}, defaulting to {}",
+                    field.name,
+                    null_count,
+                    if null_count == 1 { "" } else { "s" },
+                    default_description(value_type)
+                ));
+            }
+
+            report.properties.push(InferredProperty {
+                key: field.name.clone(),
+                column_index,
+                arrow_type: field.data_type.clone(),
+                value_type,
+                null_count,
+                default_value: default_value(value_type),
+                coercion,
+            });
+        }
+
+        report
+    }
+
+    /// Returns the name of the inspected table
+    pub fn table_name(&self) -> &str {
+        &self.table_name
+    }
+
+    /// Returns the inferred properties in column order
+    pub fn properties(&self) -> &[InferredProperty] {
+        &self.properties
+    }
+
+    /// Finds an inferred property by key
+    pub fn property(&self, key: &str) -> Option<&InferredProperty> {
+        self.properties.iter().find(|property| property.key == key)
+    }
+
+    /// Returns the columns that will not be imported
+    pub fn skipped_columns(&self) -> &[SkippedColumn] {
+        &self.skipped_columns
+    }
+
+    /// Returns human-readable warnings about nulls, coercions and skipped columns
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Returns true if every column mapped one-to-one without nulls
+    pub fn is_clean(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    /// Returns importer configurations for all inferred properties
+    pub fn property_configs(&self) -> Vec<PropertyConfig> {
+        self.properties
+            .iter()
+            .map(InferredProperty::to_property_config)
+            .collect()
+    }
+}
+
+fn infer_value_type(data_type: &DataType) -> Option<(ValueType, Option<String>)> {
+    match data_type {
+        DataType::Int64 => Some((ValueType::Long, None)),
+        DataType::Float64 => Some((ValueType::Double, None)),
+        DataType::Float32 => Some((
+            ValueType::Double,
+            Some("Float32 values widened to Double".to_string()),
+        )),
+        DataType::List(inner) => match inner.data_type() {
+            DataType::Int64 => Some((ValueType::LongArray, None)),
+            DataType::Float64 => Some((ValueType::DoubleArray, None)),
+            DataType::Float32 => Some((ValueType::FloatArray, None)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Defaults matching what the importer substitutes for null cells
+fn default_value(value_type: ValueType) -> DefaultValue {
+    match value_type {
+        ValueType::Long => DefaultValue::system_default(Some(json!(0))),
+        ValueType::Double => DefaultValue::for_double(),
+        ValueType::LongArray => DefaultValue::for_long_array(),
+        ValueType::DoubleArray => DefaultValue::for_double_array(),
+        _ => DefaultValue::for_float_array(),
+    }
+}
+
+fn default_description(value_type: ValueType) -> &'static str {
+    match value_type {
+        ValueType::Long => "0",
+        ValueType::Double => "NaN",
+        _ => "an empty array",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow2::array::{Float32Array, Int64Array, Utf8Array};
+    use arrow2::datatypes::Field;
+    use std::sync::Arc;
+
+    fn node_table(columns: Vec<(Field, Box<dyn Array>)>) -> NodeTableReference {
+        let (fields, arrays): (Vec<_>, Vec<_>) = std::iter::once((
+            Field::new("id", DataType::Int64, false),
+            Box::new(Int64Array::from_slice([1, 2, 3])) as Box<dyn Array>,
+        ))
+        .chain(columns)
+        .unzip();
+        NodeTableReference::new("nodes", Chunk::new(arrays), Arc::new(Schema::from(fields)))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_nullable_integer_column() {
+        let table = node_table(vec![(
+            Field::new("age", DataType::Int64, true),
+            Box::new(Int64Array::from(vec![Some(31), None, Some(25)])),
+        )]);
+
+        let report = SchemaInferenceReport::for_node_table(&table);
+
+        let age = report.property("age").unwrap();
+        assert_eq!(age.value_type, ValueType::Long);
+        assert_eq!(age.null_count, 1);
+        assert_eq!(age.column_index, 1);
+        assert_eq!(age.default_value.long_value().unwrap(), 0);
+        assert_eq!(age.coercion, None);
+        assert_eq!(
+            report.warnings(),
+            &["column 'age' had 1 null, defaulting to 0".to_string()]
+        );
+
+        let config = &report.property_configs()[0];
+        assert_eq!(config.key, "age");
+        assert_eq!(config.value_type, ValueType::Long);
+        assert_eq!(config.default_value.long_value().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_coercions_and_skipped_columns() {
+        let table = node_table(vec![
+            (
+                Field::new("score", DataType::Float32, false),
+                Box::new(Float32Array::from_slice([0.5, 1.0, 1.5])),
+            ),
+            (
+                Field::new("name", DataType::Utf8, false),
+                Box::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+            ),
+            (
+                Field::new("rank", DataType::Int64, false),
+                Box::new(Int64Array::from_slice([3, 2, 1])),
+            ),
+        ]);
+
+        let report = SchemaInferenceReport::for_node_table(&table);
+
+        assert_eq!(report.properties().len(), 2);
+        let score = report.property("score").unwrap();
+        assert_eq!(score.value_type, ValueType::Double);
+        assert!(score.coercion.is_some());
+        assert_eq!(report.property("rank").unwrap().null_count, 0);
+
+        assert_eq!(report.skipped_columns().len(), 1);
+        assert_eq!(report.skipped_columns()[0].name, "name");
+        assert_eq!(report.warnings().len(), 2);
+        assert!(!report.is_clean());
+    }
+
+    #[test]
+    fn test_clean_table() {
+        let table = node_table(vec![(
+            Field::new("rank", DataType::Int64, false),
+            Box::new(Int64Array::from_slice([3, 2, 1])),
+        )]);
+
+        let report = SchemaInferenceReport::for_node_table(&table);
+        assert!(report.is_clean());
+        assert_eq!(report.table_name(), "nodes");
+    }
+}
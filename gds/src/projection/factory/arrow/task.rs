@@ -14,8 +14,11 @@
 // - ImportResult: Task execution results (records, properties, duration)
 // - TaskRunner: Parallel executor using Rayon
 // - TaskFactory: Creates tasks for parallel execution
+// - ProgressTracker: Batch counters, optionally mirrored into a task store Task
 
+use super::reference::ArrowBatchReference;
 use super::scanner::{BatchScanner, ScanCursor};
+use crate::core::utils::progress::{Task, TaskRegistry};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
@@ -174,6 +177,47 @@ impl TaskRunner {
         scanner: Arc<S>,
         factory: Arc<dyn TaskFactory>,
     ) -> Result<AggregatedImportResult, TaskError>
+    where
+        S: BatchScanner + 'static,
+    {
+        self.run_tasks(scanner, factory, None)
+    }
+
+    /// Executes import tasks like [`run_import`](Self::run_import) while
+    /// reporting progress to the task store of `registry`.
+    ///
+    /// Registers an "Arrow import" [`Task`] whose volume is the scanner's row
+    /// count, marks it running, and logs the records of every consumed batch.
+    /// The task is finished on success and failed on error; it stays in the
+    /// store until the caller unregisters it.
+    pub fn run_import_with_progress<S>(
+        &self,
+        scanner: Arc<S>,
+        factory: Arc<dyn TaskFactory>,
+        registry: &TaskRegistry,
+    ) -> Result<AggregatedImportResult, TaskError>
+    where
+        S: BatchScanner + 'static,
+    {
+        let task = Task::new("Arrow import".to_string(), scanner.total_rows());
+        registry.register_task(task.clone());
+        task.start();
+
+        let tracker = ProgressTracker::new().with_task(task.clone());
+        let result = self.run_tasks(scanner, factory, Some(&tracker));
+        match &result {
+            Ok(_) => task.finish(),
+            Err(_) => task.fail(),
+        }
+        result
+    }
+
+    fn run_tasks<S>(
+        &self,
+        scanner: Arc<S>,
+        factory: Arc<dyn TaskFactory>,
+        tracker: Option<&ProgressTracker>,
+    ) -> Result<AggregatedImportResult, TaskError>
     where
         S: BatchScanner + 'static,
     {
@@ -199,8 +243,14 @@ impl TaskRunner {
                 let task_start = Instant::now();
                 let task_index = task.task_index();
 
-                // Create cursor for this task
+                // Create cursor for this task, counting batches if tracked
                 let mut cursor = scanner.create_cursor();
+                if let Some(tracker) = tracker {
+                    cursor = Box::new(TrackedCursor {
+                        inner: cursor,
+                        tracker: tracker.clone(),
+                    });
+                }
 
                 // Execute task
                 let (records, properties) = task.execute(cursor.as_mut())?;
@@ -330,11 +380,13 @@ impl AggregatedImportResult {
 /// Progress tracker for long-running imports.
 ///
 /// Thread-safe atomic counters for tracking progress across tasks.
+/// Records can also be forwarded to a [`Task`] kept in a task store.
 #[derive(Debug)]
 pub struct ProgressTracker {
     records_processed: Arc<AtomicU64>,
     properties_processed: Arc<AtomicU64>,
     batches_processed: Arc<AtomicU64>,
+    task: Option<Task>,
 }
 
 impl ProgressTracker {
@@ -344,15 +396,30 @@ impl ProgressTracker {
             records_processed: Arc::new(AtomicU64::new(0)),
             properties_processed: Arc::new(AtomicU64::new(0)),
             batches_processed: Arc::new(AtomicU64::new(0)),
+            task: None,
         }
     }
 
+    /// Forwards the records of every batch to the progress of `task`.
+    pub fn with_task(mut self, task: Task) -> Self {
+        self.task = Some(task);
+        self
+    }
+
+    /// Returns the task that receives progress, if any.
+    pub fn task(&self) -> Option<&Task> {
+        self.task.as_ref()
+    }
+
     /// Records progress for a batch.
     pub fn record_batch(&self, records: u64, properties: u64) {
         self.records_processed.fetch_add(records, Ordering::Relaxed);
         self.properties_processed
             .fetch_add(properties, Ordering::Relaxed);
         self.batches_processed.fetch_add(1, Ordering::Relaxed);
+        if let Some(task) = &self.task {
+            task.log_progress(records as usize);
+        }
     }
 
     /// Returns the current number of records processed.
@@ -390,10 +457,39 @@ impl Clone for ProgressTracker {
             records_processed: Arc::clone(&self.records_processed),
             properties_processed: Arc::clone(&self.properties_processed),
             batches_processed: Arc::clone(&self.batches_processed),
+            task: self.task.clone(),
         }
     }
 }
 
+/// Cursor wrapper that records every consumed batch in a [`ProgressTracker`].
+struct TrackedCursor {
+    inner: Box<dyn ScanCursor>,
+    tracker: ProgressTracker,
+}
+
+impl ScanCursor for TrackedCursor {
+    fn reserve_batch(&mut self) -> bool {
+        self.inner.reserve_batch()
+    }
+
+    fn consume_batch(&mut self, consumer: &mut dyn FnMut(&ArrowBatchReference) -> bool) -> bool {
+        let mut records = 0u64;
+        let consumed = self
+            .inner
+            .consume_batch(&mut |batch: &ArrowBatchReference| {
+                records += batch.len() as u64;
+                consumer(batch)
+            });
+        self.tracker.record_batch(records, 0);
+        consumed
+    }
+
+    fn batch_index(&self) -> usize {
+        self.inner.batch_index()
+    }
+}
+
 // ================================================================================================
 // Tests
 // ================================================================================================
@@ -401,12 +497,15 @@ impl Clone for ProgressTracker {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::utils::progress::tasks::Status;
+    use crate::core::utils::progress::{JobId, PerDatabaseTaskStore, TaskStore};
     use crate::projection::factory::arrow::{
         ArrowBatchReference, NodeBatchScanner, NodeTableReference,
     };
     use arrow2::array::{Array, Int64Array, Utf8Array};
     use arrow2::chunk::Chunk;
     use arrow2::datatypes::{DataType, Field, Schema};
+    use std::sync::Mutex;
 
     // Mock task that counts batches
     struct MockImportTask {
@@ -568,4 +667,87 @@ mod tests {
         tracker.record_batch(50, 25);
         assert_eq!(cloned.records_processed(), 150);
     }
+
+    // Task that records what the task store shows before each batch
+    struct ObservingImportTask {
+        store: Arc<dyn TaskStore>,
+        observed: Arc<Mutex<Vec<(Status, usize)>>>,
+    }
+
+    impl ImportTask for ObservingImportTask {
+        fn execute(&mut self, cursor: &mut dyn ScanCursor) -> Result<(u64, u64), TaskError> {
+            let mut records = 0u64;
+            while cursor.reserve_batch() {
+                let stored = self.store.query_all();
+                assert_eq!(stored.len(), 1);
+                let task = &stored[0].task;
+                self.observed
+                    .lock()
+                    .unwrap()
+                    .push((task.status(), task.current_progress()));
+                cursor.consume_batch(&mut |batch: &ArrowBatchReference| {
+                    records += batch.len() as u64;
+                    true
+                });
+            }
+            Ok((records, 0))
+        }
+
+        fn task_name(&self) -> String {
+            "observing-task".to_string()
+        }
+
+        fn task_index(&self) -> usize {
+            0
+        }
+    }
+
+    struct ObservingTaskFactory {
+        store: Arc<dyn TaskStore>,
+        observed: Arc<Mutex<Vec<(Status, usize)>>>,
+    }
+
+    impl TaskFactory for ObservingTaskFactory {
+        fn create_task(&self, _task_index: usize) -> Result<Box<dyn ImportTask>, TaskError> {
+            Ok(Box::new(ObservingImportTask {
+                store: Arc::clone(&self.store),
+                observed: Arc::clone(&self.observed),
+            }))
+        }
+    }
+
+    #[test]
+    fn test_run_import_with_progress_reports_to_task_store() {
+        let store: Arc<dyn TaskStore> = Arc::new(PerDatabaseTaskStore::new());
+        let registry = TaskRegistry::new("alice".to_string(), Arc::clone(&store), JobId::new());
+        let observed = Arc::new(Mutex::new(Vec::new()));
+        let factory = Arc::new(ObservingTaskFactory {
+            store: Arc::clone(&store),
+            observed: Arc::clone(&observed),
+        });
+
+        let runner = TaskRunner::new(1).unwrap();
+        let scanner = create_test_scanner(100, 25);
+        let result = runner
+            .run_import_with_progress(scanner, factory, &registry)
+            .unwrap();
+        assert_eq!(result.total_records_imported, 100);
+
+        // The task was running during the import and advanced batch by batch
+        let observed = observed.lock().unwrap();
+        assert_eq!(
+            *observed,
+            vec![
+                (Status::Running, 0),
+                (Status::Running, 25),
+                (Status::Running, 50),
+                (Status::Running, 75),
+            ]
+        );
+
+        let task = registry.current_task().unwrap().task;
+        assert_eq!(task.status(), Status::Finished);
+        assert!(task.get_progress().is_complete());
+        assert_eq!(task.current_progress(), 100);
+    }
 }
@@ -3,30 +3,36 @@
 // Minimal, low-risk implementation that demonstrates the factory pattern.
 // For now it delegates to `ArrowNativeFactory` to build a `DefaultGraphStore`.
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use crate::projection::factory::arrow::{
-    ArrowNativeFactory, ArrowProjectionConfig, ArrowProjectionError,
+    ArrowNativeFactory, ArrowProjectionConfig, ArrowProjectionError, EdgeTableReference,
+    NodeTableReference,
+};
+use crate::projection::factory::{GraphStoreFactory, TopologyCompression};
+use crate::projection::RelationshipType;
+use crate::types::graph::id_map::{IdMap, SimpleIdMap};
+use crate::types::graph::{
+    AdjacencyCursorExt, AdjacencyList, AdjacencyListExt, MappedNodeId, RelationshipTopology,
 };
-use crate::projection::factory::GraphStoreFactory;
 use crate::types::graph_store::DefaultGraphStore;
 
 /// Configuration for CsrHugeGraphStoreFactory.
 /// For Phase 1 we reuse ArrowProjectionConfig; in future this will include
-/// CSR-specific options (mmap paths, buffer sizes, compression flags).
-#[derive(Debug, Clone)]
+/// CSR-specific options (mmap paths, buffer sizes).
+#[derive(Debug, Clone, Default)]
 pub struct CsrHugeFactoryConfig {
     pub inner: ArrowProjectionConfig,
+    /// Adjacency storage strategy for the loaded topology.
+    pub compression: TopologyCompression,
 }
 
 impl From<ArrowProjectionConfig> for CsrHugeFactoryConfig {
     fn from(c: ArrowProjectionConfig) -> Self {
-        Self { inner: c }
-    }
-}
-
-impl Default for CsrHugeFactoryConfig {
-    fn default() -> Self {
         Self {
-            inner: ArrowProjectionConfig::default(),
+            inner: c,
+            compression: TopologyCompression::default(),
         }
     }
 }
@@ -47,6 +53,41 @@ impl CsrHugeGraphStoreFactory {
             arrow: ArrowNativeFactory::new(),
         }
     }
+
+    /// Import a node and an edge table into a [`CsrHugeGraphStore`].
+    ///
+    /// Nodes and edges are imported in parallel as configured by `config.inner`.
+    /// The adjacency of every relationship type is then stored as selected by
+    /// `config.compression`. Property columns are not imported.
+    pub fn build_from_tables(
+        &self,
+        node_table: Arc<NodeTableReference>,
+        edge_table: Arc<EdgeTableReference>,
+        config: &CsrHugeFactoryConfig,
+    ) -> Result<CsrHugeGraphStore, CsrHugeFactoryError> {
+        let nodes = self.arrow.import_nodes(node_table, vec![], &config.inner)?;
+        let id_map = Arc::new(nodes.build_id_map());
+        let topologies = self
+            .arrow
+            .import_edges(edge_table, Arc::clone(&id_map), &config.inner)?;
+
+        let adjacency = topologies
+            .into_iter()
+            .map(|(rel_type, topology)| {
+                let adjacency = match config.compression.compress(&topology) {
+                    Some(compressed) => CsrAdjacency::Compressed(compressed),
+                    None => CsrAdjacency::Uncompressed(topology),
+                };
+                (rel_type, adjacency)
+            })
+            .collect();
+
+        Ok(CsrHugeGraphStore {
+            id_map,
+            compression: config.compression,
+            adjacency,
+        })
+    }
 }
 
 impl Default for CsrHugeGraphStoreFactory {
@@ -106,6 +147,86 @@ impl crate::projection::factory::GraphStoreFactoryTyped for CsrHugeGraphStoreFac
     }
 }
 
+/// Adjacency of one relationship type in a [`CsrHugeGraphStore`].
+#[derive(Debug)]
+enum CsrAdjacency {
+    Uncompressed(RelationshipTopology),
+    Compressed(Box<dyn AdjacencyList>),
+}
+
+/// Nodes and per-type adjacency imported by [`CsrHugeGraphStoreFactory::build_from_tables`].
+///
+/// Each relationship type is held exactly once, either as plain target vectors
+/// or compressed, so a compressed load never keeps the uncompressed lists.
+/// Compressed targets are returned in ascending order.
+#[derive(Debug)]
+pub struct CsrHugeGraphStore {
+    id_map: Arc<SimpleIdMap>,
+    compression: TopologyCompression,
+    adjacency: HashMap<RelationshipType, CsrAdjacency>,
+}
+
+impl CsrHugeGraphStore {
+    /// Id map of the imported nodes.
+    pub fn id_map(&self) -> &SimpleIdMap {
+        &self.id_map
+    }
+
+    /// Compression the adjacency was stored with.
+    pub fn compression(&self) -> TopologyCompression {
+        self.compression
+    }
+
+    /// Number of imported nodes.
+    pub fn node_count(&self) -> usize {
+        self.id_map.node_count()
+    }
+
+    /// Imported relationship types.
+    pub fn relationship_types(&self) -> impl Iterator<Item = &RelationshipType> {
+        self.adjacency.keys()
+    }
+
+    /// Number of relationships of the given type.
+    pub fn relationship_count(&self, rel_type: &RelationshipType) -> usize {
+        (0..self.node_count())
+            .map(|node| self.degree(rel_type, node as MappedNodeId))
+            .sum()
+    }
+
+    /// Out-degree of `node` for the given type, 0 for unknown types.
+    pub fn degree(&self, rel_type: &RelationshipType, node: MappedNodeId) -> usize {
+        match self.adjacency.get(rel_type) {
+            Some(CsrAdjacency::Uncompressed(topology)) => {
+                topology.outgoing(node).map_or(0, <[MappedNodeId]>::len)
+            }
+            Some(CsrAdjacency::Compressed(list)) => list.degree(node),
+            None => 0,
+        }
+    }
+
+    /// Targets of `node` for the given type, decoding them when compressed.
+    pub fn targets(&self, rel_type: &RelationshipType, node: MappedNodeId) -> Vec<MappedNodeId> {
+        match self.adjacency.get(rel_type) {
+            Some(CsrAdjacency::Uncompressed(topology)) => topology
+                .outgoing(node)
+                .map(<[MappedNodeId]>::to_vec)
+                .unwrap_or_default(),
+            Some(CsrAdjacency::Compressed(list)) => list.adjacency_cursor(node).collect_remaining(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Bytes used by the adjacency of the given type, 0 for unknown types.
+    pub fn adjacency_memory_usage_bytes(&self, rel_type: &RelationshipType) -> usize {
+        match self.adjacency.get(rel_type) {
+            Some(CsrAdjacency::Uncompressed(topology)) => topology.memory_usage_bytes(),
+            Some(CsrAdjacency::Compressed(list)) => list.memory_usage_bytes().unwrap_or(0),
+            None => 0,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -118,4 +239,82 @@ mod tests {
         // Since ArrowNativeFactory is a skeleton, we expect a not-implemented Other error
         assert!(res.is_err());
     }
+
+    fn hub_tables() -> (Arc<NodeTableReference>, Arc<EdgeTableReference>) {
+        use arrow2::array::{Array, Int64Array, Utf8Array};
+        use arrow2::chunk::Chunk;
+        use arrow2::datatypes::{DataType, Field, Schema};
+
+        // Node 0 links to every other node in reverse order, node 1 links to node 0.
+        let node_count = 2_000i64;
+        let ids: Vec<i64> = (0..node_count).map(|id| 100 + id).collect();
+        let node_chunk = Chunk::new(vec![Box::new(Int64Array::from_vec(ids)) as Box<dyn Array>]);
+        let node_schema = Arc::new(Schema::from(vec![Field::new("id", DataType::Int64, false)]));
+
+        let mut sources: Vec<i64> = (1..node_count).map(|_| 100).collect();
+        let mut targets: Vec<i64> = (1..node_count).rev().map(|id| 100 + id).collect();
+        sources.push(101);
+        targets.push(100);
+        let types = vec!["LINKS"; sources.len()];
+        let edge_chunk = Chunk::new(vec![
+            Box::new(Int64Array::from_vec(sources)) as Box<dyn Array>,
+            Box::new(Int64Array::from_vec(targets)),
+            Box::new(Utf8Array::<i32>::from_slice(types)),
+        ]);
+        let edge_schema = Arc::new(Schema::from(vec![
+            Field::new("source", DataType::Int64, false),
+            Field::new("target", DataType::Int64, false),
+            Field::new("type", DataType::Utf8, false),
+        ]));
+
+        (
+            Arc::new(NodeTableReference::new("nodes", node_chunk, node_schema).unwrap()),
+            Arc::new(EdgeTableReference::new("edges", edge_chunk, edge_schema).unwrap()),
+        )
+    }
+
+    #[test]
+    fn csr_huge_factory_builds_compressed_store_from_config() {
+        let factory = CsrHugeGraphStoreFactory::new();
+        let inner = ArrowProjectionConfig::builder()
+            .concurrency(2)
+            .batch_size(256)
+            .build()
+            .unwrap();
+        let plain_config = CsrHugeFactoryConfig::from(inner.clone());
+        let compressed_config = CsrHugeFactoryConfig {
+            inner,
+            compression: TopologyCompression::DeltaVarint,
+        };
+
+        let (nodes, edges) = hub_tables();
+        let plain = factory
+            .build_from_tables(Arc::clone(&nodes), Arc::clone(&edges), &plain_config)
+            .unwrap();
+        let compressed = factory
+            .build_from_tables(nodes, edges, &compressed_config)
+            .unwrap();
+
+        let links = RelationshipType::of("LINKS");
+        assert_eq!(compressed.compression(), TopologyCompression::DeltaVarint);
+        assert_eq!(compressed.node_count(), 2_000);
+        assert_eq!(
+            compressed.relationship_types().collect::<Vec<_>>(),
+            vec![&links]
+        );
+        assert_eq!(compressed.relationship_count(&links), 2_000);
+        assert_eq!(compressed.degree(&links, 0), 1_999);
+
+        for node in 0..plain.node_count() as MappedNodeId {
+            let mut expected = plain.targets(&links, node);
+            expected.sort_unstable();
+            assert_eq!(compressed.targets(&links, node), expected);
+        }
+        assert_eq!(compressed.targets(&links, 1), vec![0]);
+
+        assert!(
+            compressed.adjacency_memory_usage_bytes(&links)
+                < plain.adjacency_memory_usage_bytes(&links)
+        );
+    }
 }
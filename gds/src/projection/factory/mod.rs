@@ -96,6 +96,7 @@ pub trait GraphStoreFactoryTyped: Send + Sync {
 /// ```
 pub mod prelude {
     pub use super::GraphStoreFactory;
+    pub use super::TopologyCompression;
 
     // Re-export Arrow factory when available
     #[cfg(feature = "arrow")]
@@ -113,6 +114,10 @@ pub mod arrow;
 #[cfg(feature = "arrow")]
 pub mod csr_huge;
 
+// Adjacency compression choice shared by all factories
+pub mod topology_compression;
+pub use topology_compression::TopologyCompression;
+
 // Relationships builder trait (minimal stub for ML negative sampling)
 pub mod relationships_builder;
 pub use relationships_builder::RelationshipsBuilder;
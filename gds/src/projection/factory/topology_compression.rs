@@ -0,0 +1,53 @@
+// Topology Compression - how factories store adjacency for loaded graphs
+//
+// Memory-constrained loads of large graphs can trade some decoding work for a
+// much smaller topology by packing sorted neighbor lists as delta-encoded varints.
+
+use crate::types::graph::{AdjacencyList, CompressedAdjacencyList, RelationshipTopology};
+
+/// Adjacency storage strategy selected at projection time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TopologyCompression {
+    /// Keep uncompressed per-node target vectors.
+    #[default]
+    None,
+    /// Sort, delta-encode and varint-pack targets into a `HugeByteArray`.
+    DeltaVarint,
+}
+
+impl TopologyCompression {
+    /// Returns a compressed adjacency view of `topology`, or `None` when
+    /// compression is disabled and the topology should be used as is.
+    pub fn compress(&self, topology: &RelationshipTopology) -> Option<Box<dyn AdjacencyList>> {
+        match self {
+            Self::None => None,
+            Self::DeltaVarint => Some(Box::new(CompressedAdjacencyList::from_topology(topology))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::graph::{AdjacencyCursorExt, AdjacencyListExt};
+
+    #[test]
+    fn test_delta_varint_preserves_topology() {
+        let topology = RelationshipTopology::new(vec![vec![3, 1], vec![], vec![0, 1, 2]], None);
+
+        assert!(TopologyCompression::None.compress(&topology).is_none());
+
+        let compressed = TopologyCompression::DeltaVarint
+            .compress(&topology)
+            .unwrap();
+        assert_eq!(compressed.degree(0), 2);
+        assert_eq!(
+            compressed.adjacency_cursor(0).collect_remaining(),
+            vec![1, 3]
+        );
+        assert_eq!(
+            compressed.adjacency_cursor(2).collect_remaining(),
+            vec![0, 1, 2]
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::sync::{Arc, RwLock};
@@ -11,7 +11,7 @@ use std::sync::{Arc, RwLock};
 /// with the same name share the same underlying instance.
 #[derive(Clone)]
 pub struct NodeLabel {
-    name: Arc<String>,
+    name: Arc<str>,
 }
 
 // Custom Serialize - just write the string
@@ -20,7 +20,7 @@ impl Serialize for NodeLabel {
     where
         S: Serializer,
     {
-        self.name.as_str().serialize(serializer)
+        self.name.serialize(serializer)
     }
 }
 
@@ -31,7 +31,7 @@ impl<'de> Deserialize<'de> for NodeLabel {
         D: Deserializer<'de>,
     {
         let name = String::deserialize(deserializer)?;
-        Ok(NodeLabel::of(name))
+        Ok(NodeLabel::of(&name))
     }
 }
 
@@ -42,9 +42,9 @@ impl NodeLabel {
     /// Creates a new NodeLabel with the given name.
     ///
     /// Note: Prefer using `NodeLabel::of()` for interned instances.
-    fn new(name: String) -> Self {
+    fn new(name: &str) -> Self {
         NodeLabel {
-            name: Arc::new(name),
+            name: Arc::from(name),
         }
     }
 
@@ -57,41 +57,58 @@ impl NodeLabel {
     ///
     /// This method ensures that NodeLabels with the same name share
     /// the same underlying data, improving memory efficiency and
-    /// enabling fast equality comparisons.
+    /// enabling fast equality comparisons. Looking up a name that is
+    /// already interned does not allocate.
     ///
     /// # Arguments
     /// * `name` - The label name
     ///
     /// # Returns
     /// An interned NodeLabel instance
-    pub fn of(name: impl Into<String>) -> Self {
-        lazy_static::lazy_static! {
-            static ref INSTANCES: RwLock<HashMap<String, NodeLabel>> = RwLock::new(HashMap::new());
-        }
-
-        let name_string = name.into();
+    pub fn of(name: impl AsRef<str>) -> Self {
+        let name = name.as_ref();
 
-        // Try read lock first for common case
+        // Try read lock first for common case; lookups by &str do not allocate
         {
             // Use unwrap_or_else to recover from a poisoned lock instead of panicking.
-            let instances = INSTANCES.read().unwrap_or_else(|e| e.into_inner());
-            if let Some(label) = instances.get(&name_string) {
-                return label.clone();
+            let instances = Self::instances().read().unwrap_or_else(|e| e.into_inner());
+            if let Some(interned) = instances.get(name) {
+                return NodeLabel {
+                    name: Arc::clone(interned),
+                };
             }
         }
 
         // Need to create new instance
-        let mut instances = INSTANCES.write().unwrap_or_else(|e| e.into_inner());
+        let mut instances = Self::instances().write().unwrap_or_else(|e| e.into_inner());
         // Check again in case another thread created it
-        if let Some(label) = instances.get(&name_string) {
-            return label.clone();
+        if let Some(interned) = instances.get(name) {
+            return NodeLabel {
+                name: Arc::clone(interned),
+            };
         }
 
-        let label = NodeLabel::new(name_string.clone());
-        instances.insert(name_string, label.clone());
+        let label = NodeLabel::new(name);
+        instances.insert(Arc::clone(&label.name));
         label
     }
 
+    /// Returns the number of distinct names interned so far.
+    pub fn interned_count() -> usize {
+        Self::instances()
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .len()
+    }
+
+    /// The intern pool; the pool and every handle share one `Arc<str>` per name.
+    fn instances() -> &'static RwLock<HashSet<Arc<str>>> {
+        lazy_static::lazy_static! {
+            static ref INSTANCES: RwLock<HashSet<Arc<str>>> = RwLock::new(HashSet::new());
+        }
+        &INSTANCES
+    }
+
     /// Creates a collection of NodeLabels from strings.
     ///
     /// # Arguments
@@ -102,19 +119,19 @@ impl NodeLabel {
     pub fn list_of<I, S>(labels: I) -> Vec<Self>
     where
         I: IntoIterator<Item = S>,
-        S: Into<String>,
+        S: AsRef<str>,
     {
         labels.into_iter().map(NodeLabel::of).collect()
     }
 
     /// Returns the name of this label.
     pub fn name(&self) -> &str {
-        self.name.as_str()
+        &self.name
     }
 
     /// Returns whether this is the ALL_NODES label.
     pub fn is_all_nodes(&self) -> bool {
-        self.name.as_str() == Self::ALL_NODES_NAME
+        &*self.name == Self::ALL_NODES_NAME
     }
 
     /// Returns the label that projects all nodes.
@@ -227,4 +244,20 @@ mod tests {
         // After deserialization, should be re-interned
         assert!(Arc::ptr_eq(&label.name, &deserialized.name));
     }
+
+    #[test]
+    fn test_interning_shares_storage() {
+        const COUNT: usize = 1_000_000;
+        let name = String::from("InterningStress");
+        let first = NodeLabel::of(&name);
+
+        let handles: Vec<NodeLabel> = (0..COUNT).map(|_| NodeLabel::of(name.as_str())).collect();
+
+        // Every handle points at the first handle's string: no per-call copies
+        assert!(handles
+            .iter()
+            .all(|h| h == &first && Arc::ptr_eq(&h.name, &first.name)));
+        assert!(Arc::strong_count(&first.name) > COUNT);
+        assert!(NodeLabel::interned_count() >= 1);
+    }
 }
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::sync::{Arc, RwLock};
@@ -11,7 +11,7 @@ use std::sync::{Arc, RwLock};
 /// with the same name share the same underlying instance.
 #[derive(Clone)]
 pub struct RelationshipType {
-    name: Arc<String>,
+    name: Arc<str>,
 }
 
 // Custom Serialize - just write the string
@@ -20,7 +20,7 @@ impl Serialize for RelationshipType {
     where
         S: Serializer,
     {
-        self.name.as_str().serialize(serializer)
+        self.name.serialize(serializer)
     }
 }
 
@@ -31,7 +31,7 @@ impl<'de> Deserialize<'de> for RelationshipType {
         D: Deserializer<'de>,
     {
         let name = String::deserialize(deserializer)?;
-        Ok(RelationshipType::of(name))
+        Ok(RelationshipType::of(&name))
     }
 }
 
@@ -42,9 +42,9 @@ impl RelationshipType {
     /// Creates a new RelationshipType with the given name.
     ///
     /// Note: Prefer using `RelationshipType::of()` for interned instances.
-    fn new(name: String) -> Self {
+    fn new(name: &str) -> Self {
         RelationshipType {
-            name: Arc::new(name),
+            name: Arc::from(name),
         }
     }
 
@@ -57,41 +57,58 @@ impl RelationshipType {
     ///
     /// This method ensures that RelationshipTypes with the same name share
     /// the same underlying data, improving memory efficiency and
-    /// enabling fast equality comparisons.
+    /// enabling fast equality comparisons. Looking up a name that is
+    /// already interned does not allocate.
     ///
     /// # Arguments
     /// * `name` - The relationship type name
     ///
     /// # Returns
     /// An interned RelationshipType instance
-    pub fn of(name: impl Into<String>) -> Self {
-        lazy_static::lazy_static! {
-            static ref INSTANCES: RwLock<HashMap<String, RelationshipType>> = RwLock::new(HashMap::new());
-        }
-
-        let name_string = name.into();
+    pub fn of(name: impl AsRef<str>) -> Self {
+        let name = name.as_ref();
 
-        // Try read lock first for common case
+        // Try read lock first for common case; lookups by &str do not allocate
         {
             // Use unwrap_or_else to recover from a poisoned lock instead of panicking.
-            let instances = INSTANCES.read().unwrap_or_else(|e| e.into_inner());
-            if let Some(rel_type) = instances.get(&name_string) {
-                return rel_type.clone();
+            let instances = Self::instances().read().unwrap_or_else(|e| e.into_inner());
+            if let Some(interned) = instances.get(name) {
+                return RelationshipType {
+                    name: Arc::clone(interned),
+                };
             }
         }
 
         // Need to create new instance
-        let mut instances = INSTANCES.write().unwrap_or_else(|e| e.into_inner());
+        let mut instances = Self::instances().write().unwrap_or_else(|e| e.into_inner());
         // Check again in case another thread created it
-        if let Some(rel_type) = instances.get(&name_string) {
-            return rel_type.clone();
+        if let Some(interned) = instances.get(name) {
+            return RelationshipType {
+                name: Arc::clone(interned),
+            };
         }
 
-        let rel_type = RelationshipType::new(name_string.clone());
-        instances.insert(name_string, rel_type.clone());
+        let rel_type = RelationshipType::new(name);
+        instances.insert(Arc::clone(&rel_type.name));
         rel_type
     }
 
+    /// Returns the number of distinct names interned so far.
+    pub fn interned_count() -> usize {
+        Self::instances()
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .len()
+    }
+
+    /// The intern pool; the pool and every handle share one `Arc<str>` per name.
+    fn instances() -> &'static RwLock<HashSet<Arc<str>>> {
+        lazy_static::lazy_static! {
+            static ref INSTANCES: RwLock<HashSet<Arc<str>>> = RwLock::new(HashSet::new());
+        }
+        &INSTANCES
+    }
+
     /// Creates a collection of RelationshipTypes from strings.
     ///
     /// # Arguments
@@ -102,19 +119,19 @@ impl RelationshipType {
     pub fn list_of<I, S>(types: I) -> Vec<Self>
     where
         I: IntoIterator<Item = S>,
-        S: Into<String>,
+        S: AsRef<str>,
     {
         types.into_iter().map(RelationshipType::of).collect()
     }
 
     /// Returns the name of this relationship type.
     pub fn name(&self) -> &str {
-        self.name.as_str()
+        &self.name
     }
 
     /// Returns whether this is the ALL_RELATIONSHIPS type.
     pub fn is_all_relationships(&self) -> bool {
-        self.name.as_str() == Self::ALL_RELATIONSHIPS_NAME
+        &*self.name == Self::ALL_RELATIONSHIPS_NAME
     }
 
     /// Returns the relationship type that projects all relationships.
@@ -234,4 +251,22 @@ mod tests {
         // After deserialization, should be re-interned
         assert!(Arc::ptr_eq(&rel_type.name, &deserialized.name));
     }
+
+    #[test]
+    fn test_interning_shares_storage() {
+        const COUNT: usize = 1_000_000;
+        let name = String::from("InterningStress");
+        let first = RelationshipType::of(&name);
+
+        let handles: Vec<RelationshipType> = (0..COUNT)
+            .map(|_| RelationshipType::of(name.as_str()))
+            .collect();
+
+        // Every handle points at the first handle's string: no per-call copies
+        assert!(handles
+            .iter()
+            .all(|h| h == &first && Arc::ptr_eq(&h.name, &first.name)));
+        assert!(Arc::strong_count(&first.name) > COUNT);
+        assert!(RelationshipType::interned_count() >= 1);
+    }
 }
@@ -0,0 +1,296 @@
+use super::{AdjacencyCursor, AdjacencyList};
+use crate::collections::HugeByteArray;
+use crate::types::graph::MappedNodeId;
+use crate::types::graph::RelationshipTopology;
+use std::fmt;
+use std::sync::Arc;
+
+/// Adjacency list storing sorted, delta-encoded, varint-packed target ids.
+///
+/// Each node's targets are sorted, replaced by the gap to their predecessor and
+/// written as LEB128 varints into a single [`HugeByteArray`]. Small gaps, which
+/// dominate in high-degree lists, take one byte instead of eight. Cursors decode
+/// lazily while iterating.
+pub struct CompressedAdjacencyList {
+    data: Arc<CompressedData>,
+}
+
+struct CompressedData {
+    bytes: HugeByteArray,
+    offsets: Vec<usize>,
+    degrees: Vec<usize>,
+}
+
+impl CompressedAdjacencyList {
+    /// Compresses the given per-node target lists.
+    ///
+    /// Targets are sorted per node; the input order is not preserved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a target id is negative.
+    pub fn from_adjacency(adjacency: &[Vec<MappedNodeId>]) -> Self {
+        let mut buffer: Vec<i8> = Vec::new();
+        let mut offsets = Vec::with_capacity(adjacency.len());
+        let mut degrees = Vec::with_capacity(adjacency.len());
+        let mut sorted = Vec::new();
+
+        for targets in adjacency {
+            offsets.push(buffer.len());
+            degrees.push(targets.len());
+
+            sorted.clear();
+            sorted.extend_from_slice(targets);
+            sorted.sort_unstable();
+
+            let mut previous = 0;
+            for &target in &sorted {
+                assert!(target >= 0, "cannot compress negative target id {target}");
+                encode_vlong((target - previous) as u64, &mut buffer);
+                previous = target;
+            }
+        }
+
+        Self {
+            data: Arc::new(CompressedData {
+                bytes: HugeByteArray::from_vec(buffer),
+                offsets,
+                degrees,
+            }),
+        }
+    }
+
+    /// Compresses the outgoing adjacency of a topology.
+    pub fn from_topology(topology: &RelationshipTopology) -> Self {
+        let adjacency: Vec<Vec<MappedNodeId>> = (0..topology.node_capacity())
+            .map(|node| {
+                topology
+                    .outgoing(node as MappedNodeId)
+                    .map(<[MappedNodeId]>::to_vec)
+                    .unwrap_or_default()
+            })
+            .collect();
+        Self::from_adjacency(&adjacency)
+    }
+
+    /// Number of nodes with an adjacency list.
+    pub fn node_count(&self) -> usize {
+        self.data.degrees.len()
+    }
+
+    /// Size of the packed target bytes.
+    pub fn compressed_size_bytes(&self) -> usize {
+        self.data.bytes.size_of()
+    }
+
+    /// Size of the packed target bytes for a single node.
+    pub fn compressed_size_bytes_of(&self, node: MappedNodeId) -> usize {
+        let index = node as usize;
+        let end = self
+            .data
+            .offsets
+            .get(index + 1)
+            .copied()
+            .unwrap_or_else(|| self.data.bytes.size());
+        end - self.data.offsets[index]
+    }
+}
+
+impl AdjacencyList for CompressedAdjacencyList {
+    fn degree(&self, node: MappedNodeId) -> usize {
+        self.data.degrees.get(node as usize).copied().unwrap_or(0)
+    }
+
+    fn raw_adjacency_cursor(&self) -> Box<dyn AdjacencyCursor> {
+        Box::new(CompressedAdjacencyCursor::new(Arc::clone(&self.data)))
+    }
+
+    fn init_cursor(&self, cursor: &mut dyn AdjacencyCursor, node: MappedNodeId) {
+        cursor.init(node as usize, self.degree(node));
+    }
+
+    fn memory_usage_bytes(&self) -> Option<usize> {
+        let index_bytes =
+            (self.data.offsets.len() + self.data.degrees.len()) * std::mem::size_of::<usize>();
+        Some(self.data.bytes.size_of() + index_bytes)
+    }
+}
+
+impl fmt::Debug for CompressedAdjacencyList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompressedAdjacencyList")
+            .field("node_count", &self.node_count())
+            .field("compressed_size_bytes", &self.compressed_size_bytes())
+            .finish()
+    }
+}
+
+/// Cursor decoding a [`CompressedAdjacencyList`] on the fly.
+pub struct CompressedAdjacencyCursor {
+    data: Arc<CompressedData>,
+    position: usize,
+    last: MappedNodeId,
+    size: usize,
+    remaining: usize,
+}
+
+impl CompressedAdjacencyCursor {
+    fn new(data: Arc<CompressedData>) -> Self {
+        Self {
+            data,
+            position: 0,
+            last: 0,
+            size: 0,
+            remaining: 0,
+        }
+    }
+
+    fn decode_at(&self, position: usize) -> (u64, usize) {
+        decode_vlong(&self.data.bytes, position)
+    }
+}
+
+impl AdjacencyCursor for CompressedAdjacencyCursor {
+    fn init(&mut self, index: usize, degree: usize) {
+        self.position = self.data.offsets.get(index).copied().unwrap_or(0);
+        self.last = 0;
+        self.size = degree;
+        self.remaining = degree;
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    fn next_vlong(&mut self) -> Option<MappedNodeId> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let (delta, next_position) = self.decode_at(self.position);
+        self.position = next_position;
+        self.last += delta as MappedNodeId;
+        self.remaining -= 1;
+        Some(self.last)
+    }
+
+    fn peek_vlong(&self) -> Option<MappedNodeId> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let (delta, _) = self.decode_at(self.position);
+        Some(self.last + delta as MappedNodeId)
+    }
+
+    fn skip_until(&mut self, node_id: MappedNodeId) -> Option<MappedNodeId> {
+        while let Some(target) = self.next_vlong() {
+            if target > node_id {
+                return Some(target);
+            }
+        }
+        None
+    }
+
+    fn advance(&mut self, node_id: MappedNodeId) -> Option<MappedNodeId> {
+        while let Some(target) = self.next_vlong() {
+            if target >= node_id {
+                return Some(target);
+            }
+        }
+        None
+    }
+
+    fn advance_by(&mut self, n: usize) -> Option<MappedNodeId> {
+        if n >= self.remaining {
+            while self.next_vlong().is_some() {}
+            return None;
+        }
+        for _ in 0..n {
+            self.next_vlong();
+        }
+        self.next_vlong()
+    }
+}
+
+impl fmt::Debug for CompressedAdjacencyCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompressedAdjacencyCursor")
+            .field("position", &self.position)
+            .field("size", &self.size)
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+fn encode_vlong(mut value: u64, out: &mut Vec<i8>) {
+    while value >= 0x80 {
+        out.push(((value & 0x7F) | 0x80) as u8 as i8);
+        value >>= 7;
+    }
+    out.push(value as u8 as i8);
+}
+
+fn decode_vlong(bytes: &HugeByteArray, mut position: usize) -> (u64, usize) {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = bytes.get(position) as u8;
+        position += 1;
+        value |= u64::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            return (value, position);
+        }
+        shift += 7;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::graph::AdjacencyCursorExt;
+    use crate::types::graph::AdjacencyListExt;
+
+    #[test]
+    fn round_trips_neighbor_lists() {
+        let adjacency = vec![
+            vec![5, 1, 300, 2, 70_000],
+            vec![],
+            vec![0, i64::MAX >> 1, 3],
+        ];
+        let list = CompressedAdjacencyList::from_adjacency(&adjacency);
+
+        for (node, targets) in adjacency.iter().enumerate() {
+            let mut expected = targets.clone();
+            expected.sort_unstable();
+            let mut cursor = list.adjacency_cursor(node as MappedNodeId);
+            assert_eq!(cursor.size(), expected.len());
+            assert_eq!(cursor.collect_remaining(), expected);
+        }
+    }
+
+    #[test]
+    fn high_degree_node_is_smaller_than_raw_ids() {
+        let targets: Vec<MappedNodeId> = (0..10_000).map(|i| 1_000_000 + i * 3).collect();
+        let list = CompressedAdjacencyList::from_adjacency(std::slice::from_ref(&targets));
+
+        let raw_bytes = targets.len() * std::mem::size_of::<u64>();
+        assert!(list.compressed_size_bytes_of(0) < raw_bytes / 4);
+        assert_eq!(list.adjacency_cursor(0).collect_remaining(), targets);
+    }
+
+    #[test]
+    fn cursor_navigation() {
+        let list = CompressedAdjacencyList::from_adjacency(&[vec![2, 4, 8, 16, 32]]);
+        let mut cursor = list.adjacency_cursor(0);
+
+        assert_eq!(cursor.peek_vlong(), Some(2));
+        assert_eq!(cursor.advance(4), Some(4));
+        assert_eq!(cursor.skip_until(8), Some(16));
+        assert_eq!(cursor.remaining(), 1);
+        assert_eq!(cursor.advance_by(1), None);
+        assert!(!cursor.has_next());
+    }
+}
@@ -0,0 +1,324 @@
+use crate::types::graph::RelationshipTopology;
+use std::cmp::Reverse;
+
+/// Computes a canonical node order for the given topologies.
+///
+/// Returns `order` with `order[new] = old`. `initial_colors` seeds the
+/// refinement, typically ranks of node label sets: nodes are first ranked by
+/// initial color, then by descending total degree and descending out-degree,
+/// and the ranking is refined by the sorted ranks of each node's neighbors
+/// (per relationship type) until stable. Initial colors must themselves be
+/// invariant, e.g. dense ranks of sorted label names, not node ids.
+///
+/// Remaining ties are resolved by backtracking: every node of the first tied
+/// class is individualized in turn, and the order whose relabeled graph is
+/// lexicographically smallest wins. Isomorphic inputs with equally ranked
+/// initial colors therefore always produce the same relabeled topology.
+/// Nodes that can be swapped without changing the graph are explored only
+/// once, which keeps cliques, stars and edgeless graphs cheap, but highly
+/// symmetric graphs without such swaps (e.g. hypercubes) still take time
+/// exponential in the number of ties.
+pub fn canonical_order(
+    topologies: &[&RelationshipTopology],
+    node_count: usize,
+    initial_colors: &[usize],
+) -> Vec<usize> {
+    assert_eq!(initial_colors.len(), node_count, "one initial color per node");
+    let (outgoing, incoming) = typed_adjacency(topologies, node_count);
+
+    let seed_keys: Vec<(usize, Reverse<usize>, Reverse<usize>)> = (0..node_count)
+        .map(|node| {
+            let out_degree: usize = outgoing[node].len();
+            let in_degree: usize = incoming[node].len();
+            (
+                initial_colors[node],
+                Reverse(out_degree + in_degree),
+                Reverse(out_degree),
+            )
+        })
+        .collect();
+    let search = Search {
+        outgoing: &outgoing,
+        incoming: &incoming,
+        initial_colors,
+    };
+    let mut best = None;
+    search.explore(rank_by(&seed_keys), &mut best);
+
+    let (_, colors) = best.expect("the search visits at least one leaf");
+    let mut order = vec![0; node_count];
+    for (node, &color) in colors.iter().enumerate() {
+        order[color] = node;
+    }
+    order
+}
+
+/// Initial colors in canonical order followed by the sorted relabeled edges
+type Certificate = (Vec<usize>, Vec<(usize, usize, usize)>);
+
+struct Search<'a> {
+    outgoing: &'a TypedAdjacency,
+    incoming: &'a TypedAdjacency,
+    initial_colors: &'a [usize],
+}
+
+impl Search<'_> {
+    /// Refine `colors` and branch on the first tied class, keeping the
+    /// discrete coloring with the smallest certificate in `best`
+    fn explore(&self, mut colors: Vec<usize>, best: &mut Option<(Certificate, Vec<usize>)>) {
+        refine(&mut colors, self.outgoing, self.incoming);
+
+        let Some(color) = first_tied_color(&colors) else {
+            let certificate = self.certificate(&colors);
+            if best.as_ref().is_none_or(|(smallest, _)| certificate < *smallest) {
+                *best = Some((certificate, colors));
+            }
+            return;
+        };
+
+        let mut explored: Vec<usize> = Vec::new();
+        for chosen in (0..colors.len()).filter(|&node| colors[node] == color) {
+            if explored.iter().any(|&other| self.swappable(other, chosen)) {
+                continue;
+            }
+            explored.push(chosen);
+            let split: Vec<(usize, bool)> = (0..colors.len())
+                .map(|node| (colors[node], node != chosen))
+                .collect();
+            self.explore(rank_by(&split), best);
+        }
+    }
+
+    fn certificate(&self, colors: &[usize]) -> Certificate {
+        let mut initial = vec![0; colors.len()];
+        for (node, &color) in colors.iter().enumerate() {
+            initial[color] = self.initial_colors[node];
+        }
+        let mut edges: Vec<(usize, usize, usize)> = self
+            .outgoing
+            .iter()
+            .enumerate()
+            .flat_map(|(node, targets)| {
+                targets
+                    .iter()
+                    .map(move |&(type_index, target)| (type_index, colors[node], colors[target]))
+            })
+            .collect();
+        edges.sort_unstable();
+        (initial, edges)
+    }
+
+    /// Whether exchanging `a` and `b` maps the graph onto itself
+    ///
+    /// Both nodes share a color class when this is asked, so the swap also
+    /// preserves the current coloring and both branches reach equal leaves.
+    fn swappable(&self, a: usize, b: usize) -> bool {
+        let swap = |node: usize| match node {
+            node if node == a => b,
+            node if node == b => a,
+            node => node,
+        };
+        let same_after_swap = |from: &[(usize, usize)], to: &[(usize, usize)]| {
+            let mut swapped: Vec<(usize, usize)> = from
+                .iter()
+                .map(|&(type_index, neighbor)| (type_index, swap(neighbor)))
+                .collect();
+            let mut expected = to.to_vec();
+            swapped.sort_unstable();
+            expected.sort_unstable();
+            swapped == expected
+        };
+        self.initial_colors[a] == self.initial_colors[b]
+            && same_after_swap(&self.outgoing[a], &self.outgoing[b])
+            && same_after_swap(&self.incoming[a], &self.incoming[b])
+    }
+}
+
+type TypedAdjacency = Vec<Vec<(usize, usize)>>;
+
+/// `(relationship type index, neighbor)` pairs per node, in both directions
+fn typed_adjacency(
+    topologies: &[&RelationshipTopology],
+    node_count: usize,
+) -> (TypedAdjacency, TypedAdjacency) {
+    let mut outgoing = vec![Vec::new(); node_count];
+    let mut incoming = vec![Vec::new(); node_count];
+    for (type_index, topology) in topologies.iter().enumerate() {
+        for (node, targets) in outgoing.iter_mut().enumerate() {
+            for &target in topology.outgoing(node as i64).unwrap_or(&[]) {
+                targets.push((type_index, target as usize));
+                incoming[target as usize].push((type_index, node));
+            }
+        }
+    }
+    (outgoing, incoming)
+}
+
+/// Dense ranks `0..k` of `keys`, equal keys sharing a rank
+fn rank_by<K: Ord + Clone>(keys: &[K]) -> Vec<usize> {
+    let mut distinct: Vec<K> = keys.to_vec();
+    distinct.sort();
+    distinct.dedup();
+    keys.iter()
+        .map(|key| distinct.binary_search(key).unwrap())
+        .collect()
+}
+
+fn class_count(colors: &[usize]) -> usize {
+    colors.iter().max().map_or(0, |max| max + 1)
+}
+
+fn first_tied_color(colors: &[usize]) -> Option<usize> {
+    let mut sizes = vec![0usize; class_count(colors)];
+    for &color in colors {
+        sizes[color] += 1;
+    }
+    sizes.iter().position(|&size| size > 1)
+}
+
+/// Split color classes by neighbor colors until the partition is stable
+fn refine(colors: &mut Vec<usize>, outgoing: &TypedAdjacency, incoming: &TypedAdjacency) {
+    loop {
+        let signature = |adjacency: &[(usize, usize)], colors: &[usize]| {
+            let mut neighbor_colors: Vec<(usize, usize)> = adjacency
+                .iter()
+                .map(|&(type_index, neighbor)| (type_index, colors[neighbor]))
+                .collect();
+            neighbor_colors.sort_unstable();
+            neighbor_colors
+        };
+        let keys: Vec<_> = (0..colors.len())
+            .map(|node| {
+                (
+                    colors[node],
+                    signature(&outgoing[node], colors),
+                    signature(&incoming[node], colors),
+                )
+            })
+            .collect();
+
+        let refined = rank_by(&keys);
+        let stable = class_count(&refined) == class_count(colors);
+        *colors = refined;
+        if stable {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orders_by_descending_degree() {
+        // Star with center 2 plus an edge 0 -> 1.
+        let topology =
+            RelationshipTopology::new(vec![vec![1], vec![], vec![0, 1, 3], vec![]], None);
+        let order = canonical_order(&[&topology], 4, &[0; 4]);
+        assert_eq!(order[0], 2);
+        assert_eq!(order.len(), 4);
+    }
+
+    #[test]
+    fn individualizes_symmetric_graphs() {
+        // Directed 4-cycle: every node looks the same after refinement.
+        let topology = RelationshipTopology::new(vec![vec![1], vec![2], vec![3], vec![0]], None);
+        let mut order = canonical_order(&[&topology], 4, &[0; 4]);
+        order.sort_unstable();
+        assert_eq!(order, vec![0, 1, 2, 3]);
+    }
+
+    /// Relabeled outgoing lists of `adjacency` under its canonical order
+    fn canonical_form(adjacency: Vec<Vec<i64>>, initial_colors: &[usize]) -> Vec<Vec<i64>> {
+        let node_count = adjacency.len();
+        let topology = RelationshipTopology::new(adjacency, None);
+        let order = canonical_order(&[&topology], node_count, initial_colors);
+        let mut canonical_id = vec![0; node_count];
+        for (new_id, &old_id) in order.iter().enumerate() {
+            canonical_id[old_id] = new_id as i64;
+        }
+        order
+            .iter()
+            .map(|&old_id| {
+                let mut targets: Vec<i64> = topology
+                    .outgoing(old_id as i64)
+                    .unwrap_or(&[])
+                    .iter()
+                    .map(|&target| canonical_id[target as usize])
+                    .collect();
+                targets.sort_unstable();
+                targets
+            })
+            .collect()
+    }
+
+    /// `adjacency` with node `i` renamed to `renamed[i]`
+    fn rename(adjacency: &[Vec<i64>], renamed: &[i64]) -> Vec<Vec<i64>> {
+        let mut result = vec![Vec::new(); adjacency.len()];
+        for (node, targets) in adjacency.iter().enumerate() {
+            result[renamed[node] as usize] =
+                targets.iter().map(|&target| renamed[target as usize]).collect();
+        }
+        result
+    }
+
+    #[test]
+    fn isomorphic_graphs_with_symmetric_ties_agree() {
+        // Two directed 3-cycles and a 2-cycle: every node has in- and
+        // out-degree one, so refinement leaves a single tied class and a
+        // greedy choice may pick a 3-cycle node in one numbering and a
+        // 2-cycle node in the other.
+        let graph = vec![vec![1], vec![2], vec![0], vec![4], vec![5], vec![3], vec![7], vec![6]];
+        let renamed = rename(&graph, &[6, 3, 7, 5, 0, 1, 2, 4]);
+        assert_eq!(canonical_form(graph, &[0; 8]), canonical_form(renamed, &[0; 8]));
+
+        let reversed = rename(
+            &[vec![1], vec![0], vec![3], vec![4], vec![2], vec![6], vec![7], vec![5]],
+            &[7, 6, 5, 4, 3, 2, 1, 0],
+        );
+        let graph = vec![vec![1], vec![2], vec![0], vec![4], vec![5], vec![3], vec![7], vec![6]];
+        assert_eq!(canonical_form(graph, &[0; 8]), canonical_form(reversed, &[0; 8]));
+    }
+
+    #[test]
+    fn undirected_cycles_agree_regardless_of_numbering() {
+        let cycle = |labels: [i64; 6]| {
+            let mut adjacency = vec![Vec::new(); 6];
+            for i in 0..6 {
+                let (a, b) = (labels[i], labels[(i + 1) % 6]);
+                adjacency[a as usize].push(b);
+                adjacency[b as usize].push(a);
+            }
+            adjacency
+        };
+        assert_eq!(
+            canonical_form(cycle([0, 1, 2, 3, 4, 5]), &[0; 6]),
+            canonical_form(cycle([3, 0, 5, 1, 4, 2]), &[0; 6])
+        );
+    }
+
+    #[test]
+    fn initial_colors_separate_otherwise_equal_nodes() {
+        // A directed 4-cycle with one node colored differently: the colored
+        // node must come first wherever it sits in the cycle.
+        let cycle = vec![vec![1], vec![2], vec![3], vec![0]];
+        let topology = RelationshipTopology::new(cycle.clone(), None);
+        for colored in 0..4 {
+            let mut colors = vec![1; 4];
+            colors[colored] = 0;
+            assert_eq!(canonical_order(&[&topology], 4, &colors)[0], colored);
+        }
+        let mut colors = vec![1; 4];
+        colors[2] = 0;
+        let mut shifted = vec![1; 4];
+        shifted[0] = 0;
+        assert_eq!(canonical_form(cycle.clone(), &colors), canonical_form(cycle, &shifted));
+    }
+
+    #[test]
+    fn edgeless_graphs_do_not_branch_over_every_permutation() {
+        let order = canonical_order(&[], 12, &[0; 12]);
+        assert_eq!(order.len(), 12);
+    }
+}
@@ -1,17 +1,21 @@
 #![allow(clippy::module_inception)]
 
 pub mod adj_list;
+pub mod canonical_order;
 pub mod characteristics;
 pub mod default_graph;
 pub mod degrees;
 pub mod graph;
 pub mod id_map;
 pub mod topology;
+pub mod undirected_topology;
 
 pub use adj_list::*;
+pub use canonical_order::*;
 pub use characteristics::*;
 pub use default_graph::*;
 pub use degrees::*;
 pub use graph::*;
 pub use id_map::*;
 pub use topology::*;
+pub use undirected_topology::*;
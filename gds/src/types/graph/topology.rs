@@ -1,4 +1,5 @@
 use crate::types::graph::id_map::NodeId;
+use std::mem::size_of;
 
 /// In-memory adjacency representation used by the default graph implementation.
 #[derive(Debug, Clone)]
@@ -61,6 +62,32 @@ impl RelationshipTopology {
     pub fn node_capacity(&self) -> usize {
         self.outgoing.len()
     }
+
+    /// Appends the relationship `source -> target`, growing the node
+    /// capacity if needed and updating the inverse index if there is one.
+    pub fn add_relationship(&mut self, source: NodeId, target: NodeId) {
+        let capacity = self.outgoing.len().max(source.max(target) as usize + 1);
+        self.outgoing.resize_with(capacity, Vec::new);
+        let targets = &mut self.outgoing[source as usize];
+        self.has_parallel_edges |= targets.contains(&target);
+        targets.push(target);
+        if let Some(incoming) = &mut self.incoming {
+            incoming.resize_with(capacity, Vec::new);
+            incoming[target as usize].push(source);
+        }
+        self.relationship_count += 1;
+    }
+
+    /// Returns the bytes held by the adjacency lists, including list headers.
+    pub fn memory_usage_bytes(&self) -> usize {
+        let lists_bytes = |lists: &Vec<Vec<NodeId>>| {
+            lists
+                .iter()
+                .map(|list| size_of::<Vec<NodeId>>() + list.len() * size_of::<NodeId>())
+                .sum::<usize>()
+        };
+        lists_bytes(&self.outgoing) + self.incoming.as_ref().map_or(0, lists_bytes)
+    }
 }
 
 #[cfg(test)]
@@ -78,4 +105,17 @@ mod tests {
         let topology = RelationshipTopology::new(vec![vec![1, 2], vec![0]], None);
         assert_eq!(topology.relationship_count(), 3);
     }
+
+    #[test]
+    fn adds_relationships() {
+        let mut topology =
+            RelationshipTopology::new(vec![vec![1], vec![]], Some(vec![vec![], vec![0]]));
+        topology.add_relationship(1, 0);
+        topology.add_relationship(0, 1);
+
+        assert_eq!(topology.relationship_count(), 3);
+        assert_eq!(topology.outgoing(1), Some(&[0][..]));
+        assert_eq!(topology.incoming(0), Some(&[1][..]));
+        assert!(topology.has_parallel_edges());
+    }
 }
@@ -0,0 +1,376 @@
+use crate::types::graph::degrees::Degrees;
+use crate::types::graph::id_map::NodeId;
+use crate::types::graph::RelationshipTopology;
+use crate::types::properties::relationship::{
+    DefaultRelationshipCursor, RelationshipCursorBox, RelationshipIterator,
+    RelationshipPredicate, RelationshipStream, WeightedRelationshipCursor,
+    WeightedRelationshipCursorBox, WeightedRelationshipStream,
+};
+use std::mem::size_of;
+use std::sync::Arc;
+
+/// Compact undirected adjacency.
+///
+/// [`RelationshipTopology`] represents an undirected graph by storing each
+/// edge in both directions as 64-bit node ids. `UndirectedTopology` sorts
+/// each edge into its canonical direction `source <= target` and keeps a
+/// single compressed sparse row index of 32-bit node ids holding the higher
+/// neighbors of every node, so every edge appears in it exactly once.
+///
+/// A second 32-bit row index lists the lower neighbors of every node, so
+/// neighbor lookups cost `O(degree)` and degrees are row lengths. Together
+/// the two indexes take roughly half the memory of the double-stored
+/// [`RelationshipTopology`].
+///
+/// The topology implements [`RelationshipIterator`] and [`Degrees`]. Both
+/// stream directions yield the same symmetric neighbors, carrying the
+/// fallback value as property.
+#[derive(Debug, Clone)]
+pub struct UndirectedTopology {
+    /// Row `node` of `higher` is `higher_offsets[node]..higher_offsets[node + 1]`
+    higher_offsets: Arc<[usize]>,
+    /// Higher (or equal, for self-loops) neighbors of each node, sorted per row
+    higher: Arc<[u32]>,
+    /// Row `node` of `lower` is `lower_offsets[node]..lower_offsets[node + 1]`
+    lower_offsets: Arc<[usize]>,
+    /// Strictly lower neighbors of each node, sorted per row
+    lower: Arc<[u32]>,
+}
+
+impl UndirectedTopology {
+    /// Creates a topology of `node_count` nodes from undirected edges.
+    ///
+    /// Each edge is listed once, in either orientation. Parallel edges are
+    /// kept; a self-loop appears once in its node's neighbors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an endpoint is not in `0..node_count`, or if `node_count`
+    /// does not fit into 32-bit node ids.
+    pub fn from_edges(
+        node_count: usize,
+        edges: impl IntoIterator<Item = (NodeId, NodeId)>,
+    ) -> Self {
+        assert!(
+            node_count <= u32::MAX as usize,
+            "UndirectedTopology supports at most {} nodes, got {}",
+            u32::MAX,
+            node_count
+        );
+        let mut canonical: Vec<(u32, u32)> = edges
+            .into_iter()
+            .map(|(source, target)| {
+                assert!(
+                    (0..node_count as NodeId).contains(&source)
+                        && (0..node_count as NodeId).contains(&target),
+                    "edge ({}, {}) has an endpoint outside of 0..{}",
+                    source,
+                    target,
+                    node_count
+                );
+                (source.min(target) as u32, source.max(target) as u32)
+            })
+            .collect();
+        canonical.sort_unstable();
+
+        let mut higher_offsets = vec![0usize; node_count + 1];
+        let mut lower_offsets = vec![0usize; node_count + 1];
+        for &(source, target) in &canonical {
+            higher_offsets[source as usize + 1] += 1;
+            if source != target {
+                lower_offsets[target as usize + 1] += 1;
+            }
+        }
+        for node in 0..node_count {
+            higher_offsets[node + 1] += higher_offsets[node];
+            lower_offsets[node + 1] += lower_offsets[node];
+        }
+
+        // Edges are sorted by source, so every lower row fills in ascending order
+        let mut lower = vec![0u32; lower_offsets[node_count]];
+        let mut next_lower = lower_offsets[..node_count].to_vec();
+        for &(source, target) in &canonical {
+            if source != target {
+                lower[next_lower[target as usize]] = source;
+                next_lower[target as usize] += 1;
+            }
+        }
+        let higher: Vec<u32> = canonical.into_iter().map(|(_, target)| target).collect();
+
+        Self {
+            higher_offsets: higher_offsets.into(),
+            higher: higher.into(),
+            lower_offsets: lower_offsets.into(),
+            lower: lower.into(),
+        }
+    }
+
+    /// Returns the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.higher_offsets.len() - 1
+    }
+
+    /// Returns the number of undirected edges, each counted once.
+    pub fn relationship_count(&self) -> usize {
+        self.higher.len()
+    }
+
+    /// Returns the number of neighbor entries of `node`.
+    pub fn degree(&self, node: NodeId) -> usize {
+        self.lower_row(node).len() + self.higher_row(node).len()
+    }
+
+    /// Returns the neighbors of `node` in ascending order, lower and higher
+    /// endpoints alike.
+    pub fn out_neighbors(&self, node: NodeId) -> Vec<NodeId> {
+        self.neighbors(node).collect()
+    }
+
+    /// Calls `consumer(source, target)` once per edge, with `source <= target`.
+    pub fn for_each_edge<F>(&self, mut consumer: F)
+    where
+        F: FnMut(NodeId, NodeId),
+    {
+        for source in 0..self.node_count() as NodeId {
+            for &target in self.higher_row(source) {
+                consumer(source, target as NodeId);
+            }
+        }
+    }
+
+    /// Expands into a [`RelationshipTopology`] storing every edge in both
+    /// directions, as used by [`DefaultGraphStore`](crate::types::graph_store::DefaultGraphStore).
+    pub fn to_relationship_topology(&self) -> RelationshipTopology {
+        let mut outgoing = vec![Vec::new(); self.node_count()];
+        self.for_each_edge(|source, target| {
+            outgoing[source as usize].push(target);
+            if source != target {
+                outgoing[target as usize].push(source);
+            }
+        });
+        for neighbors in &mut outgoing {
+            neighbors.sort_unstable();
+        }
+        RelationshipTopology::new(outgoing, None)
+    }
+
+    /// Returns the bytes held by the adjacency data.
+    pub fn memory_usage_bytes(&self) -> usize {
+        (self.higher_offsets.len() + self.lower_offsets.len()) * size_of::<usize>()
+            + (self.higher.len() + self.lower.len()) * size_of::<u32>()
+    }
+
+    fn higher_row(&self, node: NodeId) -> &[u32] {
+        let node = node as usize;
+        &self.higher[self.higher_offsets[node]..self.higher_offsets[node + 1]]
+    }
+
+    fn lower_row(&self, node: NodeId) -> &[u32] {
+        let node = node as usize;
+        &self.lower[self.lower_offsets[node]..self.lower_offsets[node + 1]]
+    }
+
+    /// Neighbors of `node` in ascending order, once per parallel edge
+    fn neighbors(&self, node: NodeId) -> impl Iterator<Item = NodeId> + Send + '_ {
+        self.lower_row(node)
+            .iter()
+            .chain(self.higher_row(node))
+            .map(|&neighbor| neighbor as NodeId)
+    }
+
+    fn relationships<'a>(
+        &'a self,
+        node_id: NodeId,
+        fallback_value: f64,
+    ) -> impl Iterator<Item = (NodeId, NodeId, f64)> + Send + 'a {
+        self.neighbors(node_id)
+            .map(move |target| (node_id, target, fallback_value))
+    }
+}
+
+impl RelationshipPredicate for UndirectedTopology {
+    fn exists(&self, source_id: NodeId, target_id: NodeId) -> bool {
+        let node_count = self.node_count() as NodeId;
+        if !(0..node_count).contains(&source_id) || !(0..node_count).contains(&target_id) {
+            return false;
+        }
+        self.higher_row(source_id.min(target_id))
+            .binary_search(&(source_id.max(target_id) as u32))
+            .is_ok()
+    }
+}
+
+impl RelationshipIterator for UndirectedTopology {
+    fn stream_relationships<'a>(
+        &'a self,
+        node_id: NodeId,
+        fallback_value: f64,
+    ) -> RelationshipStream<'a> {
+        Box::new(
+            self.relationships(node_id, fallback_value)
+                .map(|(source, target, property)| {
+                    Box::new(DefaultRelationshipCursor::new(source, target, property))
+                        as RelationshipCursorBox
+                }),
+        )
+    }
+
+    fn stream_inverse_relationships<'a>(
+        &'a self,
+        node_id: NodeId,
+        fallback_value: f64,
+    ) -> RelationshipStream<'a> {
+        self.stream_relationships(node_id, fallback_value)
+    }
+
+    fn concurrent_copy(&self) -> Box<dyn RelationshipIterator> {
+        Box::new(self.clone())
+    }
+
+    fn stream_relationships_weighted<'a>(
+        &'a self,
+        node_id: NodeId,
+        fallback_value: f64,
+    ) -> WeightedRelationshipStream<'a> {
+        Box::new(
+            self.relationships(node_id, fallback_value)
+                .map(|(source, target, weight)| {
+                    Box::new(WeightedCursor {
+                        source,
+                        target,
+                        weight,
+                    }) as WeightedRelationshipCursorBox
+                }),
+        )
+    }
+
+    fn stream_inverse_relationships_weighted<'a>(
+        &'a self,
+        node_id: NodeId,
+        fallback_value: f64,
+    ) -> WeightedRelationshipStream<'a> {
+        self.stream_relationships_weighted(node_id, fallback_value)
+    }
+}
+
+impl Degrees for UndirectedTopology {
+    fn degree(&self, node_id: NodeId) -> usize {
+        UndirectedTopology::degree(self, node_id)
+    }
+
+    fn degree_inverse(&self, node_id: NodeId) -> Option<usize> {
+        Some(UndirectedTopology::degree(self, node_id))
+    }
+
+    fn degree_without_parallel_relationships(&self, node_id: NodeId) -> usize {
+        let mut neighbors = self.out_neighbors(node_id);
+        neighbors.dedup();
+        neighbors.len()
+    }
+}
+
+#[derive(Debug)]
+struct WeightedCursor {
+    source: NodeId,
+    target: NodeId,
+    weight: f64,
+}
+
+impl WeightedRelationshipCursor for WeightedCursor {
+    fn source_id(&self) -> NodeId {
+        self.source
+    }
+
+    fn target_id(&self) -> NodeId {
+        self.target
+    }
+
+    fn weight(&self) -> f64 {
+        self.weight
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stores_edges_once_with_symmetric_access() {
+        let node_count = 40;
+        let edges: Vec<(NodeId, NodeId)> = (0..node_count as NodeId)
+            .flat_map(|source| {
+                (source + 1..node_count as NodeId)
+                    .filter(move |target| (source + target) % 3 != 0)
+                    .map(move |target| (target, source))
+            })
+            .collect();
+
+        let undirected = UndirectedTopology::from_edges(node_count, edges.iter().copied());
+        let mut both_directions = vec![Vec::new(); node_count];
+        for &(source, target) in &edges {
+            both_directions[source as usize].push(target);
+            both_directions[target as usize].push(source);
+        }
+        let doubled = RelationshipTopology::new(both_directions, None);
+
+        // Roughly half of the double-stored topology
+        assert_eq!(undirected.relationship_count(), edges.len());
+        let ratio = undirected.memory_usage_bytes() as f64 / doubled.memory_usage_bytes() as f64;
+        assert!((0.45..0.55).contains(&ratio), "memory ratio {}", ratio);
+
+        for node in 0..node_count as NodeId {
+            let mut expected = doubled.outgoing(node).unwrap().to_vec();
+            expected.sort_unstable();
+            let neighbors = undirected.out_neighbors(node);
+            assert_eq!(neighbors, expected);
+            assert_eq!(undirected.degree(node), neighbors.len());
+            for &neighbor in &neighbors {
+                assert!(undirected.out_neighbors(neighbor).contains(&node));
+                assert!(undirected.exists(node, neighbor));
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_parallel_edges_and_self_loops() {
+        let topology = UndirectedTopology::from_edges(3, vec![(1, 0), (0, 1), (2, 2)]);
+        assert_eq!(topology.out_neighbors(0), vec![1, 1]);
+        assert_eq!(topology.out_neighbors(1), vec![0, 0]);
+        assert_eq!(topology.out_neighbors(2), vec![2]);
+        assert_eq!(topology.degree_without_parallel_relationships(1), 1);
+
+        let mut edges = Vec::new();
+        topology.for_each_edge(|source, target| edges.push((source, target)));
+        assert_eq!(edges, vec![(0, 1), (0, 1), (2, 2)]);
+
+        let expanded = topology.to_relationship_topology();
+        assert_eq!(expanded.outgoing(1), Some(&[0, 0][..]));
+        assert_eq!(expanded.outgoing(2), Some(&[2][..]));
+    }
+
+    #[test]
+    fn streams_symmetric_neighbors_as_relationship_iterator() {
+        let topology = UndirectedTopology::from_edges(4, vec![(2, 0), (0, 1), (3, 2)]);
+        let iterator: Box<dyn RelationshipIterator> = topology.concurrent_copy();
+
+        let stream = |node: NodeId| -> Vec<(NodeId, NodeId, f64)> {
+            iterator
+                .stream_relationships(node, 1.5)
+                .map(|cursor| (cursor.source_id(), cursor.target_id(), cursor.property()))
+                .collect()
+        };
+        assert_eq!(stream(0), vec![(0, 1, 1.5), (0, 2, 1.5)]);
+        assert_eq!(stream(2), vec![(2, 0, 1.5), (2, 3, 1.5)]);
+        assert_eq!(stream(3), vec![(3, 2, 1.5)]);
+
+        let inverse: Vec<_> = iterator
+            .stream_inverse_relationships_weighted(2, 0.5)
+            .map(|cursor| (cursor.target_id(), cursor.weight()))
+            .collect();
+        assert_eq!(inverse, vec![(0, 0.5), (3, 0.5)]);
+
+        assert!(iterator.exists(3, 2) && iterator.exists(2, 3));
+        assert!(!iterator.exists(1, 2));
+        assert_eq!(Degrees::degree_inverse(&topology, 2), Some(2));
+    }
+}
@@ -1,6 +1,6 @@
 use super::{
-    Capabilities, DatabaseInfo, DeletionResult, GraphName, GraphStore, GraphStoreError,
-    GraphStoreResult,
+    Capabilities, DatabaseInfo, DeletionResult, GraphName, GraphSnapshot, GraphStatsCache,
+    GraphStore, GraphStoreError, GraphStoreListener, GraphStoreListeners, GraphStoreResult,
 };
 use crate::collections::backends::arrow::{ArrowDoubleArray, ArrowLongArray};
 use crate::collections::backends::factory::{
@@ -8,12 +8,16 @@ use crate::collections::backends::factory::{
     LongCollection,
 };
 use crate::collections::backends::vec::{VecDouble, VecLong};
+use crate::collections::HugeLongArray;
 use crate::config::GraphStoreConfig;
+use crate::core::utils::partition::PartitionUtils;
+use crate::core::Aggregation;
 use crate::projection::orientation::Orientation;
 use crate::projection::{NodeLabel, RelationshipType};
 use crate::types::graph::{
-    id_map::{IdMap, SimpleIdMap},
+    id_map::{IdMap, MappedNodeId, SimpleIdMap},
     DefaultGraph, Graph, GraphCharacteristics, GraphCharacteristicsBuilder, RelationshipTopology,
+    UndirectedTopology,
 };
 use crate::types::properties::graph::impls::default_graph_property_values::{
     DefaultDoubleGraphPropertyValues, DefaultLongGraphPropertyValues,
@@ -24,7 +28,9 @@ use crate::types::properties::node::impls::default_node_property_values::{
 };
 use crate::types::properties::node::NodePropertyValues;
 use crate::types::properties::relationship::default_relationship_property_store::DefaultRelationshipPropertyStore;
+use crate::types::properties::relationship::impls::default_relationship_property_values::DefaultRelationshipPropertyValues;
 use crate::types::properties::relationship::relationship_property::RelationshipProperty;
+use crate::types::properties::relationship::RelationshipIterator;
 use crate::types::properties::relationship::RelationshipPropertyValues;
 use crate::types::properties::relationship::{
     RelationshipPropertyStore, RelationshipPropertyStoreBuilder,
@@ -33,31 +39,42 @@ use crate::types::schema::{Direction, GraphSchema, PropertySchemaTrait};
 use crate::types::PropertyState;
 use crate::types::ValueType;
 use chrono::{DateTime, Utc};
+use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 /// In-memory [`GraphStore`] backed by [`SimpleIdMap`] and [`RelationshipTopology`].
 #[derive(Debug, Clone)]
 pub struct DefaultGraphStore {
-    config: Arc<GraphStoreConfig>,
-    graph_name: GraphName,
-    database_info: DatabaseInfo,
-    schema: Arc<GraphSchema>,
-    capabilities: Capabilities,
-    creation_time: DateTime<Utc>,
-    modification_time: DateTime<Utc>,
-    id_map: Arc<SimpleIdMap>,
-    relationship_topologies: HashMap<RelationshipType, Arc<RelationshipTopology>>,
-    ordered_relationship_types: Vec<RelationshipType>,
-    inverse_indexed_relationship_types: HashSet<RelationshipType>,
-    relationship_count: usize,
-    has_parallel_relationships: bool,
-    graph_characteristics: GraphCharacteristics,
-    graph_properties: HashMap<String, Arc<dyn GraphPropertyValues>>,
-    node_properties: HashMap<String, Arc<dyn NodePropertyValues>>,
-    node_properties_by_label: HashMap<String, HashSet<String>>,
-    relationship_property_stores: HashMap<RelationshipType, DefaultRelationshipPropertyStore>,
-    has_relationship_properties: bool,
+    pub(super) config: Arc<GraphStoreConfig>,
+    pub(super) graph_name: GraphName,
+    pub(super) database_info: DatabaseInfo,
+    pub(super) schema: Arc<GraphSchema>,
+    pub(super) capabilities: Capabilities,
+    pub(super) creation_time: DateTime<Utc>,
+    pub(super) modification_time: DateTime<Utc>,
+    pub(super) id_map: Arc<SimpleIdMap>,
+    pub(super) relationship_topologies: HashMap<RelationshipType, Arc<RelationshipTopology>>,
+    pub(super) ordered_relationship_types: Vec<RelationshipType>,
+    pub(super) inverse_indexed_relationship_types: HashSet<RelationshipType>,
+    pub(super) relationship_count: usize,
+    pub(super) has_parallel_relationships: bool,
+    pub(super) graph_characteristics: GraphCharacteristics,
+    pub(super) graph_properties: HashMap<String, Arc<dyn GraphPropertyValues>>,
+    /// Time-indexed graph-level scalars, sorted by timestamp
+    pub(super) graph_property_series: HashMap<String, Vec<(i64, f64)>>,
+    pub(super) node_properties: HashMap<String, Arc<dyn NodePropertyValues>>,
+    pub(super) node_properties_by_label: HashMap<String, HashSet<String>>,
+    pub(super) relationship_property_stores:
+        HashMap<RelationshipType, DefaultRelationshipPropertyStore>,
+    pub(super) has_relationship_properties: bool,
+    /// Cached out-degree per node over all relationship types, shared with
+    /// [`clone_structure`](Self::clone_structure) copies until modified
+    pub(super) degrees: Arc<HugeLongArray>,
+    /// Number of full degree recomputations, incremental updates excluded
+    pub(super) degree_rebuilds: usize,
+    pub(super) listeners: GraphStoreListeners,
+    pub(super) stats_cache: GraphStatsCache,
 }
 
 impl DefaultGraphStore {
@@ -97,14 +114,20 @@ impl DefaultGraphStore {
             has_parallel_relationships: false,
             graph_characteristics: GraphCharacteristicsBuilder::new().build(),
             graph_properties: HashMap::new(),
+            graph_property_series: HashMap::new(),
             node_properties: HashMap::new(),
             node_properties_by_label: HashMap::new(),
             relationship_property_stores: HashMap::new(),
             has_relationship_properties: false,
+            degrees: Arc::new(HugeLongArray::new(0)),
+            degree_rebuilds: 0,
+            listeners: GraphStoreListeners::new(),
+            stats_cache: GraphStatsCache::new(),
         };
 
         store.rebuild_relationship_metadata();
         store.refresh_relationship_property_state();
+        store.rebuild_degrees();
         store
     }
 
@@ -134,10 +157,498 @@ impl DefaultGraphStore {
         ))
     }
 
-    fn set_modified(&mut self) {
+    /// Returns the cached out-degree of `node` over all relationship types.
+    ///
+    /// The cache is kept up to date by the relationship mutations of this
+    /// store instead of being recomputed per call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is not a valid mapped node id.
+    pub fn degree(&self, node: MappedNodeId) -> usize {
+        self.degrees.get(node as usize) as usize
+    }
+
+    /// Calls `consumer(source, target, weight)` for every relationship, in parallel.
+    ///
+    /// Nodes are split into [`DegreePartition`](crate::core::utils::partition::DegreePartition)s
+    /// of roughly equal relationship count, one rayon task per partition, so a
+    /// few high-degree nodes do not leave a single straggler. Every
+    /// relationship is visited exactly once. The weight is the relationship
+    /// property that [`DefaultGraphStore::graph`] selects for its type, or
+    /// `1.0` if there is none.
+    pub fn par_for_each_relationship<F>(&self, consumer: F)
+    where
+        F: Fn(MappedNodeId, MappedNodeId, f64) + Sync,
+    {
+        let graph = self.graph();
+        let partitions = PartitionUtils::degree_partition(
+            self.id_map.node_count(),
+            self.relationship_count,
+            Box::new(|node| self.degrees.get(node) as usize),
+            self.config.compute.concurrency,
+            |partition| partition,
+            None,
+        );
+
+        partitions.par_iter().for_each(|partition| {
+            let start = partition.start_node();
+            for node in start..start + partition.node_count() {
+                for cursor in graph.stream_relationships(node as MappedNodeId, 1.0) {
+                    consumer(cursor.source_id(), cursor.target_id(), cursor.property());
+                }
+            }
+        });
+    }
+
+    /// Calls `consumer(source, target, weight)` for every relationship of
+    /// `rel_type`, with `weight` read from its `key` property.
+    ///
+    /// Relationships are visited in outgoing order, which is the order the
+    /// property values are stored in, so topology and values are read in a
+    /// single sequential pass instead of one property lookup per edge.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphStoreError::RelationshipTypeNotFound`] for an unknown
+    /// type and [`GraphStoreError::PropertyNotFound`] if the type has no
+    /// `key` property.
+    pub fn for_each_relationship_with_property<F>(
+        &self,
+        rel_type: &RelationshipType,
+        key: &str,
+        mut consumer: F,
+    ) -> GraphStoreResult<()>
+    where
+        F: FnMut(MappedNodeId, MappedNodeId, f64),
+    {
+        let topology = self.relationship_topologies.get(rel_type).ok_or_else(|| {
+            GraphStoreError::RelationshipTypeNotFound(rel_type.name().to_string())
+        })?;
+        let values = self.relationship_property_values(rel_type, key)?;
+        let default_value = values.default_value();
+
+        let mut index = 0u64;
+        for source in 0..topology.node_capacity() as MappedNodeId {
+            for &target in topology.outgoing(source).unwrap_or_default() {
+                let weight = values.double_value(index).unwrap_or(default_value);
+                consumer(source, target, weight);
+                index += 1;
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn rebuild_degrees(&mut self) {
+        self.degrees = Arc::new(HugeLongArray::new(self.id_map.node_count()));
+        let topologies: Vec<_> = self.relationship_topologies.values().cloned().collect();
+        for topology in topologies {
+            self.apply_degree_delta(&topology, 1);
+        }
+        self.degree_rebuilds += 1;
+    }
+
+    /// Adds (`sign = 1`) or removes (`sign = -1`) the degrees of one topology.
+    fn apply_degree_delta(&mut self, topology: &RelationshipTopology, sign: i64) {
+        let degrees = Arc::make_mut(&mut self.degrees);
+        let node_count = degrees.size().min(topology.node_capacity());
+        for node in 0..node_count {
+            let degree = topology
+                .outgoing(node as i64)
+                .map_or(0, |targets| targets.len());
+            if degree > 0 {
+                degrees.add_to(node, sign * degree as i64);
+            }
+        }
+    }
+
+    pub(super) fn set_modified(&mut self) {
         self.modification_time = Utc::now();
     }
 
+    /// Delivers a mutation event to the statistics cache and every listener.
+    pub(super) fn notify<F>(&self, event: F)
+    where
+        F: Fn(&dyn GraphStoreListener),
+    {
+        event(&self.stats_cache);
+        self.listeners.notify(event);
+    }
+
+    /// Registers a listener that is notified after every mutation of this store.
+    ///
+    /// Clones of the store start without listeners.
+    pub fn subscribe(&self, listener: Arc<dyn GraphStoreListener>) {
+        self.listeners.subscribe(listener);
+    }
+
+    /// Removes a listener registered with [`DefaultGraphStore::subscribe`].
+    pub fn unsubscribe(&self, listener: &Arc<dyn GraphStoreListener>) {
+        self.listeners.unsubscribe(listener);
+    }
+
+    /// Checks that every relationship endpoint exists in the id map.
+    ///
+    /// Importers (Arrow, CSV, builders) trust their input, so an edge may end
+    /// up pointing at a node id the id map does not contain. Walks the
+    /// outgoing lists of every relationship type and returns one
+    /// [`GraphStoreError::DanglingRelationship`] per offending edge, in
+    /// relationship type and source order. Factories can call this after
+    /// construction; it is not run automatically.
+    pub fn validate(&self) -> Result<(), Vec<GraphStoreError>> {
+        let node_count = self.id_map.node_count() as MappedNodeId;
+        let contains = |node: MappedNodeId| (0..node_count).contains(&node);
+
+        let mut violations = Vec::new();
+        for rel_type in &self.ordered_relationship_types {
+            let topology = &self.relationship_topologies[rel_type];
+            for source in 0..topology.node_capacity() as MappedNodeId {
+                for &target in topology.outgoing(source).unwrap_or_default() {
+                    if !contains(source) || !contains(target) {
+                        violations.push(GraphStoreError::DanglingRelationship {
+                            relationship_type: rel_type.name().to_string(),
+                            source_node: source,
+                            target_node: target,
+                        });
+                    }
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    /// Returns a copy of this store that shares its data until modified.
+    ///
+    /// The id map, relationship topologies, degree cache and all property
+    /// values are held behind `Arc`s, so the copy costs one pointer per entry
+    /// instead of a deep copy of the graph. Mutations of either store copy
+    /// only the part they modify: [`add_relationship`](Self::add_relationship)
+    /// copies the degree cache and the topology of one relationship type,
+    /// while all others stay shared. This lets a pipeline branch off a huge
+    /// graph to coarsen or filter it.
+    ///
+    /// Listeners are not copied; the copy starts without subscribers.
+    pub fn clone_structure(&self) -> DefaultGraphStore {
+        self.clone()
+    }
+
+    /// Captures the current state of the store for [`restore`](Self::restore).
+    ///
+    /// Costs the same as [`clone_structure`](Self::clone_structure).
+    pub fn snapshot(&self) -> GraphSnapshot {
+        GraphSnapshot {
+            store: self.clone_structure(),
+        }
+    }
+
+    /// Rolls the store back to `snapshot`.
+    ///
+    /// Topology, labels, all properties and the modification time are
+    /// restored. Registered listeners stay subscribed but are not notified.
+    pub fn restore(&mut self, snapshot: GraphSnapshot) {
+        let listeners = std::mem::take(&mut self.listeners);
+        *self = snapshot.store;
+        self.listeners = listeners;
+    }
+
+    /// Returns `true` if both stores hold the same topology allocation for
+    /// `relationship_type`, i.e. neither has modified it since they were cloned.
+    pub fn shares_topology_with(
+        &self,
+        other: &DefaultGraphStore,
+        relationship_type: &RelationshipType,
+    ) -> bool {
+        match (
+            self.relationship_topologies.get(relationship_type),
+            other.relationship_topologies.get(relationship_type),
+        ) {
+            (Some(mine), Some(theirs)) => Arc::ptr_eq(mine, theirs),
+            _ => false,
+        }
+    }
+
+    /// Returns the relationships of `relationship_type` as a compact
+    /// [`UndirectedTopology`].
+    ///
+    /// The store holds undirected relationships in both directions; the
+    /// result keeps each of them once, in canonical orientation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphStoreError::RelationshipTypeNotFound`] for an unknown
+    /// type and [`GraphStoreError::InvalidOperation`] if the schema declares
+    /// the type as directed.
+    pub fn undirected_topology(
+        &self,
+        relationship_type: &RelationshipType,
+    ) -> GraphStoreResult<UndirectedTopology> {
+        let topology = self
+            .relationship_topologies
+            .get(relationship_type)
+            .ok_or_else(|| {
+                GraphStoreError::RelationshipTypeNotFound(relationship_type.name().to_string())
+            })?;
+        if !self
+            .schema
+            .relationship_schema()
+            .is_undirected_for_type(relationship_type)
+        {
+            return Err(GraphStoreError::InvalidOperation(format!(
+                "relationship type {} is directed",
+                relationship_type.name()
+            )));
+        }
+
+        let mut edges = Vec::with_capacity(topology.relationship_count() / 2);
+        for source in 0..topology.node_capacity() as MappedNodeId {
+            for &target in topology.outgoing(source).unwrap_or_default() {
+                if source <= target {
+                    edges.push((source, target));
+                }
+            }
+        }
+        Ok(UndirectedTopology::from_edges(
+            self.id_map.node_count(),
+            edges,
+        ))
+    }
+
+    /// Adds the relationship `source -> target` to an existing type.
+    ///
+    /// On an undirected type `target -> source` is added as well, matching
+    /// how undirected topologies store both directions. A topology shared
+    /// with a [`clone_structure`](Self::clone_structure) copy is copied
+    /// before it is modified.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphStoreError::RelationshipTypeNotFound`] for an unknown
+    /// type and [`GraphStoreError::InvalidOperation`] if an endpoint is not a
+    /// node of the store or the type has relationship properties, whose
+    /// values could not be kept aligned with the new relationship.
+    pub fn add_relationship(
+        &mut self,
+        relationship_type: &RelationshipType,
+        source: MappedNodeId,
+        target: MappedNodeId,
+    ) -> GraphStoreResult<()> {
+        let node_count = self.id_map.node_count() as MappedNodeId;
+        if !(0..node_count).contains(&source) || !(0..node_count).contains(&target) {
+            return Err(GraphStoreError::InvalidOperation(format!(
+                "relationship ({}, {}) has an endpoint outside of 0..{}",
+                source, target, node_count
+            )));
+        }
+        if self
+            .relationship_property_stores
+            .get(relationship_type)
+            .is_some_and(|store| !store.is_empty())
+        {
+            return Err(GraphStoreError::InvalidOperation(format!(
+                "cannot add a relationship to type {} with relationship properties",
+                relationship_type.name()
+            )));
+        }
+        let topology = self
+            .relationship_topologies
+            .get_mut(relationship_type)
+            .ok_or_else(|| {
+                GraphStoreError::RelationshipTypeNotFound(relationship_type.name().to_string())
+            })?;
+
+        let undirected = self
+            .schema
+            .relationship_schema()
+            .is_undirected_for_type(relationship_type);
+        let topology = Arc::make_mut(topology);
+        let degrees = Arc::make_mut(&mut self.degrees);
+        topology.add_relationship(source, target);
+        degrees.add_to(source as usize, 1);
+        if undirected {
+            topology.add_relationship(target, source);
+            degrees.add_to(target as usize, 1);
+        }
+        self.rebuild_relationship_metadata();
+        self.set_modified();
+        self.notify(|l| l.on_relationship_added(relationship_type));
+        Ok(())
+    }
+
+    /// Adds relationships of the given type, replacing any existing topology for it.
+    pub fn add_relationship_type(
+        &mut self,
+        relationship_type: RelationshipType,
+        topology: RelationshipTopology,
+    ) {
+        let topology = Arc::new(topology);
+        if let Some(previous) = self
+            .relationship_topologies
+            .insert(relationship_type.clone(), Arc::clone(&topology))
+        {
+            self.apply_degree_delta(&previous, -1);
+        }
+        self.apply_degree_delta(&topology, 1);
+        self.rebuild_relationship_metadata();
+        self.set_modified();
+        self.notify(|l| l.on_relationship_added(&relationship_type));
+    }
+
+    /// Removes every relationship whose source and target are the same node
+    /// and returns how many were removed.
+    ///
+    /// Relationship properties of the remaining relationships are kept.
+    pub fn remove_self_loops(&mut self) -> usize {
+        let mut removed = 0;
+        for rel_type in self.ordered_relationship_types.clone() {
+            let topology = Arc::clone(&self.relationship_topologies[&rel_type]);
+            let mut outgoing = Vec::with_capacity(topology.node_capacity());
+            let mut index = 0u64;
+            let mut type_removed = 0;
+            for source in 0..topology.node_capacity() as MappedNodeId {
+                let mut kept = Vec::new();
+                for &target in topology.outgoing(source).unwrap_or_default() {
+                    if target == source {
+                        type_removed += 1;
+                    } else {
+                        kept.push((target, vec![index]));
+                    }
+                    index += 1;
+                }
+                outgoing.push(kept);
+            }
+            if type_removed > 0 {
+                self.replace_relationships(&rel_type, outgoing, Aggregation::Single);
+                removed += type_removed;
+            }
+        }
+        removed
+    }
+
+    /// Collapses relationships of the same type between the same source and
+    /// target into one and returns how many were removed.
+    ///
+    /// The relationship properties of each group are merged with
+    /// `aggregation`. The kept relationship takes the position of the first
+    /// one of its group.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphStoreError::InvalidOperation`] if `aggregation` is
+    /// equivalent to [`Aggregation::None`], which cannot merge values.
+    pub fn remove_parallel_edges(&mut self, aggregation: Aggregation) -> GraphStoreResult<usize> {
+        if aggregation.equivalent_to_none() {
+            return Err(GraphStoreError::InvalidOperation(format!(
+                "cannot merge parallel relationships with aggregation {:?}",
+                aggregation
+            )));
+        }
+
+        let mut removed = 0;
+        for rel_type in self.ordered_relationship_types.clone() {
+            let topology = Arc::clone(&self.relationship_topologies[&rel_type]);
+            if !topology.has_parallel_edges() {
+                continue;
+            }
+            let mut outgoing = Vec::with_capacity(topology.node_capacity());
+            let mut index = 0u64;
+            for source in 0..topology.node_capacity() as MappedNodeId {
+                let targets = topology.outgoing(source).unwrap_or_default();
+                let mut groups: Vec<(MappedNodeId, Vec<u64>)> = Vec::new();
+                let mut positions: HashMap<MappedNodeId, usize> = HashMap::new();
+                for &target in targets {
+                    match positions.get(&target) {
+                        Some(&position) => groups[position].1.push(index),
+                        None => {
+                            positions.insert(target, groups.len());
+                            groups.push((target, vec![index]));
+                        }
+                    }
+                    index += 1;
+                }
+                removed += targets.len() - groups.len();
+                outgoing.push(groups);
+            }
+            self.replace_relationships(&rel_type, outgoing, aggregation);
+        }
+        Ok(removed)
+    }
+
+    /// Replaces the relationships of `relationship_type` with `outgoing`,
+    /// where every target comes with the indices of the old relationships it
+    /// stands for. Their property values are merged with `aggregation`.
+    pub(super) fn replace_relationships(
+        &mut self,
+        relationship_type: &RelationshipType,
+        outgoing: Vec<Vec<(MappedNodeId, Vec<u64>)>>,
+        aggregation: Aggregation,
+    ) {
+        if let Some(store) = self.relationship_property_stores.remove(relationship_type) {
+            let mut builder = store.to_builder();
+            for property in store.get_all_properties() {
+                let old_values = property.values();
+                let value_at = |index: u64| {
+                    old_values
+                        .double_value(index)
+                        .unwrap_or(old_values.default_value())
+                };
+                let values: Vec<f64> = outgoing
+                    .iter()
+                    .flatten()
+                    .map(|(_, indices)| {
+                        let first = aggregation.normalize_property_value(value_at(indices[0]));
+                        indices[1..].iter().fold(first, |total, &index| {
+                            // Aggregations that cannot merge are rejected by the callers.
+                            aggregation.merge(total, value_at(index)).unwrap_or(total)
+                        })
+                    })
+                    .collect();
+                let count = values.len();
+                builder = builder.put(
+                    property.key(),
+                    RelationshipProperty::with_schema(
+                        property.property_schema().clone(),
+                        Arc::new(DefaultRelationshipPropertyValues::with_default(
+                            values, count,
+                        )),
+                    ),
+                );
+            }
+            self.relationship_property_stores
+                .insert(relationship_type.clone(), builder.build());
+        }
+
+        let outgoing: Vec<Vec<MappedNodeId>> = outgoing
+            .into_iter()
+            .map(|targets| targets.into_iter().map(|(target, _)| target).collect())
+            .collect();
+        let incoming = self.relationship_topologies[relationship_type]
+            .is_inverse_indexed()
+            .then(|| {
+                let mut incoming = vec![Vec::new(); outgoing.len()];
+                for (source, targets) in outgoing.iter().enumerate() {
+                    for &target in targets {
+                        incoming[target as usize].push(source as MappedNodeId);
+                    }
+                }
+                incoming
+            });
+        self.relationship_topologies.insert(
+            relationship_type.clone(),
+            Arc::new(RelationshipTopology::new(outgoing, incoming)),
+        );
+
+        self.rebuild_degrees();
+        self.rebuild_relationship_metadata();
+        self.refresh_relationship_property_state();
+        self.set_modified();
+        self.notify(|l| l.on_relationships_deleted(relationship_type));
+    }
+
     fn schema_labels(&self) -> HashSet<NodeLabel> {
         self.id_map
             .available_node_labels()
@@ -163,8 +674,9 @@ impl DefaultGraphStore {
         let backend = create_long_backend_from_config(&collections_config, values);
         let pv = build_node_long_property_values(backend, node_count);
 
-        self.node_properties.insert(key, pv);
+        self.node_properties.insert(key.clone(), pv);
         self.set_modified();
+        self.notify(|l| l.on_node_property_added(&key));
         Ok(())
     }
 
@@ -183,8 +695,9 @@ impl DefaultGraphStore {
         let backend = create_double_backend_from_config(&collections_config, values);
         let pv = build_node_double_property_values(backend, node_count);
 
-        self.node_properties.insert(key, pv);
+        self.node_properties.insert(key.clone(), pv);
         self.set_modified();
+        self.notify(|l| l.on_node_property_added(&key));
         Ok(())
     }
 
@@ -201,8 +714,9 @@ impl DefaultGraphStore {
         let backend = create_long_backend_from_config(&collections_config, values);
         let pv = build_graph_long_property_values(backend);
 
-        self.graph_properties.insert(key, pv);
+        self.graph_properties.insert(key.clone(), pv);
         self.set_modified();
+        self.notify(|l| l.on_graph_property_added(&key));
         Ok(())
     }
 
@@ -219,8 +733,9 @@ impl DefaultGraphStore {
         let backend = create_double_backend_from_config(&collections_config, values);
         let pv = build_graph_double_property_values(backend);
 
-        self.graph_properties.insert(key, pv);
+        self.graph_properties.insert(key.clone(), pv);
         self.set_modified();
+        self.notify(|l| l.on_graph_property_added(&key));
         Ok(())
     }
 
@@ -350,14 +865,16 @@ impl GraphStore for DefaultGraphStore {
         property_values: Arc<dyn GraphPropertyValues>,
     ) -> GraphStoreResult<()> {
         let key = property_key.into();
-        self.graph_properties.insert(key, property_values);
+        self.graph_properties.insert(key.clone(), property_values);
         self.set_modified();
+        self.notify(|l| l.on_graph_property_added(&key));
         Ok(())
     }
 
     fn remove_graph_property(&mut self, property_key: &str) -> GraphStoreResult<()> {
         if self.graph_properties.remove(property_key).is_some() {
             self.set_modified();
+            self.notify(|l| l.on_graph_property_removed(property_key));
             Ok(())
         } else {
             Err(GraphStoreError::PropertyNotFound(property_key.to_string()))
@@ -386,6 +903,7 @@ impl GraphStore for DefaultGraphStore {
         let schema_label = Self::to_schema_label(&node_label);
         Arc::make_mut(&mut self.id_map).add_node_label(schema_label);
         self.set_modified();
+        self.notify(|l| l.on_node_label_added(&node_label));
         Ok(())
     }
 
@@ -475,6 +993,7 @@ impl GraphStore for DefaultGraphStore {
         }
 
         self.set_modified();
+        self.notify(|l| l.on_node_property_added(&key));
         Ok(())
     }
 
@@ -484,6 +1003,7 @@ impl GraphStore for DefaultGraphStore {
                 keys.remove(property_key);
             }
             self.set_modified();
+            self.notify(|l| l.on_node_property_removed(property_key));
             Ok(())
         } else {
             Err(GraphStoreError::PropertyNotFound(property_key.to_string()))
@@ -599,12 +1119,13 @@ impl GraphStore for DefaultGraphStore {
             .remove(&relationship_type)
             .unwrap_or_else(RelationshipPropertyStore::empty);
 
-        let updated_store = store.to_builder().put(key, property).build();
+        let updated_store = store.to_builder().put(key.clone(), property).build();
         self.relationship_property_stores
-            .insert(relationship_type, updated_store);
+            .insert(relationship_type.clone(), updated_store);
 
         self.refresh_relationship_property_state();
         self.set_modified();
+        self.notify(|l| l.on_relationship_property_added(&relationship_type, &key));
         Ok(())
     }
 
@@ -634,6 +1155,7 @@ impl GraphStore for DefaultGraphStore {
 
         self.refresh_relationship_property_state();
         self.set_modified();
+        self.notify(|l| l.on_relationship_property_removed(relationship_type, property_key));
         Ok(())
     }
 
@@ -643,10 +1165,12 @@ impl GraphStore for DefaultGraphStore {
     ) -> GraphStoreResult<DeletionResult> {
         if let Some(topology) = self.relationship_topologies.remove(relationship_type) {
             let removed_count = topology.relationship_count();
+            self.apply_degree_delta(&topology, -1);
             self.relationship_property_stores.remove(relationship_type);
             self.rebuild_relationship_metadata();
             self.refresh_relationship_property_state();
             self.set_modified();
+            self.notify(|l| l.on_relationships_deleted(relationship_type));
             Ok(DeletionResult::with_counts(
                 self.graph_name.clone(),
                 0,
@@ -886,16 +1410,71 @@ fn build_graph_double_property_values(backend: DoubleCollection) -> Arc<dyn Grap
     }
 }
 
+pub(super) fn sorted(keys: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut keys: Vec<String> = keys.into_iter().collect();
+    keys.sort();
+    keys
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::types::graph::degrees::Degrees;
     use crate::types::graph::Graph;
-    use crate::types::graph_store::{DatabaseId, DatabaseLocation};
-    use crate::types::properties::relationship::impls::default_relationship_property_values::DefaultRelationshipPropertyValues;
+    use crate::types::graph_store::{graphs_equal, DatabaseId, DatabaseLocation};
     use std::sync::Arc;
 
+    #[test]
+    fn test_undirected_topology_matches_stored_relationships() {
+        let store = DefaultGraphStore::grid(3, 4);
+        let rel_type = RelationshipType::of("REL");
+        let stored = store.get_graph();
+
+        let topology = store.undirected_topology(&rel_type).unwrap();
+        assert_eq!(
+            topology.relationship_count() * 2,
+            stored.relationship_count()
+        );
+        for node in 0..store.node_count() as MappedNodeId {
+            let mut expected: Vec<_> = stored
+                .stream_relationships(node, 0.0)
+                .map(|cursor| cursor.target_id())
+                .collect();
+            expected.sort_unstable();
+            assert_eq!(topology.out_neighbors(node), expected);
+        }
+
+        let mixed = crate::types::random::generators::typed_store(
+            "mixed",
+            3,
+            [
+                ("FOLLOWS", Direction::Directed, vec![(0, 1), (1, 2)]),
+                ("KNOWS", Direction::Undirected, vec![(0, 2)]),
+            ],
+        );
+        assert!(matches!(
+            mixed.undirected_topology(&RelationshipType::of("FOLLOWS")),
+            Err(GraphStoreError::InvalidOperation(_))
+        ));
+        let knows = mixed
+            .undirected_topology(&RelationshipType::of("KNOWS"))
+            .unwrap();
+        assert_eq!(knows.relationship_count(), 1);
+        assert_eq!(knows.out_neighbors(2), vec![0]);
+        assert!(matches!(
+            store.undirected_topology(&RelationshipType::of("MISSING")),
+            Err(GraphStoreError::RelationshipTypeNotFound(_))
+        ));
+    }
+
     fn store_with_config(config: crate::config::GraphStoreConfig) -> DefaultGraphStore {
+        store_with_original_ids(config, [0, 1, 2])
+    }
+
+    fn store_with_original_ids(
+        config: crate::config::GraphStoreConfig,
+        original_ids: [i64; 3],
+    ) -> DefaultGraphStore {
         let graph_name = GraphName::new("g");
         let database_info = DatabaseInfo::new(
             DatabaseId::new("db"),
@@ -903,7 +1482,7 @@ mod tests {
         );
         let schema = GraphSchema::empty();
         let capabilities = Capabilities::default();
-        let id_map = SimpleIdMap::from_original_ids([0, 1, 2]);
+        let id_map = SimpleIdMap::from_original_ids(original_ids);
 
         let topology = RelationshipTopology::new(vec![vec![1, 2], vec![2], vec![]], None);
 
@@ -1039,6 +1618,84 @@ mod tests {
         assert!(!store.graph().has_relationship_property());
     }
 
+    fn assert_degrees_match_graph(store: &DefaultGraphStore) {
+        let graph = store.graph();
+        for node in 0..store.node_count() as i64 {
+            assert_eq!(store.degree(node), graph.degree(node), "node {}", node);
+        }
+    }
+
+    #[test]
+    fn caches_degrees_across_relationship_mutations() {
+        let mut store = sample_store();
+        assert_degrees_match_graph(&store);
+        assert_eq!(store.degree(0), 2);
+        assert_eq!(store.degree_rebuilds, 1);
+
+        store.add_relationship_type(
+            RelationshipType::of("LIKES"),
+            RelationshipTopology::new(vec![vec![1, 2], vec![0], vec![0, 1]], None),
+        );
+        assert_degrees_match_graph(&store);
+        assert_eq!(store.degree(0), 4);
+        assert_eq!(store.degree(2), 2);
+
+        // Replacing a type swaps its degrees instead of accumulating them.
+        store.add_relationship_type(
+            RelationshipType::of("LIKES"),
+            RelationshipTopology::new(vec![vec![], vec![0, 2], vec![]], None),
+        );
+        assert_degrees_match_graph(&store);
+        assert_eq!(store.degree(1), 3);
+
+        store
+            .delete_relationships(&RelationshipType::of("KNOWS"))
+            .unwrap();
+        assert_degrees_match_graph(&store);
+        assert_eq!(store.degree(0), 0);
+
+        assert_eq!(store.degree_rebuilds, 1);
+    }
+
+    #[test]
+    fn parallel_relationship_iteration_visits_every_edge_once() {
+        let mut store = sample_store();
+        store.add_relationship_type(
+            RelationshipType::of("LIKES"),
+            RelationshipTopology::new(vec![vec![1, 2], vec![0], vec![0, 1]], None),
+        );
+        let count = store.relationship_count_for_type(&RelationshipType::of("LIKES"));
+        store
+            .add_relationship_property(
+                RelationshipType::of("LIKES"),
+                "weight",
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    vec![0.5, 1.5, 2.5, 3.5, 4.5],
+                    count,
+                )),
+            )
+            .unwrap();
+
+        let visited = std::sync::atomic::AtomicUsize::new(0);
+        let weight_sum = std::sync::Mutex::new(0.0);
+        store.par_for_each_relationship(|_, _, weight| {
+            visited.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            *weight_sum.lock().unwrap() += weight;
+        });
+        assert_eq!(
+            visited.load(std::sync::atomic::Ordering::SeqCst),
+            store.relationship_count()
+        );
+
+        let graph = store.graph();
+        let sequential_sum: f64 = (0..store.node_count() as i64)
+            .flat_map(|node| graph.stream_relationships(node, 1.0))
+            .map(|cursor| cursor.property())
+            .sum();
+        assert_eq!(sequential_sum, 3.0 + 12.5);
+        assert!((*weight_sum.lock().unwrap() - sequential_sum).abs() < 1e-9);
+    }
+
     #[test]
     fn builds_node_values_from_arrow_backend() {
         let backend = LongCollection::Arrow(ArrowLongArray::from_vec(vec![5, 10]));
@@ -1054,4 +1711,349 @@ mod tests {
         let collected: Vec<f64> = values.double_values().collect();
         assert_eq!(collected, vec![0.25, 0.75]);
     }
+
+    #[derive(Default)]
+    struct CountingListener {
+        node_properties_added: std::sync::Mutex<Vec<String>>,
+        relationships_added: std::sync::Mutex<Vec<String>>,
+        relationship_properties_added: std::sync::Mutex<Vec<(String, String)>>,
+        removals: std::sync::atomic::AtomicUsize,
+    }
+
+    impl GraphStoreListener for CountingListener {
+        fn on_node_property_added(&self, property_key: &str) {
+            self.node_properties_added
+                .lock()
+                .unwrap()
+                .push(property_key.to_string());
+        }
+
+        fn on_node_property_removed(&self, _property_key: &str) {
+            self.removals
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
+
+        fn on_relationship_added(&self, relationship_type: &RelationshipType) {
+            self.relationships_added
+                .lock()
+                .unwrap()
+                .push(relationship_type.name().to_string());
+        }
+
+        fn on_relationship_property_added(
+            &self,
+            relationship_type: &RelationshipType,
+            property_key: &str,
+        ) {
+            self.relationship_properties_added.lock().unwrap().push((
+                relationship_type.name().to_string(),
+                property_key.to_string(),
+            ));
+        }
+    }
+
+    #[test]
+    fn listeners_observe_property_and_relationship_mutations() {
+        let mut store = sample_store();
+        let listener = Arc::new(CountingListener::default());
+        store.subscribe(listener.clone());
+
+        store
+            .add_node_property_i64("age".to_string(), vec![1, 2, 3])
+            .unwrap();
+        store.add_relationship_type(
+            RelationshipType::of("LIKES"),
+            RelationshipTopology::new(vec![vec![1], vec![], vec![0]], None),
+        );
+        store
+            .add_relationship_property(
+                RelationshipType::of("LIKES"),
+                "weight",
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    vec![0.5, 1.5],
+                    2,
+                )),
+            )
+            .unwrap();
+        store.remove_node_property("age").unwrap();
+
+        assert_eq!(*listener.node_properties_added.lock().unwrap(), vec!["age"]);
+        assert_eq!(*listener.relationships_added.lock().unwrap(), vec!["LIKES"]);
+        assert_eq!(
+            *listener.relationship_properties_added.lock().unwrap(),
+            vec![("LIKES".to_string(), "weight".to_string())]
+        );
+        assert_eq!(
+            listener.removals.load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+        assert_eq!(store.relationship_count(), 5);
+    }
+
+    #[test]
+    fn cloned_store_does_not_notify_original_listeners() {
+        let store = sample_store();
+        let listener = Arc::new(CountingListener::default());
+        store.subscribe(listener.clone());
+
+        let mut cloned = store.clone();
+        cloned
+            .add_node_property_i64("age".to_string(), vec![1, 2, 3])
+            .unwrap();
+
+        assert!(listener.node_properties_added.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_validate_accepts_consistent_store() {
+        assert!(sample_store().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_reports_dangling_relationships() {
+        let mut store = crate::types::random::generators::typed_store(
+            "g",
+            3,
+            [("REL", Direction::Directed, vec![(0, 1), (1, 2)])],
+        );
+        store.add_relationship_type(
+            RelationshipType::of("KNOWS"),
+            RelationshipTopology::new(vec![vec![1, 7], vec![2], vec![], vec![0]], None),
+        );
+
+        let violations = store.validate().unwrap_err();
+
+        assert_eq!(violations.len(), 2);
+        assert!(matches!(
+            &violations[0],
+            GraphStoreError::DanglingRelationship { relationship_type, source_node: 0, target_node: 7 }
+                if relationship_type == "KNOWS"
+        ));
+        assert!(matches!(
+            violations[1],
+            GraphStoreError::DanglingRelationship {
+                source_node: 3,
+                target_node: 0,
+                ..
+            }
+        ));
+        assert!(violations[0].to_string().contains("0 -> 7"));
+    }
+
+    #[test]
+    fn test_logical_relationship_count_of_undirected_graph() {
+        use crate::types::random::{RandomGraphConfig, RandomRelationshipConfig};
+
+        let config = RandomGraphConfig {
+            node_count: 30,
+            relationships: vec![RandomRelationshipConfig::new("KNOWS", 0.3)],
+            directed: false,
+            ..RandomGraphConfig::default()
+        }
+        .with_seed(11);
+        let store = DefaultGraphStore::random(&config).unwrap();
+
+        let raw = store.relationship_count();
+        assert!(raw > 0);
+        assert_eq!(raw % 2, 0);
+        assert_eq!(store.logical_relationship_count(), raw / 2);
+        assert_eq!(store.relationship_count_for(Orientation::Undirected), raw);
+        assert_eq!(store.relationship_count_for(Orientation::Natural), raw);
+    }
+
+    #[test]
+    fn test_relationship_count_for_directed_graph() {
+        use crate::types::random::RandomGraphConfig;
+
+        let store = DefaultGraphStore::random(&RandomGraphConfig::default().with_seed(5)).unwrap();
+
+        let raw = store.relationship_count();
+        assert_eq!(store.logical_relationship_count(), raw);
+        assert_eq!(store.relationship_count_for(Orientation::Reverse), raw);
+        assert_eq!(
+            store.relationship_count_for(Orientation::Undirected),
+            raw * 2
+        );
+    }
+
+    #[test]
+    fn test_relationship_counts_of_mixed_schema_use_direction_per_type() {
+        let store = crate::types::random::generators::typed_store(
+            "mixed",
+            4,
+            [
+                ("FOLLOWS", Direction::Directed, vec![(0, 1), (1, 2), (2, 3)]),
+                ("KNOWS", Direction::Undirected, vec![(0, 2), (1, 3)]),
+            ],
+        );
+
+        // Three directed relationships plus two undirected ones stored twice
+        assert_eq!(store.relationship_count(), 7);
+        assert_eq!(store.logical_relationship_count(), 5);
+        assert_eq!(store.relationship_count_for(Orientation::Natural), 7);
+        assert_eq!(store.relationship_count_for(Orientation::Undirected), 10);
+    }
+
+    /// Relationships as `(original source, original target, weight bits)`
+    #[test]
+    fn test_for_each_relationship_with_property() {
+        let mut store = crate::types::random::generators::typed_store(
+            "g",
+            3,
+            [(
+                "ROAD",
+                Direction::Directed,
+                vec![(0, 1), (0, 2), (1, 2), (2, 0)],
+            )],
+        );
+        let rel_type = RelationshipType::of("ROAD");
+        store
+            .add_relationship_property(
+                rel_type.clone(),
+                "weight",
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    vec![1.5, 2.5, 3.5, 4.5],
+                    4,
+                )),
+            )
+            .unwrap();
+
+        let mut visited = Vec::new();
+        store
+            .for_each_relationship_with_property(&rel_type, "weight", |source, target, weight| {
+                visited.push((source, target, weight));
+            })
+            .unwrap();
+
+        assert_eq!(
+            visited,
+            vec![(0, 1, 1.5), (0, 2, 2.5), (1, 2, 3.5), (2, 0, 4.5)]
+        );
+
+        assert!(matches!(
+            store.for_each_relationship_with_property(&rel_type, "missing", |_, _, _| {}),
+            Err(GraphStoreError::PropertyNotFound(_))
+        ));
+        assert!(matches!(
+            store.for_each_relationship_with_property(
+                &RelationshipType::of("OTHER"),
+                "weight",
+                |_, _, _| {}
+            ),
+            Err(GraphStoreError::RelationshipTypeNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn clone_structure_copies_topology_on_write() {
+        let original = sample_store();
+        let knows = RelationshipType::of("KNOWS");
+        let mut branch = original.clone_structure();
+        assert!(branch.shares_topology_with(&original, &knows));
+        assert!(Arc::ptr_eq(&branch.degrees, &original.degrees));
+
+        branch.add_relationship(&knows, 2, 0).unwrap();
+
+        assert!(!branch.shares_topology_with(&original, &knows));
+        assert!(!Arc::ptr_eq(&branch.degrees, &original.degrees));
+        assert_eq!(original.relationship_count(), 3);
+        assert_eq!(branch.relationship_count(), 4);
+        assert_eq!(original.degree(2), 0);
+        assert_eq!(branch.degree(2), 1);
+        assert_eq!(original.get_graph().stream_relationships(2, 0.0).count(), 0);
+        let targets: Vec<_> = branch
+            .get_graph()
+            .stream_relationships(2, 0.0)
+            .map(|cursor| cursor.target_id())
+            .collect();
+        assert_eq!(targets, vec![0]);
+
+        assert!(branch.add_relationship(&knows, 0, 3).is_err());
+        assert!(branch
+            .add_relationship(&RelationshipType::of("MISSING"), 0, 1)
+            .is_err());
+    }
+
+    #[test]
+    fn add_relationship_to_undirected_type_adds_both_directions() {
+        let knows = RelationshipType::of("KNOWS");
+        let mut store = crate::types::random::generators::typed_store(
+            "g",
+            3,
+            [("KNOWS", Direction::Undirected, vec![(0, 1)])],
+        );
+
+        store.add_relationship(&knows, 1, 2).unwrap();
+
+        let topology = &store.relationship_topologies[&knows];
+        assert_eq!(topology.outgoing(1).unwrap(), &[0, 2]);
+        assert_eq!(topology.outgoing(2).unwrap(), &[1]);
+        assert_eq!((store.degree(1), store.degree(2)), (2, 1));
+        assert_eq!(store.logical_relationship_count(), 2);
+        assert_degrees_match_graph(&store);
+    }
+
+    #[test]
+    fn remove_self_loops_and_parallel_edges() {
+        let mut store = sample_store();
+        let knows = RelationshipType::of("KNOWS");
+        store.add_relationship_type(
+            knows.clone(),
+            RelationshipTopology::new(vec![vec![0, 1, 1, 2], vec![1], vec![]], None),
+        );
+        store
+            .add_relationship_property(
+                knows.clone(),
+                "weight",
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    vec![1.0, 2.0, 3.0, 4.0, 5.0],
+                    5,
+                )),
+            )
+            .unwrap();
+
+        assert_eq!(store.remove_self_loops(), 2);
+        assert_eq!(store.relationship_count(), 3);
+        let topology = &store.relationship_topologies[&knows];
+        assert_eq!(topology.outgoing(0), Some(&[1, 1, 2][..]));
+        assert_eq!(topology.outgoing(1), Some(&[][..]));
+        assert_eq!(store.remove_self_loops(), 0);
+
+        assert!(store.remove_parallel_edges(Aggregation::None).is_err());
+        assert_eq!(store.remove_parallel_edges(Aggregation::Sum).unwrap(), 1);
+        assert_eq!(store.relationship_count(), 2);
+        assert!(!store.has_parallel_relationships);
+        assert_eq!(store.degree(0), 2);
+        assert_eq!(store.degree(1), 0);
+        let topology = &store.relationship_topologies[&knows];
+        assert_eq!(topology.outgoing(0), Some(&[1, 2][..]));
+
+        let weights = store.relationship_property_stores[&knows]
+            .get_property_values("weight")
+            .unwrap();
+        assert_eq!(weights.double_value(0).unwrap(), 5.0);
+        assert_eq!(weights.double_value(1).unwrap(), 4.0);
+    }
+
+    #[test]
+    fn restore_rolls_back_to_snapshot() {
+        let mut store = sample_store();
+        let before = store.clone_structure();
+        let knows = RelationshipType::of("KNOWS");
+
+        let snapshot = store.snapshot();
+        store.add_relationship(&knows, 2, 0).unwrap();
+        store.add_relationship(&knows, 1, 1).unwrap();
+        store
+            .add_node_property_f64("score".to_string(), vec![0.5, 1.5, 2.5])
+            .unwrap();
+        assert!(graphs_equal(&store, &before).is_err());
+
+        store.restore(snapshot);
+
+        assert_eq!(graphs_equal(&store, &before), Ok(()));
+        assert!(!store.has_node_property("score"));
+        assert_eq!(store.degree(2), 0);
+        assert!(store.shares_topology_with(&before, &knows));
+    }
 }
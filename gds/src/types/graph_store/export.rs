@@ -0,0 +1,174 @@
+//! Export - Plain-text edge and adjacency lists of a [`DefaultGraphStore`].
+//!
+//! Nodes are written as original node ids, so the output can be read by
+//! tools such as NetworkX or igraph without the id map.
+
+use super::DefaultGraphStore;
+use crate::types::graph::id_map::{IdMap, MappedNodeId, OriginalNodeId};
+use crate::types::properties::relationship::RelationshipIterator;
+use std::io::{self, Write};
+
+impl DefaultGraphStore {
+    /// Writes every relationship as a `source target` line, or
+    /// `source target weight` if `weighted` is set.
+    ///
+    /// Relationships are written per source node in mapped id order; the
+    /// weight is the relationship property that [`DefaultGraphStore::graph`]
+    /// selects for its type, or `1.0` if there is none. Fails with
+    /// [`io::ErrorKind::InvalidData`] if a node has no original id.
+    pub fn write_edge_list<W: Write>(&self, mut writer: W, weighted: bool) -> io::Result<()> {
+        let graph = self.graph();
+        for node in 0..self.id_map.node_count() as MappedNodeId {
+            for cursor in graph.stream_relationships(node, 1.0) {
+                let source = self.original_id(cursor.source_id())?;
+                let target = self.original_id(cursor.target_id())?;
+                if weighted {
+                    writeln!(writer, "{} {} {}", source, target, cursor.property())?;
+                } else {
+                    writeln!(writer, "{} {}", source, target)?;
+                }
+            }
+        }
+        writer.flush()
+    }
+
+    /// Writes one `node: neighbor1 neighbor2 ...` line per node, listing the
+    /// targets of its outgoing relationships.
+    ///
+    /// Like [`write_edge_list`](Self::write_edge_list), nodes are written in
+    /// mapped id order. Nodes without relationships get a line of their own,
+    /// so isolated nodes survive a round trip.
+    pub fn write_adjacency_list<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let graph = self.graph();
+        for node in 0..self.id_map.node_count() as MappedNodeId {
+            write!(writer, "{}:", self.original_id(node)?)?;
+            for cursor in graph.stream_relationships(node, 1.0) {
+                write!(writer, " {}", self.original_id(cursor.target_id())?)?;
+            }
+            writeln!(writer)?;
+        }
+        writer.flush()
+    }
+
+    fn original_id(&self, node: MappedNodeId) -> io::Result<OriginalNodeId> {
+        self.id_map.to_original_node_id(node).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("node {} has no original id", node),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projection::RelationshipType;
+    use crate::types::graph::id_map::SimpleIdMap;
+    use crate::types::graph_store::GraphStore;
+    use crate::types::properties::relationship::impls::default_relationship_property_values::DefaultRelationshipPropertyValues;
+    use crate::types::random::generators::typed_store;
+    use crate::types::schema::Direction;
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    /// A three node store with original ids that differ from the mapped ids
+    fn relabeled_sample_store() -> DefaultGraphStore {
+        let mut store = typed_store(
+            "g",
+            3,
+            [("KNOWS", Direction::Directed, vec![(0, 1), (0, 2), (1, 2)])],
+        );
+        store.id_map = Arc::new(SimpleIdMap::from_original_ids([30, 10, 20]));
+        store
+    }
+
+    fn edge_set(store: &DefaultGraphStore) -> HashSet<(i64, i64, u64)> {
+        let graph = store.graph();
+        (0..store.id_map.node_count() as MappedNodeId)
+            .flat_map(|node| graph.stream_relationships(node, 1.0).collect::<Vec<_>>())
+            .map(|cursor| {
+                (
+                    store.original_id(cursor.source_id()).unwrap(),
+                    store.original_id(cursor.target_id()).unwrap(),
+                    cursor.property().to_bits(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_write_edge_list_round_trip() {
+        let mut store = relabeled_sample_store();
+        store
+            .add_relationship_property(
+                RelationshipType::of("KNOWS"),
+                "weight",
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    vec![0.25, 1.5, 3.0],
+                    3,
+                )),
+            )
+            .unwrap();
+
+        let mut buffer = Vec::new();
+        store.write_edge_list(&mut buffer, true).unwrap();
+        let parsed: HashSet<(i64, i64, u64)> = String::from_utf8(buffer)
+            .unwrap()
+            .lines()
+            .map(|line| {
+                let fields: Vec<&str> = line.split(' ').collect();
+                assert_eq!(fields.len(), 3);
+                let weight: f64 = fields[2].parse().unwrap();
+                (
+                    fields[0].parse().unwrap(),
+                    fields[1].parse().unwrap(),
+                    weight.to_bits(),
+                )
+            })
+            .collect();
+        assert_eq!(parsed, edge_set(&store));
+        assert!(parsed.contains(&(30, 10, 0.25f64.to_bits())));
+        assert_eq!(parsed.len(), 3);
+
+        let mut buffer = Vec::new();
+        store.write_edge_list(&mut buffer, false).unwrap();
+        let text = String::from_utf8(buffer).unwrap();
+        assert_eq!(text.lines().count(), 3);
+        assert!(text.lines().all(|line| line.split(' ').count() == 2));
+    }
+
+    #[test]
+    fn test_write_adjacency_list_round_trip() {
+        let store = relabeled_sample_store();
+
+        let mut buffer = Vec::new();
+        store.write_adjacency_list(&mut buffer).unwrap();
+        let text = String::from_utf8(buffer).unwrap();
+        assert_eq!(text.lines().count(), 3);
+
+        let mut parsed = HashSet::new();
+        for line in text.lines() {
+            let (node, neighbors) = line.split_once(':').unwrap();
+            let source: i64 = node.parse().unwrap();
+            for target in neighbors.split_whitespace() {
+                parsed.insert((source, target.parse().unwrap(), 1.0f64.to_bits()));
+            }
+        }
+        assert_eq!(parsed, edge_set(&store));
+        assert!(text.starts_with("30: 10 20\n"));
+    }
+
+    #[test]
+    fn test_write_fails_for_nodes_without_original_id() {
+        let mut store = relabeled_sample_store();
+        store.id_map = Arc::new(SimpleIdMap::from_original_ids([30, 10]));
+
+        let error = store.write_edge_list(Vec::new(), false).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "node 2 has no original id");
+
+        let error = store.write_adjacency_list(Vec::new()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,436 @@
+//! GraphDiff - The first difference found between two graph stores.
+//!
+//! Round trips through serialization or export must reproduce a store
+//! exactly. [`graphs_equal`] walks two stores in a fixed order and reports
+//! the first mismatch as a `GraphDiff`, so a failing test names the offending
+//! node, relationship or property instead of two opaque stores.
+
+use super::default_graph_store::sorted;
+use super::{DefaultGraphStore, GraphStore};
+use crate::projection::RelationshipType;
+use crate::types::graph::id_map::{IdMap, MappedNodeId, OriginalNodeId};
+use crate::types::properties::graph::GraphPropertyValues;
+use crate::types::properties::node::NodePropertyValues;
+use crate::types::properties::relationship::RelationshipPropertyStore;
+use crate::types::ValueType;
+use std::fmt;
+use std::sync::Arc;
+
+/// Which of the two compared stores holds an element the other lacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphSide {
+    Left,
+    Right,
+}
+
+impl fmt::Display for GraphSide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphSide::Left => write!(f, "left"),
+            GraphSide::Right => write!(f, "right"),
+        }
+    }
+}
+
+/// First mismatch between two graph stores.
+///
+/// Key and label lists are sorted. Property values are rendered as strings so
+/// values of different types can be reported side by side.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum GraphDiff {
+    #[error("Node count differs: {left} vs {right}")]
+    NodeCount { left: usize, right: usize },
+
+    #[error("Original id of node {node} differs: {left:?} vs {right:?}")]
+    OriginalNodeId {
+        node: MappedNodeId,
+        left: Option<OriginalNodeId>,
+        right: Option<OriginalNodeId>,
+    },
+
+    #[error("Labels of node {node} differ: {left:?} vs {right:?}")]
+    NodeLabels {
+        node: MappedNodeId,
+        left: Vec<String>,
+        right: Vec<String>,
+    },
+
+    #[error("Relationship types differ: {left:?} vs {right:?}")]
+    RelationshipTypes {
+        left: Vec<String>,
+        right: Vec<String>,
+    },
+
+    #[error(
+        "Relationship {source_node} -> {target_node} of type {relationship_type} exists only in the {side} graph"
+    )]
+    Relationship {
+        relationship_type: String,
+        source_node: MappedNodeId,
+        target_node: MappedNodeId,
+        side: GraphSide,
+    },
+
+    #[error("Node property keys differ: {left:?} vs {right:?}")]
+    NodePropertyKeys {
+        left: Vec<String>,
+        right: Vec<String>,
+    },
+
+    #[error("Node property {property_key} of node {node} differs: {left} vs {right}")]
+    NodePropertyValue {
+        property_key: String,
+        node: MappedNodeId,
+        left: String,
+        right: String,
+    },
+
+    #[error(
+        "Relationship property keys of type {relationship_type} differ: {left:?} vs {right:?}"
+    )]
+    RelationshipPropertyKeys {
+        relationship_type: String,
+        left: Vec<String>,
+        right: Vec<String>,
+    },
+
+    #[error(
+        "Relationship properties of {source_node} -> {target_node} of type {relationship_type} differ: {left:?} vs {right:?}"
+    )]
+    RelationshipPropertyValues {
+        relationship_type: String,
+        source_node: MappedNodeId,
+        target_node: MappedNodeId,
+        left: Vec<f64>,
+        right: Vec<f64>,
+    },
+
+    #[error("Graph property keys differ: {left:?} vs {right:?}")]
+    GraphPropertyKeys {
+        left: Vec<String>,
+        right: Vec<String>,
+    },
+
+    #[error("Graph property {property_key} differs: {left} vs {right}")]
+    GraphPropertyValue {
+        property_key: String,
+        left: String,
+        right: String,
+    },
+}
+
+/// Compares two stores and returns the first difference, if any.
+///
+/// Checks, in this order: node count, original id and labels per node,
+/// relationship types, graph property keys and values, node property keys and
+/// values per node, then per relationship type the property keys and the
+/// relationships of each source node. Relationships are matched as multisets
+/// of target and property values, so adjacency order does not matter but
+/// parallel edges do. Meant for round-trip tests of importers, exporters and
+/// serialization.
+pub fn graphs_equal(a: &DefaultGraphStore, b: &DefaultGraphStore) -> Result<(), GraphDiff> {
+    let node_count = a.id_map.node_count();
+    if node_count != b.id_map.node_count() {
+        return Err(GraphDiff::NodeCount {
+            left: node_count,
+            right: b.id_map.node_count(),
+        });
+    }
+
+    for node in 0..node_count as MappedNodeId {
+        let left = a.id_map.to_original_node_id(node);
+        let right = b.id_map.to_original_node_id(node);
+        if left != right {
+            return Err(GraphDiff::OriginalNodeId { node, left, right });
+        }
+    }
+
+    let label_names = |store: &DefaultGraphStore, node: MappedNodeId| {
+        sorted(
+            store
+                .id_map
+                .node_labels(node)
+                .iter()
+                .map(|label| label.name().to_string()),
+        )
+    };
+    for node in 0..node_count as MappedNodeId {
+        let (left, right) = (label_names(a, node), label_names(b, node));
+        if left != right {
+            return Err(GraphDiff::NodeLabels { node, left, right });
+        }
+    }
+
+    let type_names = |store: &DefaultGraphStore| {
+        sorted(
+            store
+                .relationship_topologies
+                .keys()
+                .map(|rel_type| rel_type.name().to_string()),
+        )
+    };
+    if type_names(a) != type_names(b) {
+        return Err(GraphDiff::RelationshipTypes {
+            left: type_names(a),
+            right: type_names(b),
+        });
+    }
+
+    let graph_keys = sorted(a.graph_properties.keys().cloned());
+    if graph_keys != sorted(b.graph_properties.keys().cloned()) {
+        return Err(GraphDiff::GraphPropertyKeys {
+            left: graph_keys,
+            right: sorted(b.graph_properties.keys().cloned()),
+        });
+    }
+    for key in &graph_keys {
+        let left = render_graph_property(a.graph_properties[key].as_ref());
+        let right = render_graph_property(b.graph_properties[key].as_ref());
+        if left != right {
+            return Err(GraphDiff::GraphPropertyValue {
+                property_key: key.clone(),
+                left,
+                right,
+            });
+        }
+    }
+
+    let node_keys = sorted(a.node_properties.keys().cloned());
+    if node_keys != sorted(b.node_properties.keys().cloned()) {
+        return Err(GraphDiff::NodePropertyKeys {
+            left: node_keys,
+            right: sorted(b.node_properties.keys().cloned()),
+        });
+    }
+    for key in &node_keys {
+        let render = |values: &Arc<dyn NodePropertyValues>, node: MappedNodeId| {
+            values
+                .gds_value(node as u64)
+                .map_or_else(|e| e.to_string(), |value| value.as_object().to_string())
+        };
+        for node in 0..node_count as MappedNodeId {
+            let left = render(&a.node_properties[key], node);
+            let right = render(&b.node_properties[key], node);
+            if left != right {
+                return Err(GraphDiff::NodePropertyValue {
+                    property_key: key.clone(),
+                    node,
+                    left,
+                    right,
+                });
+            }
+        }
+    }
+
+    let mut rel_types: Vec<_> = a.relationship_topologies.keys().cloned().collect();
+    rel_types.sort_by(|x, y| x.name().cmp(y.name()));
+    for rel_type in &rel_types {
+        let relationship_type = rel_type.name().to_string();
+        let property_keys =
+            |store: &DefaultGraphStore| sorted(store.relationship_property_keys_for_type(rel_type));
+        let keys = property_keys(a);
+        if keys != property_keys(b) {
+            return Err(GraphDiff::RelationshipPropertyKeys {
+                relationship_type,
+                left: keys,
+                right: property_keys(b),
+            });
+        }
+
+        let left_edges = edges_with_properties(a, rel_type, &keys);
+        let right_edges = edges_with_properties(b, rel_type, &keys);
+        for (source_node, (left, right)) in left_edges.iter().zip(&right_edges).enumerate() {
+            let source_node = source_node as MappedNodeId;
+            let (mut l, mut r) = (0, 0);
+            while l < left.len() || r < right.len() {
+                let only = |side, target_node| GraphDiff::Relationship {
+                    relationship_type: relationship_type.clone(),
+                    source_node,
+                    target_node,
+                    side,
+                };
+                match (left.get(l), right.get(r)) {
+                    (Some((lt, lv)), Some((rt, rv))) if lt == rt => {
+                        if compare_weights(lv, rv) != std::cmp::Ordering::Equal {
+                            return Err(GraphDiff::RelationshipPropertyValues {
+                                relationship_type,
+                                source_node,
+                                target_node: *lt,
+                                left: lv.clone(),
+                                right: rv.clone(),
+                            });
+                        }
+                        l += 1;
+                        r += 1;
+                    }
+                    (Some((lt, _)), Some((rt, _))) if lt < rt => {
+                        return Err(only(GraphSide::Left, *lt))
+                    }
+                    (Some(_), Some((rt, _))) | (None, Some((rt, _))) => {
+                        return Err(only(GraphSide::Right, *rt))
+                    }
+                    (Some((lt, _)), None) => return Err(only(GraphSide::Left, *lt)),
+                    (None, None) => unreachable!(),
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Outgoing relationships of one type per source node, each with its property
+/// values in `keys` order, sorted by target and then by values.
+fn edges_with_properties(
+    store: &DefaultGraphStore,
+    rel_type: &RelationshipType,
+    keys: &[String],
+) -> Vec<Vec<(MappedNodeId, Vec<f64>)>> {
+    let node_count = store.id_map.node_count();
+    let topology = &store.relationship_topologies[rel_type];
+    let properties = store.relationship_property_stores.get(rel_type);
+    let values: Vec<_> = keys
+        .iter()
+        .filter_map(|key| properties.and_then(|store| store.get_property_values(key)))
+        .collect();
+
+    let mut index = 0u64;
+    let mut edges = vec![Vec::new(); node_count];
+    for (source, source_edges) in edges.iter_mut().enumerate() {
+        for &target in topology
+            .outgoing(source as MappedNodeId)
+            .unwrap_or_default()
+        {
+            let weights: Vec<f64> = values
+                .iter()
+                .map(|values| values.double_value(index).unwrap_or(f64::NAN))
+                .collect();
+            source_edges.push((target, weights));
+            index += 1;
+        }
+        source_edges.sort_by(|(lt, lv), (rt, rv)| lt.cmp(rt).then(compare_weights(lv, rv)));
+    }
+    edges
+}
+
+fn compare_weights(left: &[f64], right: &[f64]) -> std::cmp::Ordering {
+    left.iter()
+        .zip(right)
+        .map(|(l, r)| l.total_cmp(r))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(left.len().cmp(&right.len()))
+}
+
+fn render_graph_property(values: &dyn GraphPropertyValues) -> String {
+    match values.value_type() {
+        ValueType::Long => format!("{:?}", values.long_values().collect::<Vec<_>>()),
+        ValueType::Double => format!("{:?}", values.double_values().collect::<Vec<_>>()),
+        ValueType::LongArray => format!("{:?}", values.long_array_values().collect::<Vec<_>>()),
+        ValueType::FloatArray => format!("{:?}", values.float_array_values().collect::<Vec<_>>()),
+        ValueType::DoubleArray => {
+            format!("{:?}", values.double_array_values().collect::<Vec<_>>())
+        }
+        other => format!("{:?} x {}", other, values.value_count()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::graph::id_map::SimpleIdMap;
+    use crate::types::graph::RelationshipTopology;
+    use crate::types::properties::relationship::impls::default_relationship_property_values::DefaultRelationshipPropertyValues;
+    use crate::types::random::generators::typed_store;
+    use crate::types::schema::Direction;
+
+    fn sample_store() -> DefaultGraphStore {
+        typed_store(
+            "g",
+            3,
+            [("KNOWS", Direction::Directed, vec![(0, 1), (0, 2), (1, 2)])],
+        )
+    }
+
+    #[test]
+    fn graphs_equal_reports_first_difference() {
+        let build = || {
+            let mut store = sample_store();
+            store
+                .add_node_property_f64("score".to_string(), vec![0.5, 1.5, 2.5])
+                .unwrap();
+            store
+                .add_relationship_property(
+                    RelationshipType::of("KNOWS"),
+                    "weight".to_string(),
+                    Arc::new(DefaultRelationshipPropertyValues::with_default(
+                        vec![1.0, 2.0, 3.0],
+                        3,
+                    )),
+                )
+                .unwrap();
+            store
+        };
+        let left = build();
+        assert_eq!(graphs_equal(&left, &build()), Ok(()));
+
+        let mut right = build();
+        right.add_relationship_type(
+            RelationshipType::of("KNOWS"),
+            RelationshipTopology::new(vec![vec![1, 2], vec![2, 0], vec![]], None),
+        );
+        right
+            .add_relationship_property(
+                RelationshipType::of("KNOWS"),
+                "weight".to_string(),
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    vec![1.0, 2.0, 3.0, 4.0],
+                    4,
+                )),
+            )
+            .unwrap();
+        assert_eq!(
+            graphs_equal(&left, &right),
+            Err(GraphDiff::Relationship {
+                relationship_type: "KNOWS".to_string(),
+                source_node: 1,
+                target_node: 0,
+                side: GraphSide::Right,
+            })
+        );
+
+        let mut reweighted = build();
+        reweighted
+            .add_relationship_property(
+                RelationshipType::of("KNOWS"),
+                "weight".to_string(),
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    vec![1.0, 2.0, 9.0],
+                    3,
+                )),
+            )
+            .unwrap();
+        assert!(matches!(
+            graphs_equal(&left, &reweighted),
+            Err(GraphDiff::RelationshipPropertyValues {
+                source_node: 1,
+                target_node: 2,
+                ..
+            })
+        ));
+    }
+    #[test]
+    fn graphs_equal_compares_original_ids() {
+        let left = sample_store();
+        let mut relabeled = sample_store();
+        relabeled.id_map = Arc::new(SimpleIdMap::from_original_ids([0, 1, 7]));
+
+        assert_eq!(
+            graphs_equal(&left, &relabeled),
+            Err(GraphDiff::OriginalNodeId {
+                node: 2,
+                left: Some(2),
+                right: Some(7),
+            })
+        );
+    }
+}
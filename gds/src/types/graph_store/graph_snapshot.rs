@@ -0,0 +1,21 @@
+//! GraphSnapshot - Captured state of a graph store for rolling back mutations.
+//!
+//! Exploring a graph interactively often means trying a mutation and
+//! discarding it. [`DefaultGraphStore::snapshot`](super::DefaultGraphStore::snapshot)
+//! captures the current state and
+//! [`DefaultGraphStore::restore`](super::DefaultGraphStore::restore) rolls
+//! the store back to it.
+
+use super::DefaultGraphStore;
+
+/// State of a [`DefaultGraphStore`] at the time of the snapshot.
+///
+/// The snapshot shares the id map, topologies and property values with the
+/// store it was taken from, like a
+/// [`clone_structure`](DefaultGraphStore::clone_structure) copy. Mutating
+/// the store afterwards copies what it modifies, so the snapshot is never
+/// affected.
+#[derive(Debug, Clone)]
+pub struct GraphSnapshot {
+    pub(super) store: DefaultGraphStore,
+}
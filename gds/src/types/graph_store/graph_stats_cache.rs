@@ -0,0 +1,134 @@
+//! GraphStatsCache - Memoized topology summaries of a graph store.
+//!
+//! Density, degree statistics and the component count each need a full pass
+//! over the relationships. The cache computes them once and keeps the result
+//! until a relationship mutation is reported through [`GraphStoreListener`].
+
+use super::GraphStoreListener;
+use crate::projection::RelationshipType;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::RwLock;
+
+/// Topology summary of a graph store over all relationship types.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GraphStats {
+    pub node_count: usize,
+    pub relationship_count: usize,
+    /// `relationship_count / (n * (n - 1))`, or `0.0` for fewer than two nodes
+    pub density: f64,
+    pub min_degree: usize,
+    pub max_degree: usize,
+    pub mean_degree: f64,
+    /// Number of weakly connected components, isolated nodes included
+    pub component_count: usize,
+}
+
+/// Memoizes [`GraphStats`] until the topology changes.
+///
+/// Node label and property events leave the cached summary intact; relationship
+/// additions and deletions clear it. Cloning yields an empty cache so a cloned
+/// store never reuses a summary of the original.
+#[derive(Default)]
+pub struct GraphStatsCache {
+    stats: RwLock<Option<GraphStats>>,
+    computations: AtomicUsize,
+}
+
+impl GraphStatsCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached summary, running `compute` only if none is cached.
+    pub fn get_or_compute<F>(&self, compute: F) -> GraphStats
+    where
+        F: FnOnce() -> GraphStats,
+    {
+        if let Some(stats) = *self.stats.read().unwrap() {
+            return stats;
+        }
+        let mut slot = self.stats.write().unwrap();
+        if let Some(stats) = *slot {
+            return stats;
+        }
+        let stats = compute();
+        self.computations.fetch_add(1, Ordering::Relaxed);
+        *slot = Some(stats);
+        stats
+    }
+
+    /// Drops the cached summary.
+    pub fn invalidate(&self) {
+        *self.stats.write().unwrap() = None;
+    }
+
+    /// Returns `true` if a summary is cached.
+    pub fn is_cached(&self) -> bool {
+        self.stats.read().unwrap().is_some()
+    }
+
+    /// Returns how many times a summary was computed.
+    pub fn computation_count(&self) -> usize {
+        self.computations.load(Ordering::Relaxed)
+    }
+}
+
+impl GraphStoreListener for GraphStatsCache {
+    fn on_relationship_added(&self, _relationship_type: &RelationshipType) {
+        self.invalidate();
+    }
+
+    fn on_relationships_deleted(&self, _relationship_type: &RelationshipType) {
+        self.invalidate();
+    }
+}
+
+impl Clone for GraphStatsCache {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for GraphStatsCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GraphStatsCache")
+            .field("cached", &self.is_cached())
+            .field("computations", &self.computation_count())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(node_count: usize) -> GraphStats {
+        GraphStats {
+            node_count,
+            relationship_count: 0,
+            density: 0.0,
+            min_degree: 0,
+            max_degree: 0,
+            mean_degree: 0.0,
+            component_count: node_count,
+        }
+    }
+
+    #[test]
+    fn computes_once_until_relationships_change() {
+        let cache = GraphStatsCache::new();
+        assert_eq!(cache.get_or_compute(|| stats(3)).node_count, 3);
+        assert_eq!(cache.get_or_compute(|| stats(4)).node_count, 3);
+        assert_eq!(cache.computation_count(), 1);
+
+        cache.on_node_property_added("score");
+        assert!(cache.is_cached());
+
+        cache.on_relationships_deleted(&RelationshipType::of("REL"));
+        assert!(!cache.is_cached());
+        assert_eq!(cache.get_or_compute(|| stats(4)).node_count, 4);
+        assert_eq!(cache.computation_count(), 2);
+    }
+}
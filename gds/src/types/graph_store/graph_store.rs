@@ -3,7 +3,7 @@
 use super::{Capabilities, DatabaseInfo, DeletionResult};
 use crate::projection::{NodeLabel, RelationshipType};
 use crate::types::graph::{Graph, GraphResult};
-use crate::types::graph::id_map::IdMap;
+use crate::types::graph::id_map::{IdMap, MappedNodeId};
 use crate::projection::orientation::Orientation;
 use crate::types::properties::graph::GraphPropertyValues;
 use crate::types::properties::node::NodePropertyValues;
@@ -33,6 +33,15 @@ pub enum GraphStoreError {
 
     #[error("Schema error: {0}")]
     SchemaError(String),
+
+    #[error(
+        "Dangling relationship of type {relationship_type}: {source_node} -> {target_node} references a node missing from the id map"
+    )]
+    DanglingRelationship {
+        relationship_type: String,
+        source_node: MappedNodeId,
+        target_node: MappedNodeId,
+    },
 }
 
 /// Central interface for managing and accessing graph data.
@@ -182,6 +191,49 @@ pub trait GraphStore: Send + Sync {
     /// Returns the number of relationships of a specific type.
     fn relationship_count_for_type(&self, relationship_type: &RelationshipType) -> usize;
 
+    /// Returns the number of relationships seen when traversing with `orientation`.
+    ///
+    /// Undirected relationship types already hold every relationship in both
+    /// directions, so their stored count is used for every orientation.
+    /// Directed types double their count for `Undirected`, as each
+    /// relationship is then traversed both ways. The direction is taken per
+    /// type from the relationship schema.
+    fn relationship_count_for(&self, orientation: Orientation) -> usize {
+        let relationship_schema = self.schema().relationship_schema();
+        self.relationship_types()
+            .iter()
+            .map(|rel_type| {
+                let stored = self.relationship_count_for_type(rel_type);
+                if orientation.is_undirected()
+                    && !relationship_schema.is_undirected_for_type(rel_type)
+                {
+                    stored * 2
+                } else {
+                    stored
+                }
+            })
+            .sum()
+    }
+
+    /// Returns the number of logical relationships.
+    ///
+    /// Undirected relationship types keep each relationship once per
+    /// direction; this counts it once. Directed types are counted as stored.
+    fn logical_relationship_count(&self) -> usize {
+        let relationship_schema = self.schema().relationship_schema();
+        self.relationship_types()
+            .iter()
+            .map(|rel_type| {
+                let stored = self.relationship_count_for_type(rel_type);
+                if relationship_schema.is_undirected_for_type(rel_type) {
+                    stored / 2
+                } else {
+                    stored
+                }
+            })
+            .sum()
+    }
+
     /// Returns all relationship types in the graph store.
     fn relationship_types(&self) -> HashSet<RelationshipType>;
 
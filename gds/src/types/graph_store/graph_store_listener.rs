@@ -0,0 +1,110 @@
+//! GraphStoreListener - Notifications for graph store mutations.
+//!
+//! Dependent caches (degree arrays, CSR views, statistics) subscribe to a store
+//! and invalidate themselves when the data they were derived from changes.
+
+use crate::projection::{NodeLabel, RelationshipType};
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+/// Listener for graph store mutation events.
+///
+/// All methods have empty default implementations so a listener only needs to
+/// override the events it cares about.
+pub trait GraphStoreListener: Send + Sync {
+    /// Called after a graph property was added or replaced.
+    fn on_graph_property_added(&self, _property_key: &str) {}
+
+    /// Called after a graph property was removed.
+    fn on_graph_property_removed(&self, _property_key: &str) {}
+
+    /// Called after a node label was added.
+    fn on_node_label_added(&self, _node_label: &NodeLabel) {}
+
+    /// Called after a node property was added or replaced.
+    fn on_node_property_added(&self, _property_key: &str) {}
+
+    /// Called after a node property was removed.
+    fn on_node_property_removed(&self, _property_key: &str) {}
+
+    /// Called after relationships of the given type were added or replaced.
+    fn on_relationship_added(&self, _relationship_type: &RelationshipType) {}
+
+    /// Called after all relationships of the given type were deleted.
+    fn on_relationships_deleted(&self, _relationship_type: &RelationshipType) {}
+
+    /// Called after a relationship property was added or replaced.
+    fn on_relationship_property_added(
+        &self,
+        _relationship_type: &RelationshipType,
+        _property_key: &str,
+    ) {
+    }
+
+    /// Called after a relationship property was removed.
+    fn on_relationship_property_removed(
+        &self,
+        _relationship_type: &RelationshipType,
+        _property_key: &str,
+    ) {
+    }
+}
+
+/// Registry of [`GraphStoreListener`]s owned by a graph store.
+///
+/// Cloning a registry yields an empty one: a cloned store is a distinct store,
+/// and caches subscribed to the original must not observe its mutations.
+#[derive(Default)]
+pub struct GraphStoreListeners {
+    listeners: RwLock<Vec<Arc<dyn GraphStoreListener>>>,
+}
+
+impl GraphStoreListeners {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a listener.
+    pub fn subscribe(&self, listener: Arc<dyn GraphStoreListener>) {
+        self.listeners.write().unwrap().push(listener);
+    }
+
+    /// Removes a previously registered listener.
+    pub fn unsubscribe(&self, listener: &Arc<dyn GraphStoreListener>) {
+        self.listeners
+            .write()
+            .unwrap()
+            .retain(|l| !Arc::ptr_eq(l, listener));
+    }
+
+    /// Returns the number of registered listeners.
+    pub fn listener_count(&self) -> usize {
+        self.listeners.read().unwrap().len()
+    }
+
+    /// Invokes `event` on every registered listener.
+    pub fn notify<F>(&self, event: F)
+    where
+        F: Fn(&dyn GraphStoreListener),
+    {
+        let listeners = self.listeners.read().unwrap();
+        for listener in listeners.iter() {
+            event(listener.as_ref());
+        }
+    }
+}
+
+impl Clone for GraphStoreListeners {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for GraphStoreListeners {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GraphStoreListeners")
+            .field("listener_count", &self.listener_count())
+            .finish()
+    }
+}
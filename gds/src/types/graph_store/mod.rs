@@ -9,13 +9,25 @@ mod database_id;
 mod database_info;
 mod default_graph_store;
 mod deletion_result;
+mod export;
+mod graph_diff;
 mod graph_name;
+mod graph_snapshot;
+mod graph_stats_cache;
 mod graph_store;
+mod graph_store_listener;
+mod transforms;
+mod weight_normalization;
 
 pub use capabilities::*;
 pub use database_id::*;
 pub use database_info::*;
 pub use default_graph_store::*;
 pub use deletion_result::*;
+pub use graph_diff::*;
 pub use graph_name::*;
+pub use graph_snapshot::*;
+pub use graph_stats_cache::*;
 pub use graph_store::*;
+pub use graph_store_listener::*;
+pub use weight_normalization::*;
@@ -0,0 +1,610 @@
+//! Canonical relabeling and order-independent views of a store.
+
+use super::super::default_graph_store::sorted;
+use super::super::{DefaultGraphStore, GraphStore};
+use crate::projection::RelationshipType;
+use crate::types::graph::id_map::{IdMap, MappedNodeId, SimpleIdMap};
+use crate::types::graph::{canonical_order, RelationshipTopology};
+use crate::types::properties::relationship::impls::default_relationship_property_values::DefaultRelationshipPropertyValues;
+use crate::types::properties::relationship::RelationshipPropertyStore;
+use crate::types::ValueType;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+impl DefaultGraphStore {
+    /// Returns a copy of this store with nodes relabeled into a canonical order.
+    ///
+    /// Nodes are ordered by their label set, then by descending degree and
+    /// their sorted neighbor signature (see [`canonical_order`]), so
+    /// structurally identical stores built with different node id orders
+    /// canonicalize to the same topology and labels.
+    /// The returned mapping holds, for each canonical node id, the original id
+    /// of the node it was relabeled from.
+    ///
+    /// The canonical store carries the topology and relationship properties
+    /// of every relationship type, node labels, `Long`/`Double` node
+    /// properties and graph properties; its schema lists only those. Its
+    /// original node ids are the canonical ids `0..n`.
+    pub fn canonicalize(&self) -> (DefaultGraphStore, Vec<u64>) {
+        let node_count = self.id_map.node_count();
+        let mut rel_types: Vec<&RelationshipType> =
+            self.ordered_relationship_types.iter().collect();
+        rel_types.sort_by_key(|rel_type| rel_type.name());
+        let topologies: Vec<&RelationshipTopology> = rel_types
+            .into_iter()
+            .filter_map(|rel_type| self.relationship_topologies.get(rel_type))
+            .map(|topology| topology.as_ref())
+            .collect();
+        let label_sets: Vec<Vec<String>> = (0..node_count)
+            .map(|node| {
+                sorted(
+                    self.id_map
+                        .node_labels(node as MappedNodeId)
+                        .iter()
+                        .map(|label| label.name().to_string()),
+                )
+            })
+            .collect();
+        let mut distinct_label_sets = label_sets.clone();
+        distinct_label_sets.sort();
+        distinct_label_sets.dedup();
+        let label_colors: Vec<usize> = label_sets
+            .iter()
+            .map(|labels| distinct_label_sets.binary_search(labels).unwrap())
+            .collect();
+        let order = canonical_order(&topologies, node_count, &label_colors);
+
+        let mut canonical_id = vec![0i64; node_count];
+        for (new_id, &old_id) in order.iter().enumerate() {
+            canonical_id[old_id] = new_id as i64;
+        }
+        let mut relationship_topologies = HashMap::new();
+        let mut permuted_properties = Vec::new();
+        for rel_type in &self.ordered_relationship_types {
+            let topology = &self.relationship_topologies[rel_type];
+
+            // Index of the first outgoing relationship of every old node
+            let mut offsets = vec![0u64; node_count + 1];
+            for node in 0..node_count {
+                let degree = topology.outgoing(node as i64).unwrap_or_default().len();
+                offsets[node + 1] = offsets[node] + degree as u64;
+            }
+            // Old relationship index of every relationship in canonical order
+            let mut permutation = Vec::with_capacity(offsets[node_count] as usize);
+            let outgoing = order
+                .iter()
+                .map(|&old_id| {
+                    let mut targets: Vec<(i64, u64)> = topology
+                        .outgoing(old_id as i64)
+                        .unwrap_or_default()
+                        .iter()
+                        .enumerate()
+                        .map(|(offset, &target)| {
+                            (
+                                canonical_id[target as usize],
+                                offsets[old_id] + offset as u64,
+                            )
+                        })
+                        .collect();
+                    targets.sort_unstable();
+                    permutation.extend(targets.iter().map(|&(_, index)| index));
+                    targets.into_iter().map(|(target, _)| target).collect()
+                })
+                .collect();
+            let incoming = topology
+                .is_inverse_indexed()
+                .then(|| relabel_adjacency(&order, &canonical_id, |node| topology.incoming(node)));
+            relationship_topologies.insert(
+                rel_type.clone(),
+                RelationshipTopology::new(outgoing, incoming),
+            );
+
+            if let Some(properties) = self.relationship_property_stores.get(rel_type) {
+                for property in properties.get_all_properties() {
+                    let values = property.values();
+                    let permuted: Vec<f64> = permutation
+                        .iter()
+                        .map(|&index| values.double_value(index).unwrap_or(values.default_value()))
+                        .collect();
+                    permuted_properties.push((
+                        rel_type.clone(),
+                        property.key().to_string(),
+                        permuted,
+                    ));
+                }
+            }
+        }
+
+        let mut id_map = SimpleIdMap::from_original_ids(0..node_count as i64);
+        for (new_id, &old_id) in order.iter().enumerate() {
+            for label in self.id_map.node_labels(old_id as i64) {
+                id_map.add_node_id_to_label(new_id as i64, label);
+            }
+        }
+
+        let mut store = self.rebuild_with(
+            self.schema_retaining(
+                |key| {
+                    self.node_properties.get(key).is_some_and(|values| {
+                        matches!(values.value_type(), ValueType::Long | ValueType::Double)
+                    })
+                },
+                |_, _| true,
+            ),
+            Some(id_map),
+            relationship_topologies,
+        );
+
+        let mut property_keys: Vec<&String> = self.node_properties.keys().collect();
+        property_keys.sort();
+        for key in property_keys {
+            let values = &self.node_properties[key];
+            // Property writes on a fresh store with matching node count cannot fail.
+            match values.value_type() {
+                ValueType::Long => {
+                    let permuted = order
+                        .iter()
+                        .map(|&old_id| values.long_value(old_id as u64).unwrap_or_default())
+                        .collect();
+                    let _ = store.add_node_property_i64(key.clone(), permuted);
+                }
+                ValueType::Double => {
+                    let permuted = order
+                        .iter()
+                        .map(|&old_id| values.double_value(old_id as u64).unwrap_or(f64::NAN))
+                        .collect();
+                    let _ = store.add_node_property_f64(key.clone(), permuted);
+                }
+                _ => {}
+            }
+        }
+        for (rel_type, property_key, values) in permuted_properties {
+            let count = values.len();
+            // Adding a property to a fresh store cannot fail.
+            let _ = store.add_relationship_property(
+                rel_type,
+                property_key,
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    values, count,
+                )),
+            );
+        }
+
+        let mapping = order
+            .iter()
+            .map(|&old_id| {
+                self.id_map
+                    .to_original_node_id(old_id as i64)
+                    .unwrap_or(old_id as i64) as u64
+            })
+            .collect();
+
+        (store, mapping)
+    }
+
+    /// Returns every relationship as `(source, target, type, weight)`, sorted
+    /// by source, then target, then type name.
+    ///
+    /// The order depends only on the stored relationships, not on the order
+    /// they were added in, so exports and hashes built from it are
+    /// deterministic. The weight is the relationship property that
+    /// [`DefaultGraphStore::graph`] selects for the type, or `None` if the
+    /// type has no single property. Parallel edges are ordered by weight.
+    pub fn relationships_sorted(
+        &self,
+    ) -> impl Iterator<Item = (u64, u64, RelationshipType, Option<f64>)> {
+        let mut relationships = Vec::with_capacity(self.relationship_count);
+        for rel_type in &self.ordered_relationship_types {
+            let topology = &self.relationship_topologies[rel_type];
+            let weights = self
+                .relationship_property_stores
+                .get(rel_type)
+                .filter(|store| store.len() == 1)
+                .and_then(|store| store.get_all_properties().into_iter().next())
+                .map(|property| property.values());
+
+            let mut index = 0u64;
+            for source in 0..topology.node_capacity() as MappedNodeId {
+                for &target in topology.outgoing(source).unwrap_or_default() {
+                    let weight = weights.and_then(|values| values.double_value(index).ok());
+                    relationships.push((source as u64, target as u64, rel_type.clone(), weight));
+                    index += 1;
+                }
+            }
+        }
+
+        relationships.sort_by(|l, r| {
+            (l.0, l.1, l.2.name())
+                .cmp(&(r.0, r.1, r.2.name()))
+                .then_with(|| match (l.3, r.3) {
+                    (Some(lw), Some(rw)) => lw.total_cmp(&rw),
+                    (lw, rw) => lw.is_some().cmp(&rw.is_some()),
+                })
+        });
+        relationships.into_iter()
+    }
+
+    /// Hashes the structure of this store independently of node ids.
+    ///
+    /// Covers the [canonical](DefaultGraphStore::canonicalize) topology of
+    /// every relationship type, the labels of every canonical node, and the
+    /// graph, node and relationship property keys. Property values and
+    /// original ids are ignored, so isomorphic stores with the same schema
+    /// hash equally and tests can compare stores without pairwise checks.
+    pub fn structural_hash(&self) -> u64 {
+        let (canonical, _) = self.canonicalize();
+        let mut hasher = DefaultHasher::new();
+
+        canonical.id_map.node_count().hash(&mut hasher);
+        let mut rel_types: Vec<&RelationshipType> =
+            canonical.ordered_relationship_types.iter().collect();
+        rel_types.sort_by_key(|rel_type| rel_type.name());
+        for rel_type in rel_types {
+            rel_type.name().hash(&mut hasher);
+            let topology = &canonical.relationship_topologies[rel_type];
+            for node in 0..topology.node_capacity() {
+                topology.outgoing(node as i64).hash(&mut hasher);
+            }
+            sorted(self.relationship_property_keys_for_type(rel_type)).hash(&mut hasher);
+        }
+        for node in 0..canonical.id_map.node_count() {
+            let labels = canonical.id_map.node_labels(node as i64);
+            sorted(labels.iter().map(|label| label.name().to_string())).hash(&mut hasher);
+        }
+        sorted(self.node_properties.keys().cloned()).hash(&mut hasher);
+        sorted(self.graph_properties.keys().cloned()).hash(&mut hasher);
+
+        hasher.finish()
+    }
+}
+
+/// Adjacency lists in canonical order, with targets relabeled and sorted.
+fn relabel_adjacency<'a>(
+    order: &[usize],
+    canonical_id: &[i64],
+    lists: impl Fn(i64) -> Option<&'a [i64]>,
+) -> Vec<Vec<i64>> {
+    order
+        .iter()
+        .map(|&old_id| {
+            let mut targets: Vec<i64> = lists(old_id as i64)
+                .unwrap_or_default()
+                .iter()
+                .map(|&target| canonical_id[target as usize])
+                .collect();
+            targets.sort_unstable();
+            targets
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projection::NodeLabel;
+    use crate::types::graph::degrees::Degrees;
+    use crate::types::graph_store::{
+        Capabilities, DatabaseId, DatabaseInfo, DatabaseLocation, GraphName,
+    };
+    use crate::types::random::generators::typed_store;
+    use crate::types::schema::{Direction, GraphSchema, MutableGraphSchema};
+    use std::collections::HashSet;
+
+    fn sample_store() -> DefaultGraphStore {
+        typed_store(
+            "g",
+            3,
+            [("KNOWS", Direction::Directed, vec![(0, 1), (0, 2), (1, 2)])],
+        )
+    }
+
+    /// Undirected graph over `edges` with every node `n` renamed to `relabel[n]`
+    fn store_from_edges(edges: &[(usize, usize)], relabel: &[usize]) -> DefaultGraphStore {
+        let relabeled: Vec<(usize, usize)> = edges
+            .iter()
+            .map(|&(source, target)| (relabel[source], relabel[target]))
+            .collect();
+        crate::types::random::generators::typed_store(
+            "g",
+            relabel.len(),
+            [("REL", Direction::Undirected, relabeled)],
+        )
+    }
+
+    fn canonical_lists(store: &DefaultGraphStore) -> Vec<Vec<i64>> {
+        let topology = &store.relationship_topologies[&RelationshipType::of("REL")];
+        (0..store.node_count())
+            .map(|node| topology.outgoing(node as i64).unwrap().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn canonicalizes_isomorphic_graphs_to_same_topology() {
+        // A "house" (square 0-1-2-3 with roof 4 on 0 and 1), a pendant 5 on 2
+        // and a hexagon 6..12 hanging off node 3.
+        let mut edges = vec![
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 0),
+            (4, 0),
+            (4, 1),
+            (5, 2),
+            (3, 6),
+        ];
+        for i in 0..6 {
+            edges.push((6 + i, 6 + (i + 1) % 6));
+        }
+        let identity: Vec<usize> = (0..12).collect();
+        let shuffled = vec![7, 3, 11, 0, 9, 5, 1, 10, 2, 8, 4, 6];
+
+        let original = store_from_edges(&edges, &identity);
+        let permuted = store_from_edges(&edges, &shuffled);
+        assert_ne!(canonical_lists(&original), canonical_lists(&permuted));
+
+        let (canonical_a, mapping_a) = original.canonicalize();
+        let (canonical_b, mapping_b) = permuted.canonicalize();
+        assert_eq!(canonical_lists(&canonical_a), canonical_lists(&canonical_b));
+
+        // Canonical node 0 is the highest-degree node: node 3.
+        assert_eq!(mapping_a[0], 3);
+        assert_eq!(mapping_b[0], shuffled[3] as u64);
+        let graph = original.graph();
+        for (new_id, &original_id) in mapping_a.iter().enumerate() {
+            let old_id = graph.safe_to_mapped_node_id(original_id as i64).unwrap();
+            assert_eq!(
+                canonical_a.graph().degree(new_id as i64),
+                graph.degree(old_id)
+            );
+        }
+    }
+
+    #[test]
+    fn canonicalize_carries_relationship_properties() {
+        let edges = [
+            (0, 1, 1.0),
+            (0, 2, 2.0),
+            (1, 0, 3.0),
+            (2, 3, 4.0),
+            (3, 2, 5.0),
+            (3, 0, 6.0),
+            (3, 1, 7.0),
+        ];
+        let mut sorted_edges = edges.to_vec();
+        sorted_edges.sort_by_key(|&(source, target, _)| (source, target));
+        let mut store = crate::types::random::generators::typed_store(
+            "g",
+            4,
+            [(
+                "REL",
+                Direction::Directed,
+                sorted_edges
+                    .iter()
+                    .map(|&(source, target, _)| (source, target))
+                    .collect(),
+            )],
+        );
+        store
+            .add_relationship_property(
+                RelationshipType::of("REL"),
+                "weight",
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    sorted_edges.iter().map(|&(_, _, weight)| weight).collect(),
+                    edges.len(),
+                )),
+            )
+            .unwrap();
+
+        let (canonical, mapping) = store.canonicalize();
+
+        let relabeled: HashSet<(u64, u64, u64)> = canonical
+            .relationships_sorted()
+            .map(|(source, target, _, weight)| {
+                (
+                    mapping[source as usize],
+                    mapping[target as usize],
+                    weight.unwrap().to_bits(),
+                )
+            })
+            .collect();
+        let expected: HashSet<(u64, u64, u64)> = edges
+            .iter()
+            .map(|&(source, target, weight)| (source as u64, target as u64, f64::to_bits(weight)))
+            .collect();
+        assert_eq!(relabeled, expected);
+        assert_eq!(mapping[0], 3);
+    }
+
+    #[test]
+    fn canonicalize_schema_lists_only_carried_properties() {
+        use crate::collections::backends::vec::VecDoubleArray;
+        use crate::types::properties::node::DefaultDoubleArrayNodePropertyValues;
+
+        let mut store = sample_store();
+        let mut schema = MutableGraphSchema::from_schema(&store.schema);
+        schema
+            .node_schema_mut()
+            .get_or_create_label(NodeLabel::all_nodes())
+            .add_property("age", ValueType::Long)
+            .add_property("embedding", ValueType::DoubleArray);
+        store.schema = Arc::new(schema.build());
+        store
+            .add_node_property_i64("age".to_string(), vec![30, 40, 50])
+            .unwrap();
+        let embedding = DefaultDoubleArrayNodePropertyValues::from_collection(
+            VecDoubleArray::from(vec![Some(vec![1.0, 2.0]); 3]),
+            3,
+        );
+        store
+            .add_node_property(
+                HashSet::from([NodeLabel::all_nodes()]),
+                "embedding",
+                Arc::new(embedding),
+            )
+            .unwrap();
+
+        let (canonical, _) = store.canonicalize();
+
+        let keys: HashSet<String> = canonical
+            .schema()
+            .node_schema()
+            .get(&NodeLabel::all_nodes())
+            .unwrap()
+            .properties()
+            .keys()
+            .cloned()
+            .collect();
+        assert_eq!(keys, HashSet::from(["age".to_string()]));
+        assert!(canonical.has_node_property("age"));
+        assert!(!canonical.has_node_property("embedding"));
+        assert_eq!(
+            canonical.schema().relationship_schema().directions(),
+            store.schema().relationship_schema().directions()
+        );
+    }
+
+    /// Store over `adjacency` of type `REL` where the nodes in `labeled` carry label `A`
+    fn labeled_store(adjacency: Vec<Vec<i64>>, labeled: &[i64]) -> DefaultGraphStore {
+        let node_count = adjacency.len() as i64;
+        let mut id_map = SimpleIdMap::from_original_ids(0..node_count);
+        for &node in labeled {
+            id_map.add_node_id_to_label(node, NodeLabel::of("A"));
+        }
+        let mut relationship_topologies = HashMap::new();
+        relationship_topologies.insert(
+            RelationshipType::of("REL"),
+            RelationshipTopology::new(adjacency, None),
+        );
+        DefaultGraphStore::new(
+            crate::config::GraphStoreConfig::default(),
+            GraphName::new("g"),
+            DatabaseInfo::new(
+                DatabaseId::new("db"),
+                DatabaseLocation::remote("localhost", 7687, None, None),
+            ),
+            GraphSchema::empty(),
+            Capabilities::default(),
+            id_map,
+            relationship_topologies,
+        )
+    }
+
+    #[test]
+    fn structural_hash_agrees_on_isomorphic_stores_with_symmetric_ties() {
+        // Two directed 3-cycles and a 2-cycle: all nodes tie after refinement.
+        let cycles = vec![
+            vec![1],
+            vec![2],
+            vec![0],
+            vec![4],
+            vec![5],
+            vec![3],
+            vec![7],
+            vec![6],
+        ];
+        // The same graph with node i renamed to renamed[i].
+        let renamed = [6, 3, 7, 5, 0, 1, 2, 4];
+        let mut renamed_cycles = vec![Vec::new(); 8];
+        for (node, targets) in cycles.iter().enumerate() {
+            renamed_cycles[renamed[node] as usize] = targets
+                .iter()
+                .map(|&target| renamed[target as usize])
+                .collect();
+        }
+
+        let hash = labeled_store(cycles.clone(), &[4]).structural_hash();
+        assert_eq!(
+            hash,
+            labeled_store(renamed_cycles.clone(), &[renamed[4]]).structural_hash()
+        );
+        assert_eq!(
+            labeled_store(cycles.clone(), &[]).structural_hash(),
+            labeled_store(renamed_cycles, &[]).structural_hash()
+        );
+        // Labels seed the refinement: a labeled 2-cycle node is not isomorphic.
+        assert_ne!(hash, labeled_store(cycles, &[6]).structural_hash());
+    }
+
+    #[test]
+    fn structural_hash_is_stable_and_detects_new_edges() {
+        let config = crate::types::random::RandomGraphConfig {
+            seed: Some(11),
+            node_count: 20,
+            relationships: vec![crate::types::random::RandomRelationshipConfig::new(
+                "REL", 0.2,
+            )],
+            ..crate::types::random::RandomGraphConfig::default()
+        };
+        let store = DefaultGraphStore::random(&config).unwrap();
+        let hash = store.structural_hash();
+        assert_eq!(hash, store.structural_hash());
+        assert_eq!(
+            hash,
+            DefaultGraphStore::random(&config)
+                .unwrap()
+                .structural_hash()
+        );
+
+        let rel_type = RelationshipType::of("REL");
+        let topology = &store.relationship_topologies[&rel_type];
+        let mut adjacency: Vec<Vec<i64>> = (0..store.node_count() as i64)
+            .map(|node| topology.outgoing(node).unwrap_or_default().to_vec())
+            .collect();
+        let missing = (1..20)
+            .find(|target| !adjacency[0].contains(target))
+            .unwrap();
+        adjacency[0].push(missing);
+
+        let mut extended = store.clone();
+        extended.add_relationship_type(rel_type, RelationshipTopology::new(adjacency, None));
+        assert_ne!(hash, extended.structural_hash());
+    }
+
+    #[test]
+    fn relationships_sorted_is_independent_of_input_order() {
+        let build = |knows: Vec<Vec<i64>>, likes: Vec<Vec<i64>>| {
+            let mut store = sample_store();
+            store.add_relationship_type(
+                RelationshipType::of("KNOWS"),
+                RelationshipTopology::new(knows, None),
+            );
+            store.add_relationship_type(
+                RelationshipType::of("LIKES"),
+                RelationshipTopology::new(likes, None),
+            );
+            store
+        };
+        let store = build(
+            vec![vec![2, 1], vec![2], vec![]],
+            vec![vec![1], vec![], vec![0]],
+        );
+        let reordered = build(
+            vec![vec![1, 2], vec![2], vec![]],
+            vec![vec![1], vec![], vec![0]],
+        );
+
+        let edges: Vec<_> = store
+            .relationships_sorted()
+            .map(|(source, target, rel_type, weight)| {
+                (source, target, rel_type.name().to_string(), weight)
+            })
+            .collect();
+        let knows = |s, t| (s, t, "KNOWS".to_string(), None);
+        let likes = |s, t| (s, t, "LIKES".to_string(), None);
+        assert_eq!(
+            edges,
+            vec![
+                knows(0, 1),
+                likes(0, 1),
+                knows(0, 2),
+                knows(1, 2),
+                likes(2, 0)
+            ]
+        );
+        assert!(store
+            .relationships_sorted()
+            .eq(reordered.relationships_sorted()));
+    }
+}
@@ -0,0 +1,252 @@
+//! Coarsening matched node pairs into weighted supernodes.
+
+use super::super::{DefaultGraphStore, GraphStore, GraphStoreError, GraphStoreResult};
+use crate::collections::HugeLongArray;
+use crate::types::graph::id_map::{IdMap, MappedNodeId, SimpleIdMap};
+use crate::types::graph::RelationshipTopology;
+use crate::types::properties::relationship::impls::default_relationship_property_values::DefaultRelationshipPropertyValues;
+use crate::types::properties::relationship::RelationshipPropertyStore;
+use crate::types::schema::{Direction, MutableGraphSchema};
+use crate::types::ValueType;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+impl DefaultGraphStore {
+    /// Contracts the node pairs of `matching` into supernodes.
+    ///
+    /// Pairs sharing a node are contracted together, and supernodes are
+    /// numbered in order of their smallest member. Each relationship type is
+    /// contracted on its own: relationships between the same two supernodes
+    /// are merged into one whose `weight_property` is the sum of their
+    /// weights, and relationships inside a supernode become a self-loop so the
+    /// total weight is preserved. Types without `weight_property` count every
+    /// relationship as `1.0`.
+    ///
+    /// Supernodes have no labels or node properties, and `weight_property` is
+    /// the only relationship property left; the schema is rebuilt to match.
+    /// Graph properties are kept.
+    ///
+    /// Returns the coarsened store and the supernode of every node, or
+    /// [`GraphStoreError::InvalidOperation`] if a pair references a node the
+    /// store does not have.
+    pub fn coarsen(
+        &self,
+        matching: &[(u64, u64)],
+        weight_property: &str,
+    ) -> GraphStoreResult<(DefaultGraphStore, HugeLongArray)> {
+        let node_count = self.id_map.node_count();
+        if let Some(&(a, b)) = matching
+            .iter()
+            .find(|&&(a, b)| a.max(b) >= node_count as u64)
+        {
+            return Err(GraphStoreError::InvalidOperation(format!(
+                "matching pair ({}, {}) references a node outside 0..{}",
+                a, b, node_count
+            )));
+        }
+
+        let mut parent: Vec<usize> = (0..node_count).collect();
+        fn find(parent: &mut [usize], mut node: usize) -> usize {
+            while parent[node] != node {
+                parent[node] = parent[parent[node]];
+                node = parent[node];
+            }
+            node
+        }
+        for &(a, b) in matching {
+            let (root_a, root_b) = (find(&mut parent, a as usize), find(&mut parent, b as usize));
+            parent[root_a.max(root_b)] = root_a.min(root_b);
+        }
+
+        let mut supernode = HugeLongArray::new(node_count);
+        let mut supernode_of_root = vec![usize::MAX; node_count];
+        let mut supernode_count = 0;
+        for node in 0..node_count {
+            let root = find(&mut parent, node);
+            if supernode_of_root[root] == usize::MAX {
+                supernode_of_root[root] = supernode_count;
+                supernode_count += 1;
+            }
+            supernode.set(node, supernode_of_root[root] as i64);
+        }
+
+        let mut schema = MutableGraphSchema::empty();
+        for (key, property) in self.schema.graph_properties() {
+            schema.put_graph_property(key.clone(), property.clone());
+        }
+
+        let mut relationship_topologies = HashMap::new();
+        let mut weights = Vec::new();
+        for rel_type in &self.ordered_relationship_types {
+            let topology = &self.relationship_topologies[rel_type];
+            let values = self
+                .relationship_property_stores
+                .get(rel_type)
+                .and_then(|store| store.get_property_values(weight_property));
+
+            let mut merged: Vec<BTreeMap<i64, f64>> = vec![BTreeMap::new(); supernode_count];
+            let mut index = 0u64;
+            for source in 0..topology.node_capacity() as MappedNodeId {
+                let coarse_source = supernode.get(source as usize) as usize;
+                for &target in topology.outgoing(source).unwrap_or_default() {
+                    let weight =
+                        values.map_or(1.0, |values| values.double_value(index).unwrap_or(1.0));
+                    *merged[coarse_source]
+                        .entry(supernode.get(target as usize))
+                        .or_insert(0.0) += weight;
+                    index += 1;
+                }
+            }
+
+            let outgoing: Vec<Vec<i64>> = merged
+                .iter()
+                .map(|targets| targets.keys().copied().collect())
+                .collect();
+            let incoming = topology.is_inverse_indexed().then(|| {
+                let mut incoming = vec![Vec::new(); supernode_count];
+                for (source, targets) in outgoing.iter().enumerate() {
+                    for &target in targets {
+                        incoming[target as usize].push(source as i64);
+                    }
+                }
+                incoming
+            });
+            relationship_topologies.insert(
+                rel_type.clone(),
+                RelationshipTopology::new(outgoing, incoming),
+            );
+            weights.push((
+                rel_type.clone(),
+                merged
+                    .iter()
+                    .flat_map(|targets| targets.values().copied())
+                    .collect::<Vec<f64>>(),
+            ));
+
+            let direction = if self
+                .schema
+                .relationship_schema()
+                .is_undirected_for_type(rel_type)
+            {
+                Direction::Undirected
+            } else {
+                Direction::Directed
+            };
+            schema
+                .relationship_schema_mut()
+                .get_or_create_type(rel_type.clone(), direction)
+                .add_property(weight_property, ValueType::Double);
+        }
+
+        let mut store = self.rebuild_with(
+            schema.build(),
+            Some(SimpleIdMap::from_original_ids(0..supernode_count as i64)),
+            relationship_topologies,
+        );
+        for (rel_type, values) in weights {
+            let count = values.len();
+            // Adding a property to a fresh store cannot fail.
+            let _ = store.add_relationship_property(
+                rel_type,
+                weight_property,
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    values, count,
+                )),
+            );
+        }
+
+        Ok((store, supernode))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projection::RelationshipType;
+    use crate::types::properties::relationship::RelationshipIterator;
+    use crate::types::schema::PropertySchemaTrait;
+
+    #[test]
+    fn coarsens_matched_nodes_into_weighted_supernodes() {
+        let rel_type = RelationshipType::of("REL");
+        let mut store = crate::types::random::generators::typed_store(
+            "g",
+            4,
+            [(
+                "REL",
+                Direction::Directed,
+                vec![(0, 1), (0, 2), (1, 2), (1, 3), (2, 3)],
+            )],
+        );
+        store
+            .add_relationship_property(
+                rel_type.clone(),
+                "cost",
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    vec![1.0, 2.0, 3.0, 4.0, 5.0],
+                    5,
+                )),
+            )
+            .unwrap();
+        store
+            .add_node_property_i64("age".to_string(), vec![30, 40, 50, 60])
+            .unwrap();
+
+        let (coarse, supernode) = store.coarsen(&[(1, 2)], "cost").unwrap();
+
+        assert_eq!(supernode.to_vec(), vec![0, 1, 1, 2]);
+        assert_eq!(coarse.node_count(), 3);
+        assert_eq!(coarse.relationship_count(), 3);
+
+        let graph = coarse.graph();
+        let edges: Vec<(i64, i64, f64)> = (0..3)
+            .flat_map(|node| graph.stream_relationships(node, 0.0))
+            .map(|cursor| (cursor.source_id(), cursor.target_id(), cursor.property()))
+            .collect();
+        // 0 -> {1, 2} merges 1.0 + 2.0, the contracted edge 1 -> 2 becomes a
+        // self-loop, and 1 -> 3, 2 -> 3 merge 4.0 + 5.0.
+        assert_eq!(edges, vec![(0, 1, 3.0), (1, 1, 3.0), (1, 2, 9.0)]);
+
+        let entry = coarse
+            .schema()
+            .relationship_schema()
+            .get(&rel_type)
+            .unwrap();
+        let keys: Vec<&String> = entry.properties().keys().collect();
+        assert_eq!(keys, vec!["cost"]);
+        assert_eq!(entry.properties()["cost"].value_type(), ValueType::Double);
+        assert!(coarse.schema().node_schema().available_labels().is_empty());
+        assert!(!coarse.has_node_property("age"));
+    }
+
+    #[test]
+    fn coarsen_counts_relationships_without_the_weight_property_as_one() {
+        let store = crate::types::random::generators::typed_store(
+            "g",
+            3,
+            [("REL", Direction::Directed, vec![(0, 2), (1, 2)])],
+        );
+
+        let (coarse, _) = store.coarsen(&[(0, 1)], "cost").unwrap();
+
+        let graph = coarse.graph();
+        let edges: Vec<(i64, i64, f64)> = graph
+            .stream_relationships(0, 0.0)
+            .map(|cursor| (cursor.source_id(), cursor.target_id(), cursor.property()))
+            .collect();
+        assert_eq!(edges, vec![(0, 1, 2.0)]);
+        assert!(coarse.has_relationship_property(&RelationshipType::of("REL"), "cost"));
+    }
+
+    #[test]
+    fn coarsen_rejects_matching_ids_outside_the_store() {
+        let store = DefaultGraphStore::ring(4);
+
+        match store.coarsen(&[(0, 1), (3, 4)], "weight") {
+            Err(GraphStoreError::InvalidOperation(message)) => {
+                assert!(message.contains("(3, 4)"), "{}", message);
+            }
+            other => panic!("Expected InvalidOperation, got {:?}", other.map(|_| ())),
+        }
+    }
+}
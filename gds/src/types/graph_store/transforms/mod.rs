@@ -0,0 +1,98 @@
+//! Transforms - Derived copies and summaries of a [`DefaultGraphStore`].
+//!
+//! Each submodule adds an `impl DefaultGraphStore` block for one family of
+//! operations; the store itself keeps only storage and mutation:
+//!
+//! - `canonical` - canonical relabeling, sorted relationships, structural hash
+//! - `coarsen` - contracting matched node pairs into supernodes
+//! - `randomize` - degree-preserving edge swaps
+//! - `subgraph` - node filters and induced subgraphs
+//! - `weights` - filtering and normalizing relationship weights
+//! - `statistics` - topology stats, property summaries and histograms
+//! - `series` - time-indexed graph-level scalars
+
+mod canonical;
+mod coarsen;
+mod randomize;
+mod series;
+mod statistics;
+mod subgraph;
+mod weights;
+
+use super::DefaultGraphStore;
+use crate::projection::RelationshipType;
+use crate::types::graph::id_map::SimpleIdMap;
+use crate::types::graph::RelationshipTopology;
+use crate::types::schema::{GraphSchema, MutableGraphSchema};
+use std::collections::HashMap;
+
+impl DefaultGraphStore {
+    /// Builds a store with this store's configuration, name, database and
+    /// graph properties around `schema` and `relationship_topologies`.
+    ///
+    /// Without a new `id_map` the nodes are kept together with their labels
+    /// and node properties. A new id map renumbers the nodes, so carrying
+    /// node properties over is left to the caller.
+    fn rebuild_with(
+        &self,
+        schema: GraphSchema,
+        id_map: Option<SimpleIdMap>,
+        relationship_topologies: HashMap<RelationshipType, RelationshipTopology>,
+    ) -> DefaultGraphStore {
+        let keeps_nodes = id_map.is_none();
+        let mut store = DefaultGraphStore::new(
+            (*self.config).clone(),
+            self.graph_name.clone(),
+            self.database_info.clone(),
+            schema,
+            self.capabilities.clone(),
+            id_map.unwrap_or_else(|| (*self.id_map).clone()),
+            relationship_topologies,
+        );
+        if keeps_nodes {
+            store.node_properties = self.node_properties.clone();
+            store.node_properties_by_label = self.node_properties_by_label.clone();
+        }
+        store.graph_properties = self.graph_properties.clone();
+        store.graph_property_series = self.graph_property_series.clone();
+        store
+    }
+
+    /// Returns the schema without the node properties and relationship
+    /// properties rejected by `keep_node_property` and
+    /// `keep_relationship_property`.
+    fn schema_retaining(
+        &self,
+        keep_node_property: impl Fn(&str) -> bool,
+        keep_relationship_property: impl Fn(&RelationshipType, &str) -> bool,
+    ) -> GraphSchema {
+        let mut schema = MutableGraphSchema::from_schema(&self.schema);
+        for label in schema.node_schema().available_labels() {
+            let entry = schema.node_schema_mut().get_or_create_label(label);
+            let dropped: Vec<String> = entry
+                .properties()
+                .keys()
+                .filter(|key| !keep_node_property(key))
+                .cloned()
+                .collect();
+            for key in dropped {
+                entry.remove_property(&key);
+            }
+        }
+        for (rel_type, direction) in self.schema.relationship_schema().directions() {
+            let entry = schema
+                .relationship_schema_mut()
+                .get_or_create_type(rel_type.clone(), direction);
+            let dropped: Vec<String> = entry
+                .properties()
+                .keys()
+                .filter(|key| !keep_relationship_property(&rel_type, key))
+                .cloned()
+                .collect();
+            for key in dropped {
+                entry.remove_property(&key);
+            }
+        }
+        schema.build()
+    }
+}
@@ -0,0 +1,252 @@
+//! Degree-preserving randomization through double edge swaps.
+
+use super::super::DefaultGraphStore;
+use crate::types::graph::id_map::{IdMap, MappedNodeId};
+use crate::types::graph::RelationshipTopology;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+
+impl DefaultGraphStore {
+    /// Randomizes the relationships while keeping every node's degree.
+    ///
+    /// Builds a configuration model null model through double edge swaps:
+    /// two relationships `a -> b` and `c -> d` become `a -> d` and `c -> b`,
+    /// leaving out- and in-degrees unchanged. Swaps that would create a
+    /// self-loop or duplicate an existing relationship are rejected. Each
+    /// relationship type is shuffled on its own with ten swap attempts per
+    /// relationship; self-loops already present stay in place. Undirected
+    /// stores swap every relationship once and store it in both directions.
+    ///
+    /// Nodes, labels, node and graph properties are kept. Relationship
+    /// properties are dropped, from the schema as well, as their values no
+    /// longer belong to an edge.
+    /// The result depends only on the store and `seed`.
+    pub fn randomize_preserving_degrees(&self, seed: u64) -> DefaultGraphStore {
+        let node_count = self.id_map.node_count();
+        let undirected = self.schema.direction().is_undirected();
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let mut relationship_topologies = HashMap::new();
+        for rel_type in &self.ordered_relationship_types {
+            let topology = &self.relationship_topologies[rel_type];
+
+            let mut edges = Vec::new();
+            let mut outgoing: Vec<Vec<i64>> = vec![Vec::new(); node_count];
+            for source in 0..topology.node_capacity() as MappedNodeId {
+                for &target in topology.outgoing(source).unwrap_or_default() {
+                    if source == target {
+                        outgoing[source as usize].push(target);
+                    } else if !undirected || source < target {
+                        edges.push((source, target));
+                    }
+                }
+            }
+
+            swap_edges(&mut edges, undirected, &mut rng);
+
+            for (source, target) in edges {
+                outgoing[source as usize].push(target);
+                if undirected {
+                    outgoing[target as usize].push(source);
+                }
+            }
+            for targets in &mut outgoing {
+                targets.sort_unstable();
+            }
+            let incoming = topology.is_inverse_indexed().then(|| {
+                let mut incoming = vec![Vec::new(); node_count];
+                for (source, targets) in outgoing.iter().enumerate() {
+                    for &target in targets {
+                        incoming[target as usize].push(source as i64);
+                    }
+                }
+                incoming
+            });
+            relationship_topologies.insert(
+                rel_type.clone(),
+                RelationshipTopology::new(outgoing, incoming),
+            );
+        }
+
+        self.rebuild_with(
+            self.schema_retaining(|_| true, |_, _| false),
+            None,
+            relationship_topologies,
+        )
+    }
+}
+
+/// Double edge swaps for [`DefaultGraphStore::randomize_preserving_degrees`].
+///
+/// Undirected edges may be swapped either way round, and are compared
+/// regardless of their direction.
+fn swap_edges(edges: &mut [(i64, i64)], undirected: bool, rng: &mut StdRng) {
+    if edges.len() < 2 {
+        return;
+    }
+    let key = |(source, target): (i64, i64)| {
+        if undirected {
+            (source.min(target), source.max(target))
+        } else {
+            (source, target)
+        }
+    };
+
+    let mut present: HashMap<(i64, i64), usize> = HashMap::new();
+    for &edge in edges.iter() {
+        *present.entry(key(edge)).or_insert(0) += 1;
+    }
+
+    for _ in 0..edges.len() * 10 {
+        let (i, j) = (rng.gen_range(0..edges.len()), rng.gen_range(0..edges.len()));
+        if i == j {
+            continue;
+        }
+        let (a, b) = edges[i];
+        let (mut c, mut d) = edges[j];
+        if undirected && rng.gen_bool(0.5) {
+            std::mem::swap(&mut c, &mut d);
+        }
+        let (first, second) = ((a, d), (c, b));
+        if a == d
+            || c == b
+            || key(first) == key(second)
+            || present.contains_key(&key(first))
+            || present.contains_key(&key(second))
+        {
+            continue;
+        }
+
+        for old in [edges[i], edges[j]] {
+            if let Some(count) = present.get_mut(&key(old)) {
+                *count -= 1;
+                if *count == 0 {
+                    present.remove(&key(old));
+                }
+            }
+        }
+        present.insert(key(first), 1);
+        present.insert(key(second), 1);
+        edges[i] = first;
+        edges[j] = second;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projection::RelationshipType;
+    use crate::types::graph_store::GraphStore;
+
+    fn adjacency(store: &DefaultGraphStore) -> Vec<Vec<i64>> {
+        let topology = store.relationship_topologies.values().next().unwrap();
+        (0..store.node_count() as i64)
+            .map(|node| topology.outgoing(node).unwrap_or_default().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn test_randomize_preserving_degrees_directed() {
+        use crate::types::random::{RandomGraphConfig, RandomRelationshipConfig};
+
+        let config = RandomGraphConfig {
+            node_count: 40,
+            relationships: vec![RandomRelationshipConfig::new("REL", 0.2)],
+            ..RandomGraphConfig::default()
+        }
+        .with_seed(21);
+        let store = DefaultGraphStore::random(&config).unwrap();
+
+        let randomized = store.randomize_preserving_degrees(7);
+
+        let topology = &store.relationship_topologies[&RelationshipType::of("REL")];
+        let shuffled = &randomized.relationship_topologies[&RelationshipType::of("REL")];
+        for node in 0..40 {
+            assert_eq!(
+                topology.outgoing(node).unwrap().len(),
+                shuffled.outgoing(node).unwrap().len()
+            );
+            assert_eq!(
+                topology.incoming(node).unwrap().len(),
+                shuffled.incoming(node).unwrap().len()
+            );
+        }
+        assert_eq!(randomized.relationship_count(), store.relationship_count());
+        assert_ne!(adjacency(&randomized), adjacency(&store));
+        assert_eq!(
+            adjacency(&store.randomize_preserving_degrees(7)),
+            adjacency(&randomized)
+        );
+        assert!(randomized.has_node_property("random_score"));
+    }
+
+    #[test]
+    fn test_randomize_preserving_degrees_undirected() {
+        use crate::types::random::{RandomGraphConfig, RandomRelationshipConfig};
+
+        let config = RandomGraphConfig {
+            node_count: 30,
+            relationships: vec![RandomRelationshipConfig::new("REL", 0.25)],
+            directed: false,
+            ..RandomGraphConfig::default()
+        }
+        .with_seed(4);
+        let store = DefaultGraphStore::random(&config).unwrap();
+
+        let randomized = store.randomize_preserving_degrees(13);
+
+        for node in 0..30 {
+            assert_eq!(randomized.degree(node), store.degree(node));
+        }
+        // Every relationship is still stored in both directions
+        let shuffled = adjacency(&randomized);
+        for (source, targets) in shuffled.iter().enumerate() {
+            for &target in targets {
+                assert!(shuffled[target as usize].contains(&(source as i64)));
+            }
+        }
+        assert_ne!(shuffled, adjacency(&store));
+    }
+
+    #[test]
+    fn test_randomize_preserving_degrees_drops_relationship_property_schema() {
+        use crate::types::properties::relationship::impls::default_relationship_property_values::DefaultRelationshipPropertyValues;
+        use crate::types::random::{RandomGraphConfig, RandomRelationshipConfig};
+        use std::sync::Arc;
+
+        // Random stores declare a `weight` property on every relationship type
+        let config = RandomGraphConfig {
+            node_count: 10,
+            relationships: vec![RandomRelationshipConfig::new("REL", 0.3)],
+            ..RandomGraphConfig::default()
+        }
+        .with_seed(5);
+        let mut store = DefaultGraphStore::random(&config).unwrap();
+        let rel_type = RelationshipType::of("REL");
+        let count = store.relationship_count();
+        store
+            .add_relationship_property(
+                rel_type.clone(),
+                "weight",
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    vec![1.0; count],
+                    count,
+                )),
+            )
+            .unwrap();
+        let properties = |store: &DefaultGraphStore| {
+            store
+                .schema()
+                .relationship_schema()
+                .get(&rel_type)
+                .map(|entry| entry.properties().len())
+        };
+        assert_eq!(properties(&store), Some(1));
+        assert!(store.has_relationship_property(&rel_type, "weight"));
+
+        let randomized = store.randomize_preserving_degrees(3);
+        assert_eq!(properties(&randomized), Some(0));
+        assert!(!randomized.has_relationship_property(&rel_type, "weight"));
+    }
+}
@@ -0,0 +1,79 @@
+//! Time-indexed graph-level scalars.
+
+use super::super::DefaultGraphStore;
+use std::collections::HashSet;
+
+impl DefaultGraphStore {
+    /// Stores a time series of a graph-level scalar, such as the density
+    /// after each batch of updates, replacing any series under `key`.
+    ///
+    /// Timestamps are in milliseconds since the Unix epoch and need not be
+    /// sorted; of several values with the same timestamp the last one wins.
+    /// Series are kept apart from the regular graph properties.
+    pub fn add_graph_property_series(&mut self, key: impl Into<String>, values: Vec<(i64, f64)>) {
+        let key = key.into();
+        let mut values = values;
+        values.sort_by_key(|&(timestamp, _)| timestamp);
+        let mut series: Vec<(i64, f64)> = Vec::with_capacity(values.len());
+        for (timestamp, value) in values {
+            match series.last_mut() {
+                Some(last) if last.0 == timestamp => last.1 = value,
+                _ => series.push((timestamp, value)),
+            }
+        }
+
+        self.graph_property_series.insert(key.clone(), series);
+        self.set_modified();
+        self.notify(|l| l.on_graph_property_added(&key));
+    }
+
+    /// Returns the value of series `key` that was current at `timestamp`,
+    /// i.e. the one recorded last at or before it.
+    ///
+    /// `None` if there is no such series or `timestamp` precedes its first value.
+    pub fn graph_property_at(&self, key: &str, timestamp: i64) -> Option<f64> {
+        let series = self.graph_property_series.get(key)?;
+        let recorded = series.partition_point(|&(at, _)| at <= timestamp);
+        recorded.checked_sub(1).map(|index| series[index].1)
+    }
+
+    /// Returns the keys of all graph property series.
+    pub fn graph_property_series_keys(&self) -> HashSet<String> {
+        self.graph_property_series.keys().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::random::generators::typed_store;
+    use crate::types::schema::Direction;
+
+    fn sample_store() -> DefaultGraphStore {
+        typed_store(
+            "g",
+            3,
+            [("KNOWS", Direction::Directed, vec![(0, 1), (0, 2), (1, 2)])],
+        )
+    }
+
+    #[test]
+    fn test_graph_property_series() {
+        let mut store = sample_store();
+        store.add_graph_property_series(
+            "density",
+            vec![(3_000, 0.3), (1_000, 0.1), (2_000, 0.25), (2_000, 0.2)],
+        );
+
+        assert_eq!(store.graph_property_at("density", 1_000), Some(0.1));
+        assert_eq!(store.graph_property_at("density", 2_000), Some(0.2));
+        assert_eq!(store.graph_property_at("density", 2_999), Some(0.2));
+        assert_eq!(store.graph_property_at("density", 10_000), Some(0.3));
+        assert_eq!(store.graph_property_at("density", 999), None);
+        assert_eq!(store.graph_property_at("missing", 2_000), None);
+        assert_eq!(
+            store.graph_property_series_keys(),
+            HashSet::from(["density".to_string()])
+        );
+    }
+}
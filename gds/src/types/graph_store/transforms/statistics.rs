@@ -0,0 +1,366 @@
+//! Topology statistics and distributions of numeric properties.
+
+use super::super::{DefaultGraphStore, GraphStats, GraphStore, GraphStoreError, GraphStoreResult};
+use crate::core::utils::partition::PartitionUtils;
+use crate::core::utils::statistics::{StatisticalSummary, StatisticsConfig, StatisticsEngine};
+use crate::projection::RelationshipType;
+use crate::types::graph::id_map::IdMap;
+use crate::types::ValueType;
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+impl DefaultGraphStore {
+    /// Returns the topology summary, computed on first use and cached until
+    /// relationships are added or deleted.
+    pub fn stats(&self) -> GraphStats {
+        self.stats_cache.get_or_compute(|| self.compute_stats())
+    }
+
+    fn compute_stats(&self) -> GraphStats {
+        let node_count = self.id_map.node_count();
+        let mut min_degree = if node_count == 0 { 0 } else { usize::MAX };
+        let mut max_degree = 0;
+        for node in 0..node_count {
+            let degree = self.degrees.get(node) as usize;
+            min_degree = min_degree.min(degree);
+            max_degree = max_degree.max(degree);
+        }
+        let max_relationships = node_count.saturating_mul(node_count.saturating_sub(1));
+        let ratio = |total: usize, count: usize| {
+            if count == 0 {
+                0.0
+            } else {
+                total as f64 / count as f64
+            }
+        };
+
+        let mut parent: Vec<usize> = (0..node_count).collect();
+        fn find(parent: &mut [usize], mut node: usize) -> usize {
+            while parent[node] != node {
+                parent[node] = parent[parent[node]];
+                node = parent[node];
+            }
+            node
+        }
+        let mut component_count = node_count;
+        for topology in self.relationship_topologies.values() {
+            for source in 0..node_count.min(topology.node_capacity()) {
+                for &target in topology.outgoing(source as i64).unwrap_or(&[]) {
+                    let (a, b) = (
+                        find(&mut parent, source),
+                        find(&mut parent, target as usize),
+                    );
+                    if a != b {
+                        parent[a] = b;
+                        component_count -= 1;
+                    }
+                }
+            }
+        }
+
+        GraphStats {
+            node_count,
+            relationship_count: self.relationship_count,
+            density: ratio(self.relationship_count, max_relationships),
+            min_degree,
+            max_degree,
+            mean_degree: ratio(self.relationship_count, node_count),
+            component_count,
+        }
+    }
+
+    /// Returns the distribution of the numeric node property `key`.
+    ///
+    /// Values are read in parallel over node ranges and summarized by
+    /// [`StatisticsEngine`]; nodes without a value are not counted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphStoreError::PropertyNotFound`] for an unknown key and
+    /// [`GraphStoreError::InvalidOperation`] if the property is not a `Long`
+    /// or `Double` property.
+    pub fn node_property_stats(&self, key: &str) -> GraphStoreResult<StatisticalSummary> {
+        let values = self.node_property_values(key)?;
+        let value_type = values.value_type();
+        if !matches!(value_type, ValueType::Long | ValueType::Double) {
+            return Err(GraphStoreError::InvalidOperation(format!(
+                "node property {} of type {:?} is not numeric",
+                key, value_type
+            )));
+        }
+        let value_at = |node: u64| match value_type {
+            ValueType::Long => values.long_value(node).ok().map(|value| value as f64),
+            _ => values.double_value(node).ok(),
+        };
+
+        let partitions = PartitionUtils::range_partition(
+            self.config.compute.concurrency,
+            self.id_map.node_count(),
+            |partition| partition,
+            None,
+        );
+        let present: Vec<f64> = partitions
+            .par_iter()
+            .flat_map_iter(|partition| {
+                let start = partition.start_node();
+                (start..start + partition.node_count()).filter_map(|node| value_at(node as u64))
+            })
+            .collect();
+
+        let config = StatisticsConfig {
+            compute_histogram: false,
+            concurrency: self.config.compute.concurrency,
+            ..StatisticsConfig::default()
+        };
+        StatisticsEngine::compute_statistics_from_values(present, config)
+            .map(|(summary, _)| summary)
+            .map_err(|error| GraphStoreError::InvalidOperation(error.to_string()))
+    }
+
+    /// Returns a histogram of the `key` property of `rel_type` relationships
+    /// as `(lower bin edge, count)` pairs, one per bin.
+    ///
+    /// Values are read in parallel over relationship index ranges and binned
+    /// by [`StatisticsEngine`]: `bins` equally wide bins between the smallest
+    /// and largest value, with the largest value in the last bin. `NaN` and
+    /// infinite values are skipped. A property without finite values yields
+    /// an empty histogram, one whose finite values are all equal a single bin.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphStoreError::RelationshipTypeNotFound`] for an unknown
+    /// type, [`GraphStoreError::PropertyNotFound`] if the type has no `key`
+    /// property and [`GraphStoreError::InvalidOperation`] if `bins` is zero.
+    pub fn relationship_property_histogram(
+        &self,
+        rel_type: &RelationshipType,
+        key: &str,
+        bins: usize,
+    ) -> GraphStoreResult<Vec<(f64, usize)>> {
+        let topology = self.relationship_topologies.get(rel_type).ok_or_else(|| {
+            GraphStoreError::RelationshipTypeNotFound(rel_type.name().to_string())
+        })?;
+        let values = self.relationship_property_values(rel_type, key)?;
+        if bins == 0 {
+            return Err(GraphStoreError::InvalidOperation(
+                "histogram needs at least one bin".to_string(),
+            ));
+        }
+        let default_value = values.default_value();
+        let value_at = |index: usize| values.double_value(index as u64).unwrap_or(default_value);
+
+        let partitions = PartitionUtils::range_partition(
+            self.config.compute.concurrency,
+            topology.relationship_count(),
+            |partition| partition,
+            None,
+        );
+        let finite: Vec<f64> = partitions
+            .par_iter()
+            .flat_map_iter(|partition| {
+                let start = partition.start_node();
+                (start..start + partition.node_count())
+                    .map(value_at)
+                    .filter(|value| value.is_finite())
+            })
+            .collect();
+
+        let config = StatisticsConfig {
+            histogram_bins: bins,
+            compute_histogram: true,
+            compute_percentiles: false,
+            concurrency: self.config.compute.concurrency,
+        };
+        let (_, histogram) = StatisticsEngine::compute_statistics_from_values(finite, config)
+            .map_err(|error| GraphStoreError::InvalidOperation(error.to_string()))?;
+        Ok(histogram
+            .map(|histogram| {
+                histogram
+                    .bins
+                    .into_iter()
+                    .map(|bin| (bin.lower_bound, bin.count))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Computes the edge density of every relationship type.
+    ///
+    /// Density is the number of stored relationships of a type divided by the
+    /// maximum possible, `n * (n - 1)` without self-loops. Undirected types
+    /// store each edge in both directions, so the ratio equals the usual
+    /// `edges / (n * (n - 1) / 2)` for them as well.
+    pub fn compute_type_densities(&self) -> HashMap<RelationshipType, f64> {
+        let node_count = self.id_map.node_count();
+        let max_relationships = node_count.saturating_mul(node_count.saturating_sub(1));
+
+        self.relationship_topologies
+            .iter()
+            .map(|(rel_type, topology)| {
+                let density = if max_relationships == 0 {
+                    0.0
+                } else {
+                    topology.relationship_count() as f64 / max_relationships as f64
+                };
+                (rel_type.clone(), density)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::properties::relationship::impls::default_relationship_property_values::DefaultRelationshipPropertyValues;
+    use crate::types::random::generators::typed_store;
+    use crate::types::schema::Direction;
+    use std::sync::Arc;
+
+    fn sample_store() -> DefaultGraphStore {
+        typed_store(
+            "g",
+            3,
+            [("KNOWS", Direction::Directed, vec![(0, 1), (0, 2), (1, 2)])],
+        )
+    }
+
+    #[test]
+    fn caches_stats_until_relationships_change() {
+        let mut store = sample_store();
+        let stats = store.stats();
+        assert_eq!(stats.relationship_count, 3);
+        assert_eq!(stats.density, 0.5);
+        assert_eq!((stats.min_degree, stats.max_degree), (0, 2));
+        assert_eq!(stats.mean_degree, 1.0);
+        assert_eq!(stats.component_count, 1);
+        assert_eq!(store.stats(), stats);
+        assert_eq!(store.stats_cache.computation_count(), 1);
+
+        store
+            .add_node_property_f64("score".to_string(), vec![1.0, 2.0, 3.0])
+            .unwrap();
+        store.stats();
+        assert_eq!(store.stats_cache.computation_count(), 1);
+
+        store
+            .delete_relationships(&RelationshipType::of("KNOWS"))
+            .unwrap();
+        let stats = store.stats();
+        assert_eq!(store.stats_cache.computation_count(), 2);
+        assert_eq!(stats.relationship_count, 0);
+        assert_eq!(stats.component_count, 3);
+
+        let clone = store.clone();
+        clone.stats();
+        assert_eq!(clone.stats_cache.computation_count(), 1);
+        assert_eq!(store.stats_cache.computation_count(), 2);
+    }
+
+    #[test]
+    fn test_relationship_property_histogram() {
+        use crate::types::random::{RandomGraphConfig, RandomRelationshipConfig};
+
+        let config = RandomGraphConfig {
+            node_count: 40,
+            relationships: vec![RandomRelationshipConfig::new("REL", 0.3)],
+            ..RandomGraphConfig::default()
+        }
+        .with_seed(11);
+        let mut store = DefaultGraphStore::random(&config).unwrap();
+        let rel_type = RelationshipType::of("REL");
+        let count = store.relationship_count_for_type(&rel_type);
+        assert!(count > 200);
+
+        // Evenly spread over [0, 1)
+        let uniform: Vec<f64> = (0..count).map(|i| i as f64 / count as f64).collect();
+        // Two clusters around 1 and 9
+        let clustered: Vec<f64> = (0..count)
+            .map(|i| if i % 3 == 0 { 9.0 } else { 1.0 } + (i % 5) as f64 * 0.01)
+            .collect();
+        for (key, values) in [("uniform", uniform), ("clustered", clustered)] {
+            store
+                .add_relationship_property(
+                    rel_type.clone(),
+                    key,
+                    Arc::new(DefaultRelationshipPropertyValues::with_default(
+                        values, count,
+                    )),
+                )
+                .unwrap();
+        }
+
+        let histogram = store
+            .relationship_property_histogram(&rel_type, "uniform", 4)
+            .unwrap();
+        assert_eq!(histogram.len(), 4);
+        assert_eq!(histogram[0].0, 0.0);
+        assert_eq!(histogram.iter().map(|&(_, c)| c).sum::<usize>(), count);
+        for &(_, bin_count) in &histogram {
+            assert!(bin_count.abs_diff(count / 4) <= 1);
+        }
+
+        let histogram = store
+            .relationship_property_histogram(&rel_type, "clustered", 5)
+            .unwrap();
+        let counts: Vec<usize> = histogram.iter().map(|&(_, c)| c).collect();
+        assert_eq!(counts[0], count - count.div_ceil(3));
+        assert_eq!(counts[4], count.div_ceil(3));
+        assert_eq!(counts[1..4], [0, 0, 0]);
+
+        assert!(store
+            .relationship_property_histogram(&rel_type, "missing", 4)
+            .is_err());
+
+        // Non-finite values are left out of the range and the counts
+        let mut with_outliers: Vec<f64> = (0..count).map(|i| (i % 2) as f64).collect();
+        with_outliers[0] = f64::NAN;
+        with_outliers[1] = f64::INFINITY;
+        with_outliers[2] = f64::NEG_INFINITY;
+        store
+            .add_relationship_property(
+                rel_type.clone(),
+                "outliers",
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    with_outliers,
+                    count,
+                )),
+            )
+            .unwrap();
+        let histogram = store
+            .relationship_property_histogram(&rel_type, "outliers", 2)
+            .unwrap();
+        assert_eq!(histogram[0].0, 0.0);
+        assert_eq!(histogram[1].0, 0.5);
+        assert_eq!(histogram.iter().map(|&(_, c)| c).sum::<usize>(), count - 3);
+        assert!(store
+            .relationship_property_histogram(&rel_type, "uniform", 0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_node_property_stats_of_random_score() {
+        let config = crate::types::random::RandomGraphConfig {
+            node_count: 20_000,
+            relationships: Vec::new(),
+            ..crate::types::random::RandomGraphConfig::default()
+        }
+        .with_seed(3);
+        let store = DefaultGraphStore::random(&config).unwrap();
+
+        let stats = store.node_property_stats("random_score").unwrap();
+        assert_eq!(stats.count, 20_000);
+        assert!(0.0 <= stats.min && stats.max <= 1.0);
+        assert!((stats.mean - 0.5).abs() < 0.02);
+        let percentiles = &stats.percentiles;
+        assert!((percentiles.p50 - 0.5).abs() < 0.02);
+        assert!(percentiles.p25 <= percentiles.p50);
+        assert!(percentiles.p50 <= percentiles.p75 && percentiles.p75 <= percentiles.p99);
+        // Uniform on [0, 1] has a standard deviation of 1 / sqrt(12)
+        assert!((stats.std_dev - 12f64.sqrt().recip()).abs() < 0.02);
+
+        assert!(matches!(
+            store.node_property_stats("missing"),
+            Err(GraphStoreError::PropertyNotFound(_))
+        ));
+    }
+}
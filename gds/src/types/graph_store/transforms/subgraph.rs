@@ -0,0 +1,225 @@
+//! Node-filtered copies and induced subgraphs.
+
+use super::super::DefaultGraphStore;
+use crate::core::Aggregation;
+use crate::types::graph::id_map::{IdMap, MappedNodeId, SimpleIdMap};
+use crate::types::graph::RelationshipTopology;
+use crate::types::ValueType;
+
+impl DefaultGraphStore {
+    /// Returns a copy restricted to the subgraph induced by the nodes for
+    /// which `keep` returns `true`.
+    ///
+    /// Every relationship with a rejected source or target is dropped along
+    /// with its property values. Rejected nodes stay in the id map without
+    /// relationships, so node ids and per-node results keep lining up with
+    /// this store.
+    pub fn filter_nodes(&self, keep: impl Fn(MappedNodeId) -> bool) -> DefaultGraphStore {
+        let kept: Vec<bool> = (0..self.id_map.node_count() as MappedNodeId)
+            .map(keep)
+            .collect();
+
+        let mut store = self.clone_structure();
+        for rel_type in self.ordered_relationship_types.clone() {
+            let topology = &self.relationship_topologies[&rel_type];
+            let mut outgoing = Vec::with_capacity(topology.node_capacity());
+            let mut index = 0u64;
+            let mut removed = false;
+            for source in 0..topology.node_capacity() as MappedNodeId {
+                let mut targets = Vec::new();
+                for &target in topology.outgoing(source).unwrap_or_default() {
+                    if kept[source as usize] && kept[target as usize] {
+                        targets.push((target, vec![index]));
+                    } else {
+                        removed = true;
+                    }
+                    index += 1;
+                }
+                outgoing.push(targets);
+            }
+            if removed {
+                store.replace_relationships(&rel_type, outgoing, Aggregation::Single);
+            }
+        }
+        store
+    }
+
+    /// Builds the subgraph induced by `nodes`, renumbered in the given order.
+    ///
+    /// Node `nodes[i]` becomes node `i` of the result and keeps its original
+    /// id and labels. A relationship is kept, with its properties, if both
+    /// endpoints are in `nodes`. `Long` and `Double` node properties are
+    /// carried over, other node properties are dropped. Graph properties
+    /// are kept as they are.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nodes` contains a node twice or a node the store does not have.
+    pub fn induced_subgraph(&self, nodes: &[MappedNodeId]) -> DefaultGraphStore {
+        let node_count = nodes.len();
+        let mut new_ids = vec![None; self.id_map.node_count()];
+        for (new_id, &node) in nodes.iter().enumerate() {
+            assert!(
+                new_ids[node as usize]
+                    .replace(new_id as MappedNodeId)
+                    .is_none(),
+                "node {} is sampled twice",
+                node
+            );
+        }
+
+        let mut id_map = SimpleIdMap::from_original_ids(nodes.iter().map(|&node| {
+            self.id_map
+                .to_original_node_id(node)
+                .expect("node of the store")
+        }));
+        for label in self.id_map.available_node_labels() {
+            id_map.add_node_label(label);
+        }
+        for (new_id, &node) in nodes.iter().enumerate() {
+            for label in self.id_map.node_labels(node) {
+                id_map.add_node_id_to_label(new_id as MappedNodeId, label);
+            }
+        }
+
+        let relationship_topologies = self
+            .ordered_relationship_types
+            .iter()
+            .map(|rel_type| {
+                let inverse_indexed = self.relationship_topologies[rel_type].is_inverse_indexed();
+                let topology = RelationshipTopology::new(
+                    vec![Vec::new(); node_count],
+                    inverse_indexed.then(|| vec![Vec::new(); node_count]),
+                );
+                (rel_type.clone(), topology)
+            })
+            .collect();
+        let mut store = self.rebuild_with(
+            self.schema_retaining(
+                |key| {
+                    self.node_properties.get(key).is_some_and(|values| {
+                        matches!(values.value_type(), ValueType::Long | ValueType::Double)
+                    })
+                },
+                |_, _| true,
+            ),
+            Some(id_map),
+            relationship_topologies,
+        );
+
+        store.relationship_property_stores = self.relationship_property_stores.clone();
+        for rel_type in &self.ordered_relationship_types {
+            let topology = &self.relationship_topologies[rel_type];
+            let mut outgoing = vec![Vec::new(); node_count];
+            let mut index = 0u64;
+            for source in 0..topology.node_capacity() as MappedNodeId {
+                for &target in topology.outgoing(source).unwrap_or_default() {
+                    if let (Some(new_source), Some(new_target)) =
+                        (new_ids[source as usize], new_ids[target as usize])
+                    {
+                        outgoing[new_source as usize].push((new_target, vec![index]));
+                    }
+                    index += 1;
+                }
+            }
+            store.replace_relationships(rel_type, outgoing, Aggregation::Single);
+        }
+
+        for (key, values) in &self.node_properties {
+            // Adding a property to a fresh store cannot fail.
+            let _ = match values.value_type() {
+                ValueType::Long => store.add_node_property_i64(
+                    key.clone(),
+                    nodes
+                        .iter()
+                        .map(|&node| values.long_value(node as u64).unwrap_or_default())
+                        .collect(),
+                ),
+                ValueType::Double => store.add_node_property_f64(
+                    key.clone(),
+                    nodes
+                        .iter()
+                        .map(|&node| values.double_value(node as u64).unwrap_or(f64::NAN))
+                        .collect(),
+                ),
+                _ => continue,
+            };
+        }
+        store.node_properties_by_label = self
+            .node_properties_by_label
+            .iter()
+            .map(|(label, keys)| {
+                let kept = keys
+                    .iter()
+                    .filter(|key| store.node_properties.contains_key(*key))
+                    .cloned()
+                    .collect();
+                (label.clone(), kept)
+            })
+            .collect();
+
+        store
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projection::NodeLabel;
+    use crate::types::graph_store::GraphStore;
+    use crate::types::random::generators::typed_store;
+    use crate::types::schema::{Direction, MutableGraphSchema};
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    fn sample_store() -> DefaultGraphStore {
+        typed_store(
+            "g",
+            3,
+            [("KNOWS", Direction::Directed, vec![(0, 1), (0, 2), (1, 2)])],
+        )
+    }
+
+    #[test]
+    fn test_induced_subgraph_drops_unsupported_node_property_schema() {
+        use crate::collections::backends::vec::VecDoubleArray;
+        use crate::types::properties::node::DefaultDoubleArrayNodePropertyValues;
+
+        let mut store = sample_store();
+        let mut schema = MutableGraphSchema::empty();
+        schema
+            .node_schema_mut()
+            .get_or_create_label(NodeLabel::all_nodes())
+            .add_property("age", ValueType::Long)
+            .add_property("embedding", ValueType::DoubleArray);
+        store.schema = Arc::new(schema.build());
+        store
+            .add_node_property_i64("age".to_string(), vec![30, 40, 50])
+            .unwrap();
+        let embedding = DefaultDoubleArrayNodePropertyValues::from_collection(
+            VecDoubleArray::from(vec![Some(vec![1.0, 2.0]); 3]),
+            3,
+        );
+        store
+            .add_node_property(
+                HashSet::from([NodeLabel::all_nodes()]),
+                "embedding",
+                Arc::new(embedding),
+            )
+            .unwrap();
+
+        let subgraph = store.induced_subgraph(&[2, 0]);
+
+        let keys: HashSet<String> = subgraph
+            .schema()
+            .node_schema()
+            .get(&NodeLabel::all_nodes())
+            .unwrap()
+            .properties()
+            .keys()
+            .cloned()
+            .collect();
+        assert_eq!(keys, HashSet::from(["age".to_string()]));
+        assert!(!subgraph.has_node_property("embedding"));
+    }
+}
@@ -0,0 +1,292 @@
+//! Filtering and normalizing relationship weights.
+
+use super::super::{
+    DefaultGraphStore, GraphStore, GraphStoreError, GraphStoreResult, WeightNormalization,
+};
+use crate::types::graph::id_map::{IdMap, MappedNodeId};
+use crate::types::graph::RelationshipTopology;
+use crate::types::properties::relationship::impls::default_relationship_property_values::DefaultRelationshipPropertyValues;
+use crate::types::properties::relationship::RelationshipPropertyStore;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+impl DefaultGraphStore {
+    /// Keeps only the relationships whose `key` property is at least `min`.
+    ///
+    /// All nodes, labels, node and graph properties are retained. Every
+    /// relationship property of a filtered type is carried over for the
+    /// relationships that remain. Relationship types without a `key`
+    /// property have no weight to meet the threshold and end up empty, and
+    /// `NaN` weights never pass.
+    pub fn filter_relationships_by_weight(&self, key: &str, min: f64) -> DefaultGraphStore {
+        let node_count = self.id_map.node_count();
+
+        let mut relationship_topologies = HashMap::new();
+        let mut filtered_properties = Vec::new();
+        for rel_type in &self.ordered_relationship_types {
+            let topology = &self.relationship_topologies[rel_type];
+            let properties = self.relationship_property_stores.get(rel_type);
+            let weights = properties.and_then(|store| store.get_property_values(key));
+
+            let mut outgoing: Vec<Vec<i64>> = vec![Vec::new(); node_count];
+            let mut kept = Vec::new();
+            if let Some(weights) = weights {
+                let mut index = 0u64;
+                for source in 0..topology.node_capacity() as MappedNodeId {
+                    for &target in topology.outgoing(source).unwrap_or_default() {
+                        if weights
+                            .double_value(index)
+                            .is_ok_and(|weight| weight >= min)
+                        {
+                            outgoing[source as usize].push(target);
+                            kept.push(index);
+                        }
+                        index += 1;
+                    }
+                }
+            }
+
+            if let Some(properties) = properties.filter(|_| weights.is_some()) {
+                for property in properties.get_all_properties() {
+                    let values: Vec<f64> = kept
+                        .iter()
+                        .map(|&index| {
+                            property
+                                .values()
+                                .double_value(index)
+                                .unwrap_or(property.values().default_value())
+                        })
+                        .collect();
+                    filtered_properties.push((
+                        rel_type.clone(),
+                        property.key().to_string(),
+                        values,
+                    ));
+                }
+            }
+
+            let incoming = topology.is_inverse_indexed().then(|| {
+                let mut incoming = vec![Vec::new(); node_count];
+                for (source, targets) in outgoing.iter().enumerate() {
+                    for &target in targets {
+                        incoming[target as usize].push(source as i64);
+                    }
+                }
+                incoming
+            });
+            relationship_topologies.insert(
+                rel_type.clone(),
+                RelationshipTopology::new(outgoing, incoming),
+            );
+        }
+
+        let mut store = self.rebuild_with((*self.schema).clone(), None, relationship_topologies);
+        for (rel_type, property_key, values) in filtered_properties {
+            let count = values.len();
+            // Adding a property to a fresh store cannot fail.
+            let _ = store.add_relationship_property(
+                rel_type,
+                property_key,
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    values, count,
+                )),
+            );
+        }
+
+        store
+    }
+
+    /// Writes the `key` weights normalized by `mode` as a new relationship
+    /// property and returns its key, see [`WeightNormalization::output_key`].
+    ///
+    /// Every relationship type with a `key` property gets the new property.
+    /// Per-source modes look at the outgoing weights of a node across all of
+    /// these types, so that `PerSourceSum` yields transition probabilities
+    /// for walks over the whole graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphStoreError::PropertyNotFound`] if no relationship type
+    /// has a `key` property.
+    pub fn normalize_relationship_weights(
+        &mut self,
+        key: &str,
+        mode: WeightNormalization,
+    ) -> GraphStoreResult<String> {
+        let node_count = self.id_map.node_count();
+
+        // Weights per type in outgoing order, with their source nodes
+        let mut weighted_types = Vec::new();
+        for rel_type in &self.ordered_relationship_types {
+            let Some(values) = self
+                .relationship_property_stores
+                .get(rel_type)
+                .and_then(|store| store.get_property_values(key))
+            else {
+                continue;
+            };
+            let topology = &self.relationship_topologies[rel_type];
+            let mut sources = Vec::new();
+            let mut weights = Vec::new();
+            for source in 0..topology.node_capacity() as MappedNodeId {
+                for _ in topology.outgoing(source).unwrap_or_default() {
+                    let index = weights.len() as u64;
+                    sources.push(source as usize);
+                    weights.push(values.double_value(index).unwrap_or(values.default_value()));
+                }
+            }
+            weighted_types.push((rel_type.clone(), sources, weights));
+        }
+        if weighted_types.is_empty() {
+            return Err(GraphStoreError::PropertyNotFound(key.to_string()));
+        }
+
+        let all_weights = || weighted_types.iter().flat_map(|(_, _, weights)| weights);
+        let min = all_weights().copied().fold(f64::INFINITY, f64::min);
+        let max = all_weights().copied().fold(f64::NEG_INFINITY, f64::max);
+        let initial = match mode {
+            WeightNormalization::PerSourceMax => f64::NEG_INFINITY,
+            _ => 0.0,
+        };
+        let mut per_source = vec![initial; node_count];
+        for (_, sources, weights) in &weighted_types {
+            for (&source, &weight) in sources.iter().zip(weights) {
+                match mode {
+                    WeightNormalization::Global => {}
+                    WeightNormalization::PerSourceSum => per_source[source] += weight,
+                    WeightNormalization::PerSourceMax => {
+                        per_source[source] = f64::max(per_source[source], weight)
+                    }
+                }
+            }
+        }
+
+        let output_key = mode.output_key(key);
+        for (rel_type, sources, weights) in weighted_types {
+            let normalized: Vec<f64> = sources
+                .iter()
+                .zip(&weights)
+                .map(|(&source, &weight)| match mode {
+                    WeightNormalization::Global => mode.apply(weight, min, max - min),
+                    _ => mode.apply(weight, 0.0, per_source[source]),
+                })
+                .collect();
+            let count = normalized.len();
+            self.add_relationship_property(
+                rel_type,
+                output_key.clone(),
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    normalized, count,
+                )),
+            )?;
+        }
+        Ok(output_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projection::RelationshipType;
+    use crate::types::properties::relationship::RelationshipIterator;
+    use crate::types::random::generators::typed_store;
+    use crate::types::schema::Direction;
+    use std::collections::HashSet;
+
+    fn sample_store() -> DefaultGraphStore {
+        typed_store(
+            "g",
+            3,
+            [("KNOWS", Direction::Directed, vec![(0, 1), (0, 2), (1, 2)])],
+        )
+    }
+
+    #[test]
+    fn test_filter_relationships_by_weight() {
+        let mut store = sample_store();
+        let rel_type = RelationshipType::of("KNOWS");
+        store
+            .add_relationship_property(
+                rel_type.clone(),
+                "weight",
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    vec![0.9, 0.1, 0.5],
+                    3,
+                )),
+            )
+            .unwrap();
+
+        let strong = store.filter_relationships_by_weight("weight", 0.5);
+
+        assert_eq!(strong.node_count(), store.node_count());
+        assert_eq!(strong.relationship_count(), 2);
+        let weights = strong
+            .relationship_property_values(&rel_type, "weight")
+            .unwrap();
+        let graph = strong.graph();
+        for node in 0..strong.node_count() as i64 {
+            for cursor in graph.stream_relationships(node, 0.0) {
+                assert!(cursor.property() >= 0.5);
+            }
+        }
+        for index in 0..2 {
+            assert!(weights.double_value(index).unwrap() >= 0.5);
+        }
+
+        let none = store.filter_relationships_by_weight("missing", 0.0);
+        assert_eq!(none.node_count(), store.node_count());
+        assert_eq!(none.relationship_count(), 0);
+    }
+
+    #[test]
+    fn test_normalize_relationship_weights() {
+        let mut store = sample_store();
+        let rel_type = RelationshipType::of("KNOWS");
+        store
+            .add_relationship_property(
+                rel_type.clone(),
+                "weight",
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    vec![3.0, 1.0, 2.0],
+                    3,
+                )),
+            )
+            .unwrap();
+
+        let key = store
+            .normalize_relationship_weights("weight", WeightNormalization::PerSourceSum)
+            .unwrap();
+        let graph = store
+            .get_graph_with_types_and_selectors(
+                &HashSet::from([rel_type.clone()]),
+                &HashMap::from([(rel_type.clone(), key.clone())]),
+            )
+            .unwrap();
+        for node in 0..store.node_count() as i64 {
+            if graph.degree(node) > 0 {
+                let sum: f64 = graph
+                    .stream_relationships(node, 0.0)
+                    .map(|cursor| cursor.property())
+                    .sum();
+                assert!((sum - 1.0).abs() < 1e-12);
+            }
+        }
+
+        let global = store
+            .normalize_relationship_weights("weight", WeightNormalization::Global)
+            .unwrap();
+        let values = store
+            .relationship_property_values(&rel_type, &global)
+            .unwrap();
+        assert_eq!(
+            (0..3)
+                .map(|index| values.double_value(index).unwrap())
+                .collect::<Vec<_>>(),
+            vec![1.0, 0.0, 0.5]
+        );
+
+        assert!(store
+            .normalize_relationship_weights("missing", WeightNormalization::PerSourceMax)
+            .is_err());
+    }
+}
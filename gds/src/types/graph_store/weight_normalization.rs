@@ -0,0 +1,50 @@
+//! WeightNormalization - Rescaling of relationship weights.
+//!
+//! Weighted algorithms often expect weights on a common scale. Random walks
+//! and PageRank want transition probabilities, i.e. the outgoing weights of
+//! every node summing to one. [`DefaultGraphStore::normalize_relationship_weights`]
+//! (super::DefaultGraphStore::normalize_relationship_weights) writes the
+//! normalized weights as a new relationship property.
+
+/// Ranges below this are treated as zero to avoid dividing by noise.
+const CLOSE_TO_ZERO: f64 = 1e-15;
+
+/// How relationship weights are normalized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeightNormalization {
+    /// Min-max scaling over all weights of the graph into `[0, 1]`.
+    ///
+    /// All weights become `0.0` if they are equal.
+    Global,
+    /// Divides each weight by the sum of its source node's outgoing weights,
+    /// turning weights into transition probabilities.
+    PerSourceSum,
+    /// Divides each weight by the largest outgoing weight of its source node.
+    PerSourceMax,
+}
+
+impl WeightNormalization {
+    /// Key of the property holding weights of `key` normalized in this mode.
+    pub fn output_key(&self, key: &str) -> String {
+        let suffix = match self {
+            WeightNormalization::Global => "global",
+            WeightNormalization::PerSourceSum => "per_source_sum",
+            WeightNormalization::PerSourceMax => "per_source_max",
+        };
+        format!("{}_normalized_{}", key, suffix)
+    }
+
+    /// Normalizes `weight` given the divisor bounds computed for this mode.
+    ///
+    /// `min` is only used by [`Global`](Self::Global); `scale` is the global
+    /// range, or the per-source sum or maximum.
+    pub(crate) fn apply(&self, weight: f64, min: f64, scale: f64) -> f64 {
+        if scale.abs() < CLOSE_TO_ZERO {
+            return 0.0;
+        }
+        match self {
+            WeightNormalization::Global => (weight - min) / scale,
+            WeightNormalization::PerSourceSum | WeightNormalization::PerSourceMax => weight / scale,
+        }
+    }
+}
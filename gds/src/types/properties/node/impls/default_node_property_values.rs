@@ -99,4 +99,44 @@ mod tests {
         // Test type conversion to long
         assert_eq!(values.long_value(0).unwrap(), 1);
     }
+
+    #[test]
+    fn test_values_iter_yields_gds_values() {
+        use crate::collections::backends::vec::VecDouble;
+        use crate::values::{DefaultFloatingPointValue, DefaultLongValue, GdsValue};
+        use std::sync::Arc;
+
+        let doubles = DefaultDoubleNodePropertyValues::from_collection(
+            VecDouble::from(vec![1.5, 2.5]),
+            2,
+        );
+        let longs =
+            DefaultLongNodePropertyValues::from_collection(VecLong::from(vec![7i64, 8]), 2);
+
+        let collect = |values: &dyn NodePropertyValues| -> Vec<Arc<dyn GdsValue>> {
+            values.values_iter().collect()
+        };
+
+        let double_values = collect(&doubles);
+        assert_eq!(double_values.len(), 2);
+        assert!(double_values
+            .iter()
+            .all(|value| value.value_type() == ValueType::Double));
+        let second = double_values[1]
+            .as_any()
+            .downcast_ref::<DefaultFloatingPointValue>()
+            .unwrap();
+        assert_eq!(second.0, 2.5);
+
+        let long_values = collect(&longs);
+        assert_eq!(long_values.len(), 2);
+        assert!(long_values
+            .iter()
+            .all(|value| value.value_type() == ValueType::Long));
+        let first = long_values[0]
+            .as_any()
+            .downcast_ref::<DefaultLongValue>()
+            .unwrap();
+        assert_eq!(first.0, 7);
+    }
 }
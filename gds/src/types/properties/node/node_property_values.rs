@@ -1,8 +1,9 @@
+use crate::types::properties::PropertyValuesError;
 use crate::types::properties::{PropertyValues, PropertyValuesResult};
 use crate::types::ValueType;
-// Note: GdsValue and Arc are used in the trait definitions but not in the module itself
-// use crate::values::traits::GdsValue;
-// use std::sync::Arc;
+use crate::values::traits::{GdsNoValue, GdsValue};
+use crate::values::PrimitiveValues;
+use std::sync::Arc;
 
 /// Interface for accessing property values for nodes in a graph.
 /// Provides methods for retrieving values of different types for specific nodes.
@@ -47,6 +48,52 @@ pub trait NodePropertyValues: PropertyValues + std::fmt::Debug + Send + Sync {
 
     /// Returns whether the node has a value.
     fn has_value(&self, node_id: u64) -> bool;
+
+    /// Returns the value for the given node boxed as a [`GdsValue`].
+    ///
+    /// Integral types become longs, `Float` and `Double` become floating point
+    /// values, and arrays keep their element type. Nodes without a value yield
+    /// [`GdsNoValue`].
+    fn gds_value(&self, node_id: u64) -> PropertyValuesResult<Arc<dyn GdsValue>> {
+        if !self.has_value(node_id) {
+            return Ok(Arc::new(GdsNoValue));
+        }
+        let value = match self.value_type() {
+            ValueType::Byte | ValueType::Short | ValueType::Int | ValueType::Long => {
+                PrimitiveValues::long_value(self.long_value(node_id)?)
+            }
+            ValueType::Float | ValueType::Double => {
+                PrimitiveValues::floating_point_value(self.double_value(node_id)?)
+            }
+            ValueType::Boolean => PrimitiveValues::boolean_value(self.long_value(node_id)? != 0),
+            ValueType::ByteArray
+            | ValueType::ShortArray
+            | ValueType::IntArray
+            | ValueType::LongArray => PrimitiveValues::long_array(self.long_array_value(node_id)?),
+            ValueType::FloatArray => PrimitiveValues::float_array(self.float_array_value(node_id)?),
+            ValueType::DoubleArray => {
+                PrimitiveValues::double_array(self.double_array_value(node_id)?)
+            }
+            other => {
+                return Err(PropertyValuesError::UnsupportedOperation(format!(
+                    "cannot convert {:?} node properties to GdsValue",
+                    other
+                )))
+            }
+        };
+        Ok(value)
+    }
+
+    /// Iterates the values of all nodes in node id order, boxed as [`GdsValue`].
+    ///
+    /// Lets generic code (serialization, display) walk any property without
+    /// knowing its backend. Values that cannot be converted yield [`GdsNoValue`].
+    fn values_iter(&self) -> Box<dyn Iterator<Item = Arc<dyn GdsValue>> + '_> {
+        Box::new((0..self.node_count() as u64).map(move |node_id| {
+            self.gds_value(node_id)
+                .unwrap_or_else(|_| Arc::new(GdsNoValue) as Arc<dyn GdsValue>)
+        }))
+    }
 }
 
 // ========== Specialized traits for typed node property values ==========
@@ -1,5 +1,6 @@
 use super::PropertyCursor;
 use crate::types::graph::id_map::MappedNodeId;
+use crate::types::ValueType;
 use std::fmt::Debug;
 
 /// Accessor for relationship property values.
@@ -105,7 +106,9 @@ impl EmptyRelationshipProperties {
 
 impl Default for EmptyRelationshipProperties {
     fn default() -> Self {
-        Self { default_value: 0.0 }
+        Self {
+            default_value: ValueType::Double.default_as(),
+        }
     }
 }
 
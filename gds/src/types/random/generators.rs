@@ -0,0 +1,155 @@
+use crate::projection::RelationshipType;
+use crate::types::graph::{RelationshipTopology, SimpleIdMap};
+use crate::types::graph_store::{
+    Capabilities, DatabaseId, DatabaseInfo, DatabaseLocation, DefaultGraphStore, GraphName,
+};
+use crate::types::schema::{Direction, MutableGraphSchema};
+use std::collections::HashMap;
+
+/// Relationship type of every generated graph.
+pub const GENERATED_RELATIONSHIP_TYPE: &str = "REL";
+
+impl DefaultGraphStore {
+    /// Generates a `rows x cols` grid where each node links to its right and
+    /// lower neighbor.
+    ///
+    /// Node `r * cols + c` sits at row `r`, column `c`. The graph is
+    /// undirected with `rows * (cols - 1) + cols * (rows - 1)` edges.
+    pub fn grid(rows: usize, cols: usize) -> Self {
+        let edges = (0..rows).flat_map(|row| {
+            (0..cols).flat_map(move |col| {
+                let node = row * cols + col;
+                let right = (col + 1 < cols).then_some((node, node + 1));
+                let down = (row + 1 < rows).then_some((node, node + cols));
+                right.into_iter().chain(down)
+            })
+        });
+        undirected_store("grid", rows * cols, edges)
+    }
+
+    /// Generates an undirected cycle `0 - 1 - ... - (n - 1) - 0`.
+    ///
+    /// A ring of two nodes is a single edge; smaller rings have no edges.
+    pub fn ring(n: usize) -> Self {
+        let edges = match n {
+            0 | 1 => Vec::new(),
+            2 => vec![(0, 1)],
+            _ => (0..n).map(|node| (node, (node + 1) % n)).collect(),
+        };
+        undirected_store("ring", n, edges)
+    }
+
+    /// Generates the undirected complete graph on `n` nodes with `n * (n - 1) / 2` edges.
+    pub fn complete(n: usize) -> Self {
+        let edges = (0..n).flat_map(|source| (source + 1..n).map(move |target| (source, target)));
+        undirected_store("complete", n, edges)
+    }
+
+    /// Generates an undirected star of `n` nodes: node 0 links to every other node.
+    pub fn star(n: usize) -> Self {
+        undirected_store("star", n, (1..n).map(|leaf| (0, leaf)))
+    }
+}
+
+/// Builds an unlabeled store whose single relationship type stores every
+/// edge in both directions.
+pub(crate) fn undirected_store(
+    name: &str,
+    node_count: usize,
+    edges: impl IntoIterator<Item = (usize, usize)>,
+) -> DefaultGraphStore {
+    typed_store(
+        name,
+        node_count,
+        [(
+            GENERATED_RELATIONSHIP_TYPE,
+            Direction::Undirected,
+            edges.into_iter().collect(),
+        )],
+    )
+}
+
+/// Builds an unlabeled store with one relationship type per entry of
+/// `types`.
+///
+/// Edges of an undirected type are stored in both directions, edges of a
+/// directed type only from source to target.
+pub(crate) fn typed_store<'a>(
+    name: &str,
+    node_count: usize,
+    types: impl IntoIterator<Item = (&'a str, Direction, Vec<(usize, usize)>)>,
+) -> DefaultGraphStore {
+    let mut schema = MutableGraphSchema::empty();
+    let mut relationship_topologies = HashMap::new();
+    for (type_name, direction, edges) in types {
+        let mut adjacency: Vec<Vec<i64>> = vec![Vec::new(); node_count];
+        for (source, target) in edges {
+            adjacency[source].push(target as i64);
+            if direction.is_undirected() {
+                adjacency[target].push(source as i64);
+            }
+        }
+        for neighbors in &mut adjacency {
+            neighbors.sort_unstable();
+        }
+
+        let rel_type = RelationshipType::of(type_name);
+        schema
+            .relationship_schema_mut()
+            .add_relationship_type(rel_type.clone(), direction);
+        relationship_topologies.insert(rel_type, RelationshipTopology::new(adjacency, None));
+    }
+
+    let mut capabilities = Capabilities::new();
+    capabilities.add_feature("generated");
+    capabilities.add_feature("transient");
+
+    DefaultGraphStore::new(
+        crate::config::GraphStoreConfig::default(),
+        GraphName::new(name),
+        DatabaseInfo::new(
+            DatabaseId::new("in-memory"),
+            DatabaseLocation::remote("localhost", 7687, None, None),
+        ),
+        schema.build(),
+        capabilities,
+        SimpleIdMap::from_original_ids(0..node_count as i64),
+        relationship_topologies,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::graph_store::GraphStore;
+
+    #[test]
+    fn generates_canonical_graphs() {
+        let complete = DefaultGraphStore::complete(5);
+        assert_eq!(complete.node_count(), 5);
+        assert_eq!(complete.relationship_count(), 20);
+        assert_eq!(complete.logical_relationship_count(), 10);
+
+        let grid = DefaultGraphStore::grid(3, 3);
+        assert_eq!(grid.node_count(), 9);
+        assert_eq!(grid.logical_relationship_count(), 12);
+        // Center node of the grid has four neighbors, corners two.
+        assert_eq!(grid.degree(4), 4);
+        assert_eq!(grid.degree(0), 2);
+
+        let ring = DefaultGraphStore::ring(6);
+        assert_eq!(ring.logical_relationship_count(), 6);
+        assert!((0..6).all(|node| ring.degree(node) == 2));
+
+        let star = DefaultGraphStore::star(5);
+        assert_eq!(star.logical_relationship_count(), 4);
+        assert_eq!(star.degree(0), 4);
+        assert_eq!(star.degree(3), 1);
+    }
+
+    #[test]
+    fn generates_degenerate_rings() {
+        assert_eq!(DefaultGraphStore::ring(1).relationship_count(), 0);
+        assert_eq!(DefaultGraphStore::ring(2).logical_relationship_count(), 1);
+    }
+}
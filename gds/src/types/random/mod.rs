@@ -1,3 +1,5 @@
+pub mod generators;
 pub mod random_graph;
 
+pub use generators::*;
 pub use random_graph::*;
@@ -347,6 +347,17 @@ impl Randomizable<RandomGraphConfig> for DefaultGraphStore {
         let graph_property_values = Arc::new(DefaultDoubleGraphPropertyValues::<crate::collections::backends::vec::VecDouble>::singleton(density));
         store.add_graph_property("edge_density", graph_property_values)?;
 
+        // Per-type densities, keyed by relationship type name.
+        for (rel_type, type_density) in store.compute_type_densities() {
+            let type_density_values = Arc::new(
+                DefaultDoubleGraphPropertyValues::<VecDouble>::singleton(type_density),
+            );
+            store.add_graph_property(
+                format!("edge_density.{}", rel_type.name()),
+                type_density_values,
+            )?;
+        }
+
         Ok(store)
     }
 }
@@ -388,6 +399,44 @@ mod tests {
         assert!(store.node_property_keys().contains("random_score"));
     }
 
+    #[test]
+    fn computes_density_per_relationship_type() {
+        let config = RandomGraphConfig {
+            seed: Some(7),
+            node_count: 40,
+            relationships: vec![
+                RandomRelationshipConfig::new("SPARSE", 0.1),
+                RandomRelationshipConfig::new("DENSE", 0.6),
+            ],
+            ..RandomGraphConfig::default()
+        };
+
+        let store = DefaultGraphStore::random(&config).expect("random graph generation");
+        let densities = store.compute_type_densities();
+        let max_relationships = (config.node_count * (config.node_count - 1)) as f64;
+
+        let sparse = RelationshipType::of("SPARSE");
+        let dense = RelationshipType::of("DENSE");
+        for rel_type in [&sparse, &dense] {
+            let expected = store.relationship_count_for_type(rel_type) as f64 / max_relationships;
+            assert_eq!(densities[rel_type], expected);
+        }
+        assert!(densities[&sparse] < densities[&dense]);
+        assert!((densities[&sparse] - 0.1).abs() < 0.05);
+        assert!((densities[&dense] - 0.6).abs() < 0.05);
+
+        // Stored under the type name next to the aggregate density.
+        for rel_type in [&sparse, &dense] {
+            let key = format!("edge_density.{}", rel_type.name());
+            let stored = store.graph_property_values(&key).unwrap();
+            assert_eq!(
+                stored.double_values().collect::<Vec<_>>(),
+                vec![densities[rel_type]]
+            );
+        }
+        assert!(store.graph_property_keys().contains("edge_density"));
+    }
+
     #[test]
     fn random_node_double_property_values_are_deterministic() {
         let config = RandomNodeDoublePropertyConfig {
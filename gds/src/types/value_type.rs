@@ -1,5 +1,7 @@
+use crate::values::{FromGdsValue, GdsNoValue, GdsValue, PrimitiveValues};
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::sync::Arc;
 
 /// Represents the data types that can be used for properties.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -175,6 +177,177 @@ impl ValueType {
         }
     }
 
+    /// Canonical default value of this type, used for unset entries.
+    ///
+    /// Integral types default to `0` and floating point types to `0.0`, as
+    /// their widened `Long` and `Double` values. `Boolean` defaults to
+    /// `false` and `String` to the empty string. Integral and floating point
+    /// arrays default to an empty `LongArray`, `FloatArray` or
+    /// `DoubleArray`. All other types have no value representation and
+    /// default to [`GdsNoValue`].
+    ///
+    /// This is the value of an entry nobody wrote, not the missing-value
+    /// marker of [`DefaultValue::of`](crate::types::default_value::DefaultValue::of).
+    pub fn default_value(self) -> Arc<dyn GdsValue> {
+        match self {
+            ValueType::Byte | ValueType::Short | ValueType::Int | ValueType::Long => {
+                PrimitiveValues::long_value(0)
+            }
+            ValueType::Float | ValueType::Double => PrimitiveValues::floating_point_value(0.0),
+            ValueType::Boolean => PrimitiveValues::boolean_value(false),
+            ValueType::String => PrimitiveValues::string_value(String::new()),
+            ValueType::ByteArray
+            | ValueType::ShortArray
+            | ValueType::IntArray
+            | ValueType::LongArray => PrimitiveValues::empty_long_array(),
+            ValueType::FloatArray => PrimitiveValues::float_array(Vec::new()),
+            ValueType::DoubleArray => PrimitiveValues::double_array(Vec::new()),
+            _ => Arc::new(GdsNoValue),
+        }
+    }
+
+    /// [`default_value`](Self::default_value) of a scalar type, converted to `T`.
+    ///
+    /// Falls back to `T::default()` if the default does not convert to `T`.
+    pub fn default_as<T: FromGdsValue + Default>(self) -> T {
+        T::from_gds_value(self.default_value().as_ref()).unwrap_or_default()
+    }
+
+    /// Classifies the conversion of values of this type into `target`.
+    ///
+    /// Numeric rules follow Java's primitive conversions: widening (`Int` to
+    /// `Long`, `Long` to `Double`, `Float` to `Double`, ...) is lossless and
+    /// narrowing is lossy. `BigInt` and `Decimal` extend the numeric tower, and
+    /// `Date` widens to `DateTime`. Arrays and maps convert element-wise, and
+    /// every typed array or map widens to its untyped form. Scalars never
+    /// convert to arrays or maps and vice versa.
+    pub fn can_convert_to(self, target: ValueType) -> Conversion {
+        if self == target {
+            return Conversion::Exact;
+        }
+        if self.is_compatible_with(target)
+            && matches!(target, ValueType::UntypedArray | ValueType::UntypedMap)
+        {
+            return Conversion::Widening;
+        }
+
+        match (self.element_type(), target.element_type()) {
+            (None, None) => Self::scalar_conversion(self, target),
+            (Some(source), Some(target_element))
+                if self.is_map() == target.is_map() && self.is_array() == target.is_array() =>
+            {
+                Self::scalar_conversion(source, target_element)
+            }
+            _ => Conversion::Incompatible,
+        }
+    }
+
+    /// Element type of array and map types, `None` for scalars.
+    fn element_type(self) -> Option<ValueType> {
+        match self {
+            ValueType::ByteArray => Some(ValueType::Byte),
+            ValueType::ShortArray => Some(ValueType::Short),
+            ValueType::IntArray => Some(ValueType::Int),
+            ValueType::LongArray | ValueType::LongMap | ValueType::LongMapArray => {
+                Some(ValueType::Long)
+            }
+            ValueType::FloatArray => Some(ValueType::Float),
+            ValueType::DoubleArray | ValueType::DoubleMap | ValueType::DoubleMapArray => {
+                Some(ValueType::Double)
+            }
+            ValueType::BooleanArray | ValueType::BooleanMap | ValueType::BooleanMapArray => {
+                Some(ValueType::Boolean)
+            }
+            ValueType::CharArray => Some(ValueType::Char),
+            ValueType::StringArray | ValueType::StringMap | ValueType::StringMapArray => {
+                Some(ValueType::String)
+            }
+            ValueType::BigIntArray => Some(ValueType::BigInt),
+            ValueType::DecimalArray => Some(ValueType::Decimal),
+            ValueType::DateArray => Some(ValueType::Date),
+            ValueType::DateTimeArray => Some(ValueType::DateTime),
+            ValueType::UntypedArray | ValueType::UntypedMap => Some(ValueType::Unknown),
+            _ => None,
+        }
+    }
+
+    fn is_array(self) -> bool {
+        matches!(
+            self,
+            ValueType::ByteArray
+                | ValueType::ShortArray
+                | ValueType::IntArray
+                | ValueType::LongArray
+                | ValueType::FloatArray
+                | ValueType::DoubleArray
+                | ValueType::BooleanArray
+                | ValueType::CharArray
+                | ValueType::StringArray
+                | ValueType::BigIntArray
+                | ValueType::DecimalArray
+                | ValueType::DateArray
+                | ValueType::DateTimeArray
+                | ValueType::UntypedArray
+                | ValueType::StringMapArray
+                | ValueType::LongMapArray
+                | ValueType::DoubleMapArray
+                | ValueType::BooleanMapArray
+        )
+    }
+
+    fn is_map(self) -> bool {
+        matches!(
+            self,
+            ValueType::StringMap
+                | ValueType::LongMap
+                | ValueType::DoubleMap
+                | ValueType::BooleanMap
+                | ValueType::StringMapArray
+                | ValueType::LongMapArray
+                | ValueType::DoubleMapArray
+                | ValueType::BooleanMapArray
+                | ValueType::UntypedMap
+        )
+    }
+
+    /// Position in the numeric tower; `Char` converts like an unsigned short.
+    fn numeric_rank(self) -> Option<u8> {
+        match self {
+            ValueType::Byte => Some(0),
+            ValueType::Short | ValueType::Char => Some(1),
+            ValueType::Int => Some(2),
+            ValueType::Long => Some(3),
+            ValueType::BigInt => Some(4),
+            ValueType::Float => Some(5),
+            ValueType::Double => Some(6),
+            ValueType::Decimal => Some(7),
+            _ => None,
+        }
+    }
+
+    fn scalar_conversion(source: ValueType, target: ValueType) -> Conversion {
+        if source == target {
+            return Conversion::Exact;
+        }
+        match (source, target) {
+            (ValueType::Date, ValueType::DateTime) => return Conversion::Widening,
+            (ValueType::DateTime, ValueType::Date) => return Conversion::Lossy,
+            // Neither can hold the other's full range.
+            (ValueType::Char, ValueType::Short) | (ValueType::Short, ValueType::Char) => {
+                return Conversion::Lossy
+            }
+            // Big integers exceed the range of binary floating point.
+            (ValueType::BigInt, ValueType::Float | ValueType::Double) => return Conversion::Lossy,
+            (_, ValueType::Char) if source.numeric_rank().is_some() => return Conversion::Lossy,
+            _ => {}
+        }
+        match (source.numeric_rank(), target.numeric_rank()) {
+            (Some(from), Some(to)) if from < to => Conversion::Widening,
+            (Some(_), Some(_)) => Conversion::Lossy,
+            _ => Conversion::Incompatible,
+        }
+    }
+
     pub fn is_compatible_with(self, other: ValueType) -> bool {
         if self == other {
             return true;
@@ -207,6 +380,31 @@ impl ValueType {
     }
 }
 
+/// Result of converting values of one [`ValueType`] into another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Conversion {
+    /// Source and target are the same type.
+    Exact,
+    /// Every source value is representable in the target type (Java widening).
+    Widening,
+    /// The conversion is possible but may lose range or precision.
+    Lossy,
+    /// No meaningful conversion exists.
+    Incompatible,
+}
+
+impl Conversion {
+    /// Returns true for conversions that never change a value.
+    pub fn is_lossless(self) -> bool {
+        matches!(self, Conversion::Exact | Conversion::Widening)
+    }
+
+    /// Returns true unless the types are incompatible.
+    pub fn is_possible(self) -> bool {
+        self != Conversion::Incompatible
+    }
+}
+
 /// Visitor trait for ValueType. Optional visitor methods return Option<R>.
 pub trait Visitor<R> {
     fn visit_byte(&self) -> R;
@@ -249,3 +447,171 @@ impl fmt::Display for ValueType {
         f.write_str(self.name())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_conversions() {
+        assert_eq!(
+            ValueType::Long.can_convert_to(ValueType::Long),
+            Conversion::Exact
+        );
+        assert_eq!(
+            ValueType::Long.can_convert_to(ValueType::Double),
+            Conversion::Widening
+        );
+        assert_eq!(
+            ValueType::Int.can_convert_to(ValueType::Long),
+            Conversion::Widening
+        );
+        assert_eq!(
+            ValueType::Float.can_convert_to(ValueType::Double),
+            Conversion::Widening
+        );
+        assert_eq!(
+            ValueType::Double.can_convert_to(ValueType::Long),
+            Conversion::Lossy
+        );
+        assert_eq!(
+            ValueType::Double.can_convert_to(ValueType::Float),
+            Conversion::Lossy
+        );
+        assert_eq!(
+            ValueType::Long.can_convert_to(ValueType::Int),
+            Conversion::Lossy
+        );
+        assert_eq!(
+            ValueType::Int.can_convert_to(ValueType::Char),
+            Conversion::Lossy
+        );
+        assert_eq!(
+            ValueType::BigInt.can_convert_to(ValueType::Double),
+            Conversion::Lossy
+        );
+    }
+
+    #[test]
+    fn test_array_and_map_conversions() {
+        assert_eq!(
+            ValueType::Long.can_convert_to(ValueType::LongArray),
+            Conversion::Incompatible
+        );
+        assert_eq!(
+            ValueType::LongArray.can_convert_to(ValueType::Long),
+            Conversion::Incompatible
+        );
+        assert_eq!(
+            ValueType::LongArray.can_convert_to(ValueType::DoubleArray),
+            Conversion::Widening
+        );
+        assert_eq!(
+            ValueType::DoubleArray.can_convert_to(ValueType::FloatArray),
+            Conversion::Lossy
+        );
+        assert_eq!(
+            ValueType::FloatArray.can_convert_to(ValueType::UntypedArray),
+            Conversion::Widening
+        );
+        assert_eq!(
+            ValueType::LongMap.can_convert_to(ValueType::DoubleMap),
+            Conversion::Widening
+        );
+        assert_eq!(
+            ValueType::LongMap.can_convert_to(ValueType::LongMapArray),
+            Conversion::Incompatible
+        );
+        assert_eq!(
+            ValueType::LongArray.can_convert_to(ValueType::LongMap),
+            Conversion::Incompatible
+        );
+    }
+
+    #[test]
+    fn test_other_conversions() {
+        assert_eq!(
+            ValueType::Date.can_convert_to(ValueType::DateTime),
+            Conversion::Widening
+        );
+        assert_eq!(
+            ValueType::DateTime.can_convert_to(ValueType::Date),
+            Conversion::Lossy
+        );
+        assert_eq!(
+            ValueType::String.can_convert_to(ValueType::Long),
+            Conversion::Incompatible
+        );
+        assert_eq!(
+            ValueType::Boolean.can_convert_to(ValueType::Int),
+            Conversion::Incompatible
+        );
+        assert!(ValueType::Int
+            .can_convert_to(ValueType::Double)
+            .is_lossless());
+        assert!(!ValueType::Double
+            .can_convert_to(ValueType::Int)
+            .is_lossless());
+        assert!(!ValueType::Unknown
+            .can_convert_to(ValueType::Long)
+            .is_possible());
+    }
+
+    #[test]
+    fn test_default_values() {
+        use crate::values::{Array, DefaultDoubleArray, DefaultFloatArray, DefaultLongArray};
+
+        for value_type in [ValueType::Byte, ValueType::Int, ValueType::Long] {
+            let default = value_type.default_value();
+            assert_eq!(default.value_type(), ValueType::Long);
+            assert_eq!(i64::from_gds_value(default.as_ref()), Ok(0));
+            assert_eq!(value_type.default_as::<i64>(), 0);
+        }
+        for value_type in [ValueType::Float, ValueType::Double] {
+            let default = value_type.default_value();
+            assert_eq!(default.value_type(), ValueType::Double);
+            assert_eq!(f64::from_gds_value(default.as_ref()), Ok(0.0));
+            assert_eq!(value_type.default_as::<f64>(), 0.0);
+        }
+        assert_eq!(
+            bool::from_gds_value(ValueType::Boolean.default_value().as_ref()),
+            Ok(false)
+        );
+        assert_eq!(
+            String::from_gds_value(ValueType::String.default_value().as_ref()),
+            Ok(String::new())
+        );
+
+        for value_type in [ValueType::IntArray, ValueType::LongArray] {
+            let default = value_type.default_value();
+            let array = default.as_any().downcast_ref::<DefaultLongArray>().unwrap();
+            assert_eq!(array.length(), 0);
+        }
+        let default = ValueType::FloatArray.default_value();
+        assert_eq!(
+            default
+                .as_any()
+                .downcast_ref::<DefaultFloatArray>()
+                .unwrap()
+                .length(),
+            0
+        );
+        let default = ValueType::DoubleArray.default_value();
+        assert_eq!(
+            default
+                .as_any()
+                .downcast_ref::<DefaultDoubleArray>()
+                .unwrap()
+                .length(),
+            0
+        );
+
+        for value_type in [ValueType::Date, ValueType::StringMap, ValueType::Unknown] {
+            assert!(value_type
+                .default_value()
+                .as_any()
+                .downcast_ref::<GdsNoValue>()
+                .is_some());
+        }
+    }
+}